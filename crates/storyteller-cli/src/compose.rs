@@ -90,7 +90,7 @@ pub async fn run(args: ComposeArgs) -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Connect to server and compose
-    let config = ClientConfig::from_env();
+    let config = ClientConfig::load();
     let mut client = StorytellerClient::connect(config).await?;
 
     let request = storyteller_client::ComposeSceneRequest {
@@ -102,6 +102,7 @@ pub async fn run(args: ComposeArgs) -> Result<(), Box<dyn std::error::Error>> {
         title_override: None,
         setting_override: None,
         player_character: None,
+        setting_id: None,
     };
 
     let mut stream = client.compose_scene(request).await?;
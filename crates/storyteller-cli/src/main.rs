@@ -9,6 +9,7 @@
 //!  - `compose` — compose a scene and capture its JSON
 //!  - `composer sync` — sync descriptor cache from the server
 //!  - `composer list <category>` — list cached descriptors
+//!  - `config` — show the configuration the server is running with
 
 use clap::Parser;
 
@@ -35,6 +36,9 @@ enum Commands {
     /// Manage the local composer descriptor cache
     #[command(subcommand)]
     Composer(ComposerCommands),
+
+    /// Show the server's effective configuration (secrets redacted)
+    Config,
 }
 
 #[derive(clap::Subcommand)]
@@ -93,7 +97,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Compose(args) => compose::run(args).await?,
         Commands::Composer(cmd) => match cmd {
             ComposerCommands::Sync => {
-                let config = storyteller_client::ClientConfig::from_env();
+                let config = storyteller_client::ClientConfig::load();
                 let mut client = storyteller_client::StorytellerClient::connect(config).await?;
                 let cache = composer_cache::ComposerCache::new(
                     composer_cache::ComposerCache::default_path(),
@@ -121,6 +125,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
         },
+        Commands::Config => {
+            let config = storyteller_client::ClientConfig::load();
+            let mut client = storyteller_client::StorytellerClient::connect(config).await?;
+            let effective = client.get_effective_config().await?;
+            println!("# environment: {}", effective.environment);
+            println!("{}", effective.raw_json);
+        }
     }
 
     Ok(())
@@ -11,6 +11,7 @@
 use crate::player_simulation::PlayerSimulation;
 use std::time::Instant;
 use storyteller_client::{engine_event, ClientConfig, StorytellerClient};
+use storyteller_core::config::ConfigLoader;
 use storyteller_core::types::health::HealthStatus;
 
 #[derive(clap::Args)]
@@ -23,7 +24,7 @@ pub struct PlaytestArgs {
     #[arg(long, default_value = "5")]
     turns: u32,
 
-    /// Player simulation model name (falls back to STORYTELLER_PLAYER_MODEL env var)
+    /// Player simulation model name (falls back to `provider.player_model`)
     #[arg(long)]
     player_model: Option<String>,
 }
@@ -31,8 +32,16 @@ pub struct PlaytestArgs {
 pub async fn run(args: PlaytestArgs) -> Result<(), Box<dyn std::error::Error>> {
     let start = Instant::now();
 
+    let stored_config = ConfigLoader::load()
+        .inspect_err(
+            |e| tracing::warn!(error = %e, "storyteller config unavailable, using environment"),
+        )
+        .ok();
+
     // Connect and health check
-    let config = ClientConfig::from_env();
+    let config = stored_config
+        .as_ref()
+        .map_or_else(ClientConfig::from_env, ClientConfig::from_config);
     let mut client = StorytellerClient::connect(config).await?;
 
     let health = client.check_health().await?;
@@ -86,12 +95,18 @@ pub async fn run(args: PlaytestArgs) -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Set up player simulation
-    let ollama_url =
-        std::env::var("OLLAMA_URL").unwrap_or_else(|_| "http://localhost:11434".to_string());
-    let player_model = args
-        .player_model
-        .or_else(|| std::env::var("STORYTELLER_PLAYER_MODEL").ok())
-        .unwrap_or_else(|| "qwen2.5:7b-instruct".to_string());
+    let (ollama_url, configured_player_model) = match &stored_config {
+        Some(config) => {
+            let provider = config.provider();
+            (provider.ollama_url, provider.player_model)
+        }
+        None => (
+            std::env::var("OLLAMA_URL").unwrap_or_else(|_| "http://localhost:11434".to_string()),
+            std::env::var("STORYTELLER_PLAYER_MODEL")
+                .unwrap_or_else(|_| "qwen2.5:7b-instruct".to_string()),
+        ),
+    };
+    let player_model = args.player_model.unwrap_or(configured_player_model);
     let protagonist_context =
         PlayerSimulation::build_protagonist_context(&protagonist_name, &composition_json);
     let player_sim = PlayerSimulation::new(&ollama_url, &player_model, &protagonist_context);
@@ -210,5 +225,6 @@ fn build_compose_request(
             .and_then(|v| v.as_str())
             .map(|s| s.to_string()),
         player_character: None,
+        setting_id: None,
     })
 }
@@ -4,6 +4,7 @@
 
 //! [`StorytellerClient`] — typed wrapper around generated tonic stubs.
 
+use storyteller_core::config::{ConfigLoader, StorytellerConfig};
 use storyteller_core::types::health::ServerHealth;
 use thiserror::Error;
 
@@ -22,6 +23,27 @@ impl Default for ClientConfig {
 }
 
 impl ClientConfig {
+    /// Resolve the endpoint from the layered [`StorytellerConfig`]
+    /// (`server.url`).
+    ///
+    /// Falls back to [`from_env`](Self::from_env) with a warning when the
+    /// config cannot be loaded, e.g. when run outside the workspace.
+    pub fn load() -> Self {
+        match ConfigLoader::load() {
+            Ok(config) => Self::from_config(&config),
+            Err(e) => {
+                tracing::warn!(error = %e, "storyteller config unavailable, using environment");
+                Self::from_env()
+            }
+        }
+    }
+
+    pub fn from_config(config: &StorytellerConfig) -> Self {
+        Self {
+            endpoint: config.server().url,
+        }
+    }
+
     pub fn from_env() -> Self {
         Self {
             endpoint: std::env::var("STORYTELLER_SERVER_URL")
@@ -127,6 +149,27 @@ impl StorytellerClient {
         Ok(response.into_inner())
     }
 
+    pub async fn enter_layer(
+        &mut self,
+        request: crate::proto::EnterLayerRequest,
+    ) -> Result<crate::proto::LayerState, ClientError> {
+        let response = self.engine.enter_layer(request).await?;
+        Ok(response.into_inner())
+    }
+
+    pub async fn exit_layer(
+        &mut self,
+        session_id: &str,
+    ) -> Result<crate::proto::LayerState, ClientError> {
+        let response = self
+            .engine
+            .exit_layer(crate::proto::ExitLayerRequest {
+                session_id: session_id.to_string(),
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
+
     pub async fn list_sessions(&mut self) -> Result<crate::proto::SessionList, ClientError> {
         let response = self.engine.list_sessions(()).await?;
         Ok(response.into_inner())
@@ -157,18 +200,32 @@ impl StorytellerClient {
         Ok(response.into_inner())
     }
 
+    /// The configuration the server is running with, secrets redacted.
+    pub async fn get_effective_config(
+        &mut self,
+    ) -> Result<crate::proto::EffectiveConfig, ClientError> {
+        let response = self.engine.get_effective_config(()).await?;
+        Ok(response.into_inner())
+    }
+
     /// Subscribe to a server-streaming log feed.
     ///
     /// Returns a `tonic::Streaming<LogEntry>` that yields log entries matching
-    /// the optional `level` and `target` filters.
+    /// the optional `level`, `target`, and `session_id` filters. Without a
+    /// session the server requires an admin credential.
     pub async fn stream_logs(
         &mut self,
         level: Option<String>,
         target: Option<String>,
+        session_id: Option<String>,
     ) -> Result<tonic::Streaming<crate::proto::LogEntry>, ClientError> {
         let response = self
             .engine
-            .stream_logs(crate::proto::LogFilter { level, target })
+            .stream_logs(crate::proto::LogFilter {
+                level,
+                target,
+                session_id,
+            })
             .await?;
         Ok(response.into_inner())
     }
@@ -286,6 +343,18 @@ mod tests {
         assert_eq!(config.endpoint, "http://localhost:50051");
     }
 
+    #[test]
+    fn config_from_storyteller_config_uses_server_url() {
+        let stored: StorytellerConfig = toml::from_str(
+            "[database]\nurl = \"postgres://localhost/db\"\n[server]\nurl = \"http://engine:6000\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            ClientConfig::from_config(&stored).endpoint,
+            "http://engine:6000"
+        );
+    }
+
     #[test]
     fn client_error_display() {
         let err = ClientError::ConnectionFailed("http://localhost:50051".to_string());
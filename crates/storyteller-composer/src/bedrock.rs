@@ -0,0 +1,672 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Descriptor hydration from the bedrock narrative data layer.
+//!
+//! See: `docs/superpowers/specs/2026-03-25-bedrock-query-trait-design.md`
+//!
+//! Bedrock holds what writers edit — archetypes, dynamics, scene profiles,
+//! settings, and goals per genre — in the narrative-data schema, which is
+//! richer and differently shaped than the descriptor JSON files. This module
+//! translates one into the other: personality profiles become tensor axis
+//! ranges, dynamic valence becomes substrate ranges, profile dimensional
+//! properties become scene type, tension, and cast size.
+//!
+//! Bedrock has no axis vocabulary, cross-dimensions, or name pools; those
+//! come from the JSON fallback when one is supplied. When bedrock cannot be
+//! reached or holds no genres, the fallback is used whole.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+
+use storyteller_core::game_design::WorldAffordances;
+use storyteller_core::traits::bedrock::BedrockQuery;
+use storyteller_core::types::bedrock::{
+    ArchetypeRecord, DynamicRecord, GenreContext, GoalRecord, ProfileRecord, SettingRecord,
+};
+use storyteller_core::{StorytellerError, StorytellerResult};
+
+use crate::descriptors::{
+    ActionTendencies, Archetype, ArchetypeAxis, DescriptorSet, Dynamic, EmotionalPrimary,
+    EmotionalProfile, Genre, Goal, Profile, RangeBounds, RelationalEdge, SelfEdge,
+    SettingCollection, SettingDescriptor,
+};
+
+/// The seven core personality axes of a bedrock archetype.
+const PERSONALITY_AXES: [&str; 7] = [
+    "warmth",
+    "authority",
+    "openness",
+    "interiority",
+    "stability",
+    "agency",
+    "morality",
+];
+
+impl DescriptorSet {
+    /// Load descriptors from a bedrock implementation.
+    ///
+    /// Every genre bedrock knows is assembled from its `genre_context`.
+    /// `fallback` supplies the axis vocabulary, cross-dimensions, and name
+    /// pools, and is returned whole if bedrock holds no genres.
+    ///
+    /// # Errors
+    ///
+    /// Propagates bedrock query failures. Returns `StorytellerError::Config`
+    /// if bedrock holds no genres and there is no fallback.
+    pub async fn from_bedrock(
+        bedrock: &dyn BedrockQuery,
+        fallback: Option<DescriptorSet>,
+    ) -> StorytellerResult<Self> {
+        let mut contexts = Vec::new();
+        for genre in bedrock.genres().await? {
+            contexts.push(bedrock.genre_context(&genre.slug).await?);
+        }
+        Self::from_genre_contexts(&contexts, fallback)
+    }
+
+    /// Assemble descriptors from already-fetched genre contexts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorytellerError::Config` if `contexts` is empty and there is
+    /// no fallback.
+    pub fn from_genre_contexts(
+        contexts: &[GenreContext],
+        fallback: Option<DescriptorSet>,
+    ) -> StorytellerResult<Self> {
+        if contexts.is_empty() {
+            return fallback.ok_or_else(|| {
+                StorytellerError::Config(
+                    "bedrock holds no genres and no descriptor fallback was given".to_string(),
+                )
+            });
+        }
+
+        let mut set = match fallback {
+            Some(fallback) => DescriptorSet {
+                archetypes: Vec::new(),
+                genres: Vec::new(),
+                profiles: Vec::new(),
+                dynamics: Vec::new(),
+                axes: fallback.axes,
+                dimensions: fallback.dimensions,
+                names: fallback.names,
+                settings: HashMap::new(),
+                goals: Vec::new(),
+            },
+            None => DescriptorSet {
+                archetypes: Vec::new(),
+                genres: Vec::new(),
+                profiles: Vec::new(),
+                dynamics: Vec::new(),
+                axes: Vec::new(),
+                dimensions: Vec::new(),
+                names: HashMap::new(),
+                settings: HashMap::new(),
+                goals: Vec::new(),
+            },
+        };
+
+        let mut goal_ids = HashSet::new();
+        for context in contexts {
+            let slug = context.genre.slug.clone();
+
+            let goals: Vec<Goal> = context.goals.iter().map(goal_from_record).collect();
+            let scene_goals: Vec<String> = context
+                .goals
+                .iter()
+                .filter(|g| g.goal_scale.as_deref() == Some("scene"))
+                .map(|g| g.entity_slug.clone())
+                .collect();
+
+            let archetypes: Vec<Archetype> = context
+                .archetypes
+                .iter()
+                .map(|a| archetype_from_record(a, &context.goals))
+                .collect();
+            let dynamics: Vec<Dynamic> = context.dynamics.iter().map(dynamic_from_record).collect();
+            let profiles: Vec<Profile> = context
+                .profiles
+                .iter()
+                .map(|p| profile_from_record(p, &scene_goals))
+                .collect();
+
+            // Valid lists use record UUIDs, so slugs shared across genres
+            // still resolve to each genre's own variant.
+            set.genres.push(Genre {
+                id: slug.clone(),
+                entity_id: context.genre.id.to_string(),
+                display_name: context.genre.name.clone(),
+                description: context.genre.description.clone().unwrap_or_default(),
+                valid_archetypes: archetypes.iter().map(|a| a.entity_id.clone()).collect(),
+                valid_dynamics: dynamics.iter().map(|d| d.entity_id.clone()).collect(),
+                valid_profiles: profiles.iter().map(|p| p.entity_id.clone()).collect(),
+                excluded_combinations: Vec::new(),
+                world_affordances: WorldAffordances::from_genre_context(context),
+                resolution_tuning: None,
+            });
+            if let Some(settings) = settings_from_records(&context.settings) {
+                set.settings.insert(slug, settings);
+            }
+            set.archetypes.extend(archetypes);
+            set.dynamics.extend(dynamics);
+            set.profiles.extend(profiles);
+            set.goals
+                .extend(goals.into_iter().filter(|g| goal_ids.insert(g.id.clone())));
+        }
+        Ok(set)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Record translation
+// ---------------------------------------------------------------------------
+
+fn text<'a>(payload: &'a Value, key: &str) -> Option<&'a str> {
+    payload.get(key).and_then(Value::as_str)
+}
+
+fn strings(payload: &Value, key: &str) -> Vec<String> {
+    payload
+        .get(key)
+        .and_then(Value::as_array)
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A band of `±half` around `center`, clamped to `[low, high]`.
+fn band(center: f64, half: f64, low: f64, high: f64) -> RangeBounds {
+    RangeBounds {
+        min: (center - half).clamp(low, high),
+        max: (center + half).clamp(low, high),
+    }
+}
+
+fn range(min: f64, max: f64) -> RangeBounds {
+    RangeBounds { min, max }
+}
+
+/// Translate a bedrock archetype.
+///
+/// Personality values are normalized to `[0, 1]`; tensor axes run
+/// `[-1, 1]`, so each is rescaled before banding. Goals whose
+/// `archetype_refs` name this archetype become its pursuable goals.
+fn archetype_from_record(record: &ArchetypeRecord, goals: &[GoalRecord]) -> Archetype {
+    let payload = &record.payload;
+    let profile = payload.get("personality_profile");
+    let trait_value = |axis: &str| {
+        profile
+            .and_then(|p| p.get(axis))
+            .and_then(Value::as_f64)
+            .unwrap_or(0.5)
+    };
+
+    let mut axis_values: Vec<(String, f64)> = PERSONALITY_AXES
+        .iter()
+        .map(|axis| (axis.to_string(), trait_value(axis)))
+        .collect();
+    if let Some(extended) = payload.get("extended_axes").and_then(Value::as_object) {
+        let mut extended: Vec<(String, f64)> = extended
+            .iter()
+            .filter_map(|(axis, v)| Some((axis.clone(), v.as_f64()?)))
+            .collect();
+        extended.sort_by(|a, b| a.0.cmp(&b.0));
+        axis_values.extend(extended);
+    }
+    let axes = axis_values
+        .into_iter()
+        .map(|(axis_id, value)| {
+            let center = value * 2.0 - 1.0;
+            ArchetypeAxis {
+                axis_id,
+                central_tendency: band(center, 0.05, -1.0, 1.0),
+                variance: range(0.1, 0.15),
+                range_low: band(center - 0.3, 0.05, -1.0, 1.0),
+                range_high: band(center + 0.3, 0.05, -1.0, 1.0),
+                layer: "sediment".to_string(),
+                provenance: "bedrock".to_string(),
+            }
+        })
+        .collect();
+
+    let canonical = text(payload, "canonical_name").unwrap_or(&record.name);
+    let pursuable_goals = goals
+        .iter()
+        .filter(|g| {
+            strings(&g.payload, "archetype_refs")
+                .iter()
+                .any(|r| r == canonical)
+        })
+        .map(|g| g.entity_slug.clone())
+        .collect();
+
+    let unit = |value: f64| band(value, 0.1, 0.0, 1.0);
+    Archetype {
+        id: record.entity_slug.clone(),
+        entity_id: record.id.to_string(),
+        display_name: record.name.clone(),
+        description: text(payload, "distinguishing_tension")
+            .or_else(|| text(payload, "flavor_text"))
+            .unwrap_or_default()
+            .to_string(),
+        axes,
+        default_emotional_profile: EmotionalProfile {
+            grammar_id: "plutchik_western".to_string(),
+            primaries: vec![
+                EmotionalPrimary {
+                    primary_id: "trust".to_string(),
+                    intensity: unit(trait_value("warmth")),
+                    awareness: "recognizable".to_string(),
+                },
+                EmotionalPrimary {
+                    primary_id: "anticipation".to_string(),
+                    intensity: unit(trait_value("agency")),
+                    awareness: "recognizable".to_string(),
+                },
+            ],
+        },
+        default_self_edge: SelfEdge {
+            trust_competence: unit(trait_value("agency")),
+            trust_intentions: unit(trait_value("morality")),
+            trust_reliability: unit(trait_value("stability")),
+            affection: unit(trait_value("warmth")),
+            debt: range(0.0, 0.2),
+            history_weight: range(0.4, 0.6),
+            projection_accuracy: unit(trait_value("interiority")),
+        },
+        action_tendencies: ActionTendencies {
+            primary_action_types: Vec::new(),
+            primary_action_contexts: Vec::new(),
+            speech_likelihood: 0.5,
+            speech_registers: Vec::new(),
+            default_awareness: "recognizable".to_string(),
+        },
+        pursuable_goals,
+    }
+}
+
+/// Substrate ranges for a dynamic's valence.
+fn edge_for_valence(valence: Option<&str>) -> RelationalEdge {
+    let (trust, affection, debt) = match valence {
+        Some("nurturing") => (range(0.5, 0.8), range(0.5, 0.8), range(0.1, 0.3)),
+        Some("protective") => (range(0.4, 0.7), range(0.4, 0.7), range(0.2, 0.4)),
+        Some("sacred") => (range(0.6, 0.9), range(0.4, 0.7), range(0.3, 0.5)),
+        Some("hostile") => (range(-0.7, -0.3), range(-0.8, -0.4), range(0.0, 0.2)),
+        Some("parasitic") => (range(-0.2, 0.2), range(0.0, 0.3), range(0.4, 0.7)),
+        Some("transactional") => (range(0.0, 0.4), range(-0.1, 0.2), range(0.3, 0.6)),
+        _ => (range(-0.1, 0.3), range(-0.1, 0.1), range(0.0, 0.1)),
+    };
+    RelationalEdge {
+        trust_reliability: trust.clone(),
+        trust_competence: trust.clone(),
+        trust_benevolence: trust,
+        affection,
+        debt,
+    }
+}
+
+/// Translate a bedrock dynamic.
+///
+/// Roles come from the first two role slots; both directions share the
+/// valence's substrate ranges. A hub or bridge network position makes
+/// role A the hub or bridge.
+fn dynamic_from_record(record: &DynamicRecord) -> Dynamic {
+    let payload = &record.payload;
+    let roles: Vec<String> = payload
+        .get("role_slots")
+        .and_then(Value::as_array)
+        .map(|slots| {
+            slots
+                .iter()
+                .filter_map(|s| text(s, "role").map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let edge = edge_for_valence(text(payload, "valence"));
+    let topology_a = match text(payload, "network_position") {
+        Some("hub") => "Hub",
+        Some("bridge" | "brokerage") => "Bridge",
+        _ => "Periphery",
+    };
+
+    Dynamic {
+        id: record.entity_slug.clone(),
+        entity_id: record.id.to_string(),
+        display_name: record.name.clone(),
+        description: text(payload, "flavor_text")
+            .or(record.edge_type.as_deref())
+            .unwrap_or_default()
+            .to_string(),
+        role_a: roles.first().cloned().unwrap_or_else(|| "a".to_string()),
+        role_b: roles.get(1).cloned().unwrap_or_else(|| "b".to_string()),
+        edge_a_to_b: edge.clone(),
+        edge_b_to_a: edge,
+        topology_a: topology_a.to_string(),
+        topology_b: "Periphery".to_string(),
+        enabled_goals: Vec::new(),
+        blocked_goals: Vec::new(),
+    }
+}
+
+/// Translate a bedrock scene profile from its dimensional properties.
+fn profile_from_record(record: &ProfileRecord, scene_goals: &[String]) -> Profile {
+    let payload = &record.payload;
+    let properties = payload
+        .get("dimensional_properties")
+        .unwrap_or(&Value::Null);
+    let (scene_type, tension) = match text(properties, "tension_signature") {
+        Some("spiking_explosive") => ("gravitational", range(0.6, 0.9)),
+        Some("oscillating") => ("threshold", range(0.4, 0.7)),
+        Some("ambient_sustained") => ("connective", range(0.3, 0.5)),
+        _ => ("connective", range(0.3, 0.6)),
+    };
+    let cast_size = match text(properties, "cast_density") {
+        Some("solitary") => range(1.0, 1.0),
+        Some("intimate_few") => range(2.0, 3.0),
+        Some("ensemble_crowded") => range(4.0, 6.0),
+        _ => range(2.0, 4.0),
+    };
+
+    Profile {
+        id: record.entity_slug.clone(),
+        entity_id: record.id.to_string(),
+        display_name: record.name.clone(),
+        description: text(payload, "core_identity")
+            .unwrap_or_default()
+            .to_string(),
+        scene_type: scene_type.to_string(),
+        tension,
+        cast_size,
+        characteristic_events: Vec::new(),
+        scene_goals: scene_goals.to_vec(),
+    }
+}
+
+/// Translate a bedrock goal. Its scale stands in for a category.
+fn goal_from_record(record: &GoalRecord) -> Goal {
+    Goal {
+        id: record.entity_slug.clone(),
+        entity_id: record.id.to_string(),
+        description: text(&record.payload, "description")
+            .unwrap_or(&record.name)
+            .to_string(),
+        category: record
+            .goal_scale
+            .clone()
+            .unwrap_or_else(|| "scene".to_string()),
+        visibility: "Signaled".to_string(),
+        valence: "neutral".to_string(),
+        lexicon: Vec::new(),
+    }
+}
+
+/// A genre's setting collection: the first bedrock setting is the default.
+fn settings_from_records(records: &[SettingRecord]) -> Option<SettingCollection> {
+    let record = records.first()?;
+    let payload = &record.payload;
+    let description = text(payload, "narrative_function")
+        .or_else(|| {
+            payload
+                .get("communicability")
+                .and_then(|c| text(c, "atmospheric"))
+        })
+        .map(str::to_string);
+    let mut extra = HashMap::new();
+    extra.insert(
+        "sensory_palette".to_string(),
+        Value::from(strings(payload, "sensory_vocabulary")),
+    );
+
+    Some(SettingCollection {
+        default_setting: Some(SettingDescriptor {
+            name: Some(record.name.clone()),
+            description,
+            extra,
+        }),
+        profile_settings: HashMap::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use storyteller_core::types::bedrock::GenreRecord;
+    use uuid::Uuid;
+
+    fn genre_context() -> GenreContext {
+        let genre_id = Uuid::now_v7();
+        let now = Utc::now();
+        GenreContext {
+            genre: GenreRecord {
+                id: genre_id,
+                slug: "folk-horror".to_string(),
+                name: "Folk Horror".to_string(),
+                description: Some("The land remembers".to_string()),
+                payload: serde_json::json!({
+                    "world_affordances": { "magic": "subtle", "technology": "historical" }
+                }),
+                source_hash: String::new(),
+                created_at: now,
+                updated_at: now,
+            },
+            archetypes: vec![ArchetypeRecord {
+                id: Uuid::now_v7(),
+                genre_id,
+                cluster_id: None,
+                entity_slug: "the-keeper".to_string(),
+                name: "The Keeper".to_string(),
+                payload: serde_json::json!({
+                    "canonical_name": "Keeper",
+                    "personality_profile": {
+                        "warmth": 0.2, "authority": 0.9, "openness": 0.1,
+                        "interiority": 0.7, "stability": 0.8, "agency": 0.6, "morality": 0.4
+                    },
+                    "extended_axes": { "piety": 1.0 },
+                    "distinguishing_tension": "Guards the rite that harms the village"
+                }),
+                source_hash: String::new(),
+                created_at: now,
+                updated_at: now,
+                archetype_family: None,
+                primary_scale: None,
+            }],
+            dynamics: vec![DynamicRecord {
+                id: Uuid::now_v7(),
+                genre_id,
+                cluster_id: None,
+                entity_slug: "bound-by-debt".to_string(),
+                name: "Bound by Debt".to_string(),
+                payload: serde_json::json!({
+                    "valence": "hostile",
+                    "network_position": "hub",
+                    "role_slots": [{ "role": "creditor" }, { "role": "debtor" }]
+                }),
+                source_hash: String::new(),
+                created_at: now,
+                updated_at: now,
+                edge_type: Some("obligation".to_string()),
+                scale: Some("scene".to_string()),
+            }],
+            settings: vec![SettingRecord {
+                id: Uuid::now_v7(),
+                genre_id,
+                cluster_id: None,
+                entity_slug: "the-green".to_string(),
+                name: "The Village Green".to_string(),
+                payload: serde_json::json!({
+                    "narrative_function": "Where the village gathers and watches",
+                    "sensory_vocabulary": ["woodsmoke", "wet grass"]
+                }),
+                source_hash: String::new(),
+                created_at: now,
+                updated_at: now,
+                setting_type: None,
+            }],
+            goals: vec![GoalRecord {
+                id: Uuid::now_v7(),
+                genre_id,
+                cluster_id: None,
+                entity_slug: "preserve-the-rite".to_string(),
+                name: "Preserve the Rite".to_string(),
+                payload: serde_json::json!({ "archetype_refs": ["Keeper"] }),
+                source_hash: String::new(),
+                created_at: now,
+                updated_at: now,
+                goal_scale: Some("scene".to_string()),
+            }],
+            profiles: vec![ProfileRecord {
+                id: Uuid::now_v7(),
+                genre_id,
+                cluster_id: None,
+                entity_slug: "the-reckoning".to_string(),
+                name: "The Reckoning".to_string(),
+                payload: serde_json::json!({
+                    "core_identity": "The village turns on the outsider",
+                    "dimensional_properties": {
+                        "tension_signature": "spiking_explosive",
+                        "cast_density": "intimate_few"
+                    }
+                }),
+                source_hash: String::new(),
+                created_at: now,
+                updated_at: now,
+            }],
+            tropes: vec![],
+            narrative_shapes: vec![],
+            ontological_posture: vec![],
+            spatial_topology: vec![],
+            place_entities: vec![],
+            archetype_dynamics: vec![],
+            dimensions: None,
+        }
+    }
+
+    fn empty_set() -> DescriptorSet {
+        DescriptorSet {
+            archetypes: vec![],
+            genres: vec![],
+            profiles: vec![],
+            dynamics: vec![],
+            axes: vec![],
+            dimensions: vec![],
+            names: HashMap::new(),
+            settings: HashMap::new(),
+            goals: vec![],
+        }
+    }
+
+    #[test]
+    fn genre_context_becomes_descriptors() {
+        let context = genre_context();
+        let set = DescriptorSet::from_genre_contexts(std::slice::from_ref(&context), None).unwrap();
+
+        let genre = &set.genres[0];
+        assert_eq!(genre.id, "folk-horror");
+        assert_eq!(
+            genre.valid_archetypes,
+            vec![context.archetypes[0].id.to_string()]
+        );
+        assert_eq!(genre.world_affordances.as_ref().unwrap().magic, "subtle");
+
+        let keeper = &set.archetypes[0];
+        assert_eq!(keeper.axes.len(), 8);
+        let authority = &keeper.axes[1];
+        assert_eq!(authority.axis_id, "authority");
+        // 0.9 on the [0, 1] profile is 0.8 on the tensor's [-1, 1] scale.
+        let center = (authority.central_tendency.min + authority.central_tendency.max) / 2.0;
+        assert!((center - 0.8).abs() < 1e-9);
+        assert_eq!(keeper.axes[7].axis_id, "piety");
+        assert_eq!(keeper.pursuable_goals, vec!["preserve-the-rite"]);
+
+        let dynamic = &set.dynamics[0];
+        assert_eq!(
+            (dynamic.role_a.as_str(), dynamic.role_b.as_str()),
+            ("creditor", "debtor")
+        );
+        assert!(dynamic.edge_a_to_b.affection.max < 0.0);
+        assert_eq!(dynamic.topology_a, "Hub");
+
+        let profile = &set.profiles[0];
+        assert_eq!(profile.scene_type, "gravitational");
+        assert!((profile.cast_size.max - 3.0).abs() < f64::EPSILON);
+        assert_eq!(profile.scene_goals, vec!["preserve-the-rite"]);
+
+        let setting = set.settings["folk-horror"]
+            .default_setting
+            .as_ref()
+            .unwrap();
+        assert_eq!(setting.name.as_deref(), Some("The Village Green"));
+        assert_eq!(set.goals[0].category, "scene");
+    }
+
+    #[test]
+    fn bedrock_composer_composes_a_scene() {
+        let context = genre_context();
+        let set = DescriptorSet::from_genre_contexts(std::slice::from_ref(&context), None).unwrap();
+        let composer = crate::SceneComposer::from_descriptors(set);
+
+        let archetype = context.archetypes[0].id.to_string();
+        let selections = crate::SceneSelections {
+            genre_id: "folk-horror".to_string(),
+            profile_id: "the-reckoning".to_string(),
+            cast: vec![
+                crate::CastSelection {
+                    archetype_id: archetype.clone(),
+                    name: None,
+                    role: "protagonist".to_string(),
+                },
+                crate::CastSelection {
+                    archetype_id: archetype,
+                    name: None,
+                    role: "antagonist".to_string(),
+                },
+            ],
+            dynamics: vec![crate::DynamicSelection {
+                dynamic_id: "bound-by-debt".to_string(),
+                cast_index_a: 0,
+                cast_index_b: 1,
+            }],
+            title_override: None,
+            setting_override: None,
+            seed: Some(11),
+        };
+
+        let composed = composer.compose(&selections).unwrap();
+        assert_eq!(composed.characters.len(), 2);
+        assert_eq!(composed.characters[0].relationships.len(), 1);
+        assert_eq!(
+            composed.scene.setting.description,
+            "Where the village gathers and watches"
+        );
+    }
+
+    #[test]
+    fn fallback_supplies_vocabularies_and_empty_bedrock() {
+        let mut fallback = empty_set();
+        fallback.names.insert(
+            "folk-horror".to_string(),
+            crate::descriptors::NameCollection {
+                names: vec!["Agnes".to_string()],
+                source: "test".to_string(),
+            },
+        );
+
+        let set =
+            DescriptorSet::from_genre_contexts(&[genre_context()], Some(fallback.clone())).unwrap();
+        assert_eq!(set.names["folk-horror"].names, vec!["Agnes"]);
+
+        let set = DescriptorSet::from_genre_contexts(&[], Some(fallback)).unwrap();
+        assert!(set.genres.is_empty());
+        assert!(set.names.contains_key("folk-horror"));
+
+        assert!(DescriptorSet::from_genre_contexts(&[], None).is_err());
+    }
+}
@@ -8,10 +8,15 @@
 //! archetypes, dynamics, profiles, and names scoped to a specific genre. The
 //! summary types are lightweight projections designed for UI consumption.
 
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use serde::Serialize;
 
+use storyteller_core::game_design::{
+    GameDesignRegistry, GenreRules, ResolutionTuning, WorldAffordances,
+};
+use storyteller_core::traits::bedrock::BedrockQuery;
 use storyteller_core::StorytellerError;
 
 use crate::descriptors::{Archetype, DescriptorSet, Dynamic, Genre, Profile};
@@ -87,6 +92,47 @@ impl SceneComposer {
         Ok(Self { descriptors })
     }
 
+    /// Wrap an already-assembled descriptor set.
+    pub fn from_descriptors(descriptors: DescriptorSet) -> Self {
+        Self { descriptors }
+    }
+
+    /// Load descriptors from bedrock, falling back to the JSON descriptor
+    /// files under `fallback_path`.
+    ///
+    /// The JSON files supply what bedrock does not hold (axis vocabulary,
+    /// cross-dimensions, name pools), and stand in whole when bedrock is
+    /// unreachable or empty. A missing or unreadable fallback is logged and
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns the bedrock error when bedrock fails and there is no usable
+    /// fallback.
+    pub async fn from_bedrock(
+        bedrock: &dyn BedrockQuery,
+        fallback_path: Option<&Path>,
+    ) -> Result<Self, StorytellerError> {
+        let fallback = fallback_path.and_then(|path| match DescriptorSet::load(path) {
+            Ok(set) => Some(set),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "descriptor fallback unavailable");
+                None
+            }
+        });
+
+        match DescriptorSet::from_bedrock(bedrock, fallback.clone()).await {
+            Ok(descriptors) => Ok(Self { descriptors }),
+            Err(e) => match fallback {
+                Some(descriptors) => {
+                    tracing::warn!(error = %e, "bedrock unavailable, using descriptor files");
+                    Ok(Self { descriptors })
+                }
+                None => Err(e),
+            },
+        }
+    }
+
     // -- catalog queries ---------------------------------------------------
 
     /// Return summaries of all available genres.
@@ -194,6 +240,52 @@ impl SceneComposer {
             .collect()
     }
 
+    /// Return the arbitration rules for the given genre.
+    ///
+    /// Uses the descriptor's world affordances when present, otherwise the
+    /// built-in affordances for known genres; unknown genres get empty rules.
+    pub fn genre_rules(&self, genre_id: &str) -> GenreRules {
+        let genre = self.find_genre(genre_id);
+        let affordances = genre
+            .and_then(|g| g.world_affordances.clone())
+            .or_else(|| WorldAffordances::builtin(genre.map_or(genre_id, |g| g.id.as_str())));
+        affordances
+            .map(|a| GenreRules::derive(&a, &[]))
+            .unwrap_or_default()
+    }
+
+    /// Build the resolution tunings for every loaded genre.
+    ///
+    /// A genre resolves with its entry in `overrides` (the `game_design`
+    /// config, by slug or entity ID), else its descriptor's
+    /// `resolution_tuning`, else the built-in tuning for its slug. Each
+    /// tuning is registered under both the slug and the entity ID, so a
+    /// session carrying either resolves the same way.
+    pub fn game_design(
+        &self,
+        overrides: &BTreeMap<String, ResolutionTuning>,
+    ) -> GameDesignRegistry {
+        let builtin = GameDesignRegistry::builtin();
+        let mut registry = overrides
+            .iter()
+            .fold(builtin.clone(), |registry, (id, tuning)| {
+                registry.with_genre(id.clone(), tuning.clone())
+            });
+        for genre in &self.descriptors.genres {
+            let tuning = overrides
+                .get(&genre.id)
+                .or_else(|| overrides.get(&genre.entity_id))
+                .or(genre.resolution_tuning.as_ref())
+                .unwrap_or_else(|| builtin.tuning_for(&genre.id))
+                .clone();
+            if !genre.entity_id.is_empty() {
+                registry = registry.with_genre(genre.entity_id.clone(), tuning.clone());
+            }
+            registry = registry.with_genre(genre.id.clone(), tuning);
+        }
+        registry
+    }
+
     /// Return the name pool for the given genre, or an empty vec if the genre
     /// has no associated names.
     pub fn names_for_genre(&self, genre_id: &str) -> Vec<String> {
@@ -487,4 +579,153 @@ mod tests {
             .is_empty());
         assert!(composer.names_for_genre("nonexistent_genre").is_empty());
     }
+
+    #[test]
+    fn genre_rules_prefer_descriptor_affordances() {
+        let genre = |id: &str, affordances: serde_json::Value| -> Genre {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "display_name": id,
+                "description": "",
+                "valid_archetypes": [],
+                "valid_dynamics": [],
+                "valid_profiles": [],
+                "excluded_combinations": [],
+                "world_affordances": affordances,
+            }))
+            .unwrap()
+        };
+        let composer = SceneComposer {
+            descriptors: DescriptorSet {
+                archetypes: vec![],
+                genres: vec![
+                    genre("space_opera", serde_json::json!({"magic": "absent"})),
+                    genre("low_fantasy_folklore", serde_json::Value::Null),
+                ],
+                profiles: vec![],
+                dynamics: vec![],
+                axes: vec![],
+                dimensions: vec![],
+                names: Default::default(),
+                settings: Default::default(),
+                goals: vec![],
+            },
+        };
+
+        let rules = composer.genre_rules("space_opera");
+        assert!(rules.lexicon.entries.contains_key("magic"));
+        assert!(!rules.lexicon.entries.contains_key("modern_technology"));
+
+        // No descriptor affordances — the built-in preset applies.
+        let rules = composer.genre_rules("low_fantasy_folklore");
+        assert!(rules.lexicon.entries.contains_key("overt_magic"));
+
+        assert!(composer
+            .genre_rules("nonexistent_genre")
+            .constraints
+            .is_empty());
+    }
+
+    #[test]
+    fn configured_tuning_shifts_resolution_outcomes() {
+        use storyteller_core::types::entity::EntityId;
+        use storyteller_core::types::prediction::{
+            ActionPrediction, ActionType, ActivatedTensorFrame, CharacterPrediction,
+            ThoughtPrediction,
+        };
+        use storyteller_core::types::resolver::SuccessDegree;
+        use storyteller_core::types::tensor::AwarenessLevel;
+        use storyteller_core::types::world_model::WorldModel;
+
+        let genre: Genre = serde_json::from_value(serde_json::json!({
+            "id": "dark_fantasy",
+            "entity_id": "019d0000-0000-7000-8000-00000000df01",
+            "display_name": "Dark Fantasy",
+            "description": "",
+            "valid_archetypes": [],
+            "valid_dynamics": [],
+            "valid_profiles": [],
+            "excluded_combinations": [],
+            "resolution_tuning": { "base_chance": 0.7 },
+        }))
+        .unwrap();
+        let composer = SceneComposer {
+            descriptors: DescriptorSet {
+                archetypes: vec![],
+                genres: vec![genre],
+                profiles: vec![],
+                dynamics: vec![],
+                axes: vec![],
+                dimensions: vec![],
+                names: Default::default(),
+                settings: Default::default(),
+                goals: vec![],
+            },
+        };
+
+        // The descriptor's tuning replaces the built-in gritty odds, under
+        // the slug and the entity ID alike.
+        let registry = composer.game_design(&BTreeMap::new());
+        assert_eq!(registry.tuning_for("dark_fantasy").base_chance, 0.7);
+        assert_eq!(
+            registry.tuning_for("019d0000-0000-7000-8000-00000000df01"),
+            registry.tuning_for("dark_fantasy")
+        );
+
+        // Config overrides win, whichever ID they name.
+        let hopeless = ResolutionTuning {
+            base_chance: 0.05,
+            max_chance: 0.05,
+            ..ResolutionTuning::standard()
+        };
+        let overrides =
+            BTreeMap::from([("019d0000-0000-7000-8000-00000000df01".to_string(), hopeless)]);
+        let configured = composer.game_design(&overrides);
+        assert_eq!(configured.tuning_for("dark_fantasy").max_chance, 0.05);
+
+        let prediction = CharacterPrediction {
+            character_id: EntityId::new(),
+            character_name: "Aldric".to_string(),
+            frame: ActivatedTensorFrame {
+                activated_axes: vec![],
+                confidence: 0.8,
+            },
+            actions: vec![ActionPrediction {
+                description: "climbs the crumbling wall".to_string(),
+                confidence: 0.7,
+                action_type: ActionType::Perform,
+                target: None,
+            }],
+            speech: None,
+            thought: ThoughtPrediction {
+                emotional_subtext: String::new(),
+                awareness_level: AwarenessLevel::Recognizable,
+                internal_conflict: None,
+            },
+            emotional_deltas: vec![],
+        };
+        let world = WorldModel {
+            genre_physics: vec![],
+            spatial_zones: vec![],
+            environmental_constraints: vec![],
+        };
+        let successes = |registry: &GameDesignRegistry| {
+            (0..200u64)
+                .filter(|&seed| {
+                    let system = registry.system_for("dark_fantasy", &[], seed);
+                    let resolved = system.resolve_single(&prediction, &world).unwrap();
+                    matches!(
+                        resolved.outcomes[0].success,
+                        SuccessDegree::FullSuccess | SuccessDegree::PartialSuccess
+                    )
+                })
+                .count()
+        };
+        let authored = successes(&registry);
+        let hopeless = successes(&configured);
+        assert!(
+            hopeless < authored / 4,
+            "configured tuning should mostly fail: {hopeless} vs {authored} of 200"
+        );
+    }
 }
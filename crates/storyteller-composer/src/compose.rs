@@ -8,24 +8,32 @@
 //! entry point. It takes a [`SceneSelections`] struct (genre, profile, cast with
 //! archetypes, optional dynamics) and produces a [`ComposedScene`] containing
 //! fully-instantiated scene data and character sheets with sampled tensor values.
+//! [`compose_in_setting`](SceneComposer::compose_in_setting) places the scene
+//! into an existing setting instead, carrying that setting's accumulated state.
 
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
 use storyteller_core::grammars::PlutchikWestern;
+use storyteller_core::graph::settings::PlacedSetting;
 use storyteller_core::types::character::{
     CastEntry, CharacterSheet, CharacterTensor, EmotionalPrimary, EmotionalState, SceneConstraints,
     SceneData, SceneSetting, SelfEdge, SelfEdgeTrust, SelfKnowledge,
 };
 use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::relational::CastRelationship;
 use storyteller_core::types::scene::{SceneId, SceneType};
+use storyteller_core::types::setting::SettingId;
 use storyteller_core::types::tensor::{AwarenessLevel, AxisValue, Provenance, TemporalLayer};
 use storyteller_core::types::world_model::CapabilityProfile;
+use storyteller_ml::matrix::descriptors::{DynamicDescriptor, SubstrateTemplate, ValueRange};
+use storyteller_ml::matrix::dynamics::{instantiate_edges, parse_topology};
 
 use crate::catalog::SceneComposer;
 use crate::descriptors::{
-    Archetype, EmotionalProfile, RangeBounds, SelfEdge as DescriptorSelfEdge,
+    Archetype, Dynamic, EmotionalProfile, RangeBounds, RelationalEdge,
+    SelfEdge as DescriptorSelfEdge,
 };
 use crate::names::select_names;
 
@@ -78,9 +86,9 @@ fn default_role() -> String {
 
 /// A relational dynamic pairing two cast members by index.
 ///
-/// NOTE: Dynamic edge weights are NOT yet wired into character sheets —
-/// the relational web between characters is a future concern. These
-/// selections are persisted for forward compatibility and UI display.
+/// Composition samples a directed edge in each direction from the dynamic's
+/// substrate ranges and records it as a [`CastRelationship`] on both
+/// character sheets.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DynamicSelection {
     /// Dynamic id from the descriptor set.
@@ -105,6 +113,8 @@ pub struct ComposedScene {
     pub characters: Vec<CharacterSheet>,
     /// The selections that produced this scene (for persistence / replay).
     pub selections: SceneSelections,
+    /// The persistent setting the scene was placed in, if any.
+    pub setting_id: Option<SettingId>,
 }
 
 // ---------------------------------------------------------------------------
@@ -118,6 +128,33 @@ impl SceneComposer {
     /// set, samples tensor values within archetype-defined ranges, and builds
     /// the full `SceneData` + `Vec<CharacterSheet>` pair.
     pub fn compose(&self, selections: &SceneSelections) -> Result<ComposedScene, String> {
+        self.compose_with(selections, None)
+    }
+
+    /// Compose a scene inside an existing setting.
+    ///
+    /// The setting comes from [`SettingTopologyStore::place_scene`] with every
+    /// change from earlier scenes applied, replacing the descriptor-built
+    /// setting. `setting_override` still replaces the description. The
+    /// scene id should be the one passed to `place_scene`.
+    ///
+    /// [`SettingTopologyStore::place_scene`]: storyteller_core::graph::settings::SettingTopologyStore::place_scene
+    pub fn compose_in_setting(
+        &self,
+        selections: &SceneSelections,
+        scene_id: SceneId,
+        placed: &PlacedSetting,
+    ) -> Result<ComposedScene, String> {
+        let mut composed = self.compose_with(selections, Some(placed))?;
+        composed.scene.scene_id = scene_id;
+        Ok(composed)
+    }
+
+    fn compose_with(
+        &self,
+        selections: &SceneSelections,
+        placed: Option<&PlacedSetting>,
+    ) -> Result<ComposedScene, String> {
         // Validate genre
         let genre = self
             .find_genre(&selections.genre_id)
@@ -165,7 +202,7 @@ impl SceneComposer {
             .collect();
 
         // Compose character sheets
-        let characters: Vec<CharacterSheet> = archetypes
+        let mut characters: Vec<CharacterSheet> = archetypes
             .iter()
             .zip(names.iter())
             .zip(selections.cast.iter())
@@ -174,6 +211,20 @@ impl SceneComposer {
             })
             .collect();
 
+        // Instantiate the relational web from the selected dynamics
+        for selection in &selections.dynamics {
+            let dynamic = self
+                .find_dynamic(&selection.dynamic_id)
+                .ok_or_else(|| format!("unknown dynamic: '{}'", selection.dynamic_id))?;
+            compose_relationship(
+                dynamic,
+                &mut characters,
+                selection.cast_index_a,
+                selection.cast_index_b,
+                &mut rng,
+            )?;
+        }
+
         // Build cast entries for SceneData
         let cast: Vec<CastEntry> = characters
             .iter()
@@ -194,13 +245,22 @@ impl SceneComposer {
             .clone()
             .unwrap_or_else(|| profile.display_name.clone());
 
-        // Setting
-        let setting = compose_setting(
-            &self.descriptors.settings,
-            &genre.id,
-            &profile.id,
-            selections.setting_override.as_deref(),
-        );
+        // Setting — an existing one carries its accumulated state
+        let setting = match placed {
+            Some(placed) => {
+                let mut setting = placed.setting.clone();
+                if let Some(text) = &selections.setting_override {
+                    setting.description = text.clone();
+                }
+                setting
+            }
+            None => compose_setting(
+                &self.descriptors.settings,
+                &genre.id,
+                &profile.id,
+                selections.setting_override.as_deref(),
+            ),
+        };
 
         // Stakes from profile characteristic events
         let stakes = compose_stakes(profile);
@@ -230,12 +290,14 @@ impl SceneComposer {
                     )
                 })
                 .collect(),
+            event_dependencies: Default::default(),
         };
 
         Ok(ComposedScene {
             scene,
             characters,
             selections: selections.clone(),
+            setting_id: placed.map(|p| p.setting_id),
         })
     }
 }
@@ -297,6 +359,80 @@ fn compose_character<R: Rng>(
         knows: vec![format!("I am {name}")],
         does_not_know: vec!["The full scope of the story".to_string()],
         capabilities: CapabilityProfile::default(),
+        relationships: Vec::new(),
+    }
+}
+
+/// Instantiate a dynamic between two cast members, recording each side's
+/// edge on its character sheet.
+fn compose_relationship<R: Rng>(
+    dynamic: &Dynamic,
+    characters: &mut [CharacterSheet],
+    index_a: usize,
+    index_b: usize,
+    rng: &mut R,
+) -> Result<(), String> {
+    let cast_size = characters.len();
+    if index_a >= cast_size || index_b >= cast_size {
+        return Err(format!(
+            "dynamic '{}' references cast index out of range (cast size {cast_size})",
+            dynamic.id
+        ));
+    }
+    if index_a == index_b {
+        return Err(format!(
+            "dynamic '{}' pairs cast member {index_a} with itself",
+            dynamic.id
+        ));
+    }
+
+    let descriptor = DynamicDescriptor {
+        id: dynamic.id.clone(),
+        display_name: dynamic.display_name.clone(),
+        description: dynamic.description.clone(),
+        role_a: dynamic.role_a.clone(),
+        role_b: dynamic.role_b.clone(),
+        edge_a_to_b: substrate_template(&dynamic.edge_a_to_b),
+        edge_b_to_a: substrate_template(&dynamic.edge_b_to_a),
+        topology_a: dynamic.topology_a.clone(),
+        topology_b: dynamic.topology_b.clone(),
+    };
+    let (edge_ab, edge_ba) = instantiate_edges(
+        &descriptor,
+        characters[index_a].entity_id,
+        characters[index_b].entity_id,
+        rng,
+    );
+
+    characters[index_a].relationships.push(CastRelationship {
+        edge: edge_ab,
+        target_topology: parse_topology(&dynamic.topology_b),
+        dynamic: dynamic.display_name.clone(),
+        role: dynamic.role_a.clone(),
+        target_role: dynamic.role_b.clone(),
+    });
+    characters[index_b].relationships.push(CastRelationship {
+        edge: edge_ba,
+        target_topology: parse_topology(&dynamic.topology_a),
+        dynamic: dynamic.display_name.clone(),
+        role: dynamic.role_b.clone(),
+        target_role: dynamic.role_a.clone(),
+    });
+    Ok(())
+}
+
+/// Convert a descriptor edge's range bounds into an ML substrate template.
+fn substrate_template(edge: &RelationalEdge) -> SubstrateTemplate {
+    let range = |bounds: &RangeBounds| ValueRange {
+        min: bounds.min as f32,
+        max: bounds.max as f32,
+    };
+    SubstrateTemplate {
+        trust_reliability: range(&edge.trust_reliability),
+        trust_competence: range(&edge.trust_competence),
+        trust_benevolence: range(&edge.trust_benevolence),
+        affection: range(&edge.affection),
+        debt: range(&edge.debt),
     }
 }
 
@@ -482,6 +618,7 @@ fn parse_awareness(s: &str) -> AwarenessLevel {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use storyteller_core::types::relational::TopologicalRole;
 
     /// Returns the storyteller-data base path from the environment, or None.
     fn data_path() -> Option<std::path::PathBuf> {
@@ -604,6 +741,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compose_in_setting_carries_accumulated_state() {
+        let Some(composer) = load_composer() else {
+            eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+            return;
+        };
+
+        let placed = PlacedSetting {
+            setting_id: SettingId::new(),
+            name: "The Witch's cottage".to_string(),
+            setting: SceneSetting {
+                description: "A low cottage. The hearth is cold.".to_string(),
+                affordances: vec!["search the ashes".to_string()],
+                sensory_details: vec!["wet ash".to_string()],
+                aesthetic_detail: "a cracked blue bowl".to_string(),
+            },
+            visits: 2,
+        };
+        let scene_id = SceneId::new();
+
+        let composed = composer
+            .compose_in_setting(&make_selections(&composer, Some(42)), scene_id, &placed)
+            .expect("composition should succeed");
+        assert_eq!(composed.scene.scene_id, scene_id);
+        assert_eq!(composed.setting_id, Some(placed.setting_id));
+        assert_eq!(
+            composed.scene.setting.description,
+            "A low cottage. The hearth is cold."
+        );
+        assert_eq!(composed.scene.setting.affordances, vec!["search the ashes"]);
+    }
+
     #[test]
     fn compose_with_name_override() {
         let Some(composer) = load_composer() else {
@@ -669,6 +838,145 @@ mod tests {
         assert!(result.unwrap_err().contains("unknown archetype"));
     }
 
+    #[test]
+    fn compose_with_dynamic_relates_both_cast_members() {
+        let Some(composer) = load_composer() else {
+            eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+            return;
+        };
+
+        let mut selections = make_selections(&composer, Some(7));
+        let archetype_ids: Vec<String> = selections
+            .cast
+            .iter()
+            .map(|c| c.archetype_id.clone())
+            .collect();
+        let Some(dynamic) = composer
+            .dynamics_for_genre(&selections.genre_id, &archetype_ids)
+            .into_iter()
+            .next()
+        else {
+            eprintln!("genre has no dynamics — skipping");
+            return;
+        };
+        selections.dynamics = vec![DynamicSelection {
+            dynamic_id: dynamic.id,
+            cast_index_a: 0,
+            cast_index_b: 1,
+        }];
+
+        let composed = composer
+            .compose(&selections)
+            .expect("compose should succeed");
+        let (a, b) = (&composed.characters[0], &composed.characters[1]);
+        assert_eq!(a.relationships.len(), 1);
+        assert_eq!(b.relationships.len(), 1);
+        assert_eq!(a.relationships[0].edge.target, b.entity_id);
+        assert_eq!(b.relationships[0].edge.target, a.entity_id);
+        assert_eq!(a.relationships[0].role, dynamic.role_a);
+    }
+
+    fn test_dynamic() -> Dynamic {
+        let edge = |low: f64, high: f64| RelationalEdge {
+            trust_reliability: RangeBounds {
+                min: low,
+                max: high,
+            },
+            trust_competence: RangeBounds {
+                min: low,
+                max: high,
+            },
+            trust_benevolence: RangeBounds {
+                min: low,
+                max: high,
+            },
+            affection: RangeBounds {
+                min: low,
+                max: high,
+            },
+            debt: RangeBounds { min: 0.0, max: 0.0 },
+        };
+        Dynamic {
+            id: "rivals".to_string(),
+            entity_id: String::new(),
+            display_name: "Rivals".to_string(),
+            description: "Two who want the same thing".to_string(),
+            role_a: "challenger".to_string(),
+            role_b: "incumbent".to_string(),
+            edge_a_to_b: edge(-0.6, -0.4),
+            edge_b_to_a: edge(0.1, 0.2),
+            topology_a: "Periphery".to_string(),
+            topology_b: "Gate".to_string(),
+            enabled_goals: Vec::new(),
+            blocked_goals: Vec::new(),
+        }
+    }
+
+    fn test_cast() -> Vec<CharacterSheet> {
+        let archetype: Archetype = serde_json::from_value(serde_json::json!({
+            "id": "test",
+            "display_name": "Test",
+            "description": "A test archetype",
+            "axes": [],
+            "default_emotional_profile": { "grammar_id": "plutchik_western", "primaries": [] },
+            "default_self_edge": {
+                "trust_competence": { "min": 0.5, "max": 0.5 },
+                "trust_intentions": { "min": 0.5, "max": 0.5 },
+                "trust_reliability": { "min": 0.5, "max": 0.5 },
+                "affection": { "min": 0.5, "max": 0.5 },
+                "debt": { "min": 0.0, "max": 0.0 },
+                "history_weight": { "min": 0.5, "max": 0.5 },
+                "projection_accuracy": { "min": 0.5, "max": 0.5 }
+            },
+            "action_tendencies": {
+                "primary_action_types": [],
+                "primary_action_contexts": [],
+                "speech_registers": [],
+                "speech_likelihood": 0.5,
+                "default_awareness": "Conscious"
+            }
+        }))
+        .expect("test archetype should deserialize");
+        let mut rng = StdRng::seed_from_u64(1);
+        ["Ada", "Brin"]
+            .iter()
+            .map(|name| compose_character(&archetype, name, "cast", &mut rng))
+            .collect()
+    }
+
+    #[test]
+    fn compose_relationship_samples_each_direction() {
+        let mut cast = test_cast();
+        let mut rng = StdRng::seed_from_u64(3);
+        compose_relationship(&test_dynamic(), &mut cast, 0, 1, &mut rng).unwrap();
+
+        let challenger = &cast[0].relationships[0];
+        assert_eq!(challenger.edge.source, cast[0].entity_id);
+        assert_eq!(challenger.edge.target, cast[1].entity_id);
+        assert_eq!(challenger.target_topology, TopologicalRole::Gate);
+        assert_eq!(challenger.target_role, "incumbent");
+        let affection = challenger.edge.substrate.affection.central_tendency;
+        assert!((-0.6..=-0.4).contains(&affection));
+
+        let incumbent = &cast[1].relationships[0];
+        assert_eq!(incumbent.edge.target, cast[0].entity_id);
+        assert_eq!(incumbent.target_topology, TopologicalRole::Periphery);
+        assert_eq!(incumbent.role, "incumbent");
+        let affection = incumbent.edge.substrate.affection.central_tendency;
+        assert!((0.1..=0.2).contains(&affection));
+    }
+
+    #[test]
+    fn compose_relationship_rejects_bad_indices() {
+        let mut cast = test_cast();
+        let mut rng = StdRng::seed_from_u64(3);
+        let err = compose_relationship(&test_dynamic(), &mut cast, 0, 2, &mut rng).unwrap_err();
+        assert!(err.contains("out of range"));
+        let err = compose_relationship(&test_dynamic(), &mut cast, 1, 1, &mut rng).unwrap_err();
+        assert!(err.contains("with itself"));
+        assert!(cast.iter().all(|c| c.relationships.is_empty()));
+    }
+
     #[test]
     fn sample_in_range_equal_bounds() {
         let bounds = RangeBounds { min: 0.5, max: 0.5 };
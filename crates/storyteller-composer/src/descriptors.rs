@@ -11,6 +11,7 @@ use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
 use std::path::Path;
 
+use storyteller_core::game_design::{ResolutionTuning, WorldAffordances};
 use storyteller_core::StorytellerError;
 
 /// Deserialize `null` as `Default::default()` (e.g. empty Vec).
@@ -131,6 +132,13 @@ pub struct Genre {
     pub valid_dynamics: Vec<String>,
     pub valid_profiles: Vec<String>,
     pub excluded_combinations: Vec<ExcludedCombination>,
+    /// What the genre's physics permit; drives arbitration constraints.
+    #[serde(default)]
+    pub world_affordances: Option<WorldAffordances>,
+    /// The genre's resolution odds, over the standard tuning; replaces the
+    /// built-in tuning for the genre when present.
+    #[serde(default)]
+    pub resolution_tuning: Option<ResolutionTuning>,
 }
 
 /// A combination of archetype + dynamic + profile that is excluded for narrative reasons.
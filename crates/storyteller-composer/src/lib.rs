@@ -6,9 +6,11 @@
 //!
 //! This crate owns the creative assembly layer: descriptor catalogs,
 //! genre/archetype/setting/dynamics selection, character generation,
-//! scene composition, and goal intersection. Hydrated from JSON descriptor
-//! files today, eventually database-backed.
+//! scene composition, and goal intersection. Hydrated from the bedrock data
+//! layer through any `BedrockQuery` implementation, or from JSON descriptor
+//! files.
 
+pub mod bedrock;
 pub mod catalog;
 pub mod compose;
 pub mod descriptors;
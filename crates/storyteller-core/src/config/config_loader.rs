@@ -12,7 +12,8 @@ use std::path::Path;
 
 use regex::Regex;
 
-use super::storyteller::StorytellerConfig;
+use super::merger::ConfigMerger;
+use super::storyteller::{StorytellerConfig, NARRATOR_PROVIDERS};
 use crate::{StorytellerError, StorytellerResult};
 
 /// Loads and validates storyteller configuration from TOML files.
@@ -27,6 +28,38 @@ impl ConfigLoader {
         std::env::var("STORYTELLER_ENV").unwrap_or_else(|_| "development".to_string())
     }
 
+    /// Loads configuration the way the storyteller binaries do.
+    ///
+    /// Loads `.env`, then reads the merged file at `STORYTELLER_CONFIG_PATH`
+    /// if it exists. Otherwise merges `base/` and the environment overlay
+    /// from `STORYTELLER_CONFIG_DIR` (default `config/storyteller`) for
+    /// [`detect_environment`](Self::detect_environment) in memory, so a
+    /// fresh checkout runs without generating the merged file first.
+    pub fn load() -> StorytellerResult<StorytellerConfig> {
+        // Best-effort .env loading — missing file is fine.
+        let _ = dotenvy::dotenv();
+
+        if let Ok(config_path) = std::env::var("STORYTELLER_CONFIG_PATH") {
+            if Path::new(&config_path).exists() {
+                return Self::load_from_path(&config_path);
+            }
+        }
+
+        let source_dir = std::env::var("STORYTELLER_CONFIG_DIR")
+            .unwrap_or_else(|_| "config/storyteller".to_string());
+        Self::load_layered(source_dir, &Self::detect_environment())
+    }
+
+    /// Merges base and environment TOML from `source_dir`, substitutes env
+    /// vars, parses, and validates.
+    pub fn load_layered<P: AsRef<Path>>(
+        source_dir: P,
+        environment: &str,
+    ) -> StorytellerResult<StorytellerConfig> {
+        let merged = ConfigMerger::new(source_dir, environment).merge()?;
+        Self::load_from_str(&merged)
+    }
+
     /// Loads `.env` via dotenvy, reads `STORYTELLER_CONFIG_PATH`, and delegates
     /// to [`load_from_path`](Self::load_from_path).
     pub fn load_from_env() -> StorytellerResult<StorytellerConfig> {
@@ -52,7 +85,12 @@ impl ConfigLoader {
             ))
         })?;
 
-        let substituted = Self::substitute_env_vars(&raw)?;
+        Self::load_from_str(&raw)
+    }
+
+    /// Substitutes env vars in raw TOML, parses, and validates.
+    fn load_from_str(raw: &str) -> StorytellerResult<StorytellerConfig> {
+        let substituted = Self::substitute_env_vars(raw)?;
 
         let config: StorytellerConfig = toml::from_str(&substituted)
             .map_err(|e| StorytellerError::Config(format!("failed to parse config TOML: {e}")))?;
@@ -140,19 +178,116 @@ impl ConfigLoader {
             "STORYTELLER_CONFIG_PATH",
             "STORYTELLER_DATA_PATH",
             "STORYTELLER_MODEL_PATH",
+            "STORYTELLER_GRAMMARS_PATH",
             "OLLAMA_BASE_URL",
+            "OLLAMA_URL",
+            "STORYTELLER_GRPC_PORT",
+            "STORYTELLER_HTTP_PORT",
+            "STORYTELLER_SERVER_URL",
+            "STORYTELLER_SESSIONS_DIR",
+            "STORYTELLER_CHECKPOINT_INTERVAL",
+            "STORYTELLER_NARRATOR_PROVIDER",
+            "STORYTELLER_NARRATOR_MODEL",
+            "STORYTELLER_DECOMPOSITION_MODEL",
+            "STORYTELLER_INTENT_MODEL",
+            "STORYTELLER_PLAYER_MODEL",
+            "STORYTELLER_CLOUD_API_KEY",
+            "STORYTELLER_CLOUD_BASE_URL",
+            "STORYTELLER_LOCAL_LLM_PATH",
+            "STORYTELLER_API_KEYS",
+            "STORYTELLER_ADMIN_KEYS",
+            "STORYTELLER_TOKEN_SECRET",
         ]
     }
 
     /// Validates a parsed config for semantic correctness.
+    ///
+    /// Errors name the offending key and, for settings usually supplied
+    /// through the environment, the variable to set.
     fn validate_config(config: &StorytellerConfig) -> StorytellerResult<()> {
+        let invalid = |msg: String| Err(StorytellerError::Config(msg));
+
         if config.database.url.is_empty() {
-            return Err(StorytellerError::Config(
-                "database.url must not be empty".to_string(),
+            return invalid("database.url must not be empty".to_string());
+        }
+
+        let server = config.server();
+        for (key, port) in [
+            ("server.grpc_port", server.grpc_port),
+            ("server.http_port", server.http_port),
+        ] {
+            if port == 0 {
+                return invalid(format!("{key} must be a non-zero port"));
+            }
+        }
+        if server.grpc_port == server.http_port {
+            return invalid(format!(
+                "server.grpc_port and server.http_port must differ (both are {})",
+                server.grpc_port
+            ));
+        }
+        Self::validate_url("server.url", &server.url)?;
+        if server.data_path.trim().is_empty() {
+            return invalid("server.data_path must not be empty".to_string());
+        }
+
+        let provider = config.provider();
+        if !NARRATOR_PROVIDERS.contains(&provider.narrator.as_str()) {
+            return invalid(format!(
+                "provider.narrator must be one of {}, got '{}'",
+                NARRATOR_PROVIDERS.join(", "),
+                provider.narrator
             ));
         }
+        let hosted = matches!(provider.narrator.as_str(), "anthropic" | "openai");
+        if hosted && provider.cloud_api_key.is_none() {
+            return invalid(format!(
+                "provider.cloud_api_key is required for the {} narrator \
+                 (set STORYTELLER_CLOUD_API_KEY)",
+                provider.narrator
+            ));
+        }
+        if provider.narrator == "local" && provider.local_model_path.is_none() {
+            return invalid(
+                "provider.local_model_path is required for the local narrator \
+                 (set STORYTELLER_LOCAL_LLM_PATH)"
+                    .to_string(),
+            );
+        }
+        for (key, model) in [
+            ("provider.narrator_model", &provider.narrator_model),
+            (
+                "provider.decomposition_model",
+                &provider.decomposition_model,
+            ),
+            ("provider.intent_model", &provider.intent_model),
+            ("provider.player_model", &provider.player_model),
+        ] {
+            if model.trim().is_empty() {
+                return invalid(format!("{key} must not be empty"));
+            }
+        }
+        Self::validate_url("provider.ollama_url", &provider.ollama_url)?;
+        if let Some(base_url) = &provider.cloud_base_url {
+            Self::validate_url("provider.cloud_base_url", base_url)?;
+        }
+
+        if config.persistence().sessions_dir.trim().is_empty() {
+            return invalid("persistence.sessions_dir must not be empty".to_string());
+        }
         Ok(())
     }
+
+    /// Requires an `http://` or `https://` URL.
+    fn validate_url(key: &str, url: &str) -> StorytellerResult<()> {
+        if url.starts_with("http://") || url.starts_with("https://") {
+            Ok(())
+        } else {
+            Err(StorytellerError::Config(format!(
+                "{key} must be an http:// or https:// URL, got '{url}'"
+            )))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -279,10 +414,76 @@ max_connections = 5
             llm: None,
             inference: None,
             context: None,
+            server: None,
+            provider: None,
+            auth: None,
+            persistence: None,
+            game_design: None,
         };
         let result = ConfigLoader::validate_config(&config);
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("must not be empty"));
     }
+
+    fn validation_error(extra: &str) -> String {
+        let raw = format!("[database]\nurl = \"postgresql://localhost/db\"\n{extra}");
+        ConfigLoader::load_from_str(&raw).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_validate_config_colliding_ports() {
+        let err = validation_error("[server]\ngrpc_port = 9000\nhttp_port = 9000\n");
+        assert!(err.contains("must differ (both are 9000)"));
+    }
+
+    #[test]
+    fn test_validate_config_unknown_narrator() {
+        let err = validation_error("[provider]\nnarrator = \"llamafile\"\n");
+        assert!(err.contains("provider.narrator must be one of ollama, anthropic, openai, local"));
+    }
+
+    #[test]
+    fn test_validate_config_cloud_narrator_needs_key() {
+        let err = validation_error("[provider]\nnarrator = \"anthropic\"\n");
+        assert!(err.contains("STORYTELLER_CLOUD_API_KEY"));
+    }
+
+    #[test]
+    fn test_validate_config_local_narrator_needs_model_path() {
+        let err = validation_error("[provider]\nnarrator = \"local\"\n");
+        assert!(err.contains("STORYTELLER_LOCAL_LLM_PATH"));
+
+        let raw = "[database]\nurl = \"postgresql://localhost/db\"\n\
+                   [provider]\nnarrator = \"local\"\nlocal_model_path = \"models/smollm2\"\n";
+        let config = ConfigLoader::load_from_str(raw).unwrap();
+        assert_eq!(
+            config.provider().local_model_path.as_deref(),
+            Some("models/smollm2")
+        );
+    }
+
+    #[test]
+    fn test_validate_config_bad_url() {
+        let err = validation_error("[provider]\nollama_url = \"localhost:11434\"\n");
+        assert!(err.contains("provider.ollama_url must be an http:// or https:// URL"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_layered_repo_config() {
+        // The checked-in base and test overlay must load and validate as-is.
+        for var in [
+            "DATABASE_URL",
+            "STORYTELLER_GRPC_PORT",
+            "STORYTELLER_HTTP_PORT",
+        ] {
+            std::env::remove_var(var);
+        }
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../config/storyteller");
+        let config = ConfigLoader::load_layered(&source, "test").unwrap();
+        assert!(config.database.url.ends_with("storyteller_test"));
+        assert_eq!(config.server().grpc_port, 50051);
+        assert_eq!(config.provider().narrator, "ollama");
+    }
 }
@@ -9,3 +9,502 @@
 //! Checkpoints capture the full in-memory state (truth set, entity states,
 //! scene context) at regular intervals. Recovery replays only events since
 //! the last checkpoint.
+//!
+//! The pieces:
+//!
+//! - [`Checkpoint`] — a snapshot of some session state `S`, stamped with the
+//!   last [`LedgerSequence`] folded into it
+//! - [`LedgerReplay`] — how a state type folds ledger entries into itself.
+//!   The live turn path and recovery both go through it, so a recovered
+//!   state is identical to the one the session held before it stopped
+//! - [`CheckpointStore`] — where checkpoints are kept
+//! - [`recover`] — latest checkpoint plus the ledger tail after it
+//!
+//! The state type is generic because the full runtime snapshot lives above
+//! this crate (it carries ML prediction history); [`TruthSet`] is the part
+//! of it defined here.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use super::ledger::{EventLedger, LedgerEntry, LedgerEntryKind, LedgerSequence};
+use crate::errors::StorytellerResult;
+use crate::traits::storykeeper::{CheckpointId, SessionId};
+use crate::types::event::NarrativeEvent;
+use crate::types::event_grammar::{EventAtom, EventPayload};
+
+// ---------------------------------------------------------------------------
+// Replay
+// ---------------------------------------------------------------------------
+
+/// State that can be rebuilt by folding ledger entries into it in order.
+///
+/// Implementations must be deterministic: everything an entry changes has
+/// to come from the entry itself (including timestamps — use
+/// [`LedgerEntry::recorded_at`], not the clock).
+pub trait LedgerReplay {
+    /// Fold one entry into the state.
+    fn apply_entry(&mut self, entry: &LedgerEntry) -> StorytellerResult<()>;
+}
+
+/// Fold `entries` into `state` in order, returning the last sequence applied.
+pub fn replay<S: LedgerReplay>(
+    state: &mut S,
+    entries: &[LedgerEntry],
+) -> StorytellerResult<Option<LedgerSequence>> {
+    let mut last = None;
+    for entry in entries {
+        state.apply_entry(entry)?;
+        last = Some(entry.sequence);
+    }
+    Ok(last)
+}
+
+// ---------------------------------------------------------------------------
+// Truth set
+// ---------------------------------------------------------------------------
+
+/// Classified events the session has committed as having happened.
+///
+/// Events enter as `pending` when their `ClassifiedEvent` entry is replayed
+/// and become committed only when the turn's `TurnCommit` lists them, so a
+/// turn interrupted mid-pipeline never leaks into the truth set.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct TruthSet {
+    /// Committed events, in commit order.
+    pub events: Vec<NarrativeEvent>,
+    /// Events classified for the current, not-yet-committed turn.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending: Vec<NarrativeEvent>,
+}
+
+impl TruthSet {
+    /// Number of committed events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether nothing has been committed yet.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Committed event atoms, in commit order. Compound and untyped
+    /// payloads are skipped.
+    pub fn atoms(&self) -> impl Iterator<Item = &EventAtom> {
+        self.events.iter().filter_map(|e| match &e.payload {
+            EventPayload::Atom(atom) => Some(atom),
+            _ => None,
+        })
+    }
+
+    /// Commit events produced outside the turn pipeline — deferred workflow
+    /// results arriving at a turn boundary. Events already committed are
+    /// skipped, so redelivered results merge once. Returns how many were added.
+    pub fn merge_committed(&mut self, events: impl IntoIterator<Item = NarrativeEvent>) -> usize {
+        let mut added = 0;
+        for event in events {
+            if !self.events.iter().any(|e| e.id == event.id) {
+                self.events.push(event);
+                added += 1;
+            }
+        }
+        added
+    }
+}
+
+impl LedgerReplay for TruthSet {
+    fn apply_entry(&mut self, entry: &LedgerEntry) -> StorytellerResult<()> {
+        match &entry.kind {
+            // A new command starts a new turn — anything still pending
+            // belonged to a turn that never committed. An abandoned
+            // command's events never will.
+            LedgerEntryKind::Command { .. } | LedgerEntryKind::CommandAbandoned { .. } => {
+                self.pending.clear()
+            }
+            LedgerEntryKind::ClassifiedEvent { event } => {
+                if !self.pending.iter().any(|e| e.id == event.id) {
+                    self.pending.push((**event).clone());
+                }
+            }
+            LedgerEntryKind::TurnCommit { commit } => {
+                let mut pending = std::mem::take(&mut self.pending);
+                for id in &commit.event_ids {
+                    if let Some(pos) = pending.iter().position(|e| e.id == *id) {
+                        self.events.push(pending.remove(pos));
+                    }
+                }
+            }
+            LedgerEntryKind::DeferredResult { events, .. } => {
+                self.merge_committed(events.iter().cloned());
+            }
+            LedgerEntryKind::JournalCompressed { .. }
+            | LedgerEntryKind::LayerEntered { .. }
+            | LedgerEntryKind::LayerExited { .. } => {}
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Checkpoints
+// ---------------------------------------------------------------------------
+
+/// A snapshot of session state at a known ledger position.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Checkpoint<S> {
+    /// Unique checkpoint identifier.
+    pub id: CheckpointId,
+    /// The session the state belongs to.
+    pub session_id: SessionId,
+    /// Turn the session was on when the checkpoint was taken.
+    pub turn_number: u32,
+    /// Last ledger entry folded into `state`; `None` if it predates the ledger.
+    pub ledger_sequence: Option<LedgerSequence>,
+    /// When the checkpoint was taken.
+    pub created_at: DateTime<Utc>,
+    /// The captured state.
+    pub state: S,
+}
+
+impl<S> Checkpoint<S> {
+    /// Capture `state` as of `ledger_sequence`.
+    pub fn new(
+        session_id: SessionId,
+        turn_number: u32,
+        ledger_sequence: Option<LedgerSequence>,
+        state: S,
+    ) -> Self {
+        Self {
+            id: CheckpointId::new(),
+            session_id,
+            turn_number,
+            ledger_sequence,
+            created_at: Utc::now(),
+            state,
+        }
+    }
+}
+
+/// How often the live turn path writes a checkpoint.
+///
+/// Recovery cost is bounded by the interval: at most `interval_turns`
+/// committed turns are replayed from the ledger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointPolicy {
+    /// Write a checkpoint after every `interval_turns` committed turns.
+    pub interval_turns: u32,
+}
+
+impl CheckpointPolicy {
+    /// Whether a checkpoint is due after committing `turn_number`.
+    ///
+    /// An interval of zero is treated as one — checkpoint every turn.
+    pub fn should_checkpoint(&self, turn_number: u32) -> bool {
+        turn_number.is_multiple_of(self.interval_turns.max(1))
+    }
+}
+
+impl Default for CheckpointPolicy {
+    fn default() -> Self {
+        Self { interval_turns: 5 }
+    }
+}
+
+/// Durable storage for checkpoints of state `S`.
+#[async_trait::async_trait]
+pub trait CheckpointStore<S>: Send + Sync
+where
+    S: Send + Sync,
+{
+    /// Persist a checkpoint.
+    async fn write(&self, checkpoint: &Checkpoint<S>) -> StorytellerResult<()>;
+
+    /// The most recently written checkpoint for a session, if any.
+    async fn latest(&self, session_id: &SessionId) -> StorytellerResult<Option<Checkpoint<S>>>;
+}
+
+/// In-memory [`CheckpointStore`] for tests and ephemeral sessions.
+#[derive(Debug)]
+pub struct InMemoryCheckpointStore<S> {
+    checkpoints: Mutex<HashMap<SessionId, Vec<Checkpoint<S>>>>,
+}
+
+impl<S> InMemoryCheckpointStore<S> {
+    pub fn new() -> Self {
+        Self {
+            checkpoints: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<S> Default for InMemoryCheckpointStore<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> CheckpointStore<S> for InMemoryCheckpointStore<S>
+where
+    S: Clone + Send + Sync,
+{
+    async fn write(&self, checkpoint: &Checkpoint<S>) -> StorytellerResult<()> {
+        self.checkpoints
+            .lock()
+            .expect("checkpoint lock poisoned")
+            .entry(checkpoint.session_id)
+            .or_default()
+            .push(checkpoint.clone());
+        Ok(())
+    }
+
+    async fn latest(&self, session_id: &SessionId) -> StorytellerResult<Option<Checkpoint<S>>> {
+        Ok(self
+            .checkpoints
+            .lock()
+            .expect("checkpoint lock poisoned")
+            .get(session_id)
+            .and_then(|c| c.last().cloned()))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Recovery
+// ---------------------------------------------------------------------------
+
+/// State rebuilt by [`recover`].
+#[derive(Debug, Clone)]
+pub struct Recovered<S> {
+    /// The restored state, with the ledger tail folded in.
+    pub state: S,
+    /// The checkpoint recovery started from.
+    pub checkpoint_id: CheckpointId,
+    /// Last ledger entry folded into `state`.
+    pub ledger_sequence: Option<LedgerSequence>,
+    /// How many ledger entries were replayed on top of the checkpoint.
+    pub replayed: usize,
+}
+
+/// Restore a session from its latest checkpoint and replay the ledger tail.
+///
+/// Returns `None` when the session has no checkpoint — there is no base
+/// state to replay onto.
+pub async fn recover<S>(
+    store: &dyn CheckpointStore<S>,
+    ledger: &dyn EventLedger,
+    session_id: &SessionId,
+) -> StorytellerResult<Option<Recovered<S>>>
+where
+    S: LedgerReplay + Send + Sync,
+{
+    let Some(checkpoint) = store.latest(session_id).await? else {
+        return Ok(None);
+    };
+
+    let tail = ledger
+        .entries_since(session_id, checkpoint.ledger_sequence)
+        .await?;
+    let mut state = checkpoint.state;
+    let last = replay(&mut state, &tail)?;
+
+    Ok(Some(Recovered {
+        state,
+        checkpoint_id: checkpoint.id,
+        ledger_sequence: last.or(checkpoint.ledger_sequence),
+        replayed: tail.len(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::ledger::{InMemoryLedger, TurnCommitRecord};
+    use crate::types::event::{EventId, EventPriority};
+    use crate::types::message::PlayerInput;
+
+    /// Minimal replayable state: the truth set plus committed prose.
+    #[derive(Debug, Clone, Default)]
+    struct ProseState {
+        truth: TruthSet,
+        prose: Vec<String>,
+    }
+
+    impl LedgerReplay for ProseState {
+        fn apply_entry(&mut self, entry: &LedgerEntry) -> StorytellerResult<()> {
+            self.truth.apply_entry(entry)?;
+            if let LedgerEntryKind::TurnCommit { commit } = &entry.kind {
+                self.prose.push(commit.narrator_prose.clone());
+            }
+            Ok(())
+        }
+    }
+
+    fn event() -> NarrativeEvent {
+        NarrativeEvent {
+            id: EventId::new(),
+            timestamp: Utc::now(),
+            priority: EventPriority::Normal,
+            payload: EventPayload::Untyped(serde_json::json!({"kind": "test"})),
+        }
+    }
+
+    async fn play_turn(
+        ledger: &InMemoryLedger,
+        session_id: &SessionId,
+        turn_number: u32,
+    ) -> NarrativeEvent {
+        let input = PlayerInput {
+            text: format!("turn {turn_number}"),
+            turn_number,
+        };
+        let sourced = ledger.source_command(session_id, &input).await.unwrap();
+        let e = event();
+        ledger.append_event(session_id, &sourced, &e).await.unwrap();
+        ledger
+            .commit_turn(
+                session_id,
+                &sourced,
+                &TurnCommitRecord {
+                    event_ids: vec![e.id],
+                    narrator_prose: format!("prose {turn_number}"),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        e
+    }
+
+    #[test]
+    fn policy_checkpoints_on_interval() {
+        let policy = CheckpointPolicy { interval_turns: 3 };
+        assert!(!policy.should_checkpoint(1));
+        assert!(!policy.should_checkpoint(2));
+        assert!(policy.should_checkpoint(3));
+        assert!(policy.should_checkpoint(6));
+
+        let every = CheckpointPolicy { interval_turns: 0 };
+        assert!(every.should_checkpoint(1));
+    }
+
+    #[tokio::test]
+    async fn truth_set_excludes_uncommitted_events() {
+        let ledger = InMemoryLedger::new();
+        let session_id = SessionId::new();
+        let committed = play_turn(&ledger, &session_id, 1).await;
+
+        // Turn 2 classifies an event, then the server dies before commit.
+        let sourced = ledger
+            .source_command(
+                &session_id,
+                &PlayerInput {
+                    text: "turn 2".to_string(),
+                    turn_number: 2,
+                },
+            )
+            .await
+            .unwrap();
+        ledger
+            .append_event(&session_id, &sourced, &event())
+            .await
+            .unwrap();
+
+        let mut truth = TruthSet::default();
+        let entries = ledger.entries_since(&session_id, None).await.unwrap();
+        replay(&mut truth, &entries).unwrap();
+
+        assert_eq!(truth.len(), 1);
+        assert_eq!(truth.events[0].id, committed.id);
+        assert_eq!(truth.pending.len(), 1);
+    }
+
+    #[test]
+    fn merge_committed_skips_redelivered_events() {
+        let mut truth = TruthSet::default();
+        let reaction = event();
+        assert_eq!(truth.merge_committed([reaction.clone()]), 1);
+        assert_eq!(truth.merge_committed([reaction, event()]), 1);
+        assert_eq!(truth.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn replay_reproduces_deferred_results() {
+        let ledger = InMemoryLedger::new();
+        let session_id = SessionId::new();
+        play_turn(&ledger, &session_id, 1).await;
+        let reaction = event();
+        let correlation_id = uuid::Uuid::now_v7();
+        for _ in 0..2 {
+            ledger
+                .record_deferred_result(
+                    &session_id,
+                    1,
+                    correlation_id,
+                    std::slice::from_ref(&reaction),
+                )
+                .await
+                .unwrap();
+        }
+
+        let mut truth = TruthSet::default();
+        let entries = ledger.entries_since(&session_id, None).await.unwrap();
+        assert_eq!(entries.len(), 4, "redelivery is recorded once");
+        replay(&mut truth, &entries).unwrap();
+        assert_eq!(truth.len(), 2);
+        assert_eq!(truth.events[1].id, reaction.id);
+    }
+
+    #[tokio::test]
+    async fn recover_without_checkpoint_is_none() {
+        let store = InMemoryCheckpointStore::<ProseState>::new();
+        let ledger = InMemoryLedger::new();
+        let recovered = recover(&store, &ledger, &SessionId::new()).await.unwrap();
+        assert!(recovered.is_none());
+    }
+
+    #[tokio::test]
+    async fn recover_replays_only_the_tail_after_the_checkpoint() {
+        let store = InMemoryCheckpointStore::new();
+        let ledger = InMemoryLedger::new();
+        let session_id = SessionId::new();
+
+        // Live state folds every entry as it is committed.
+        let mut live = ProseState::default();
+        play_turn(&ledger, &session_id, 1).await;
+        play_turn(&ledger, &session_id, 2).await;
+        let head = ledger.entries_since(&session_id, None).await.unwrap();
+        let checkpointed_at = replay(&mut live, &head).unwrap();
+        store
+            .write(&Checkpoint::new(
+                session_id,
+                2,
+                checkpointed_at,
+                live.clone(),
+            ))
+            .await
+            .unwrap();
+
+        play_turn(&ledger, &session_id, 3).await;
+        let tail = ledger
+            .entries_since(&session_id, checkpointed_at)
+            .await
+            .unwrap();
+        replay(&mut live, &tail).unwrap();
+
+        let recovered = recover(&store, &ledger, &session_id)
+            .await
+            .unwrap()
+            .expect("checkpoint exists");
+
+        assert_eq!(recovered.replayed, 3, "command, event, commit of turn 3");
+        assert_eq!(recovered.ledger_sequence, Some(LedgerSequence(9)));
+        assert_eq!(recovered.state.prose, live.prose);
+        assert_eq!(
+            serde_json::to_value(&recovered.state.truth).unwrap(),
+            serde_json::to_value(&live.truth).unwrap()
+        );
+    }
+}
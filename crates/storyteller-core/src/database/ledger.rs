@@ -9,3 +9,1099 @@
 //! Design decision: Command sourcing — player input is persisted to the event
 //! ledger BEFORE processing begins. Server crashes are recoverable via
 //! checkpoint + ledger replay.
+//!
+//! The ledger holds eight kinds of entry, all stamped with a per-session
+//! monotonic [`LedgerSequence`]:
+//!
+//! - [`LedgerEntryKind::Command`] — the player's input, sourced before the
+//!   turn pipeline runs
+//! - [`LedgerEntryKind::ClassifiedEvent`] — a classified narrative event
+//!   produced while processing that command
+//! - [`LedgerEntryKind::TurnCommit`] — the marker that the command's turn
+//!   completed
+//! - [`LedgerEntryKind::CommandAbandoned`] — the marker that the command's
+//!   turn never will, freeing the turn for new input
+//! - [`LedgerEntryKind::DeferredResult`] — events a deferred workflow
+//!   produced for an earlier turn, merged at a turn boundary
+//! - [`LedgerEntryKind::JournalCompressed`] — a model-written summary of a
+//!   committed turn's journal entry, produced after the turn
+//! - [`LedgerEntryKind::LayerEntered`] / [`LedgerEntryKind::LayerExited`] —
+//!   the session stepping into or out of a nested narrative layer between
+//!   turns
+//!
+//! Every append after the command is keyed by the [`CommandSourced`]
+//! receipt, which makes appends idempotent: re-sourcing the same command,
+//! re-appending an event with the same ID, or re-committing the same turn
+//! returns the original entry instead of writing a duplicate. A command
+//! without a matching turn commit is exactly what a crash mid-turn leaves
+//! behind — see [`SessionLedger::pending_command`]. Abandoning it (see
+//! [`SessionLedger::abandon_command`]) lets the player send something else
+//! for that turn.
+//!
+//! [`SessionLedger`] holds the ordering and idempotency rules for one
+//! session so every backend — [`InMemoryLedger`] here, file- or
+//! database-backed ledgers elsewhere — enforces them identically.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::errors::{StorytellerError, StorytellerResult};
+use crate::traits::storykeeper::{CommandSourced, SessionId};
+use crate::types::entity::EntityId;
+use crate::types::event::{EventId, NarrativeEvent};
+use crate::types::message::PlayerInput;
+use crate::types::narrative_layer::{LayerId, NarrativeLayer};
+use crate::types::narrator_context::JournalCompression;
+use crate::types::prediction::CharacterPrediction;
+use crate::types::resolver::StateChange;
+
+// ---------------------------------------------------------------------------
+// Entry types
+// ---------------------------------------------------------------------------
+
+/// Position of an entry in a session's ledger.
+///
+/// Sequences start at 1 and increase by exactly 1 per appended entry.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct LedgerSequence(pub u64);
+
+impl LedgerSequence {
+    /// The sequence assigned to the first entry of every session.
+    pub const FIRST: Self = Self(1);
+
+    /// The sequence that follows this one.
+    pub fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+/// What a ledger entry records.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LedgerEntryKind {
+    /// Player input, durably recorded before processing.
+    Command {
+        /// The input exactly as the player sent it.
+        input: PlayerInput,
+    },
+    /// A classified event produced while processing a command.
+    ClassifiedEvent {
+        /// The classified event. Boxed — atoms dwarf the other variants.
+        event: Box<NarrativeEvent>,
+    },
+    /// The turn for a command completed and was committed.
+    TurnCommit {
+        /// What the turn produced.
+        commit: TurnCommitRecord,
+    },
+    /// The turn's latest command will never commit — it failed, or was
+    /// interrupted and superseded. Events classified for it are discarded.
+    CommandAbandoned {
+        /// Why the command was abandoned.
+        reason: String,
+    },
+    /// A deferred workflow dispatched by an earlier turn finished; its
+    /// events are committed directly, outside any turn.
+    DeferredResult {
+        /// Matches the result to the request that produced it.
+        correlation_id: Uuid,
+        /// Events the workflow produced.
+        events: Vec<NarrativeEvent>,
+    },
+    /// A committed turn's journal entry was summarized after the turn.
+    JournalCompressed {
+        /// The summary and skeleton renderings of the entry.
+        compression: JournalCompression,
+    },
+    /// The session entered a nested narrative layer after the turn.
+    LayerEntered {
+        /// The layer as entered, with an empty journal.
+        layer: NarrativeLayer,
+    },
+    /// The session left its innermost narrative layer after the turn.
+    LayerExited {
+        /// The layer left.
+        layer_id: LayerId,
+    },
+}
+
+/// Summary of a committed turn, recorded as the turn's final ledger entry.
+///
+/// Carries everything the turn changed in session state that cannot be
+/// recomputed deterministically (model outputs, extracted markers), so
+/// checkpoint recovery can replay the turn without re-running the pipeline.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct TurnCommitRecord {
+    /// Classified events committed with this turn, in append order.
+    pub event_ids: Vec<EventId>,
+    /// The narrator prose the player saw for this turn.
+    pub narrator_prose: String,
+    /// The player's entity in the scene, if one was cast.
+    #[serde(default)]
+    pub player_entity_id: Option<EntityId>,
+    /// Entities referenced by the turn's journal entry.
+    #[serde(default)]
+    pub referenced_entities: Vec<EntityId>,
+    /// Emotional markers extracted from the player's input.
+    #[serde(default)]
+    pub emotional_markers: Vec<String>,
+    /// Character predictions produced for this turn.
+    #[serde(default)]
+    pub predictions: Vec<CharacterPrediction>,
+    /// State changes from the turn's resolved actions, applied to the live
+    /// cast state on commit.
+    #[serde(default)]
+    pub state_changes: Vec<StateChange>,
+}
+
+/// A single append-only ledger entry.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LedgerEntry {
+    /// Monotonic position within the session.
+    pub sequence: LedgerSequence,
+    /// The session this entry belongs to.
+    pub session_id: SessionId,
+    /// The turn this entry belongs to.
+    pub turn_number: u32,
+    /// When the entry was appended.
+    pub recorded_at: DateTime<Utc>,
+    /// What the entry records.
+    #[serde(flatten)]
+    pub kind: LedgerEntryKind,
+}
+
+/// Outcome of an append against a [`SessionLedger`].
+#[derive(Debug, Clone)]
+pub struct LedgerAppend {
+    /// The entry now in the ledger — newly appended or previously recorded.
+    pub entry: LedgerEntry,
+    /// `false` when the append was an idempotent repeat and nothing was written.
+    pub newly_appended: bool,
+}
+
+// ---------------------------------------------------------------------------
+// SessionLedger — ordering and idempotency rules for one session
+// ---------------------------------------------------------------------------
+
+/// The ledger for one session: entries in sequence order plus the
+/// idempotency rules every backend shares.
+///
+/// Backends that persist entries call the append methods, write the entry
+/// only when [`LedgerAppend::newly_appended`] is set, and rebuild state on
+/// startup with [`SessionLedger::from_entries`].
+#[derive(Debug, Clone)]
+pub struct SessionLedger {
+    session_id: SessionId,
+    entries: Vec<LedgerEntry>,
+}
+
+impl SessionLedger {
+    /// An empty ledger for a session.
+    pub fn new(session_id: SessionId) -> Self {
+        Self {
+            session_id,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Rebuild a ledger from previously persisted entries.
+    ///
+    /// Fails if the entries belong to another session or their sequences
+    /// are not exactly `1, 2, 3, …` — a gap means the persisted log is
+    /// damaged and replaying it would silently lose history.
+    pub fn from_entries(
+        session_id: SessionId,
+        entries: Vec<LedgerEntry>,
+    ) -> StorytellerResult<Self> {
+        let mut expected = LedgerSequence::FIRST;
+        for entry in &entries {
+            if entry.session_id != session_id {
+                return Err(StorytellerError::Ledger(format!(
+                    "entry {} belongs to session {}, not {}",
+                    entry.sequence.0, entry.session_id.0, session_id.0
+                )));
+            }
+            if entry.sequence != expected {
+                return Err(StorytellerError::Ledger(format!(
+                    "sequence gap: expected {}, found {}",
+                    expected.0, entry.sequence.0
+                )));
+            }
+            expected = expected.next();
+        }
+        Ok(Self {
+            session_id,
+            entries,
+        })
+    }
+
+    /// The session this ledger belongs to.
+    pub fn session_id(&self) -> SessionId {
+        self.session_id
+    }
+
+    /// All entries in sequence order.
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    /// The most recently assigned sequence, if any entry exists.
+    pub fn last_sequence(&self) -> Option<LedgerSequence> {
+        self.entries.last().map(|e| e.sequence)
+    }
+
+    /// Entries strictly after `after`, or every entry when `after` is `None`.
+    pub fn entries_since(&self, after: Option<LedgerSequence>) -> &[LedgerEntry] {
+        match after {
+            // Sequences are dense from 1, so sequence N sits at index N - 1.
+            Some(seq) => {
+                let start = (seq.0 as usize).min(self.entries.len());
+                &self.entries[start..]
+            }
+            None => &self.entries,
+        }
+    }
+
+    /// Durably record a player command for its turn.
+    ///
+    /// Re-sourcing the same text for an already-sourced turn returns the
+    /// original receipt. Sourcing different text for that turn is an error:
+    /// the ledger already says what the player sent. Once the turn's
+    /// command is abandoned, any input may be sourced for it again.
+    pub fn source_command(
+        &mut self,
+        input: &PlayerInput,
+    ) -> StorytellerResult<(CommandSourced, LedgerAppend)> {
+        if let Some(existing) = self
+            .command_entry(input.turn_number)
+            .filter(|e| !self.is_abandoned(e))
+        {
+            let LedgerEntryKind::Command { input: recorded } = &existing.kind else {
+                unreachable!("command_entry only returns command entries");
+            };
+            if recorded.text != input.text {
+                return Err(StorytellerError::Ledger(format!(
+                    "turn {} already sourced with different input",
+                    input.turn_number
+                )));
+            }
+            let existing = existing.clone();
+            return Ok((
+                receipt(&existing),
+                LedgerAppend {
+                    entry: existing,
+                    newly_appended: false,
+                },
+            ));
+        }
+
+        let append = self.push(
+            input.turn_number,
+            LedgerEntryKind::Command {
+                input: input.clone(),
+            },
+        );
+        Ok((receipt(&append.entry), append))
+    }
+
+    /// Append a classified event produced while processing `command`.
+    ///
+    /// Idempotent on the event ID. Fails if the command was never sourced
+    /// or its turn is already committed.
+    pub fn append_event(
+        &mut self,
+        command: &CommandSourced,
+        event: &NarrativeEvent,
+    ) -> StorytellerResult<LedgerAppend> {
+        self.require_command(command)?;
+
+        let existing = self.entries.iter().find(|e| {
+            e.turn_number == command.turn_number
+                && matches!(&e.kind, LedgerEntryKind::ClassifiedEvent { event: recorded } if recorded.id == event.id)
+        });
+        if let Some(existing) = existing {
+            return Ok(LedgerAppend {
+                entry: existing.clone(),
+                newly_appended: false,
+            });
+        }
+
+        if self.commit_entry(command.turn_number).is_some() {
+            return Err(StorytellerError::Ledger(format!(
+                "turn {} is already committed",
+                command.turn_number
+            )));
+        }
+
+        Ok(self.push(
+            command.turn_number,
+            LedgerEntryKind::ClassifiedEvent {
+                event: Box::new(event.clone()),
+            },
+        ))
+    }
+
+    /// Record that the turn for `command` completed.
+    ///
+    /// Committing the same turn twice returns the original commit entry.
+    pub fn commit_turn(
+        &mut self,
+        command: &CommandSourced,
+        commit: &TurnCommitRecord,
+    ) -> StorytellerResult<LedgerAppend> {
+        self.require_command(command)?;
+
+        if let Some(existing) = self.commit_entry(command.turn_number) {
+            return Ok(LedgerAppend {
+                entry: existing.clone(),
+                newly_appended: false,
+            });
+        }
+
+        Ok(self.push(
+            command.turn_number,
+            LedgerEntryKind::TurnCommit {
+                commit: commit.clone(),
+            },
+        ))
+    }
+
+    /// Record that the turn for `command` will never commit.
+    ///
+    /// Abandoning the same command twice returns the original entry. Fails
+    /// if the turn is already committed.
+    pub fn abandon_command(
+        &mut self,
+        command: &CommandSourced,
+        reason: &str,
+    ) -> StorytellerResult<LedgerAppend> {
+        if let Some(existing) = self
+            .command_entry(command.turn_number)
+            .filter(|e| e.recorded_at == command.sourced_at)
+            .and_then(|e| self.abandonment_of(e))
+        {
+            return Ok(LedgerAppend {
+                entry: existing.clone(),
+                newly_appended: false,
+            });
+        }
+        self.require_command(command)?;
+
+        if self.commit_entry(command.turn_number).is_some() {
+            return Err(StorytellerError::Ledger(format!(
+                "turn {} is already committed",
+                command.turn_number
+            )));
+        }
+
+        Ok(self.push(
+            command.turn_number,
+            LedgerEntryKind::CommandAbandoned {
+                reason: reason.to_string(),
+            },
+        ))
+    }
+
+    /// Record a deferred workflow result for the turn that dispatched it.
+    ///
+    /// Idempotent on the correlation id, so a redelivered result is
+    /// recorded once.
+    pub fn record_deferred_result(
+        &mut self,
+        turn_number: u32,
+        correlation_id: Uuid,
+        events: &[NarrativeEvent],
+    ) -> LedgerAppend {
+        let existing = self.entries.iter().find(|e| {
+            matches!(&e.kind, LedgerEntryKind::DeferredResult { correlation_id: recorded, .. } if *recorded == correlation_id)
+        });
+        if let Some(existing) = existing {
+            return LedgerAppend {
+                entry: existing.clone(),
+                newly_appended: false,
+            };
+        }
+
+        self.push(
+            turn_number,
+            LedgerEntryKind::DeferredResult {
+                correlation_id,
+                events: events.to_vec(),
+            },
+        )
+    }
+
+    /// Record the compression of a turn's journal entry.
+    ///
+    /// The first compression recorded for a turn stands; later ones return
+    /// the original entry.
+    pub fn record_journal_compression(
+        &mut self,
+        turn_number: u32,
+        compression: &JournalCompression,
+    ) -> LedgerAppend {
+        let existing = self.entries.iter().find(|e| {
+            e.turn_number == turn_number
+                && matches!(e.kind, LedgerEntryKind::JournalCompressed { .. })
+        });
+        if let Some(existing) = existing {
+            return LedgerAppend {
+                entry: existing.clone(),
+                newly_appended: false,
+            };
+        }
+
+        self.push(
+            turn_number,
+            LedgerEntryKind::JournalCompressed {
+                compression: compression.clone(),
+            },
+        )
+    }
+
+    /// Record that the session entered `layer` after `turn_number`.
+    ///
+    /// Idempotent on the layer id.
+    pub fn record_layer_entry(&mut self, turn_number: u32, layer: &NarrativeLayer) -> LedgerAppend {
+        let existing = self.entries.iter().find(|e| {
+            matches!(&e.kind, LedgerEntryKind::LayerEntered { layer: entered } if entered.id == layer.id)
+        });
+        if let Some(existing) = existing {
+            return LedgerAppend {
+                entry: existing.clone(),
+                newly_appended: false,
+            };
+        }
+
+        self.push(
+            turn_number,
+            LedgerEntryKind::LayerEntered {
+                layer: layer.clone(),
+            },
+        )
+    }
+
+    /// Record that the session left layer `layer_id` after `turn_number`.
+    ///
+    /// Idempotent on the layer id. Fails unless `layer_id` is the innermost
+    /// layer still entered — layers unwind in order.
+    pub fn record_layer_exit(
+        &mut self,
+        turn_number: u32,
+        layer_id: LayerId,
+    ) -> StorytellerResult<LedgerAppend> {
+        let existing = self.entries.iter().find(|e| {
+            matches!(&e.kind, LedgerEntryKind::LayerExited { layer_id: left } if *left == layer_id)
+        });
+        if let Some(existing) = existing {
+            return Ok(LedgerAppend {
+                entry: existing.clone(),
+                newly_appended: false,
+            });
+        }
+        if self.open_layers().last() != Some(&layer_id) {
+            return Err(StorytellerError::Scene(format!(
+                "layer {} is not the innermost entered layer",
+                layer_id.0
+            )));
+        }
+
+        Ok(self.push(turn_number, LedgerEntryKind::LayerExited { layer_id }))
+    }
+
+    /// The most recent command whose turn never committed, if any.
+    ///
+    /// After a crash this is the input the player sent that the engine
+    /// never finished processing. Abandoned commands are not pending.
+    pub fn pending_command(&self) -> Option<(CommandSourced, PlayerInput)> {
+        let entry = self
+            .entries
+            .iter()
+            .rev()
+            .find(|e| matches!(e.kind, LedgerEntryKind::Command { .. }))?;
+        if self.commit_entry(entry.turn_number).is_some() || self.is_abandoned(entry) {
+            return None;
+        }
+        let LedgerEntryKind::Command { input } = &entry.kind else {
+            unreachable!("filtered to command entries");
+        };
+        Some((receipt(entry), input.clone()))
+    }
+
+    fn push(&mut self, turn_number: u32, kind: LedgerEntryKind) -> LedgerAppend {
+        let sequence = self
+            .last_sequence()
+            .map(LedgerSequence::next)
+            .unwrap_or(LedgerSequence::FIRST);
+        let entry = LedgerEntry {
+            sequence,
+            session_id: self.session_id,
+            turn_number,
+            recorded_at: Utc::now(),
+            kind,
+        };
+        self.entries.push(entry.clone());
+        LedgerAppend {
+            entry,
+            newly_appended: true,
+        }
+    }
+
+    /// Layers entered and not yet exited, outermost first.
+    fn open_layers(&self) -> Vec<LayerId> {
+        let mut open = Vec::new();
+        for entry in &self.entries {
+            match &entry.kind {
+                LedgerEntryKind::LayerEntered { layer } => open.push(layer.id),
+                LedgerEntryKind::LayerExited { .. } => {
+                    open.pop();
+                }
+                _ => {}
+            }
+        }
+        open
+    }
+
+    /// The turn's latest command — earlier ones were abandoned.
+    fn command_entry(&self, turn_number: u32) -> Option<&LedgerEntry> {
+        self.entries.iter().rev().find(|e| {
+            e.turn_number == turn_number && matches!(e.kind, LedgerEntryKind::Command { .. })
+        })
+    }
+
+    /// The entry abandoning `command`, if it was abandoned.
+    fn abandonment_of(&self, command: &LedgerEntry) -> Option<&LedgerEntry> {
+        self.entries_since(Some(command.sequence))
+            .iter()
+            .take_while(|e| !matches!(e.kind, LedgerEntryKind::Command { .. }))
+            .find(|e| {
+                e.turn_number == command.turn_number
+                    && matches!(e.kind, LedgerEntryKind::CommandAbandoned { .. })
+            })
+    }
+
+    fn is_abandoned(&self, command: &LedgerEntry) -> bool {
+        self.abandonment_of(command).is_some()
+    }
+
+    fn commit_entry(&self, turn_number: u32) -> Option<&LedgerEntry> {
+        self.entries.iter().find(|e| {
+            e.turn_number == turn_number && matches!(e.kind, LedgerEntryKind::TurnCommit { .. })
+        })
+    }
+
+    fn require_command(&self, command: &CommandSourced) -> StorytellerResult<()> {
+        match self.command_entry(command.turn_number) {
+            Some(entry) if entry.recorded_at == command.sourced_at => {
+                if self.is_abandoned(entry) {
+                    Err(StorytellerError::Ledger(format!(
+                        "command for turn {} was abandoned",
+                        command.turn_number
+                    )))
+                } else {
+                    Ok(())
+                }
+            }
+            Some(_) => Err(StorytellerError::Ledger(format!(
+                "receipt for turn {} does not match the sourced command",
+                command.turn_number
+            ))),
+            None => Err(StorytellerError::Ledger(format!(
+                "no command sourced for turn {}",
+                command.turn_number
+            ))),
+        }
+    }
+}
+
+/// The `CommandSourced` receipt for a command entry.
+fn receipt(entry: &LedgerEntry) -> CommandSourced {
+    CommandSourced {
+        turn_number: entry.turn_number,
+        sourced_at: entry.recorded_at,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Trait: EventLedger
+// ---------------------------------------------------------------------------
+
+/// An append-only, command-sourced event ledger.
+///
+/// Implementations persist entries however they like, but must delegate
+/// ordering and idempotency to [`SessionLedger`] so that every backend
+/// assigns the same sequences for the same appends.
+#[async_trait::async_trait]
+pub trait EventLedger: Send + Sync + std::fmt::Debug {
+    /// Durably store player input before processing begins.
+    async fn source_command(
+        &self,
+        session_id: &SessionId,
+        input: &PlayerInput,
+    ) -> StorytellerResult<CommandSourced>;
+
+    /// Append a classified event produced while processing `command`.
+    async fn append_event(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        event: &NarrativeEvent,
+    ) -> StorytellerResult<LedgerSequence>;
+
+    /// Record that the turn for `command` completed.
+    async fn commit_turn(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        commit: &TurnCommitRecord,
+    ) -> StorytellerResult<LedgerSequence>;
+
+    /// Record that the turn for `command` will never commit.
+    async fn abandon_command(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        reason: &str,
+    ) -> StorytellerResult<LedgerSequence>;
+
+    /// Record a deferred workflow result for the turn that dispatched it.
+    async fn record_deferred_result(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        correlation_id: Uuid,
+        events: &[NarrativeEvent],
+    ) -> StorytellerResult<LedgerSequence>;
+
+    /// Record the compression of a committed turn's journal entry.
+    async fn record_journal_compression(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        compression: &JournalCompression,
+    ) -> StorytellerResult<LedgerSequence>;
+
+    /// Record that the session entered a nested narrative layer.
+    async fn record_layer_entry(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        layer: &NarrativeLayer,
+    ) -> StorytellerResult<LedgerSequence>;
+
+    /// Record that the session left its innermost narrative layer.
+    async fn record_layer_exit(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        layer_id: LayerId,
+    ) -> StorytellerResult<LedgerSequence>;
+
+    /// Entries strictly after `after`, or every entry when `after` is `None`.
+    async fn entries_since(
+        &self,
+        session_id: &SessionId,
+        after: Option<LedgerSequence>,
+    ) -> StorytellerResult<Vec<LedgerEntry>>;
+
+    /// The most recent command whose turn never committed, if any.
+    async fn pending_command(
+        &self,
+        session_id: &SessionId,
+    ) -> StorytellerResult<Option<(CommandSourced, PlayerInput)>> {
+        let entries = self.entries_since(session_id, None).await?;
+        Ok(SessionLedger::from_entries(*session_id, entries)?.pending_command())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// InMemoryLedger
+// ---------------------------------------------------------------------------
+
+/// In-process `EventLedger` for tests and ephemeral sessions.
+#[derive(Debug, Default)]
+pub struct InMemoryLedger {
+    sessions: Mutex<HashMap<SessionId, SessionLedger>>,
+}
+
+impl InMemoryLedger {
+    /// Create an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_session<T>(
+        &self,
+        session_id: &SessionId,
+        f: impl FnOnce(&mut SessionLedger) -> StorytellerResult<T>,
+    ) -> StorytellerResult<T> {
+        let mut sessions = self.sessions.lock().expect("ledger lock poisoned");
+        let ledger = sessions
+            .entry(*session_id)
+            .or_insert_with(|| SessionLedger::new(*session_id));
+        f(ledger)
+    }
+}
+
+#[async_trait::async_trait]
+impl EventLedger for InMemoryLedger {
+    async fn source_command(
+        &self,
+        session_id: &SessionId,
+        input: &PlayerInput,
+    ) -> StorytellerResult<CommandSourced> {
+        self.with_session(session_id, |ledger| {
+            ledger.source_command(input).map(|(sourced, _)| sourced)
+        })
+    }
+
+    async fn append_event(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        event: &NarrativeEvent,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.with_session(session_id, |ledger| {
+            ledger
+                .append_event(command, event)
+                .map(|append| append.entry.sequence)
+        })
+    }
+
+    async fn commit_turn(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        commit: &TurnCommitRecord,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.with_session(session_id, |ledger| {
+            ledger
+                .commit_turn(command, commit)
+                .map(|append| append.entry.sequence)
+        })
+    }
+
+    async fn abandon_command(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        reason: &str,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.with_session(session_id, |ledger| {
+            ledger
+                .abandon_command(command, reason)
+                .map(|append| append.entry.sequence)
+        })
+    }
+
+    async fn record_deferred_result(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        correlation_id: Uuid,
+        events: &[NarrativeEvent],
+    ) -> StorytellerResult<LedgerSequence> {
+        self.with_session(session_id, |ledger| {
+            Ok(ledger
+                .record_deferred_result(turn_number, correlation_id, events)
+                .entry
+                .sequence)
+        })
+    }
+
+    async fn record_journal_compression(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        compression: &JournalCompression,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.with_session(session_id, |ledger| {
+            Ok(ledger
+                .record_journal_compression(turn_number, compression)
+                .entry
+                .sequence)
+        })
+    }
+
+    async fn record_layer_entry(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        layer: &NarrativeLayer,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.with_session(session_id, |ledger| {
+            Ok(ledger.record_layer_entry(turn_number, layer).entry.sequence)
+        })
+    }
+
+    async fn record_layer_exit(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        layer_id: LayerId,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.with_session(session_id, |ledger| {
+            ledger
+                .record_layer_exit(turn_number, layer_id)
+                .map(|append| append.entry.sequence)
+        })
+    }
+
+    async fn entries_since(
+        &self,
+        session_id: &SessionId,
+        after: Option<LedgerSequence>,
+    ) -> StorytellerResult<Vec<LedgerEntry>> {
+        self.with_session(session_id, |ledger| {
+            Ok(ledger.entries_since(after).to_vec())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::event::EventPriority;
+    use crate::types::event_grammar::EventPayload;
+
+    fn input(turn_number: u32, text: &str) -> PlayerInput {
+        PlayerInput {
+            text: text.to_string(),
+            turn_number,
+        }
+    }
+
+    fn event() -> NarrativeEvent {
+        NarrativeEvent {
+            id: EventId::new(),
+            timestamp: Utc::now(),
+            priority: EventPriority::Normal,
+            payload: EventPayload::Untyped(serde_json::json!({"kind": "test"})),
+        }
+    }
+
+    fn commit(event_ids: Vec<EventId>) -> TurnCommitRecord {
+        TurnCommitRecord {
+            event_ids,
+            narrator_prose: "The gate creaks open.".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sequences_are_dense_and_monotonic() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (sourced, first) = ledger.source_command(&input(1, "open the gate")).unwrap();
+        let second = ledger.append_event(&sourced, &event()).unwrap();
+        let third = ledger.commit_turn(&sourced, &commit(vec![])).unwrap();
+
+        assert_eq!(first.entry.sequence, LedgerSequence(1));
+        assert_eq!(second.entry.sequence, LedgerSequence(2));
+        assert_eq!(third.entry.sequence, LedgerSequence(3));
+        assert_eq!(ledger.last_sequence(), Some(LedgerSequence(3)));
+    }
+
+    #[test]
+    fn resourcing_same_command_is_idempotent() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (first, _) = ledger.source_command(&input(1, "open the gate")).unwrap();
+        let (second, append) = ledger.source_command(&input(1, "open the gate")).unwrap();
+
+        assert!(!append.newly_appended);
+        assert_eq!(first.sourced_at, second.sourced_at);
+        assert_eq!(ledger.entries().len(), 1);
+    }
+
+    #[test]
+    fn resourcing_different_text_for_turn_fails() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        ledger.source_command(&input(1, "open the gate")).unwrap();
+        let err = ledger
+            .source_command(&input(1, "climb the wall"))
+            .unwrap_err();
+        assert!(matches!(err, StorytellerError::Ledger(_)));
+    }
+
+    #[test]
+    fn event_append_is_idempotent_on_event_id() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (sourced, _) = ledger.source_command(&input(1, "look")).unwrap();
+        let e = event();
+
+        let first = ledger.append_event(&sourced, &e).unwrap();
+        let again = ledger.append_event(&sourced, &e).unwrap();
+
+        assert!(first.newly_appended);
+        assert!(!again.newly_appended);
+        assert_eq!(first.entry.sequence, again.entry.sequence);
+        assert_eq!(ledger.entries().len(), 2);
+    }
+
+    #[test]
+    fn append_requires_sourced_command() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let unsourced = CommandSourced {
+            turn_number: 4,
+            sourced_at: Utc::now(),
+        };
+        assert!(ledger.append_event(&unsourced, &event()).is_err());
+        assert!(ledger.commit_turn(&unsourced, &commit(vec![])).is_err());
+    }
+
+    #[test]
+    fn stale_receipt_is_rejected() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (sourced, _) = ledger.source_command(&input(1, "look")).unwrap();
+        let stale = CommandSourced {
+            turn_number: sourced.turn_number,
+            sourced_at: sourced.sourced_at - chrono::Duration::seconds(1),
+        };
+        assert!(ledger.append_event(&stale, &event()).is_err());
+    }
+
+    #[test]
+    fn commit_is_idempotent_and_closes_turn() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (sourced, _) = ledger.source_command(&input(1, "look")).unwrap();
+        let first = ledger.commit_turn(&sourced, &commit(vec![])).unwrap();
+        let again = ledger.commit_turn(&sourced, &commit(vec![])).unwrap();
+
+        assert!(!again.newly_appended);
+        assert_eq!(first.entry.sequence, again.entry.sequence);
+        assert!(ledger.append_event(&sourced, &event()).is_err());
+    }
+
+    #[test]
+    fn pending_command_reports_uncommitted_turn() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (t1, _) = ledger.source_command(&input(1, "look")).unwrap();
+        ledger.commit_turn(&t1, &commit(vec![])).unwrap();
+        assert!(ledger.pending_command().is_none());
+
+        ledger.source_command(&input(2, "run")).unwrap();
+        let (pending, recorded) = ledger.pending_command().unwrap();
+        assert_eq!(pending.turn_number, 2);
+        assert_eq!(recorded.text, "run");
+    }
+
+    #[test]
+    fn abandoned_command_frees_turn_for_new_input() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (failed, _) = ledger.source_command(&input(1, "open the gate")).unwrap();
+        ledger.append_event(&failed, &event()).unwrap();
+
+        let first = ledger.abandon_command(&failed, "narrator failed").unwrap();
+        let again = ledger.abandon_command(&failed, "narrator failed").unwrap();
+        assert!(!again.newly_appended);
+        assert_eq!(first.entry.sequence, again.entry.sequence);
+        assert!(ledger.pending_command().is_none());
+        assert!(ledger.append_event(&failed, &event()).is_err());
+        assert!(ledger.commit_turn(&failed, &commit(vec![])).is_err());
+
+        let (retry, _) = ledger.source_command(&input(1, "climb the wall")).unwrap();
+        assert_eq!(retry.turn_number, 1);
+        assert_ne!(retry.sourced_at, failed.sourced_at);
+        assert_eq!(ledger.pending_command().unwrap().1.text, "climb the wall");
+        assert!(
+            ledger.abandon_command(&failed, "stale").is_err(),
+            "the stale receipt no longer names the turn's command"
+        );
+        ledger.commit_turn(&retry, &commit(vec![])).unwrap();
+        assert!(ledger.abandon_command(&retry, "too late").is_err());
+    }
+
+    #[test]
+    fn entries_since_skips_through_sequence() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (sourced, _) = ledger.source_command(&input(1, "look")).unwrap();
+        ledger.append_event(&sourced, &event()).unwrap();
+        ledger.commit_turn(&sourced, &commit(vec![])).unwrap();
+
+        assert_eq!(ledger.entries_since(None).len(), 3);
+        let tail = ledger.entries_since(Some(LedgerSequence(1)));
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail[0].sequence, LedgerSequence(2));
+        assert!(ledger.entries_since(Some(LedgerSequence(9))).is_empty());
+    }
+
+    #[test]
+    fn from_entries_rejects_gaps() {
+        let session_id = SessionId::new();
+        let mut ledger = SessionLedger::new(session_id);
+        let (sourced, _) = ledger.source_command(&input(1, "look")).unwrap();
+        ledger.append_event(&sourced, &event()).unwrap();
+
+        let mut entries = ledger.entries().to_vec();
+        assert!(SessionLedger::from_entries(session_id, entries.clone()).is_ok());
+
+        entries.remove(0);
+        assert!(SessionLedger::from_entries(session_id, entries).is_err());
+    }
+
+    #[test]
+    fn entry_serde_roundtrip() {
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let (sourced, append) = ledger.source_command(&input(1, "look")).unwrap();
+        let json = serde_json::to_string(&append.entry).unwrap();
+        assert!(json.contains("\"kind\":\"command\""));
+
+        let back: LedgerEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.sequence, LedgerSequence(1));
+        assert_eq!(back.recorded_at, sourced.sourced_at);
+    }
+
+    #[test]
+    fn layer_exits_unwind_in_order_and_are_idempotent() {
+        use crate::types::narrative_layer::LayerKind;
+        use crate::types::scene::SceneId;
+
+        let mut ledger = SessionLedger::new(SessionId::new());
+        let scene_id = SceneId::new();
+        let memory = NarrativeLayer::new(LayerKind::Memory, "The Winter Road", scene_id);
+        let dream = NarrativeLayer::new(LayerKind::Dream, "Drowned Bells", scene_id);
+        ledger.record_layer_entry(1, &memory);
+        assert!(!ledger.record_layer_entry(1, &memory).newly_appended);
+        ledger.record_layer_entry(2, &dream);
+
+        assert!(ledger.record_layer_exit(3, memory.id).is_err());
+        assert!(
+            ledger
+                .record_layer_exit(3, dream.id)
+                .unwrap()
+                .newly_appended
+        );
+        assert!(
+            !ledger
+                .record_layer_exit(3, dream.id)
+                .unwrap()
+                .newly_appended
+        );
+        assert!(
+            ledger
+                .record_layer_exit(3, memory.id)
+                .unwrap()
+                .newly_appended
+        );
+        assert_eq!(ledger.entries().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn in_memory_ledger_isolates_sessions() {
+        let ledger = InMemoryLedger::new();
+        let a = SessionId::new();
+        let b = SessionId::new();
+
+        let sourced = ledger.source_command(&a, &input(1, "look")).await.unwrap();
+        ledger.append_event(&a, &sourced, &event()).await.unwrap();
+
+        assert_eq!(ledger.entries_since(&a, None).await.unwrap().len(), 2);
+        assert!(ledger.entries_since(&b, None).await.unwrap().is_empty());
+        assert!(ledger.pending_command(&a).await.unwrap().is_some());
+        assert!(ledger.pending_command(&b).await.unwrap().is_none());
+    }
+}
@@ -37,6 +37,26 @@ pub enum StorytellerError {
     #[error("llm error: {0}")]
     Llm(String),
 
+    /// LLM provider rejected the request under its rate limit. `retry_after`
+    /// carries the provider's suggested delay when it sent one.
+    #[error("llm rate limited: {message}")]
+    LlmRateLimited {
+        message: String,
+        retry_after: Option<std::time::Duration>,
+    },
+
+    /// LLM provider is temporarily overloaded or unavailable.
+    #[error("llm overloaded: {0}")]
+    LlmOverloaded(String),
+
+    /// Event ledger append violated ordering or idempotency rules.
+    #[error("ledger error: {0}")]
+    Ledger(String),
+
+    /// Checkpoint could not be written or restored.
+    #[error("checkpoint error: {0}")]
+    Checkpoint(String),
+
     /// Graph query failed.
     #[error("graph error: {0}")]
     Graph(String),
@@ -54,5 +74,13 @@ pub enum StorytellerError {
     Other(#[from] anyhow::Error),
 }
 
+impl StorytellerError {
+    /// Whether the failure is transient and the same request may succeed if
+    /// retried later (rate limiting, provider overload).
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::LlmRateLimited { .. } | Self::LlmOverloaded(_))
+    }
+}
+
 /// Convenience alias used throughout the storyteller crates.
 pub type StorytellerResult<T> = Result<T, StorytellerError>;
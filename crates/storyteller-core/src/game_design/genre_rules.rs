@@ -0,0 +1,472 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Genre rules for action arbitration — typed constraints and the capability
+//! lexicon that lets arbitration recognize them in player input.
+//!
+//! See: `docs/plans/2026-03-09-event-classification-and-action-arbitration-design.md`
+//!
+//! A genre's world affordances (what its physics permit: magic, technology,
+//! violence, death, the supernatural) and its ontological postures are
+//! bedrock data. [`GenreRules::derive`] turns them into [`GenreConstraint`]s
+//! plus a seed [`CapabilityLexicon`] covering every constrained capability.
+//! Rules are derived once per composition — the lexicon can then be expanded
+//! at authoring time — and handed to arbitration every turn.
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::StorytellerResult;
+use crate::traits::bedrock::BedrockQuery;
+use crate::types::bedrock::{GenreContext, OntologicalPostureRecord};
+use crate::types::capability_lexicon::{CapabilityLexicon, LexiconEntry};
+use crate::types::world_model::GenreConstraint;
+
+/// What is possible in a genre's physics.
+///
+/// Values follow the narrative-data region schema — e.g. magic is one of
+/// `absent`, `subtle`, `rule-bound`, `wild-mythic`. Unrecognized or empty
+/// values constrain nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorldAffordances {
+    /// `absent` / `subtle` / `rule-bound` / `wild-mythic`.
+    pub magic: String,
+    /// `historical` / `contemporary` / `speculative` / `post-human`.
+    pub technology: String,
+    /// `consequence-laden` / `stylized` / `absent`.
+    pub violence: String,
+    /// `permanent` / `negotiable` / `metaphorical`.
+    pub death: String,
+    /// `nonexistent` / `ambiguous` / `matter-of-fact`.
+    pub supernatural: String,
+}
+
+impl WorldAffordances {
+    /// Read affordances from a genre's bedrock payloads — the dimensional
+    /// analysis first, then the genre record itself.
+    pub fn from_genre_context(context: &GenreContext) -> Option<Self> {
+        context
+            .dimensions
+            .as_ref()
+            .and_then(|d| d.payload.get("world_affordances"))
+            .or_else(|| context.genre.payload.get("world_affordances"))
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+
+    /// Affordances for the built-in genres, keyed by genre ID or slug.
+    pub fn builtin(genre_id: &str) -> Option<Self> {
+        let preset = |magic: &str, technology: &str, violence: &str, death: &str, sn: &str| Self {
+            magic: magic.to_string(),
+            technology: technology.to_string(),
+            violence: violence.to_string(),
+            death: death.to_string(),
+            supernatural: sn.to_string(),
+        };
+        match genre_id.replace('-', "_").as_str() {
+            "low_fantasy_folklore" => Some(preset(
+                "subtle",
+                "historical",
+                "consequence-laden",
+                "permanent",
+                "ambiguous",
+            )),
+            "dark_fantasy" => Some(preset(
+                "rule-bound",
+                "historical",
+                "consequence-laden",
+                "permanent",
+                "matter-of-fact",
+            )),
+            "folk_horror" => Some(preset(
+                "subtle",
+                "historical",
+                "consequence-laden",
+                "permanent",
+                "ambiguous",
+            )),
+            "sci_fi_noir" => Some(preset(
+                "absent",
+                "speculative",
+                "consequence-laden",
+                "permanent",
+                "nonexistent",
+            )),
+            "cozy_ghost_story" => Some(preset(
+                "absent",
+                "contemporary",
+                "absent",
+                "negotiable",
+                "matter-of-fact",
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Normalize an affordance value: `"Rule Bound"` → `"rule-bound"`.
+fn normalized(value: &str) -> String {
+    value.trim().to_lowercase().replace(['_', ' '], "-")
+}
+
+/// Typed constraints and the lexicon arbitration matches them with.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GenreRules {
+    /// Constraints checked against every player action.
+    pub constraints: Vec<GenreConstraint>,
+    /// Natural-language terms for each constrained capability.
+    pub lexicon: CapabilityLexicon,
+}
+
+impl GenreRules {
+    /// Derive rules from a genre's affordances and ontological postures.
+    ///
+    /// Every affordance becomes a `PhysicsOverride` for resolution; the
+    /// restrictive ones also forbid or condition capabilities, each of which
+    /// gets a seed lexicon entry.
+    pub fn derive(affordances: &WorldAffordances, postures: &[OntologicalPostureRecord]) -> Self {
+        let mut rules = Self::default();
+
+        match normalized(&affordances.magic).as_str() {
+            "absent" => {
+                rules.forbid("magic", "Magic does not exist in this world");
+                rules.forbid("overt_magic", "Magic does not exist in this world");
+            }
+            "subtle" => {
+                rules.forbid(
+                    "overt_magic",
+                    "Magic here is quiet and deniable — nothing so overt as open spellcasting",
+                );
+                rules.condition(
+                    "magic",
+                    &["a working that is slow, costly, and deniable — charms, omens, bargains"],
+                );
+            }
+            "rule-bound" => rules.condition(
+                "overt_magic",
+                &[
+                    "training in the world's magical tradition",
+                    "paying the cost the tradition demands",
+                ],
+            ),
+            _ => {}
+        }
+
+        match normalized(&affordances.technology).as_str() {
+            "historical" => {
+                rules.forbid(
+                    "modern_technology",
+                    "This world's technology is historical — no modern devices exist",
+                );
+                rules.forbid(
+                    "speculative_technology",
+                    "Speculative technology does not exist in this world",
+                );
+            }
+            "contemporary" => rules.forbid(
+                "speculative_technology",
+                "Speculative technology does not exist in this world",
+            ),
+            _ => {}
+        }
+
+        if normalized(&affordances.violence) == "absent" {
+            rules.condition("lethal_violence", &["the story keeps killing off the page"]);
+        }
+
+        match normalized(&affordances.death).as_str() {
+            "permanent" => rules.forbid("resurrection", "Death is permanent in this world"),
+            "negotiable" => rules.condition("resurrection", &["a bargain, and a price paid"]),
+            _ => {}
+        }
+
+        match normalized(&affordances.supernatural).as_str() {
+            "nonexistent" => rules.forbid(
+                "spirit_summoning",
+                "Nothing answers from beyond in this world",
+            ),
+            "ambiguous" => rules.condition(
+                "spirit_summoning",
+                &["an answer that stays uncertain — nothing is ever confirmed"],
+            ),
+            _ => {}
+        }
+
+        for (property, value) in [
+            ("magic", &affordances.magic),
+            ("technology", &affordances.technology),
+            ("violence", &affordances.violence),
+            ("death", &affordances.death),
+            ("supernatural", &affordances.supernatural),
+        ] {
+            if !value.trim().is_empty() {
+                rules.constraints.push(GenreConstraint::PhysicsOverride {
+                    property: property.to_string(),
+                    value: normalized(value),
+                });
+            }
+        }
+        for posture in postures {
+            if let Some(ref stability) = posture.boundary_stability {
+                rules.constraints.push(GenreConstraint::PhysicsOverride {
+                    property: format!("ontological_posture:{}", posture.entity_slug),
+                    value: stability.clone(),
+                });
+            }
+        }
+
+        rules
+    }
+
+    /// Derive rules from a genre's assembled bedrock context, falling back to
+    /// the built-in affordances for the genre's slug.
+    pub fn from_genre_context(context: &GenreContext) -> Self {
+        let affordances = WorldAffordances::from_genre_context(context)
+            .or_else(|| WorldAffordances::builtin(&context.genre.slug))
+            .unwrap_or_default();
+        Self::derive(&affordances, &context.ontological_posture)
+    }
+
+    /// Capabilities the rules forbid or condition.
+    pub fn constrained_capabilities(&self) -> Vec<&str> {
+        self.constraints
+            .iter()
+            .filter_map(|c| match c {
+                GenreConstraint::Forbidden { capability, .. }
+                | GenreConstraint::Conditional { capability, .. } => Some(capability.as_str()),
+                GenreConstraint::PhysicsOverride { .. } => None,
+            })
+            .collect()
+    }
+
+    fn forbid(&mut self, capability: &str, reason: &str) {
+        self.constraints.push(GenreConstraint::Forbidden {
+            capability: capability.to_string(),
+            reason: reason.to_string(),
+        });
+        self.lexicon.merge(seed_entry(capability));
+    }
+
+    fn condition(&mut self, capability: &str, requires: &[&str]) {
+        self.constraints.push(GenreConstraint::Conditional {
+            capability: capability.to_string(),
+            requires: requires.iter().map(|r| r.to_string()).collect(),
+        });
+        self.lexicon.merge(seed_entry(capability));
+    }
+}
+
+/// Derive a genre's rules from the bedrock data layer.
+///
+/// # Errors
+///
+/// Propagates query failures from `BedrockQuery::genre_context`.
+pub async fn genre_rules_from_bedrock(
+    bedrock: &dyn BedrockQuery,
+    genre_slug: &str,
+) -> StorytellerResult<GenreRules> {
+    let context = bedrock.genre_context(genre_slug).await?;
+    Ok(GenreRules::from_genre_context(&context))
+}
+
+/// The hand-seeded terms for a constrained capability.
+///
+/// Matching is substring-based, so terms avoid short words that hide inside
+/// ordinary ones ("gun" in "begun"). Authoring-time expansion adds the rest.
+fn seed_entry(capability: &str) -> LexiconEntry {
+    let terms = |words: &[&str]| words.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+    let (synonyms, verbs, objects, phrases): (&[&str], &[&str], &[&str], &[&str]) = match capability
+    {
+        "magic" => (
+            &["magic", "sorcery", "witchcraft", "wizardry", "spellcraft"],
+            &["enchant", "bewitch"],
+            &["spellbook", "incantation", "wand"],
+            &["cast a spell", "casts a spell", "weave a spell"],
+        ),
+        "overt_magic" => (
+            &["evocation", "battle magic"],
+            &["conjure", "teleport", "levitate", "incinerate"],
+            &[
+                "fireball",
+                "lightning bolt",
+                "magic missile",
+                "arcane blast",
+            ],
+            &["hurl fire", "hurls fire", "call down lightning"],
+        ),
+        "modern_technology" => (
+            &["electricity"],
+            &[],
+            &[
+                "smartphone",
+                "cell phone",
+                "computer",
+                "laptop",
+                "automobile",
+                "flashlight",
+                "radio",
+                "television",
+                "machine gun",
+            ],
+            &["call the police"],
+        ),
+        "speculative_technology" => (
+            &["cybernetics"],
+            &[],
+            &[
+                "laser",
+                "blaster",
+                "teleporter",
+                "hologram",
+                "starship",
+                "plasma rifle",
+            ],
+            &["beam me up"],
+        ),
+        "lethal_violence" => (
+            &["bloodshed"],
+            &["murder", "slay", "behead", "strangle"],
+            &[],
+            &["kill him", "kill her", "kill them", "to the death"],
+        ),
+        "resurrection" => (
+            &["necromancy"],
+            &["resurrect", "reanimate"],
+            &[],
+            &["raise the dead", "bring back from the dead", "back to life"],
+        ),
+        "spirit_summoning" => (
+            &["seance", "séance"],
+            &["exorcise"],
+            &[],
+            &[
+                "summon a spirit",
+                "summon the dead",
+                "summon a demon",
+                "speak with the dead",
+                "commune with the dead",
+            ],
+        ),
+        _ => (&[], &[], &[], &[]),
+    };
+    LexiconEntry {
+        synonyms: terms(synonyms),
+        action_verbs: terms(verbs),
+        implied_objects: terms(objects),
+        idiomatic_phrases: terms(phrases),
+        ..LexiconEntry::new(capability)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::bedrock::GenreRecord;
+
+    fn forbidden(rules: &GenreRules) -> Vec<&str> {
+        rules
+            .constraints
+            .iter()
+            .filter_map(|c| match c {
+                GenreConstraint::Forbidden { capability, .. } => Some(capability.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn low_magic_forbids_overt_spellcasting() {
+        let affordances = WorldAffordances::builtin("low_fantasy_folklore").unwrap();
+        let rules = GenreRules::derive(&affordances, &[]);
+
+        assert_eq!(
+            forbidden(&rules),
+            vec![
+                "overt_magic",
+                "modern_technology",
+                "speculative_technology",
+                "resurrection"
+            ]
+        );
+        assert_eq!(
+            rules.lexicon.match_text("I cast a fireball at the miller"),
+            vec!["overt_magic"]
+        );
+        // Every constrained capability can be recognized.
+        for capability in rules.constrained_capabilities() {
+            assert!(
+                rules.lexicon.entries.contains_key(capability),
+                "{capability}"
+            );
+        }
+        assert!(rules.constraints.iter().any(|c| matches!(
+            c,
+            GenreConstraint::PhysicsOverride { property, value }
+                if property == "magic" && value == "subtle"
+        )));
+    }
+
+    #[test]
+    fn wild_worlds_constrain_nothing() {
+        let affordances = WorldAffordances {
+            magic: "Wild Mythic".to_string(),
+            technology: "post_human".to_string(),
+            ..WorldAffordances::default()
+        };
+        let rules = GenreRules::derive(&affordances, &[]);
+        assert!(rules.constrained_capabilities().is_empty());
+        assert!(rules.lexicon.entries.is_empty());
+        assert_eq!(rules.constraints.len(), 2); // magic + technology overrides
+    }
+
+    #[test]
+    fn affordances_read_from_bedrock_payload() {
+        let now = chrono::Utc::now();
+        let context = GenreContext {
+            genre: GenreRecord {
+                id: uuid::Uuid::nil(),
+                slug: "folk-horror".to_string(),
+                name: "Folk Horror".to_string(),
+                description: None,
+                payload: serde_json::json!({
+                    "world_affordances": {"magic": "absent", "technology": "contemporary"}
+                }),
+                source_hash: String::new(),
+                created_at: now,
+                updated_at: now,
+            },
+            archetypes: vec![],
+            dynamics: vec![],
+            settings: vec![],
+            goals: vec![],
+            profiles: vec![],
+            tropes: vec![],
+            narrative_shapes: vec![],
+            ontological_posture: vec![],
+            spatial_topology: vec![],
+            place_entities: vec![],
+            archetype_dynamics: vec![],
+            dimensions: None,
+        };
+        let rules = GenreRules::from_genre_context(&context);
+        assert_eq!(
+            forbidden(&rules),
+            vec!["magic", "overt_magic", "speculative_technology"]
+        );
+
+        // Without a payload, the slug's built-in affordances apply.
+        let mut bare = context;
+        bare.genre.payload = serde_json::json!({});
+        let rules = GenreRules::from_genre_context(&bare);
+        assert!(forbidden(&rules).contains(&"modern_technology"));
+    }
+
+    #[test]
+    fn rules_roundtrip_through_json() {
+        let rules = GenreRules::derive(&WorldAffordances::builtin("sci_fi_noir").unwrap(), &[]);
+        let json = serde_json::to_value(&rules).unwrap();
+        let back: GenreRules = serde_json::from_value(json).unwrap();
+        assert_eq!(back.constraints.len(), rules.constraints.len());
+        assert_eq!(back.lexicon.entries.len(), rules.lexicon.entries.len());
+    }
+}
@@ -0,0 +1,1031 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Graduated-success resolution — the reference `GameDesignSystem`.
+//!
+//! See: `docs/technical/narrator-architecture.md`
+//!
+//! Each predicted action is measured against the acting character's hidden
+//! capabilities: the best-matching skill blended with the attributes that
+//! govern it, or — when no skill applies — the attribute that governs the
+//! action type. Competence, prediction confidence, and world-model penalties
+//! set a success chance, and a seeded roll against that chance lands in one
+//! of the four [`SuccessDegree`] bands.
+//!
+//! Actions that collide — two characters acting on each other, one resisting
+//! the other, or both reaching for the same target — are contested: the
+//! action that cleared its chance by the wider margin prevails and the other
+//! is downgraded. Characters are then sequenced by initiative.
+//!
+//! Resolution is deterministic for a given seed, so a replayed turn resolves
+//! identically. Every number lives in [`ResolutionTuning`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::errors::StorytellerResult;
+use crate::traits::GameDesignSystem;
+use crate::types::character::CharacterSheet;
+use crate::types::entity::EntityId;
+use crate::types::prediction::{ActionPrediction, ActionType, CharacterPrediction};
+use crate::types::resolver::{
+    ActionOutcome, ConflictResolution, ResolvedCharacterAction, ResolverOutput, StateChange,
+    SuccessDegree,
+};
+use crate::types::world_model::{CapabilityProfile, Skill, WorldModel};
+
+/// Weight of the matched skill's own value in a skill-based competence.
+const SKILL_WEIGHT: f32 = 0.6;
+/// Weight of the skill's primary attribute.
+const PRIMARY_WEIGHT: f32 = 0.3;
+/// Weight of the mean of the skill's secondary attributes.
+const SECONDARY_WEIGHT: f32 = 0.1;
+/// Skill names match action descriptions on this many leading characters,
+/// so "Persuasion" matches "tries to persuade".
+const SKILL_STEM_LEN: usize = 5;
+
+// ---------------------------------------------------------------------------
+// Tuning
+// ---------------------------------------------------------------------------
+
+/// The numbers behind graduated-success resolution.
+///
+/// A roll below `chance - partial_band` is a full success, a roll below
+/// `chance` is a partial success, and anything above is a failure — of which
+/// `opportunity_share` open a door rather than cost something.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ResolutionTuning {
+    /// Success chance for an average (0.5) character with no penalties.
+    pub base_chance: f32,
+    /// How far competence above or below 0.5 moves the chance.
+    pub competence_weight: f32,
+    /// How far prediction confidence above or below 0.5 moves the chance.
+    pub confidence_weight: f32,
+    /// Width of the partial-success band just below the success chance.
+    pub partial_band: f32,
+    /// Fraction of failures that fail with opportunity rather than consequence.
+    pub opportunity_share: f32,
+    /// Chance penalty per environmental constraint bearing on the action.
+    pub constraint_penalty: f32,
+    /// Chance penalty for speaking to someone beyond earshot.
+    pub distance_penalty: f32,
+    /// Floor for the success chance.
+    pub min_chance: f32,
+    /// Ceiling for the success chance.
+    pub max_chance: f32,
+    /// Competence assumed when no skill or attribute applies.
+    pub default_competence: f32,
+    /// Magnitude of the relational shifts produced by targeted actions.
+    pub relational_step: f32,
+    /// Random spread added to initiative when sequencing characters.
+    pub initiative_jitter: f32,
+}
+
+impl ResolutionTuning {
+    /// Balanced defaults — roughly even odds of a clean success for an
+    /// average character, with failure as likely to open a door as close one.
+    pub fn standard() -> Self {
+        Self {
+            base_chance: 0.55,
+            competence_weight: 0.6,
+            confidence_weight: 0.1,
+            partial_band: 0.2,
+            opportunity_share: 0.5,
+            constraint_penalty: 0.1,
+            distance_penalty: 0.15,
+            min_chance: 0.05,
+            max_chance: 0.95,
+            default_competence: 0.5,
+            relational_step: 0.05,
+            initiative_jitter: 0.1,
+        }
+    }
+
+    /// Harsher odds — more failures, and most of them cost something.
+    pub fn gritty() -> Self {
+        Self {
+            base_chance: 0.45,
+            partial_band: 0.25,
+            opportunity_share: 0.3,
+            constraint_penalty: 0.15,
+            relational_step: 0.08,
+            ..Self::standard()
+        }
+    }
+
+    /// Gentler odds — fewer failures, and most of them open a door.
+    pub fn gentle() -> Self {
+        Self {
+            base_chance: 0.65,
+            partial_band: 0.15,
+            opportunity_share: 0.7,
+            constraint_penalty: 0.05,
+            relational_step: 0.04,
+            ..Self::standard()
+        }
+    }
+
+    /// Success chance for an action, clamped to `[min_chance, max_chance]`.
+    pub fn chance(&self, competence: f32, confidence: f32, penalty: f32) -> f32 {
+        let raw = self.base_chance
+            + (competence - 0.5) * self.competence_weight
+            + (confidence - 0.5) * self.confidence_weight
+            - penalty;
+        raw.clamp(self.min_chance, self.max_chance)
+    }
+
+    /// The success degree a roll lands in. `failure_roll` splits failures
+    /// between consequence and opportunity.
+    pub fn degree(&self, roll: f32, chance: f32, failure_roll: f32) -> SuccessDegree {
+        if roll < chance - self.partial_band {
+            SuccessDegree::FullSuccess
+        } else if roll < chance {
+            SuccessDegree::PartialSuccess
+        } else if failure_roll < self.opportunity_share {
+            SuccessDegree::FailureWithOpportunity
+        } else {
+            SuccessDegree::FailureWithConsequence
+        }
+    }
+}
+
+impl Default for ResolutionTuning {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resolution
+// ---------------------------------------------------------------------------
+
+/// One rolled action, before sequencing.
+#[derive(Debug)]
+struct Attempt {
+    action: ActionPrediction,
+    competence: f32,
+    /// How far the roll cleared (positive) or missed (negative) the chance.
+    margin: f32,
+    success: SuccessDegree,
+    consequences: Vec<String>,
+}
+
+/// General-purpose graduated-success resolution.
+///
+/// Constructed per turn: the character capabilities and the seed are
+/// specific to the scene and turn being resolved.
+#[derive(Debug, Clone, Default)]
+pub struct GraduatedResolution {
+    tuning: ResolutionTuning,
+    capabilities: BTreeMap<EntityId, CapabilityProfile>,
+    seed: u64,
+}
+
+impl GraduatedResolution {
+    /// Identifier for this game design system.
+    pub const NAME: &'static str = "graduated_success";
+
+    /// Create a resolver with the given tuning, no capabilities, and seed 0.
+    pub fn new(tuning: ResolutionTuning) -> Self {
+        Self {
+            tuning,
+            capabilities: BTreeMap::new(),
+            seed: 0,
+        }
+    }
+
+    /// Seed the resolution RNG.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Register a character's capability profile.
+    pub fn with_capabilities(mut self, character_id: EntityId, profile: CapabilityProfile) -> Self {
+        self.capabilities.insert(character_id, profile);
+        self
+    }
+
+    /// Register the capability profiles of every character in a cast.
+    pub fn with_characters(mut self, characters: &[CharacterSheet]) -> Self {
+        for sheet in characters {
+            self.capabilities
+                .insert(sheet.entity_id, sheet.capabilities.clone());
+        }
+        self
+    }
+
+    /// The tuning in effect.
+    pub fn tuning(&self) -> &ResolutionTuning {
+        &self.tuning
+    }
+
+    /// A character's competence at an action, in `[0.0, 1.0]`.
+    pub fn competence(&self, character_id: EntityId, action: &ActionPrediction) -> f32 {
+        let fallback = self.tuning.default_competence;
+        let Some(profile) = self.capabilities.get(&character_id) else {
+            return fallback;
+        };
+
+        if let Some(skill) = matching_skill(profile, &action.description) {
+            let primary = attribute_value(profile, &skill.primary_attribute).unwrap_or(fallback);
+            let secondary = if skill.secondary_attributes.is_empty() {
+                primary
+            } else {
+                skill
+                    .secondary_attributes
+                    .iter()
+                    .map(|name| attribute_value(profile, name).unwrap_or(fallback))
+                    .sum::<f32>()
+                    / skill.secondary_attributes.len() as f32
+            };
+            return SKILL_WEIGHT * skill.base_value
+                + PRIMARY_WEIGHT * primary
+                + SECONDARY_WEIGHT * secondary;
+        }
+
+        governing_attribute(action.action_type)
+            .and_then(|name| attribute_value(profile, name))
+            .unwrap_or(fallback)
+    }
+
+    /// Chance penalty the world model imposes on an action, or `None` if the
+    /// action's target is beyond perception.
+    fn penalty(
+        &self,
+        character_id: EntityId,
+        action: &ActionPrediction,
+        world_model: &WorldModel,
+    ) -> Option<f32> {
+        let type_name = action_type_name(action.action_type);
+        let constraints = world_model
+            .environmental_constraints
+            .iter()
+            .filter(|c| {
+                c.affected_action_types.is_empty()
+                    || c.affected_action_types
+                        .iter()
+                        .any(|t| t.eq_ignore_ascii_case(type_name))
+            })
+            .count();
+        let mut penalty = constraints as f32 * self.tuning.constraint_penalty;
+
+        if let Some(target) = action.target {
+            let zone = world_model.spatial_zones.iter().find(|entry| {
+                (entry.entity_a == character_id && entry.entity_b == target)
+                    || (entry.entity_a == target && entry.entity_b == character_id)
+            });
+            if let Some(entry) = zone {
+                if !entry.zone.is_perceptible() {
+                    return None;
+                }
+                if action.action_type == ActionType::Speak && !entry.zone.can_hear_speech() {
+                    penalty += self.tuning.distance_penalty;
+                }
+            }
+        }
+        Some(penalty)
+    }
+
+    /// Roll every action in one prediction.
+    fn attempts(
+        &self,
+        rng: &mut StdRng,
+        prediction: &CharacterPrediction,
+        world_model: &WorldModel,
+    ) -> Vec<Attempt> {
+        prediction
+            .actions
+            .iter()
+            .map(|action| {
+                let competence = self.competence(prediction.character_id, action);
+                // Always draw both rolls so one action's shape never shifts
+                // the rolls of the actions after it.
+                let roll: f32 = rng.random();
+                let failure_roll: f32 = rng.random();
+
+                if action.action_type == ActionType::Wait {
+                    return Attempt {
+                        action: action.clone(),
+                        competence,
+                        margin: 0.0,
+                        success: SuccessDegree::FullSuccess,
+                        consequences: vec![],
+                    };
+                }
+
+                match self.penalty(prediction.character_id, action, world_model) {
+                    Some(penalty) => {
+                        let chance = self.tuning.chance(competence, action.confidence, penalty);
+                        let success = self.tuning.degree(roll, chance, failure_roll);
+                        Attempt {
+                            action: action.clone(),
+                            competence,
+                            margin: chance - roll,
+                            success,
+                            consequences: degree_consequence(success)
+                                .map(String::from)
+                                .into_iter()
+                                .collect(),
+                        }
+                    }
+                    None => Attempt {
+                        action: action.clone(),
+                        competence,
+                        margin: -1.0,
+                        success: SuccessDegree::FailureWithConsequence,
+                        consequences: vec!["The target is beyond reach.".to_string()],
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Find and settle contested actions between characters, downgrading
+    /// the losers in place.
+    fn contest(
+        &self,
+        predictions: &[CharacterPrediction],
+        attempts: &mut [Vec<Attempt>],
+    ) -> Vec<ConflictResolution> {
+        let mut pairs = Vec::new();
+        let mut claimed = BTreeSet::new();
+        for a in 0..predictions.len() {
+            for b in (a + 1)..predictions.len() {
+                for (ia, attempt_a) in attempts[a].iter().enumerate() {
+                    for (ib, attempt_b) in attempts[b].iter().enumerate() {
+                        if claimed.contains(&(a, ia)) || claimed.contains(&(b, ib)) {
+                            continue;
+                        }
+                        if is_contested(
+                            predictions[a].character_id,
+                            &attempt_a.action,
+                            predictions[b].character_id,
+                            &attempt_b.action,
+                        ) {
+                            claimed.insert((a, ia));
+                            claimed.insert((b, ib));
+                            pairs.push(((a, ia), (b, ib)));
+                        }
+                    }
+                }
+            }
+        }
+
+        pairs
+            .into_iter()
+            .map(|((a, ia), (b, ib))| {
+                let name_a = &predictions[a].character_name;
+                let name_b = &predictions[b].character_name;
+                let description = format!(
+                    "{name_a} ({}) and {name_b} ({}) act against each other",
+                    attempts[a][ia].action.description, attempts[b][ib].action.description,
+                );
+                let margin_a = attempts[a][ia].margin;
+                let margin_b = attempts[b][ib].margin;
+
+                if (margin_a - margin_b).abs() <= f32::EPSILON {
+                    for (c, i) in [(a, ia), (b, ib)] {
+                        let attempt = &mut attempts[c][i];
+                        attempt.success = downgrade(attempt.success);
+                        attempt
+                            .consequences
+                            .push("Neither side gives way.".to_string());
+                    }
+                    return ConflictResolution {
+                        description,
+                        resolution: "Neither prevails — both are checked".to_string(),
+                        winner: None,
+                    };
+                }
+
+                let (winner, loser) = if margin_a > margin_b {
+                    (a, (b, ib))
+                } else {
+                    (b, (a, ia))
+                };
+                let winner_name = &predictions[winner].character_name;
+                let attempt = &mut attempts[loser.0][loser.1];
+                attempt.success = downgrade(attempt.success);
+                attempt
+                    .consequences
+                    .push(format!("Checked by {winner_name}."));
+                ConflictResolution {
+                    description,
+                    resolution: format!("{winner_name} prevails"),
+                    winner: Some(predictions[winner].character_id),
+                }
+            })
+            .collect()
+    }
+
+    /// Turn one character's attempts into resolved outcomes with state changes.
+    fn resolved(
+        &self,
+        prediction: &CharacterPrediction,
+        attempts: Vec<Attempt>,
+    ) -> ResolvedCharacterAction {
+        let outcomes = attempts
+            .into_iter()
+            .enumerate()
+            .map(|(index, attempt)| {
+                let mut state_changes = Vec::new();
+
+                // The character's predicted emotional movement lands with
+                // their first action, scaled by how that action went.
+                if index == 0 {
+                    let scale = emotional_scale(attempt.success);
+                    for delta in &prediction.emotional_deltas {
+                        let change = delta.intensity_change * scale;
+                        if change != 0.0 {
+                            state_changes.push(StateChange::EmotionalShift {
+                                character_id: prediction.character_id,
+                                primary_id: delta.primary_id.clone(),
+                                intensity_change: change,
+                            });
+                        }
+                    }
+                }
+
+                match attempt.action.target {
+                    Some(target) if target != prediction.character_id => {
+                        if let Some((dimension, factor)) =
+                            relational_effect(attempt.action.action_type, attempt.success)
+                        {
+                            state_changes.push(StateChange::RelationalShift {
+                                source: prediction.character_id,
+                                target,
+                                dimension: dimension.to_string(),
+                                change: factor * self.tuning.relational_step,
+                            });
+                        }
+                    }
+                    None if matches!(
+                        attempt.action.action_type,
+                        ActionType::Perform | ActionType::Move
+                    ) && matches!(
+                        attempt.success,
+                        SuccessDegree::FullSuccess | SuccessDegree::PartialSuccess
+                    ) =>
+                    {
+                        state_changes.push(StateChange::WorldState {
+                            description: format!(
+                                "{}: {}",
+                                prediction.character_name, attempt.action.description
+                            ),
+                        });
+                    }
+                    _ => {}
+                }
+
+                ActionOutcome {
+                    action: attempt.action,
+                    success: attempt.success,
+                    consequences: attempt.consequences,
+                    state_changes,
+                }
+            })
+            .collect();
+
+        ResolvedCharacterAction {
+            character_id: prediction.character_id,
+            character_name: prediction.character_name.clone(),
+            outcomes,
+        }
+    }
+}
+
+impl GameDesignSystem for GraduatedResolution {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn resolve(
+        &self,
+        predictions: &[CharacterPrediction],
+        world_model: &WorldModel,
+    ) -> StorytellerResult<ResolverOutput> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut attempts: Vec<Vec<Attempt>> = predictions
+            .iter()
+            .map(|p| self.attempts(&mut rng, p, world_model))
+            .collect();
+        let conflicts = self.contest(predictions, &mut attempts);
+
+        // Initiative: competence at what they're attempting, plus a little luck.
+        let mut order: Vec<(usize, f32)> = attempts
+            .iter()
+            .map(|character_attempts| {
+                let competence = if character_attempts.is_empty() {
+                    self.tuning.default_competence
+                } else {
+                    character_attempts.iter().map(|a| a.competence).sum::<f32>()
+                        / character_attempts.len() as f32
+                };
+                competence + rng.random::<f32>() * self.tuning.initiative_jitter
+            })
+            .enumerate()
+            .collect();
+        order.sort_by(|(a, score_a), (b, score_b)| {
+            score_b.total_cmp(score_a).then_with(|| {
+                predictions[*a]
+                    .character_id
+                    .cmp(&predictions[*b].character_id)
+            })
+        });
+
+        let mut slots: Vec<Option<Vec<Attempt>>> = attempts.into_iter().map(Some).collect();
+        let sequenced_actions: Vec<ResolvedCharacterAction> = order
+            .into_iter()
+            .filter_map(|(index, _)| {
+                slots[index]
+                    .take()
+                    .map(|a| self.resolved(&predictions[index], a))
+            })
+            .collect();
+
+        let action_count: usize = sequenced_actions.iter().map(|a| a.outcomes.len()).sum();
+        let scene_dynamics = format!(
+            "Resolved {action_count} action(s) across {} character(s); {} contested",
+            sequenced_actions.len(),
+            conflicts.len(),
+        );
+
+        Ok(ResolverOutput {
+            sequenced_actions,
+            original_predictions: predictions.to_vec(),
+            scene_dynamics,
+            conflicts,
+            intent_statements: None,
+        })
+    }
+
+    fn resolve_single(
+        &self,
+        prediction: &CharacterPrediction,
+        world_model: &WorldModel,
+    ) -> StorytellerResult<ResolvedCharacterAction> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let attempts = self.attempts(&mut rng, prediction, world_model);
+        Ok(self.resolved(prediction, attempts))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Lowercase name of an action type, as used in `affected_action_types`.
+fn action_type_name(action_type: ActionType) -> &'static str {
+    match action_type {
+        ActionType::Perform => "perform",
+        ActionType::Speak => "speak",
+        ActionType::Move => "move",
+        ActionType::Examine => "examine",
+        ActionType::Wait => "wait",
+        ActionType::Resist => "resist",
+    }
+}
+
+/// The attribute that governs an action type when no skill applies.
+fn governing_attribute(action_type: ActionType) -> Option<&'static str> {
+    match action_type {
+        ActionType::Perform | ActionType::Speak => Some("Presence"),
+        ActionType::Examine => Some("Insight"),
+        ActionType::Move | ActionType::Resist => Some("Resilience"),
+        ActionType::Wait => None,
+    }
+}
+
+/// Case-insensitive attribute lookup by key or name.
+fn attribute_value(profile: &CapabilityProfile, name: &str) -> Option<f32> {
+    profile
+        .attributes
+        .iter()
+        .find(|(key, attr)| key.eq_ignore_ascii_case(name) || attr.name.eq_ignore_ascii_case(name))
+        .map(|(_, attr)| attr.base_value)
+}
+
+/// The highest-valued skill whose name shares a stem with a word of the
+/// action description.
+fn matching_skill<'a>(profile: &'a CapabilityProfile, description: &str) -> Option<&'a Skill> {
+    let words: Vec<String> = description
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    profile
+        .skills
+        .values()
+        .filter(|skill| {
+            skill
+                .name
+                .split_whitespace()
+                .map(str::to_lowercase)
+                .any(|part| {
+                    let stem: String = part.chars().take(SKILL_STEM_LEN).collect();
+                    stem.chars().count() >= 3 && words.iter().any(|w| w.starts_with(&stem))
+                })
+        })
+        .max_by(|a, b| a.base_value.total_cmp(&b.base_value))
+}
+
+/// Whether two characters' actions collide.
+fn is_contested(
+    id_a: EntityId,
+    a: &ActionPrediction,
+    id_b: EntityId,
+    b: &ActionPrediction,
+) -> bool {
+    let physical = |t: ActionType| matches!(t, ActionType::Perform | ActionType::Move);
+    let resists = |resister: &ActionPrediction, other: EntityId| {
+        resister.action_type == ActionType::Resist
+            && resister.target.is_none_or(|target| target == other)
+    };
+
+    let mutual = a.target == Some(id_b)
+        && b.target == Some(id_a)
+        && (physical(a.action_type) || physical(b.action_type));
+    let opposed = (a.target == Some(id_b) && resists(b, id_a))
+        || (b.target == Some(id_a) && resists(a, id_b));
+    let same_target = a.target.is_some()
+        && a.target == b.target
+        && a.action_type == ActionType::Perform
+        && b.action_type == ActionType::Perform;
+
+    mutual || opposed || same_target
+}
+
+/// One step worse — the loser of a contest.
+fn downgrade(degree: SuccessDegree) -> SuccessDegree {
+    match degree {
+        SuccessDegree::FullSuccess => SuccessDegree::PartialSuccess,
+        SuccessDegree::PartialSuccess => SuccessDegree::FailureWithConsequence,
+        other => other,
+    }
+}
+
+/// Narrator-facing hint for a degree. Full success needs none.
+fn degree_consequence(degree: SuccessDegree) -> Option<&'static str> {
+    match degree {
+        SuccessDegree::FullSuccess => None,
+        SuccessDegree::PartialSuccess => {
+            Some("It works, but not cleanly — something is complicated or spent.")
+        }
+        SuccessDegree::FailureWithConsequence => Some("It fails, and the failure costs something."),
+        SuccessDegree::FailureWithOpportunity => {
+            Some("It fails, but the attempt opens something unexpected.")
+        }
+    }
+}
+
+/// How much of a predicted emotional shift survives the outcome.
+fn emotional_scale(degree: SuccessDegree) -> f32 {
+    match degree {
+        SuccessDegree::FullSuccess => 1.0,
+        SuccessDegree::PartialSuccess => 0.6,
+        SuccessDegree::FailureWithOpportunity => 0.4,
+        SuccessDegree::FailureWithConsequence => 0.2,
+    }
+}
+
+/// The substrate dimension a targeted action moves, and in which direction
+/// (as a multiple of `relational_step`).
+fn relational_effect(
+    action_type: ActionType,
+    degree: SuccessDegree,
+) -> Option<(&'static str, f32)> {
+    let (dimension, factor) = match (action_type, degree) {
+        (ActionType::Speak, d) => ("affection", warmth_factor(d)),
+        (ActionType::Perform, d) => ("trust_benevolence", warmth_factor(d)),
+        // Successful resistance cools the relationship; failed resistance
+        // leaves it where it was.
+        (ActionType::Resist, SuccessDegree::FullSuccess) => ("affection", -1.0),
+        (ActionType::Resist, SuccessDegree::PartialSuccess) => ("affection", -0.5),
+        _ => return None,
+    };
+    (factor != 0.0).then_some((dimension, factor))
+}
+
+fn warmth_factor(degree: SuccessDegree) -> f32 {
+    match degree {
+        SuccessDegree::FullSuccess => 1.0,
+        SuccessDegree::PartialSuccess => 0.5,
+        SuccessDegree::FailureWithConsequence => -1.0,
+        SuccessDegree::FailureWithOpportunity => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::prediction::{ActivatedTensorFrame, EmotionalDelta, ThoughtPrediction};
+    use crate::types::tensor::{AwarenessLevel, Provenance};
+    use crate::types::world_model::{
+        Attribute, DistanceEntry, EnvironmentalConstraint, NarrativeDistanceZone,
+    };
+
+    fn action(
+        action_type: ActionType,
+        description: &str,
+        target: Option<EntityId>,
+    ) -> ActionPrediction {
+        ActionPrediction {
+            description: description.to_string(),
+            confidence: 0.7,
+            action_type,
+            target,
+        }
+    }
+
+    fn prediction(id: EntityId, name: &str, actions: Vec<ActionPrediction>) -> CharacterPrediction {
+        CharacterPrediction {
+            character_id: id,
+            character_name: name.to_string(),
+            frame: ActivatedTensorFrame {
+                activated_axes: vec![],
+                confidence: 0.8,
+            },
+            actions,
+            speech: None,
+            thought: ThoughtPrediction {
+                emotional_subtext: String::new(),
+                awareness_level: AwarenessLevel::Recognizable,
+                internal_conflict: None,
+            },
+            emotional_deltas: vec![EmotionalDelta {
+                primary_id: "joy".to_string(),
+                intensity_change: 0.5,
+                awareness_change: None,
+            }],
+        }
+    }
+
+    fn open_world() -> WorldModel {
+        WorldModel {
+            genre_physics: vec![],
+            spatial_zones: vec![],
+            environmental_constraints: vec![],
+        }
+    }
+
+    fn persuader(value: f32) -> CapabilityProfile {
+        let mut profile = CapabilityProfile::default();
+        profile.attributes.insert(
+            "Presence".to_string(),
+            Attribute {
+                name: "Presence".to_string(),
+                base_value: value,
+                provenance: Provenance::Authored,
+            },
+        );
+        profile.skills.insert(
+            "Persuasion".to_string(),
+            Skill {
+                name: "Persuasion".to_string(),
+                description: "Moving others with words".to_string(),
+                primary_attribute: "Presence".to_string(),
+                secondary_attributes: vec![],
+                base_value: value,
+            },
+        );
+        profile
+    }
+
+    fn success_rate(resolver: &GraduatedResolution, pred: &CharacterPrediction) -> usize {
+        (0..400u64)
+            .filter(|seed| {
+                let outcome = resolver
+                    .clone()
+                    .with_seed(*seed)
+                    .resolve_single(pred, &open_world())
+                    .unwrap();
+                matches!(
+                    outcome.outcomes[0].success,
+                    SuccessDegree::FullSuccess | SuccessDegree::PartialSuccess
+                )
+            })
+            .count()
+    }
+
+    #[test]
+    fn degree_bands_follow_the_tuning() {
+        let tuning = ResolutionTuning::standard();
+        assert_eq!(tuning.degree(0.1, 0.6, 0.0), SuccessDegree::FullSuccess);
+        assert_eq!(tuning.degree(0.5, 0.6, 0.0), SuccessDegree::PartialSuccess);
+        assert_eq!(
+            tuning.degree(0.7, 0.6, 0.1),
+            SuccessDegree::FailureWithOpportunity
+        );
+        assert_eq!(
+            tuning.degree(0.7, 0.6, 0.9),
+            SuccessDegree::FailureWithConsequence
+        );
+        assert_eq!(tuning.chance(1.0, 1.0, -1.0), tuning.max_chance);
+        assert_eq!(tuning.chance(0.0, 0.0, 1.0), tuning.min_chance);
+    }
+
+    #[test]
+    fn same_seed_resolves_identically() {
+        let (a, b) = (EntityId::new(), EntityId::new());
+        let predictions = vec![
+            prediction(
+                a,
+                "Sarah",
+                vec![action(
+                    ActionType::Perform,
+                    "Reaches for the flute",
+                    Some(b),
+                )],
+            ),
+            prediction(
+                b,
+                "Pyotir",
+                vec![action(ActionType::Resist, "Holds the flute close", None)],
+            ),
+        ];
+        let resolver = GraduatedResolution::default().with_seed(42);
+        let first = resolver.resolve(&predictions, &open_world()).unwrap();
+        let second = resolver.resolve(&predictions, &open_world()).unwrap();
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+    }
+
+    #[test]
+    fn skill_matches_on_stem_and_raises_competence() {
+        let id = EntityId::new();
+        let resolver = GraduatedResolution::default().with_capabilities(id, persuader(0.9));
+        let persuade = action(ActionType::Speak, "Tries to persuade him to play", None);
+        let dig = action(ActionType::Examine, "Digs through the soil", None);
+        assert!(resolver.competence(id, &persuade) > 0.85);
+        assert_eq!(resolver.competence(id, &dig), 0.5);
+        assert_eq!(resolver.competence(EntityId::new(), &persuade), 0.5);
+    }
+
+    #[test]
+    fn skilled_characters_succeed_more_often() {
+        let id = EntityId::new();
+        let pred = prediction(
+            id,
+            "Sarah",
+            vec![action(ActionType::Speak, "Persuades the elder", None)],
+        );
+        let skilled = GraduatedResolution::default().with_capabilities(id, persuader(0.9));
+        let unskilled = GraduatedResolution::default().with_capabilities(id, persuader(0.1));
+        assert!(success_rate(&skilled, &pred) > success_rate(&unskilled, &pred) + 100);
+    }
+
+    #[test]
+    fn genre_tuning_moves_failure_rates() {
+        let id = EntityId::new();
+        let pred = prediction(
+            id,
+            "Sarah",
+            vec![action(ActionType::Perform, "Lifts the stone", None)],
+        );
+        let gritty = GraduatedResolution::new(ResolutionTuning::gritty());
+        let gentle = GraduatedResolution::new(ResolutionTuning::gentle());
+        assert!(success_rate(&gentle, &pred) > success_rate(&gritty, &pred));
+    }
+
+    #[test]
+    fn contested_actions_produce_a_conflict_and_a_downgrade() {
+        let (a, b) = (EntityId::new(), EntityId::new());
+        let predictions = vec![
+            prediction(
+                a,
+                "Sarah",
+                vec![action(ActionType::Perform, "Grabs his wrist", Some(b))],
+            ),
+            prediction(
+                b,
+                "Pyotir",
+                vec![action(ActionType::Resist, "Pulls away", None)],
+            ),
+        ];
+        for seed in 0..20 {
+            let output = GraduatedResolution::default()
+                .with_seed(seed)
+                .resolve(&predictions, &open_world())
+                .unwrap();
+            assert_eq!(output.conflicts.len(), 1);
+            let checked = output
+                .sequenced_actions
+                .iter()
+                .flat_map(|a| &a.outcomes)
+                .filter(|o| {
+                    o.consequences
+                        .iter()
+                        .any(|c| c.starts_with("Checked by") || c.starts_with("Neither"))
+                })
+                .count();
+            assert!(checked >= 1);
+        }
+    }
+
+    #[test]
+    fn waiting_always_succeeds_and_absent_targets_fail() {
+        let (a, b) = (EntityId::new(), EntityId::new());
+        let world = WorldModel {
+            spatial_zones: vec![DistanceEntry {
+                entity_a: a,
+                entity_b: b,
+                zone: NarrativeDistanceZone::Absent,
+            }],
+            ..open_world()
+        };
+        let pred = prediction(
+            a,
+            "Sarah",
+            vec![
+                action(ActionType::Wait, "Stays still", None),
+                action(ActionType::Speak, "Calls out to him", Some(b)),
+            ],
+        );
+        let resolved = GraduatedResolution::default()
+            .resolve_single(&pred, &world)
+            .unwrap();
+        assert_eq!(resolved.outcomes[0].success, SuccessDegree::FullSuccess);
+        assert_eq!(
+            resolved.outcomes[1].success,
+            SuccessDegree::FailureWithConsequence
+        );
+    }
+
+    #[test]
+    fn environmental_constraints_lower_the_odds() {
+        let id = EntityId::new();
+        let pred = prediction(
+            id,
+            "Sarah",
+            vec![action(ActionType::Move, "Climbs the wall", None)],
+        );
+        let resolver = GraduatedResolution::default();
+        let baseline = success_rate(&resolver, &pred);
+
+        let icy = WorldModel {
+            environmental_constraints: vec![
+                EnvironmentalConstraint {
+                    name: "Ice".to_string(),
+                    description: "Everything is slick".to_string(),
+                    affected_action_types: vec!["Move".to_string()],
+                };
+                3
+            ],
+            ..open_world()
+        };
+        let constrained = (0..400u64)
+            .filter(|seed| {
+                let outcome = resolver
+                    .clone()
+                    .with_seed(*seed)
+                    .resolve_single(&pred, &icy)
+                    .unwrap();
+                matches!(
+                    outcome.outcomes[0].success,
+                    SuccessDegree::FullSuccess | SuccessDegree::PartialSuccess
+                )
+            })
+            .count();
+        assert!(constrained < baseline);
+    }
+
+    #[test]
+    fn outcomes_carry_state_changes() {
+        let (a, b) = (EntityId::new(), EntityId::new());
+        let pred = prediction(
+            a,
+            "Sarah",
+            vec![action(ActionType::Speak, "Thanks him warmly", Some(b))],
+        );
+        // Find a seed that fully succeeds, then check what it emits.
+        let resolved = (0..100u64)
+            .map(|seed| {
+                GraduatedResolution::default()
+                    .with_seed(seed)
+                    .resolve_single(&pred, &open_world())
+                    .unwrap()
+            })
+            .find(|r| r.outcomes[0].success == SuccessDegree::FullSuccess)
+            .expect("some seed should fully succeed");
+
+        let changes = &resolved.outcomes[0].state_changes;
+        assert!(changes.iter().any(|c| matches!(
+            c,
+            StateChange::EmotionalShift { primary_id, intensity_change, .. }
+                if primary_id == "joy" && (*intensity_change - 0.5).abs() < f32::EPSILON
+        )));
+        assert!(changes.iter().any(|c| matches!(
+            c,
+            StateChange::RelationalShift { target, dimension, change, .. }
+                if *target == b && dimension == "affection" && *change > 0.0
+        )));
+    }
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Game design system implementations.
+//!
+//! Each implementation of [`GameDesignSystem`] resolves character predictions
+//! into graduated outcomes. Systems are selected per genre: the
+//! [`GameDesignRegistry`] maps genre IDs to a tuning, and builds a system for
+//! a turn from the scene's cast and a seed. Arbitration's per-genre
+//! constraints and capability lexicon come from [`genre_rules`].
+//!
+//! See: `docs/technical/narrator-architecture.md`
+
+pub mod genre_rules;
+pub mod graduated;
+
+use std::collections::BTreeMap;
+
+use crate::traits::GameDesignSystem;
+use crate::types::character::CharacterSheet;
+
+pub use genre_rules::{genre_rules_from_bedrock, GenreRules, WorldAffordances};
+pub use graduated::{GraduatedResolution, ResolutionTuning};
+
+/// Per-genre selection of resolution mechanics.
+///
+/// Genres without an entry resolve with the default tuning.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameDesignRegistry {
+    default: ResolutionTuning,
+    genres: BTreeMap<String, ResolutionTuning>,
+}
+
+impl GameDesignRegistry {
+    /// An empty registry — every genre uses `default`.
+    pub fn new(default: ResolutionTuning) -> Self {
+        Self {
+            default,
+            genres: BTreeMap::new(),
+        }
+    }
+
+    /// The built-in genre tunings.
+    pub fn builtin() -> Self {
+        Self::new(ResolutionTuning::standard())
+            .with_genre("low_fantasy_folklore", ResolutionTuning::standard())
+            .with_genre("dark_fantasy", ResolutionTuning::gritty())
+            .with_genre("folk_horror", ResolutionTuning::gritty())
+            .with_genre("sci_fi_noir", ResolutionTuning::gritty())
+            .with_genre("cozy_ghost_story", ResolutionTuning::gentle())
+    }
+
+    /// Set (or replace) the tuning for a genre.
+    pub fn with_genre(mut self, genre_id: impl Into<String>, tuning: ResolutionTuning) -> Self {
+        self.genres.insert(genre_id.into(), tuning);
+        self
+    }
+
+    /// The tuning a genre resolves with.
+    pub fn tuning_for(&self, genre_id: &str) -> &ResolutionTuning {
+        self.genres.get(genre_id).unwrap_or(&self.default)
+    }
+
+    /// Build the game design system for one turn of a scene in `genre_id`.
+    pub fn system_for(
+        &self,
+        genre_id: &str,
+        characters: &[CharacterSheet],
+        seed: u64,
+    ) -> Box<dyn GameDesignSystem> {
+        Box::new(
+            GraduatedResolution::new(self.tuning_for(genre_id).clone())
+                .with_characters(characters)
+                .with_seed(seed),
+        )
+    }
+}
+
+impl Default for GameDesignRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+/// Derive a per-turn seed from a session seed, so each turn rolls
+/// differently but a replayed turn rolls the same.
+pub fn turn_seed(session_seed: u64, turn: u32) -> u64 {
+    session_seed ^ u64::from(turn).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn genres_select_their_tuning() {
+        let registry = GameDesignRegistry::builtin();
+        assert_eq!(
+            registry.tuning_for("dark_fantasy"),
+            &ResolutionTuning::gritty()
+        );
+        assert_eq!(
+            registry.tuning_for("cozy_ghost_story"),
+            &ResolutionTuning::gentle()
+        );
+        assert_eq!(
+            registry.tuning_for("unheard_of"),
+            &ResolutionTuning::standard()
+        );
+
+        let custom = ResolutionTuning {
+            base_chance: 0.3,
+            ..ResolutionTuning::standard()
+        };
+        let registry = registry.with_genre("dark_fantasy", custom.clone());
+        assert_eq!(registry.tuning_for("dark_fantasy"), &custom);
+    }
+
+    #[test]
+    fn system_for_builds_graduated_resolution() {
+        let system = GameDesignRegistry::default().system_for("low_fantasy_folklore", &[], 7);
+        assert_eq!(system.name(), GraduatedResolution::NAME);
+    }
+
+    #[test]
+    fn turn_seeds_differ_per_turn() {
+        assert_ne!(turn_seed(1, 1), turn_seed(1, 2));
+        assert_eq!(turn_seed(1, 3), turn_seed(1, 3));
+        assert_eq!(turn_seed(9, 0), 9);
+    }
+
+    #[test]
+    fn tuning_deserializes_partially() {
+        let tuning: ResolutionTuning = serde_json::from_str(r#"{"base_chance": 0.4}"#).unwrap();
+        assert_eq!(tuning.base_chance, 0.4);
+        assert_eq!(
+            tuning.partial_band,
+            ResolutionTuning::standard().partial_band
+        );
+    }
+}
@@ -0,0 +1,324 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Data-driven emotional grammars — vocabularies declared in TOML or JSON.
+//!
+//! A grammar file names the grammar, its intensity range, and its primaries
+//! with their opposites and gradient labels:
+//!
+//! ```toml
+//! id = "wu_xing"
+//! name = "Wu Xing Five Emotions"
+//! intensity_range = [0.0, 1.0]
+//!
+//! [[primaries]]
+//! id = "joy"
+//! name = "Joy"
+//! low_intensity_label = "ease"
+//! high_intensity_label = "overexcitement"
+//! ```
+//!
+//! Opposition is optional, but when a primary names an opposite the
+//! opposite must name it back. Files are validated on load, so a grammar
+//! that reaches the [`GrammarRegistry`](super::GrammarRegistry) is
+//! internally consistent.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{StorytellerError, StorytellerResult};
+use crate::traits::emotional_grammar::{EmotionalGrammar, PrimaryDef};
+use crate::types::character::EmotionalState;
+
+/// The on-disk shape of a grammar file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrammarDefinition {
+    /// Grammar identifier, matched against `CharacterSheet::grammar_id`.
+    pub id: String,
+    /// Human-readable name.
+    pub name: String,
+    /// Valid intensity range for every primary.
+    #[serde(default = "default_intensity_range")]
+    pub intensity_range: (f32, f32),
+    /// The vocabulary, in the order features and prompts present it.
+    pub primaries: Vec<PrimaryDef>,
+}
+
+fn default_intensity_range() -> (f32, f32) {
+    (0.0, 1.0)
+}
+
+impl GrammarDefinition {
+    /// Check the definition for internal consistency.
+    ///
+    /// Returns every problem found rather than stopping at the first, so a
+    /// writer fixing a file sees the whole list at once.
+    pub fn check(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if self.id.trim().is_empty() {
+            errors.push("grammar id is empty".to_string());
+        }
+        if self.primaries.is_empty() {
+            errors.push("grammar declares no primaries".to_string());
+        }
+
+        let (lo, hi) = self.intensity_range;
+        if !(lo.is_finite() && hi.is_finite() && lo < hi) {
+            errors.push(format!("intensity_range [{lo}, {hi}] is not a valid range"));
+        }
+
+        let mut seen = HashSet::new();
+        for primary in &self.primaries {
+            if primary.id.trim().is_empty() {
+                errors.push("primary with an empty id".to_string());
+            } else if !seen.insert(primary.id.as_str()) {
+                errors.push(format!("duplicate primary: '{}'", primary.id));
+            }
+        }
+
+        for primary in &self.primaries {
+            let Some(opposite_id) = &primary.opposite_id else {
+                continue;
+            };
+            if *opposite_id == primary.id {
+                errors.push(format!("primary '{}' is its own opposite", primary.id));
+                continue;
+            }
+            match self.primaries.iter().find(|p| p.id == *opposite_id) {
+                None => errors.push(format!(
+                    "primary '{}' names unknown opposite '{opposite_id}'",
+                    primary.id
+                )),
+                Some(opposite) if opposite.opposite_id.as_deref() != Some(&primary.id) => {
+                    errors.push(format!(
+                        "opposition between '{}' and '{opposite_id}' is not symmetric",
+                        primary.id
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// An emotional grammar built from a [`GrammarDefinition`].
+#[derive(Debug, Clone)]
+pub struct DataGrammar {
+    definition: GrammarDefinition,
+}
+
+impl DataGrammar {
+    /// Build a grammar from a definition, rejecting inconsistent ones.
+    pub fn new(definition: GrammarDefinition) -> StorytellerResult<Self> {
+        definition.check().map_err(|errors| {
+            StorytellerError::Config(format!(
+                "invalid grammar '{}': {}",
+                definition.id,
+                errors.join("; ")
+            ))
+        })?;
+        Ok(Self { definition })
+    }
+
+    /// Parse and validate a TOML grammar definition.
+    pub fn from_toml_str(content: &str) -> StorytellerResult<Self> {
+        let definition = toml::from_str(content)
+            .map_err(|e| StorytellerError::Config(format!("failed to parse grammar TOML: {e}")))?;
+        Self::new(definition)
+    }
+
+    /// Parse and validate a JSON grammar definition.
+    pub fn from_json_str(content: &str) -> StorytellerResult<Self> {
+        let definition = serde_json::from_str(content)
+            .map_err(|e| StorytellerError::Config(format!("failed to parse grammar JSON: {e}")))?;
+        Self::new(definition)
+    }
+
+    /// Load a grammar file, choosing the format by extension (`.toml` or
+    /// `.json`).
+    pub fn load(path: &Path) -> StorytellerResult<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            StorytellerError::Config(format!("failed to read grammar {}: {e}", path.display()))
+        })?;
+        let parsed = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("json") => Self::from_json_str(&content),
+            _ => {
+                return Err(StorytellerError::Config(format!(
+                    "grammar {} is neither .toml nor .json",
+                    path.display()
+                )))
+            }
+        };
+        parsed.map_err(|e| StorytellerError::Config(format!("{}: {e}", path.display())))
+    }
+
+    /// The definition this grammar was built from.
+    pub fn definition(&self) -> &GrammarDefinition {
+        &self.definition
+    }
+}
+
+impl EmotionalGrammar for DataGrammar {
+    fn id(&self) -> &str {
+        &self.definition.id
+    }
+
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn primaries(&self) -> &[PrimaryDef] {
+        &self.definition.primaries
+    }
+
+    fn intensity_range(&self) -> (f32, f32) {
+        self.definition.intensity_range
+    }
+
+    fn validate_state(&self, state: &EmotionalState) -> Result<(), Vec<String>> {
+        super::validate_vocabulary(self, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::character::EmotionalPrimary;
+    use crate::types::tensor::AwarenessLevel;
+
+    const TWO_PRIMARIES: &str = r#"
+        id = "tidal"
+        name = "Tidal"
+        intensity_range = [0.0, 2.0]
+
+        [[primaries]]
+        id = "flood"
+        name = "Flood"
+        opposite_id = "ebb"
+        low_intensity_label = "swell"
+        high_intensity_label = "deluge"
+
+        [[primaries]]
+        id = "ebb"
+        name = "Ebb"
+        opposite_id = "flood"
+        low_intensity_label = "slack"
+        high_intensity_label = "drought"
+    "#;
+
+    #[test]
+    fn toml_and_json_definitions_agree() {
+        let from_toml = DataGrammar::from_toml_str(TWO_PRIMARIES).unwrap();
+        let json = serde_json::to_string(from_toml.definition()).unwrap();
+        let from_json = DataGrammar::from_json_str(&json).unwrap();
+
+        assert_eq!(from_toml.definition(), from_json.definition());
+        assert_eq!(from_toml.id(), "tidal");
+        assert_eq!(from_toml.intensity_range(), (0.0, 2.0));
+        assert_eq!(from_toml.primaries().len(), 2);
+        assert_eq!(from_toml.primaries()[0].opposite_id.as_deref(), Some("ebb"));
+    }
+
+    #[test]
+    fn intensity_range_defaults_to_unit() {
+        let grammar = DataGrammar::from_json_str(
+            r#"{"id": "one", "name": "One", "primaries": [
+                {"id": "calm", "name": "Calm",
+                 "low_intensity_label": "still", "high_intensity_label": "serene"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(grammar.intensity_range(), (0.0, 1.0));
+        assert!(grammar.primaries()[0].opposite_id.is_none());
+    }
+
+    #[test]
+    fn inconsistent_definitions_are_rejected_with_every_problem() {
+        let definition = GrammarDefinition {
+            id: "broken".to_string(),
+            name: "Broken".to_string(),
+            intensity_range: (1.0, 0.0),
+            primaries: vec![
+                primary("a", Some("b")),
+                primary("b", None),
+                primary("c", Some("missing")),
+                primary("a", None),
+            ],
+        };
+        let errors = definition.check().unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("not a valid range")));
+        assert!(errors.iter().any(|e| e.contains("duplicate primary: 'a'")));
+        assert!(errors.iter().any(|e| e.contains("not symmetric")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("unknown opposite 'missing'")));
+
+        let err = DataGrammar::new(definition).unwrap_err();
+        assert!(err.to_string().contains("invalid grammar 'broken'"));
+    }
+
+    #[test]
+    fn states_validate_against_the_declared_vocabulary() {
+        let grammar = DataGrammar::from_toml_str(TWO_PRIMARIES).unwrap();
+        let mut state = EmotionalState {
+            grammar_id: "tidal".to_string(),
+            primaries: vec![
+                EmotionalPrimary {
+                    primary_id: "flood".to_string(),
+                    intensity: 1.5,
+                    awareness: AwarenessLevel::Articulate,
+                },
+                EmotionalPrimary {
+                    primary_id: "ebb".to_string(),
+                    intensity: 0.2,
+                    awareness: AwarenessLevel::Preconscious,
+                },
+            ],
+            mood_vector_notes: vec![],
+        };
+        assert!(grammar.validate_state(&state).is_ok());
+
+        state.primaries.pop();
+        state.primaries[0].intensity = 2.5;
+        let errors = grammar.validate_state(&state).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("missing primary: 'ebb'")));
+        assert!(errors.iter().any(|e| e.contains("out of range")));
+    }
+
+    #[test]
+    fn load_picks_the_format_from_the_extension() {
+        let dir = std::env::temp_dir().join(format!("grammar-load-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let toml_path = dir.join("tidal.toml");
+        std::fs::write(&toml_path, TWO_PRIMARIES).unwrap();
+        let yaml_path = dir.join("tidal.yaml");
+        std::fs::write(&yaml_path, TWO_PRIMARIES).unwrap();
+
+        assert_eq!(DataGrammar::load(&toml_path).unwrap().id(), "tidal");
+        let err = DataGrammar::load(&yaml_path).unwrap_err();
+        assert!(err.to_string().contains("neither .toml nor .json"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn primary(id: &str, opposite: Option<&str>) -> PrimaryDef {
+        PrimaryDef {
+            id: id.to_string(),
+            name: id.to_string(),
+            opposite_id: opposite.map(str::to_string),
+            low_intensity_label: "low".to_string(),
+            high_intensity_label: "high".to_string(),
+        }
+    }
+}
@@ -6,23 +6,86 @@
 //!
 //! Each grammar defines a bounded vocabulary of primary emotions with
 //! opposition structure and intensity gradients. Grammars are resolved
-//! by ID at runtime.
+//! by ID at runtime through the [`GrammarRegistry`]: `plutchik_western` is
+//! compiled in, and further vocabularies are declared as data
+//! ([`DataGrammar`]) under `config/grammars/`.
 //!
 //! See: `docs/foundation/emotional-model.md`
 
+pub mod definition;
 pub mod plutchik_western;
+pub mod registry;
 
 use crate::traits::EmotionalGrammar;
+use crate::types::character::EmotionalState;
 
+pub use definition::{DataGrammar, GrammarDefinition};
 pub use plutchik_western::PlutchikWestern;
+pub use registry::GrammarRegistry;
 
-/// Look up an emotional grammar by its ID.
+/// Look up a compiled-in emotional grammar by its ID.
 ///
-/// Returns `None` for unrecognized IDs. In the future, this could be
-/// replaced by a registry that supports runtime-registered grammars.
+/// Returns `None` for unrecognized IDs. Data-driven grammars are only
+/// reachable through a [`GrammarRegistry`].
 pub fn lookup(grammar_id: &str) -> Option<Box<dyn EmotionalGrammar>> {
     match grammar_id {
         PlutchikWestern::GRAMMAR_ID => Some(Box::new(PlutchikWestern::new())),
         _ => None,
     }
 }
+
+/// Validate a state against a grammar's vocabulary: the grammar ID matches,
+/// every primary is present, no unknown primaries, intensities in range.
+pub(crate) fn validate_vocabulary(
+    grammar: &dyn EmotionalGrammar,
+    state: &EmotionalState,
+) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+
+    // Grammar ID must match
+    if state.grammar_id != grammar.id() {
+        errors.push(format!(
+            "grammar_id mismatch: expected '{}', got '{}'",
+            grammar.id(),
+            state.grammar_id
+        ));
+    }
+
+    // Every primary should be present
+    let expected_ids: Vec<&str> = grammar.primaries().iter().map(|p| p.id.as_str()).collect();
+    let present_ids: Vec<&str> = state
+        .primaries
+        .iter()
+        .map(|p| p.primary_id.as_str())
+        .collect();
+
+    for expected in &expected_ids {
+        if !present_ids.contains(expected) {
+            errors.push(format!("missing primary: '{expected}'"));
+        }
+    }
+
+    // No unknown primaries
+    for present in &present_ids {
+        if !expected_ids.contains(present) {
+            errors.push(format!("unknown primary: '{present}'"));
+        }
+    }
+
+    // Intensities in range
+    let (lo, hi) = grammar.intensity_range();
+    for primary in &state.primaries {
+        if primary.intensity < lo || primary.intensity > hi {
+            errors.push(format!(
+                "primary '{}' intensity {} out of range [{}, {}]",
+                primary.primary_id, primary.intensity, lo, hi
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
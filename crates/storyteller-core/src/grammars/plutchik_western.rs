@@ -149,54 +149,7 @@ impl EmotionalGrammar for PlutchikWestern {
     }
 
     fn validate_state(&self, state: &EmotionalState) -> Result<(), Vec<String>> {
-        let mut errors = Vec::new();
-
-        // Grammar ID must match
-        if state.grammar_id != self.id() {
-            errors.push(format!(
-                "grammar_id mismatch: expected '{}', got '{}'",
-                self.id(),
-                state.grammar_id
-            ));
-        }
-
-        // All 8 primaries should be present
-        let expected_ids: Vec<&str> = self.primaries.iter().map(|p| p.id.as_str()).collect();
-        let present_ids: Vec<&str> = state
-            .primaries
-            .iter()
-            .map(|p| p.primary_id.as_str())
-            .collect();
-
-        for expected in &expected_ids {
-            if !present_ids.contains(expected) {
-                errors.push(format!("missing primary: '{expected}'"));
-            }
-        }
-
-        // No unknown primaries
-        for present in &present_ids {
-            if !expected_ids.contains(present) {
-                errors.push(format!("unknown primary: '{present}'"));
-            }
-        }
-
-        // Intensities in range
-        let (lo, hi) = self.intensity_range();
-        for primary in &state.primaries {
-            if primary.intensity < lo || primary.intensity > hi {
-                errors.push(format!(
-                    "primary '{}' intensity {} out of range [{}, {}]",
-                    primary.primary_id, primary.intensity, lo, hi
-                ));
-            }
-        }
-
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(errors)
-        }
+        super::validate_vocabulary(self, state)
     }
 }
 
@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Grammar registry — resolves `grammar_id` to an [`EmotionalGrammar`].
+//!
+//! The registry starts with the compiled-in grammars and takes data-driven
+//! ones from a directory of TOML/JSON files (see [`DataGrammar`]). A sheet
+//! whose `grammar_id` is not registered resolves to the default grammar, so
+//! a missing file degrades a character's vocabulary rather than failing the
+//! turn.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::{DataGrammar, PlutchikWestern};
+use crate::errors::{StorytellerError, StorytellerResult};
+use crate::traits::EmotionalGrammar;
+
+/// Emotional grammars keyed by ID, with a default for unknown IDs.
+#[derive(Debug, Clone)]
+pub struct GrammarRegistry {
+    default: Arc<dyn EmotionalGrammar>,
+    grammars: BTreeMap<String, Arc<dyn EmotionalGrammar>>,
+}
+
+impl GrammarRegistry {
+    /// A registry holding only `default`.
+    pub fn new(default: Arc<dyn EmotionalGrammar>) -> Self {
+        let mut grammars = BTreeMap::new();
+        grammars.insert(default.id().to_string(), default.clone());
+        Self { default, grammars }
+    }
+
+    /// The compiled-in grammars, defaulting to `plutchik_western`.
+    pub fn builtin() -> Self {
+        Self::new(Arc::new(PlutchikWestern::new()))
+    }
+
+    /// Add (or replace) a grammar, keyed by its own ID.
+    pub fn with_grammar(mut self, grammar: Arc<dyn EmotionalGrammar>) -> Self {
+        self.register(grammar);
+        self
+    }
+
+    /// Add (or replace) a grammar, keyed by its own ID. Returns the grammar
+    /// it replaced, if any.
+    pub fn register(
+        &mut self,
+        grammar: Arc<dyn EmotionalGrammar>,
+    ) -> Option<Arc<dyn EmotionalGrammar>> {
+        let id = grammar.id().to_string();
+        if id == self.default.id() {
+            self.default = grammar.clone();
+        }
+        self.grammars.insert(id, grammar)
+    }
+
+    /// Load every `.toml` and `.json` grammar file in `dir`.
+    ///
+    /// Files load in name order; other files are ignored. Two files
+    /// declaring the same ID is an error, but a file may replace a
+    /// compiled-in grammar. Returns the IDs loaded.
+    pub fn load_dir(&mut self, dir: &Path) -> StorytellerResult<Vec<String>> {
+        let entries = std::fs::read_dir(dir).map_err(|e| {
+            StorytellerError::Config(format!(
+                "failed to read grammar directory {}: {e}",
+                dir.display()
+            ))
+        })?;
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.is_file()
+                    && matches!(
+                        path.extension().and_then(|ext| ext.to_str()),
+                        Some("toml" | "json")
+                    )
+            })
+            .collect();
+        paths.sort();
+
+        let mut loaded: Vec<String> = Vec::with_capacity(paths.len());
+        for path in paths {
+            let grammar = DataGrammar::load(&path)?;
+            let id = grammar.id().to_string();
+            if loaded.contains(&id) {
+                return Err(StorytellerError::Config(format!(
+                    "grammar '{id}' is declared twice in {}",
+                    dir.display()
+                )));
+            }
+            if self.register(Arc::new(grammar)).is_some() {
+                tracing::info!(grammar_id = %id, path = %path.display(), "Grammar file replaces a built-in grammar");
+            } else {
+                tracing::debug!(grammar_id = %id, path = %path.display(), "Grammar loaded");
+            }
+            loaded.push(id);
+        }
+        Ok(loaded)
+    }
+
+    /// The grammar registered under `grammar_id`, if any.
+    pub fn get(&self, grammar_id: &str) -> Option<&Arc<dyn EmotionalGrammar>> {
+        self.grammars.get(grammar_id)
+    }
+
+    /// The grammar a sheet with `grammar_id` uses — the default when the ID
+    /// is not registered.
+    pub fn grammar_for(&self, grammar_id: &str) -> &dyn EmotionalGrammar {
+        match self.grammars.get(grammar_id) {
+            Some(grammar) => grammar.as_ref(),
+            None => {
+                tracing::warn!(
+                    grammar_id,
+                    fallback = self.default.id(),
+                    "Unknown emotional grammar, using the default"
+                );
+                self.default.as_ref()
+            }
+        }
+    }
+
+    /// The grammar unknown IDs resolve to.
+    pub fn default_grammar(&self) -> &dyn EmotionalGrammar {
+        self.default.as_ref()
+    }
+
+    /// Registered grammar IDs, in order.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.grammars.keys().map(String::as_str)
+    }
+}
+
+impl Default for GrammarRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammars::GrammarDefinition;
+    use crate::traits::emotional_grammar::PrimaryDef;
+
+    fn tiny(id: &str) -> Arc<dyn EmotionalGrammar> {
+        Arc::new(
+            DataGrammar::new(GrammarDefinition {
+                id: id.to_string(),
+                name: id.to_string(),
+                intensity_range: (0.0, 1.0),
+                primaries: vec![PrimaryDef {
+                    id: "calm".to_string(),
+                    name: "Calm".to_string(),
+                    opposite_id: None,
+                    low_intensity_label: "still".to_string(),
+                    high_intensity_label: "serene".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn unknown_ids_resolve_to_the_default() {
+        let registry = GrammarRegistry::builtin().with_grammar(tiny("calm_only"));
+        assert_eq!(
+            registry.grammar_for(PlutchikWestern::GRAMMAR_ID).id(),
+            PlutchikWestern::GRAMMAR_ID
+        );
+        assert_eq!(registry.grammar_for("calm_only").primaries().len(), 1);
+        assert_eq!(
+            registry.grammar_for("unheard_of").id(),
+            PlutchikWestern::GRAMMAR_ID
+        );
+        assert!(registry.get("unheard_of").is_none());
+        assert_eq!(
+            registry.ids().collect::<Vec<_>>(),
+            vec!["calm_only", PlutchikWestern::GRAMMAR_ID]
+        );
+    }
+
+    #[test]
+    fn replacing_the_default_updates_the_fallback() {
+        let mut registry = GrammarRegistry::builtin();
+        let replaced = registry.register(tiny(PlutchikWestern::GRAMMAR_ID));
+        assert!(replaced.is_some());
+        assert_eq!(registry.default_grammar().primaries().len(), 1);
+        assert_eq!(registry.grammar_for("unheard_of").primaries().len(), 1);
+    }
+
+    #[test]
+    fn repo_grammars_load_from_the_config_directory() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../config/grammars");
+        let mut registry = GrammarRegistry::builtin();
+        let loaded = registry.load_dir(&dir).unwrap();
+        assert!(loaded.iter().any(|id| id == "wu_xing"));
+
+        let wu_xing = registry.grammar_for("wu_xing");
+        assert_eq!(wu_xing.primaries().len(), 5);
+        assert!(wu_xing.primaries().iter().all(|p| p.opposite_id.is_none()));
+    }
+
+    #[test]
+    fn duplicate_ids_in_one_directory_are_rejected() {
+        let dir = std::env::temp_dir().join(format!("grammar-registry-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let grammar = r#"
+            id = "twice"
+            name = "Twice"
+            [[primaries]]
+            id = "calm"
+            name = "Calm"
+            low_intensity_label = "still"
+            high_intensity_label = "serene"
+        "#;
+        std::fs::write(dir.join("a.toml"), grammar).unwrap();
+        std::fs::write(dir.join("b.toml"), grammar).unwrap();
+        std::fs::write(dir.join("notes.md"), "not a grammar").unwrap();
+
+        let err = GrammarRegistry::builtin().load_dir(&dir).unwrap_err();
+        assert!(err.to_string().contains("declared twice"));
+
+        std::fs::remove_file(dir.join("b.toml")).unwrap();
+        let mut registry = GrammarRegistry::builtin();
+        assert_eq!(registry.load_dir(&dir).unwrap(), vec!["twice"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
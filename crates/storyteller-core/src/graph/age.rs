@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Apache AGE session plumbing shared by the graph query modules.
+//!
+//! See: `docs/technical/age-persistence/README.md`
+//!
+//! AGE must be loaded and `ag_catalog` put on the search path per
+//! connection; [`begin`] does both inside a transaction so the settings
+//! never leak back into the pool. Cypher text is assembled with
+//! [`uuid_literal`] and [`float_literal`] only — values that cannot carry
+//! quotes — because AGE does not accept bind parameters inside `cypher()`.
+
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::errors::{StorytellerError, StorytellerResult};
+
+/// The single AGE graph all label namespaces live in.
+pub const GRAPH_NAME: &str = "storyteller";
+
+/// Whether the AGE extension is installed and the storyteller graph exists.
+///
+/// Callers use this to choose between an AGE-backed store and the
+/// in-process fallback.
+pub async fn age_available(pool: &PgPool) -> StorytellerResult<bool> {
+    let exists: Option<bool> = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'age') \
+         AND to_regclass('storyteller._ag_label_vertex') IS NOT NULL",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(StorytellerError::Database)?;
+    Ok(exists.unwrap_or(false))
+}
+
+/// Start a transaction with AGE loaded and `ag_catalog` on the search path.
+pub async fn begin(pool: &PgPool) -> StorytellerResult<Transaction<'static, Postgres>> {
+    let mut tx = pool.begin().await.map_err(StorytellerError::Database)?;
+    sqlx::query("LOAD 'age'")
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+    sqlx::query("SET LOCAL search_path = ag_catalog, \"$user\", public")
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+    Ok(tx)
+}
+
+/// Register a story that has no authored `stories` row yet.
+///
+/// Graph records live in story-scoped baseline tables; composed stories
+/// are registered on first write, as the Storykeeper does for sessions.
+pub async fn register_story(conn: &mut PgConnection, story_id: Uuid) -> StorytellerResult<()> {
+    sqlx::query(
+        "INSERT INTO stories (id, title) VALUES ($1, 'Composed story') \
+         ON CONFLICT (id) DO NOTHING",
+    )
+    .bind(story_id)
+    .execute(conn)
+    .await
+    .map_err(StorytellerError::Database)?;
+    Ok(())
+}
+
+/// A UUID as a quoted Cypher string literal.
+pub fn uuid_literal(id: Uuid) -> String {
+    format!("'{id}'")
+}
+
+/// A Cypher list of UUID string literals.
+pub fn uuid_list(ids: impl IntoIterator<Item = Uuid>) -> String {
+    let items: Vec<String> = ids.into_iter().map(uuid_literal).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// A float as a Cypher numeric literal. Non-finite values become `0.0`.
+pub fn float_literal(value: f32) -> String {
+    if value.is_finite() {
+        format!("{value:.6}")
+    } else {
+        "0.0".to_string()
+    }
+}
+
+/// Parse an agtype string scalar rendered through `::text` (`"…"`) as a UUID.
+pub fn parse_uuid(agtype_text: &str) -> StorytellerResult<Uuid> {
+    let raw = agtype_text.trim().trim_matches('"');
+    Uuid::parse_str(raw).map_err(|e| StorytellerError::Graph(format!("bad pg_id {raw}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literals_render_as_cypher() {
+        let id = Uuid::nil();
+        assert_eq!(
+            uuid_list([id, id]),
+            format!("['{id}', '{id}']"),
+            "list of quoted uuids"
+        );
+        assert_eq!(float_literal(0.25), "0.250000");
+        assert_eq!(float_literal(f32::NAN), "0.0");
+    }
+
+    #[test]
+    fn parse_uuid_strips_agtype_quotes() {
+        let id = Uuid::now_v7();
+        assert_eq!(parse_uuid(&format!("\"{id}\"")).unwrap(), id);
+        assert!(parse_uuid("\"nope\"").is_err());
+    }
+}
@@ -9,7 +9,11 @@
 //! All graph data (relational web, narrative graph, setting topology) lives in
 //! PostgreSQL with Apache AGE providing openCypher query support. No separate
 //! graph database needed.
+//!
+//! Each graph module pairs an AGE-backed store with an in-process fallback
+//! behind one trait, so queries run unchanged in tests without Postgres.
 
+pub mod age;
 pub mod narrative;
 pub mod relational_web;
 pub mod settings;
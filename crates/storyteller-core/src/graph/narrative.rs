@@ -4,7 +4,1088 @@
 
 //! Narrative graph queries — scene connectivity and gravitational landscape.
 //!
-//! See: `docs/technical/narrative-graph-case-study-tfatd.md`
+//! See: `docs/technical/narrative-graph-case-study-tfatd.md`,
+//! `docs/technical/graph-strategy/narrative-gravity.md`,
+//! `docs/technical/age-persistence/narrative-graph-age.md`
 //!
 //! Queries for scene adjacency, attractor basins, gravitational pull
 //! calculations, and path-dependent approach vectors.
+//!
+//! Scenes are [`NarrativeScene`] nodes joined by [`SceneTransition`]s typed
+//! by [`DepartureType`]. Pull follows the inverse-square law from the design:
+//!
+//! ```text
+//! pull(current, s) = M_effective(s) / max(D(current, s), MIN_DISTANCE)²
+//! M_effective(s)   = authored_base + structural_modifier + satisfaction(s) × 0.2
+//! ```
+//!
+//! where `satisfaction` is the best-matching authored approach vector
+//! evaluated against the committed [`TruthSet`]. Narrative distance `D` is
+//! currently the structural component only — the sum of transition lengths
+//! along the shortest path, each `1 − weight`. The information, emotional,
+//! and relational components join as their graphs come online.
+//!
+//! As with the relational web, a [`NarrativeGraphStore`] backend loads a
+//! bounded region into an in-process [`NarrativeGraph`] and all scoring runs
+//! there, so [`AgeNarrativeGraph`] and [`InMemoryNarrativeGraph`] agree.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::RwLock;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::age;
+use crate::database::checkpoint::TruthSet;
+use crate::errors::{StorytellerError, StorytellerResult};
+use crate::types::event_grammar::{EventKind, SceneLifecycleType};
+use crate::types::narrative::{
+    NarrativeMass, NarrativeScene, SceneApproach, SceneTransition, TruthPredicate,
+};
+use crate::types::scene::{DepartureType, SceneId, SceneType};
+
+/// Floor on narrative distance — prevents infinite pull at arrival.
+pub const MIN_DISTANCE: f32 = 0.05;
+
+/// Scale from approach satisfaction `[0, 1]` to dynamic mass `[0, 0.2]`.
+pub const DYNAMIC_MASS_SCALE: f32 = 0.2;
+
+// ---------------------------------------------------------------------------
+// Approach vectors and mass
+// ---------------------------------------------------------------------------
+
+/// How well the truth set satisfies one predicate, in `[0.0, 1.0]`.
+pub fn predicate_satisfaction(predicate: &TruthPredicate, truth: &TruthSet) -> f32 {
+    let contains = |text: &str, needle: &str| text.to_lowercase().contains(&needle.to_lowercase());
+
+    match predicate {
+        TruthPredicate::FactEstablished { fact } => {
+            let established = truth.atoms().any(|a| match &a.kind {
+                EventKind::StateAssertion { assertion } => contains(assertion, fact),
+                EventKind::InformationTransfer { content_summary } => {
+                    contains(content_summary, fact)
+                }
+                _ => false,
+            });
+            f32::from(u8::from(established))
+        }
+        TruthPredicate::EntityInvolved {
+            entity_id,
+            min_events,
+        } => {
+            if *min_events == 0 {
+                return 1.0;
+            }
+            let count = truth
+                .atoms()
+                .filter(|a| {
+                    a.participants
+                        .iter()
+                        .any(|p| p.entity.entity_id() == Some(*entity_id))
+                })
+                .count();
+            (count as f32 / *min_events as f32).min(1.0)
+        }
+        TruthPredicate::EmotionExpressed { emotion } => {
+            let expressed = truth.atoms().any(|a| {
+                matches!(
+                    &a.kind,
+                    EventKind::EmotionalExpression { emotion_hint: Some(hint), .. }
+                        if contains(hint, emotion)
+                )
+            });
+            f32::from(u8::from(expressed))
+        }
+        TruthPredicate::SceneEntered { scene_id } => {
+            let entered = truth.atoms().any(|a| {
+                a.scene_id == *scene_id
+                    && matches!(
+                        a.kind,
+                        EventKind::SceneLifecycle {
+                            lifecycle_type: SceneLifecycleType::Entered
+                        }
+                    )
+            });
+            f32::from(u8::from(entered))
+        }
+    }
+}
+
+/// Weighted mean predicate satisfaction for one approach vector.
+pub fn approach_satisfaction(approach: &SceneApproach, truth: &TruthSet) -> f32 {
+    let mut total_weight = 0.0;
+    let mut total_match = 0.0;
+    for wp in &approach.predicates {
+        let weight = wp.weight.max(0.0);
+        total_weight += weight;
+        total_match += weight * predicate_satisfaction(&wp.predicate, truth);
+    }
+    if total_weight == 0.0 {
+        0.0
+    } else {
+        total_match / total_weight
+    }
+}
+
+/// The approach vector a player's truth set best matches.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ApproachMatch {
+    /// Name of the matched approach vector.
+    pub name: String,
+    /// Its satisfaction, in `[0.0, 1.0]`.
+    pub satisfaction: f32,
+}
+
+/// The best-satisfied approach vector for a scene, if it has any.
+pub fn best_approach(scene: &NarrativeScene, truth: &TruthSet) -> Option<ApproachMatch> {
+    scene
+        .approach_vectors
+        .iter()
+        .map(|a| ApproachMatch {
+            name: a.name.clone(),
+            satisfaction: approach_satisfaction(a, truth),
+        })
+        .max_by(|a, b| a.satisfaction.total_cmp(&b.satisfaction))
+}
+
+/// The scene's mass with `dynamic_adjustment` computed from the truth set.
+pub fn effective_mass(scene: &NarrativeScene, truth: &TruthSet) -> NarrativeMass {
+    let satisfaction = best_approach(scene, truth).map_or(0.0, |m| m.satisfaction);
+    NarrativeMass {
+        dynamic_adjustment: satisfaction * DYNAMIC_MASS_SCALE,
+        ..scene.mass
+    }
+}
+
+/// Narrative length of one transition — stronger transitions are shorter.
+pub fn transition_length(transition: &SceneTransition) -> f32 {
+    (1.0 - transition.weight.clamp(0.0, 1.0)).max(MIN_DISTANCE)
+}
+
+// ---------------------------------------------------------------------------
+// Position and pull
+// ---------------------------------------------------------------------------
+
+/// Where a session stands in the narrative graph.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NarrativePosition {
+    /// The scene being played.
+    pub current: SceneId,
+    /// Scenes already completed this session.
+    pub completed: BTreeSet<SceneId>,
+}
+
+impl NarrativePosition {
+    /// A fresh position at `scene` with nothing completed.
+    pub fn at(scene: SceneId) -> Self {
+        Self {
+            current: scene,
+            completed: BTreeSet::new(),
+        }
+    }
+}
+
+/// Gravitational pull of one candidate scene.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ScenePull {
+    /// The candidate scene.
+    pub scene_id: SceneId,
+    /// Its title.
+    pub title: String,
+    /// Its type.
+    pub scene_type: SceneType,
+    /// Effective mass, with the dynamic adjustment filled in.
+    pub mass: NarrativeMass,
+    /// Shortest narrative distance from the current scene.
+    pub distance: f32,
+    /// Transitions along that shortest path.
+    pub hops: usize,
+    /// `mass / max(distance, MIN_DISTANCE)²`.
+    pub pull: f32,
+    /// The approach vector the player currently best matches.
+    pub approach: Option<ApproachMatch>,
+}
+
+// ---------------------------------------------------------------------------
+// In-process graph
+// ---------------------------------------------------------------------------
+
+/// An in-process narrative graph: scenes and their typed transitions.
+///
+/// At most one transition per `(from, to, departure)`.
+#[derive(Debug, Clone, Default)]
+pub struct NarrativeGraph {
+    scenes: BTreeMap<SceneId, NarrativeScene>,
+    transitions: BTreeMap<SceneId, Vec<SceneTransition>>,
+}
+
+impl NarrativeGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a graph from scenes and transitions.
+    pub fn from_parts(
+        scenes: impl IntoIterator<Item = NarrativeScene>,
+        transitions: impl IntoIterator<Item = SceneTransition>,
+    ) -> Self {
+        let mut graph = Self::new();
+        for scene in scenes {
+            graph.upsert_scene(scene);
+        }
+        for transition in transitions {
+            graph.upsert_transition(transition);
+        }
+        graph
+    }
+
+    /// Insert or replace a scene.
+    pub fn upsert_scene(&mut self, scene: NarrativeScene) {
+        self.scenes.insert(scene.scene_id, scene);
+    }
+
+    /// Insert or replace the transition for `(from, to, departure)`.
+    pub fn upsert_transition(&mut self, transition: SceneTransition) {
+        let outgoing = self.transitions.entry(transition.from).or_default();
+        outgoing.retain(|t| !(t.to == transition.to && t.departure == transition.departure));
+        outgoing.push(transition);
+    }
+
+    /// A scene by id.
+    pub fn scene(&self, scene_id: SceneId) -> Option<&NarrativeScene> {
+        self.scenes.get(&scene_id)
+    }
+
+    /// All scenes, ordered by id.
+    pub fn scenes(&self) -> impl Iterator<Item = &NarrativeScene> {
+        self.scenes.values()
+    }
+
+    /// Transitions leaving `scene_id`.
+    pub fn transitions_from(&self, scene_id: SceneId) -> &[SceneTransition] {
+        self.transitions
+            .get(&scene_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Transitions a departure of the given type can take from `current`.
+    pub fn next_scenes(&self, current: SceneId, departure: DepartureType) -> Vec<&SceneTransition> {
+        self.transitions_from(current)
+            .iter()
+            .filter(|t| t.departure == departure)
+            .collect()
+    }
+
+    /// The region within `max_hops` transitions of `from`, including `from`.
+    pub fn reachable(&self, from: SceneId, max_hops: usize) -> NarrativeGraph {
+        let mut region = NarrativeGraph::new();
+        let mut seen: BTreeSet<SceneId> = BTreeSet::from([from]);
+        let mut frontier = vec![from];
+
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for scene_id in frontier {
+                for t in self.transitions_from(scene_id) {
+                    region.upsert_transition(t.clone());
+                    if seen.insert(t.to) {
+                        next.push(t.to);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        for scene_id in seen {
+            if let Some(scene) = self.scenes.get(&scene_id) {
+                region.upsert_scene(scene.clone());
+            }
+        }
+        region
+    }
+
+    /// Shortest narrative distance and hop count from `from` to every scene
+    /// reachable within `max_hops`, excluding `from` itself.
+    pub fn distances(&self, from: SceneId, max_hops: usize) -> HashMap<SceneId, (f32, usize)> {
+        // Hop-bounded Bellman-Ford: layer h holds the shortest distance
+        // using exactly h transitions, so the hop limit is exact.
+        let mut best: HashMap<SceneId, (f32, usize)> = HashMap::new();
+        let mut layer: HashMap<SceneId, f32> = HashMap::from([(from, 0.0)]);
+
+        for hops in 1..=max_hops {
+            let mut next: HashMap<SceneId, f32> = HashMap::new();
+            for (&scene_id, &dist) in &layer {
+                for t in self.transitions_from(scene_id) {
+                    let d = dist + transition_length(t);
+                    let slot = next.entry(t.to).or_insert(f32::INFINITY);
+                    if d < *slot {
+                        *slot = d;
+                    }
+                }
+            }
+            for (&scene_id, &d) in &next {
+                if scene_id == from {
+                    continue;
+                }
+                let improves = best.get(&scene_id).is_none_or(|(b, _)| d < *b);
+                if improves {
+                    best.insert(scene_id, (d, hops));
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            layer = next;
+        }
+        best
+    }
+
+    /// Pull of every candidate scene within `max_hops`, strongest first.
+    ///
+    /// The current scene is never a candidate. Completed scenes are skipped
+    /// unless they are connective — travel and texture can be revisited.
+    pub fn pull_scores(
+        &self,
+        position: &NarrativePosition,
+        truth: &TruthSet,
+        max_hops: usize,
+    ) -> Vec<ScenePull> {
+        let mut pulls: Vec<ScenePull> = self
+            .distances(position.current, max_hops)
+            .into_iter()
+            .filter_map(|(scene_id, (distance, hops))| {
+                let scene = self.scenes.get(&scene_id)?;
+                if position.completed.contains(&scene_id)
+                    && scene.scene_type != SceneType::Connective
+                {
+                    return None;
+                }
+                let mass = effective_mass(scene, truth);
+                let clamped = distance.max(MIN_DISTANCE);
+                Some(ScenePull {
+                    scene_id,
+                    title: scene.title.clone(),
+                    scene_type: scene.scene_type,
+                    mass,
+                    distance,
+                    hops,
+                    pull: mass.effective() / (clamped * clamped),
+                    approach: best_approach(scene, truth),
+                })
+            })
+            .collect();
+
+        pulls.sort_by(|a, b| {
+            b.pull
+                .total_cmp(&a.pull)
+                .then_with(|| a.scene_id.cmp(&b.scene_id))
+        });
+        pulls
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Storage backends
+// ---------------------------------------------------------------------------
+
+/// Storage for the narrative graph of one story.
+///
+/// Backends store scenes and transitions and load reachable regions; the
+/// provided query methods score on the loaded [`NarrativeGraph`].
+#[async_trait::async_trait]
+pub trait NarrativeGraphStore: Send + Sync {
+    /// Insert or replace a scene node.
+    async fn upsert_scene(&self, scene: &NarrativeScene) -> StorytellerResult<()>;
+
+    /// Insert or replace the transition for `(from, to, departure)`.
+    async fn upsert_transition(&self, transition: &SceneTransition) -> StorytellerResult<()>;
+
+    /// A scene node by id.
+    async fn scene(&self, scene_id: SceneId) -> StorytellerResult<Option<NarrativeScene>>;
+
+    /// The region within `max_hops` transitions of `from`, including `from`.
+    async fn reachable(&self, from: SceneId, max_hops: usize) -> StorytellerResult<NarrativeGraph>;
+
+    /// Transitions a departure of the given type can take from `current`.
+    async fn next_scenes(
+        &self,
+        current: SceneId,
+        departure: DepartureType,
+    ) -> StorytellerResult<Vec<SceneTransition>> {
+        let region = self.reachable(current, 1).await?;
+        Ok(region
+            .next_scenes(current, departure)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Pull of every candidate scene within `max_hops`, strongest first.
+    ///
+    /// See [`NarrativeGraph::pull_scores`].
+    async fn pull_scores(
+        &self,
+        position: &NarrativePosition,
+        truth: &TruthSet,
+        max_hops: usize,
+    ) -> StorytellerResult<Vec<ScenePull>> {
+        let region = self.reachable(position.current, max_hops).await?;
+        Ok(region.pull_scores(position, truth, max_hops))
+    }
+}
+
+/// In-process [`NarrativeGraphStore`] — the fallback when AGE is unavailable.
+#[derive(Debug, Default)]
+pub struct InMemoryNarrativeGraph {
+    graph: RwLock<NarrativeGraph>,
+}
+
+impl InMemoryNarrativeGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A copy of the full graph.
+    pub fn snapshot(&self) -> NarrativeGraph {
+        self.graph.read().expect("graph lock poisoned").clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl NarrativeGraphStore for InMemoryNarrativeGraph {
+    async fn upsert_scene(&self, scene: &NarrativeScene) -> StorytellerResult<()> {
+        self.graph
+            .write()
+            .expect("graph lock poisoned")
+            .upsert_scene(scene.clone());
+        Ok(())
+    }
+
+    async fn upsert_transition(&self, transition: &SceneTransition) -> StorytellerResult<()> {
+        self.graph
+            .write()
+            .expect("graph lock poisoned")
+            .upsert_transition(transition.clone());
+        Ok(())
+    }
+
+    async fn scene(&self, scene_id: SceneId) -> StorytellerResult<Option<NarrativeScene>> {
+        Ok(self
+            .graph
+            .read()
+            .expect("graph lock poisoned")
+            .scene(scene_id)
+            .cloned())
+    }
+
+    async fn reachable(&self, from: SceneId, max_hops: usize) -> StorytellerResult<NarrativeGraph> {
+        Ok(self
+            .graph
+            .read()
+            .expect("graph lock poisoned")
+            .reachable(from, max_hops))
+    }
+}
+
+/// Label for a scene type, shared by the `:Scene.scene_type` property and
+/// the baseline `scene_type` enum.
+pub fn scene_type_label(scene_type: SceneType) -> &'static str {
+    match scene_type {
+        SceneType::Gravitational => "gravitational",
+        SceneType::Connective => "connective",
+        SceneType::Gate => "gate",
+        SceneType::Threshold => "threshold",
+    }
+}
+
+fn parse_scene_type(label: &str) -> StorytellerResult<SceneType> {
+    match label {
+        "gravitational" => Ok(SceneType::Gravitational),
+        "connective" => Ok(SceneType::Connective),
+        "gate" => Ok(SceneType::Gate),
+        "threshold" => Ok(SceneType::Threshold),
+        other => Err(StorytellerError::Graph(format!(
+            "unknown scene type {other}"
+        ))),
+    }
+}
+
+/// Label for a departure type, shared by `:TRANSITIONS_TO.departure` and
+/// `scene_transitions.departure_conditions->>'departure'`.
+fn departure_label(departure: DepartureType) -> &'static str {
+    match departure {
+        DepartureType::Completed => "completed",
+        DepartureType::Abandoned => "abandoned",
+        DepartureType::Interrupted => "interrupted",
+        DepartureType::Traversed => "traversed",
+    }
+}
+
+fn parse_departure(label: &str) -> StorytellerResult<DepartureType> {
+    match label {
+        "completed" => Ok(DepartureType::Completed),
+        "abandoned" => Ok(DepartureType::Abandoned),
+        "interrupted" => Ok(DepartureType::Interrupted),
+        "traversed" => Ok(DepartureType::Traversed),
+        other => Err(StorytellerError::Graph(format!(
+            "unknown departure {other}"
+        ))),
+    }
+}
+
+/// Columns selected for a scene: id, title, type, mass, approach vectors.
+const SCENE_COLUMNS: &str = "id, title, scene_type::text, narrative_mass, \
+     COALESCE(scene_data->'approach_vectors', '[]'::jsonb)";
+
+type SceneRow = (
+    Uuid,
+    String,
+    String,
+    sqlx::types::Json<NarrativeMass>,
+    sqlx::types::Json<Vec<SceneApproach>>,
+);
+
+fn scene_from_row(
+    (id, title, scene_type, mass, approaches): SceneRow,
+) -> StorytellerResult<NarrativeScene> {
+    Ok(NarrativeScene {
+        scene_id: SceneId(id),
+        title,
+        scene_type: parse_scene_type(&scene_type)?,
+        mass: mass.0,
+        approach_vectors: approaches.0,
+    })
+}
+
+type TransitionRow = (Uuid, Uuid, String, f32, String);
+
+fn transition_from_row(
+    (from, to, departure, weight, outcome_label): TransitionRow,
+) -> StorytellerResult<SceneTransition> {
+    Ok(SceneTransition {
+        from: SceneId(from),
+        to: SceneId(to),
+        departure: parse_departure(&departure)?,
+        weight,
+        outcome_label,
+    })
+}
+
+/// AGE-backed [`NarrativeGraphStore`] for one story.
+///
+/// The baseline tables stay authoritative: scenes are rows in `scenes`
+/// (approach vectors under `scene_data.approach_vectors`) and transitions
+/// are rows in `scene_transitions`, with the departure type in
+/// `departure_conditions` and the weight in `momentum_transfer`. The graph
+/// projects them: `:Scene` vertices carry `scene_type` and `authored_base`
+/// for Cypher filtering, and `:TRANSITIONS_TO` edges carry `departure` and
+/// `transition_weight`.
+///
+/// Transitions reference their scenes, so both ends must be upserted
+/// first. `scene_transitions` keys a transition by its outcome label, so
+/// within one pair of scenes each departure needs its own label.
+#[derive(Debug, Clone)]
+pub struct AgeNarrativeGraph {
+    pool: PgPool,
+    story_id: Uuid,
+}
+
+impl AgeNarrativeGraph {
+    pub fn new(pool: PgPool, story_id: Uuid) -> Self {
+        Self { pool, story_id }
+    }
+
+    async fn run_cypher(
+        &self,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+        cypher: &str,
+    ) -> StorytellerResult<()> {
+        sqlx::query(&format!(
+            "SELECT * FROM cypher('{graph}', $$ {cypher} $$) AS (result agtype)",
+            graph = age::GRAPH_NAME,
+        ))
+        .execute(&mut **tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        Ok(())
+    }
+
+    /// One hop of transitions out of `frontier`, with full records.
+    async fn hop(&self, frontier: &[SceneId]) -> StorytellerResult<Vec<SceneTransition>> {
+        let cypher = format!(
+            "MATCH (a:Scene)-[t:TRANSITIONS_TO]->(b:Scene) \
+             WHERE a.story_id = {story} AND a.pg_id IN {ids} \
+             RETURN a.pg_id, b.pg_id, t.departure",
+            story = age::uuid_literal(self.story_id),
+            ids = age::uuid_list(frontier.iter().map(|s| s.0)),
+        );
+        let sql = format!(
+            "WITH matched AS ( \
+                 SELECT * FROM cypher('{graph}', $$ {cypher} $$) \
+                 AS (source agtype, target agtype, departure agtype) \
+             ) \
+             SELECT t.from_scene_id, t.to_scene_id, \
+                    t.departure_conditions->>'departure', \
+                    (t.momentum_transfer->>'weight')::real, t.outcome_label \
+             FROM matched m \
+             JOIN scene_transitions t \
+               ON t.story_id = $1 \
+              AND t.from_scene_id = trim(both '\"' from m.source::text)::uuid \
+              AND t.to_scene_id = trim(both '\"' from m.target::text)::uuid \
+              AND t.departure_conditions->>'departure' = trim(both '\"' from m.departure::text) \
+             ORDER BY t.from_scene_id, t.to_scene_id, t.outcome_label",
+            graph = age::GRAPH_NAME,
+        );
+
+        let mut tx = age::begin(&self.pool).await?;
+        let rows: Vec<TransitionRow> = sqlx::query_as(&sql)
+            .bind(self.story_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(StorytellerError::Database)?;
+        tx.commit().await.map_err(StorytellerError::Database)?;
+        rows.into_iter().map(transition_from_row).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl NarrativeGraphStore for AgeNarrativeGraph {
+    async fn upsert_scene(&self, scene: &NarrativeScene) -> StorytellerResult<()> {
+        let mut tx = age::begin(&self.pool).await?;
+        age::register_story(&mut tx, self.story_id).await?;
+        // Approach vectors merge into `scene_data`, which may also hold the
+        // authored scene content.
+        sqlx::query(
+            r#"
+            INSERT INTO scenes (id, story_id, title, scene_type, narrative_mass, scene_data)
+            VALUES ($1, $2, $3, $4::scene_type, $5, jsonb_build_object('approach_vectors', $6::jsonb))
+            ON CONFLICT (id) DO UPDATE SET
+                title = EXCLUDED.title,
+                scene_type = EXCLUDED.scene_type,
+                narrative_mass = EXCLUDED.narrative_mass,
+                scene_data = scenes.scene_data || EXCLUDED.scene_data
+            WHERE scenes.story_id = EXCLUDED.story_id
+            "#,
+        )
+        .bind(scene.scene_id.0)
+        .bind(self.story_id)
+        .bind(&scene.title)
+        .bind(scene_type_label(scene.scene_type))
+        .bind(sqlx::types::Json(&scene.mass))
+        .bind(sqlx::types::Json(&scene.approach_vectors))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+
+        self.run_cypher(
+            &mut tx,
+            &format!(
+                "MERGE (s:Scene {{pg_id: {id}, story_id: {story}}}) \
+                 SET s.scene_type = '{scene_type}', s.authored_base = {base} \
+                 RETURN s",
+                id = age::uuid_literal(scene.scene_id.0),
+                story = age::uuid_literal(self.story_id),
+                scene_type = scene_type_label(scene.scene_type),
+                base = age::float_literal(scene.mass.authored_base),
+            ),
+        )
+        .await?;
+        tx.commit().await.map_err(StorytellerError::Database)
+    }
+
+    async fn upsert_transition(&self, transition: &SceneTransition) -> StorytellerResult<()> {
+        let departure = departure_label(transition.departure);
+        let mut tx = age::begin(&self.pool).await?;
+        // At most one transition per (from, to, departure): drop one with
+        // the same departure under another label before writing this one.
+        sqlx::query(
+            r#"
+            DELETE FROM scene_transitions
+            WHERE story_id = $1 AND from_scene_id = $2 AND to_scene_id = $3
+              AND departure_conditions->>'departure' = $4
+              AND outcome_label <> $5
+            "#,
+        )
+        .bind(self.story_id)
+        .bind(transition.from.0)
+        .bind(transition.to.0)
+        .bind(departure)
+        .bind(&transition.outcome_label)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        sqlx::query(
+            r#"
+            INSERT INTO scene_transitions
+                (story_id, from_scene_id, to_scene_id, outcome_label,
+                 departure_conditions, momentum_transfer)
+            VALUES ($1, $2, $3, $4,
+                    jsonb_build_object('departure', $5::text),
+                    jsonb_build_object('weight', $6::real))
+            ON CONFLICT (story_id, from_scene_id, to_scene_id, outcome_label)
+            DO UPDATE SET
+                departure_conditions = COALESCE(scene_transitions.departure_conditions, '{}')
+                    || EXCLUDED.departure_conditions,
+                momentum_transfer = COALESCE(scene_transitions.momentum_transfer, '{}')
+                    || EXCLUDED.momentum_transfer
+            "#,
+        )
+        .bind(self.story_id)
+        .bind(transition.from.0)
+        .bind(transition.to.0)
+        .bind(&transition.outcome_label)
+        .bind(departure)
+        .bind(transition.weight)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+
+        let story = age::uuid_literal(self.story_id);
+        self.run_cypher(
+            &mut tx,
+            &format!(
+                "MERGE (a:Scene {{pg_id: {from}, story_id: {story}}}) \
+                 MERGE (b:Scene {{pg_id: {to}, story_id: {story}}}) \
+                 MERGE (a)-[t:TRANSITIONS_TO {{departure: '{departure}'}}]->(b) \
+                 SET t.transition_weight = {weight} \
+                 RETURN t",
+                from = age::uuid_literal(transition.from.0),
+                to = age::uuid_literal(transition.to.0),
+                weight = age::float_literal(transition.weight),
+            ),
+        )
+        .await?;
+        tx.commit().await.map_err(StorytellerError::Database)
+    }
+
+    async fn scene(&self, scene_id: SceneId) -> StorytellerResult<Option<NarrativeScene>> {
+        let row: Option<SceneRow> = sqlx::query_as(&format!(
+            "SELECT {SCENE_COLUMNS} FROM scenes WHERE story_id = $1 AND id = $2"
+        ))
+        .bind(self.story_id)
+        .bind(scene_id.0)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(StorytellerError::Database)?;
+        row.map(scene_from_row).transpose()
+    }
+
+    async fn reachable(&self, from: SceneId, max_hops: usize) -> StorytellerResult<NarrativeGraph> {
+        let mut transitions = Vec::new();
+        let mut seen: BTreeSet<SceneId> = BTreeSet::from([from]);
+        let mut frontier = vec![from];
+
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for t in self.hop(&frontier).await? {
+                if seen.insert(t.to) {
+                    next.push(t.to);
+                }
+                transitions.push(t);
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        let ids: Vec<Uuid> = seen.iter().map(|s| s.0).collect();
+        let rows: Vec<SceneRow> = sqlx::query_as(&format!(
+            "SELECT {SCENE_COLUMNS} FROM scenes WHERE story_id = $1 AND id = ANY($2)"
+        ))
+        .bind(self.story_id)
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorytellerError::Database)?;
+        let scenes = rows
+            .into_iter()
+            .map(scene_from_row)
+            .collect::<StorytellerResult<Vec<_>>>()?;
+
+        Ok(NarrativeGraph::from_parts(scenes, transitions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::checkpoint::LedgerReplay;
+    use crate::database::ledger::{LedgerEntry, LedgerEntryKind, LedgerSequence, TurnCommitRecord};
+    use crate::traits::storykeeper::SessionId;
+    use crate::types::entity::{EntityId, EntityRef};
+    use crate::types::event::{EventId, EventPriority, NarrativeEvent};
+    use crate::types::event_grammar::{
+        ConfidenceEvidence, EventAtom, EventConfidence, EventPayload, EventSource, Participant,
+        ParticipantRole,
+    };
+    use crate::types::narrative::WeightedPredicate;
+    use crate::types::prediction::ActionType;
+    use chrono::Utc;
+
+    fn scene(title: &str, scene_type: SceneType, base: f32) -> NarrativeScene {
+        NarrativeScene {
+            scene_id: SceneId::new(),
+            title: title.to_string(),
+            scene_type,
+            mass: NarrativeMass {
+                authored_base: base,
+                structural_modifier: 0.0,
+                dynamic_adjustment: 0.0,
+            },
+            approach_vectors: Vec::new(),
+        }
+    }
+
+    fn transition(from: &NarrativeScene, to: &NarrativeScene, weight: f32) -> SceneTransition {
+        SceneTransition {
+            from: from.scene_id,
+            to: to.scene_id,
+            departure: DepartureType::Completed,
+            weight,
+            outcome_label: format!("{} → {}", from.title, to.title),
+        }
+    }
+
+    /// Commit one atom of `kind` into a truth set through the ledger path.
+    fn commit_atom(
+        truth: &mut TruthSet,
+        kind: EventKind,
+        participants: Vec<Participant>,
+        scene_id: SceneId,
+    ) {
+        let atom = EventAtom {
+            id: EventId::new(),
+            timestamp: Utc::now(),
+            kind,
+            participants,
+            relational_implications: Vec::new(),
+            source: EventSource::System {
+                component: "test".to_string(),
+            },
+            confidence: EventConfidence {
+                value: 1.0,
+                evidence: ConfidenceEvidence::SystemProduced,
+            },
+            priority: EventPriority::Normal,
+            scene_id,
+            turn_id: None,
+        };
+        let event = NarrativeEvent {
+            id: atom.id,
+            timestamp: atom.timestamp,
+            priority: EventPriority::Normal,
+            payload: EventPayload::Atom(atom),
+        };
+        let entry = |kind| LedgerEntry {
+            sequence: LedgerSequence::FIRST,
+            session_id: SessionId::new(),
+            turn_number: 1,
+            recorded_at: Utc::now(),
+            kind,
+        };
+        truth
+            .apply_entry(&entry(LedgerEntryKind::ClassifiedEvent {
+                event: Box::new(event.clone()),
+            }))
+            .unwrap();
+        truth
+            .apply_entry(&entry(LedgerEntryKind::TurnCommit {
+                commit: TurnCommitRecord {
+                    event_ids: vec![event.id],
+                    ..Default::default()
+                },
+            }))
+            .unwrap();
+    }
+
+    /// The road → ford → (other bank | witch's cottage), ford → road.
+    fn landscape() -> (NarrativeGraph, [NarrativeScene; 4]) {
+        let road = scene("The Road", SceneType::Connective, 0.3);
+        let ford = scene("The Ford", SceneType::Connective, 0.4);
+        let mut bank = scene("The Other Bank", SceneType::Gravitational, 0.9);
+        bank.approach_vectors = vec![SceneApproach {
+            name: "knows_the_water".to_string(),
+            predicates: vec![WeightedPredicate {
+                predicate: TruthPredicate::FactEstablished {
+                    fact: "water is a border".to_string(),
+                },
+                weight: 1.0,
+            }],
+        }];
+        let witch = scene("The Witch", SceneType::Gravitational, 1.0);
+
+        let graph = NarrativeGraph::from_parts(
+            [road.clone(), ford.clone(), bank.clone(), witch.clone()],
+            [
+                transition(&road, &ford, 0.8),
+                transition(&ford, &bank, 0.8),
+                transition(&ford, &witch, 0.3),
+                transition(&ford, &road, 0.5),
+            ],
+        );
+        (graph, [road, ford, bank, witch])
+    }
+
+    #[test]
+    fn pull_follows_inverse_square_of_distance() {
+        let (graph, [road, ford, bank, witch]) = landscape();
+        let pulls = graph.pull_scores(
+            &NarrativePosition::at(road.scene_id),
+            &TruthSet::default(),
+            2,
+        );
+
+        let ids: Vec<SceneId> = pulls.iter().map(|p| p.scene_id).collect();
+        assert_eq!(ids, vec![ford.scene_id, bank.scene_id, witch.scene_id]);
+
+        let ford_pull = &pulls[0];
+        assert_eq!(ford_pull.hops, 1);
+        assert!((ford_pull.distance - 0.2).abs() < 1e-6);
+        assert!((ford_pull.pull - 0.4 / 0.04).abs() < 1e-3);
+
+        let witch_pull = &pulls[2];
+        assert!((witch_pull.distance - 0.9).abs() < 1e-6);
+        assert!(pulls.iter().all(|p| p.scene_id != road.scene_id));
+    }
+
+    #[test]
+    fn satisfied_approach_vector_raises_mass() {
+        let (graph, [_, ford, bank, _]) = landscape();
+        let position = NarrativePosition::at(ford.scene_id);
+
+        let before = graph.pull_scores(&position, &TruthSet::default(), 1);
+        let bank_before = before.iter().find(|p| p.scene_id == bank.scene_id).unwrap();
+        assert_eq!(bank_before.approach.as_ref().unwrap().satisfaction, 0.0);
+
+        let mut truth = TruthSet::default();
+        commit_atom(
+            &mut truth,
+            EventKind::InformationTransfer {
+                content_summary: "The Wolf explains the Water is a border.".to_string(),
+            },
+            Vec::new(),
+            ford.scene_id,
+        );
+        let after = graph.pull_scores(&position, &truth, 1);
+        let bank_after = after.iter().find(|p| p.scene_id == bank.scene_id).unwrap();
+
+        let matched = bank_after.approach.as_ref().unwrap();
+        assert_eq!(matched.name, "knows_the_water");
+        assert_eq!(matched.satisfaction, 1.0);
+        assert!((bank_after.mass.dynamic_adjustment - DYNAMIC_MASS_SCALE).abs() < 1e-6);
+        assert!(bank_after.pull > bank_before.pull);
+    }
+
+    #[test]
+    fn completed_scenes_stop_pulling_unless_connective() {
+        let (graph, [road, ford, bank, _]) = landscape();
+        let mut position = NarrativePosition::at(ford.scene_id);
+        position.completed.insert(road.scene_id);
+        position.completed.insert(bank.scene_id);
+
+        let ids: Vec<SceneId> = graph
+            .pull_scores(&position, &TruthSet::default(), 1)
+            .iter()
+            .map(|p| p.scene_id)
+            .collect();
+        assert!(
+            ids.contains(&road.scene_id),
+            "connective scenes can be revisited"
+        );
+        assert!(!ids.contains(&bank.scene_id));
+    }
+
+    #[test]
+    fn distances_respect_hop_limit() {
+        let (graph, [road, _, bank, _]) = landscape();
+        assert!(!graph
+            .distances(road.scene_id, 1)
+            .contains_key(&bank.scene_id));
+        let (d, hops) = graph.distances(road.scene_id, 2)[&bank.scene_id];
+        assert!((d - 0.4).abs() < 1e-6);
+        assert_eq!(hops, 2);
+    }
+
+    #[test]
+    fn transitions_are_typed_by_departure() {
+        let (mut graph, [road, ford, _, witch]) = landscape();
+        graph.upsert_transition(SceneTransition {
+            departure: DepartureType::Abandoned,
+            ..transition(&ford, &witch, 0.9)
+        });
+
+        let completed = graph.next_scenes(ford.scene_id, DepartureType::Completed);
+        assert_eq!(completed.len(), 3);
+        let abandoned = graph.next_scenes(ford.scene_id, DepartureType::Abandoned);
+        assert_eq!(abandoned.len(), 1);
+        assert_eq!(abandoned[0].to, witch.scene_id);
+
+        // Re-upserting the same (from, to, departure) replaces.
+        graph.upsert_transition(transition(&ford, &road, 0.1));
+        assert_eq!(
+            graph
+                .next_scenes(ford.scene_id, DepartureType::Completed)
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn entity_involvement_is_partial() {
+        let entity_id = EntityId::new();
+        let predicate = TruthPredicate::EntityInvolved {
+            entity_id,
+            min_events: 4,
+        };
+        let mut truth = TruthSet::default();
+        assert_eq!(predicate_satisfaction(&predicate, &truth), 0.0);
+
+        let actor = || {
+            vec![Participant {
+                entity: EntityRef::Resolved(entity_id),
+                role: ParticipantRole::Actor,
+            }]
+        };
+        let action = || EventKind::ActionOccurrence {
+            action_type: ActionType::Perform,
+        };
+        let scene_id = SceneId::new();
+        commit_atom(&mut truth, action(), actor(), scene_id);
+        commit_atom(&mut truth, action(), actor(), scene_id);
+        commit_atom(&mut truth, action(), Vec::new(), scene_id);
+        assert!((predicate_satisfaction(&predicate, &truth) - 0.5).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_scores_like_the_graph() {
+        let (graph, [road, ..]) = landscape();
+        let store = InMemoryNarrativeGraph::new();
+        for s in graph.scenes() {
+            store.upsert_scene(s).await.unwrap();
+        }
+        for s in graph.scenes() {
+            for t in graph.transitions_from(s.scene_id) {
+                store.upsert_transition(t).await.unwrap();
+            }
+        }
+
+        let position = NarrativePosition::at(road.scene_id);
+        let truth = TruthSet::default();
+        assert_eq!(
+            store.pull_scores(&position, &truth, 2).await.unwrap(),
+            graph.pull_scores(&position, &truth, 2)
+        );
+        assert_eq!(
+            store
+                .next_scenes(road.scene_id, DepartureType::Completed)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -4,7 +4,748 @@
 
 //! Relational web graph queries — character relationship traversal.
 //!
-//! See: `docs/technical/relational-web-tfatd.md`, `docs/foundation/power.md`
+//! See: `docs/technical/relational-web-tfatd.md`, `docs/foundation/power.md`,
+//! `docs/technical/age-persistence/relational-web-age.md`
 //!
 //! Queries for directed edges, topological roles, path analysis,
 //! and power computation from substrate configuration + network position.
+//!
+//! Storage and traversal are split. A [`RelationalWeb`] backend stores
+//! [`DirectedEdge`]s and loads bounded neighbourhoods into an in-process
+//! [`RelationalGraph`]; every query (social distance, leverage paths) then
+//! runs on that graph. Two backends exist:
+//!
+//! - [`AgeRelationalWeb`] — `:RelEntity` vertices and `:RELATES_TO` edges in
+//!   the AGE graph, with the full edge record in
+//!   `storykeeper.relational_edge_details`
+//! - [`InMemoryRelationalWeb`] — the fallback when AGE is unavailable, and
+//!   what tests use without Postgres
+//!
+//! Because both backends feed the same query code, they answer identically
+//! for the same edges.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::RwLock;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::age;
+use crate::errors::{StorytellerError, StorytellerResult};
+use crate::types::entity::EntityId;
+use crate::types::relational::DirectedEdge;
+
+// ---------------------------------------------------------------------------
+// Power computation
+// ---------------------------------------------------------------------------
+
+/// How much leverage an edge's target holds over its source, in `[0.0, 1.0]`.
+///
+/// Leverage is computed from the *dependent* party's perspective — the
+/// source owes the target (positive debt), relies on their competence, or
+/// is attached to them. Power is never stored; see `docs/foundation/power.md`.
+pub fn edge_leverage(edge: &DirectedEdge) -> f32 {
+    let s = &edge.substrate;
+    let owed = s.debt.central_tendency.max(0.0);
+    let reliance = s.trust_competence.central_tendency.max(0.0);
+    let attachment = s.affection.central_tendency.max(0.0);
+    (0.5 * owed + 0.25 * reliance + 0.25 * attachment).clamp(0.0, 1.0)
+}
+
+/// A chain through which `holder` can exert leverage on a target.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LeveragePath {
+    /// The entity at the end of the chain who holds the leverage.
+    pub holder: EntityId,
+    /// Entities from the target to the holder, inclusive of both.
+    pub path: Vec<EntityId>,
+    /// Product of [`edge_leverage`] along the chain.
+    pub strength: f32,
+}
+
+impl LeveragePath {
+    /// Number of edges in the chain.
+    pub fn hops(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+}
+
+/// Which edges to follow when loading a neighbourhood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Edges whose source is in the frontier.
+    Outgoing,
+    /// Edges whose target is in the frontier.
+    Incoming,
+    /// Both.
+    Both,
+}
+
+// ---------------------------------------------------------------------------
+// In-process graph
+// ---------------------------------------------------------------------------
+
+/// An in-process directed graph of relational edges.
+///
+/// At most one edge per ordered `(source, target)` pair — relationships are
+/// asymmetric, so `A→B` and `B→A` are distinct.
+#[derive(Debug, Clone, Default)]
+pub struct RelationalGraph {
+    edges: BTreeMap<(EntityId, EntityId), DirectedEdge>,
+    outgoing: HashMap<EntityId, BTreeSet<EntityId>>,
+    incoming: HashMap<EntityId, BTreeSet<EntityId>>,
+}
+
+impl RelationalGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a graph from edges; later edges replace earlier ones for the same pair.
+    pub fn from_edges(edges: impl IntoIterator<Item = DirectedEdge>) -> Self {
+        let mut graph = Self::new();
+        for edge in edges {
+            graph.upsert_edge(edge);
+        }
+        graph
+    }
+
+    /// Insert or replace the edge for `(edge.source, edge.target)`.
+    pub fn upsert_edge(&mut self, edge: DirectedEdge) {
+        self.outgoing
+            .entry(edge.source)
+            .or_default()
+            .insert(edge.target);
+        self.incoming
+            .entry(edge.target)
+            .or_default()
+            .insert(edge.source);
+        self.edges.insert((edge.source, edge.target), edge);
+    }
+
+    /// The edge from `source` toward `target`, if any.
+    pub fn edge(&self, source: EntityId, target: EntityId) -> Option<&DirectedEdge> {
+        self.edges.get(&(source, target))
+    }
+
+    /// All edges, ordered by `(source, target)`.
+    pub fn edges(&self) -> impl Iterator<Item = &DirectedEdge> {
+        self.edges.values()
+    }
+
+    /// Number of edges.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Whether the graph has no edges.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Edges from `entity` toward others.
+    pub fn outgoing(&self, entity: EntityId) -> Vec<&DirectedEdge> {
+        self.outgoing
+            .get(&entity)
+            .into_iter()
+            .flatten()
+            .filter_map(|t| self.edges.get(&(entity, *t)))
+            .collect()
+    }
+
+    /// Edges from others toward `entity`.
+    pub fn incoming(&self, entity: EntityId) -> Vec<&DirectedEdge> {
+        self.incoming
+            .get(&entity)
+            .into_iter()
+            .flatten()
+            .filter_map(|s| self.edges.get(&(*s, entity)))
+            .collect()
+    }
+
+    /// Every directed edge with both endpoints in `cast`.
+    pub fn cast_subgraph(&self, cast: &[EntityId]) -> RelationalGraph {
+        let members: HashSet<EntityId> = cast.iter().copied().collect();
+        RelationalGraph::from_edges(
+            self.edges
+                .values()
+                .filter(|e| members.contains(&e.source) && members.contains(&e.target))
+                .cloned(),
+        )
+    }
+
+    /// Edges reachable from `entity` within `max_hops`, following `direction`.
+    pub fn neighbourhood(
+        &self,
+        entity: EntityId,
+        max_hops: usize,
+        direction: Direction,
+    ) -> RelationalGraph {
+        let mut found = RelationalGraph::new();
+        let mut visited: HashSet<EntityId> = HashSet::from([entity]);
+        let mut frontier = vec![entity];
+
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for node in frontier {
+                let mut step = Vec::new();
+                if matches!(direction, Direction::Outgoing | Direction::Both) {
+                    step.extend(self.outgoing(node).into_iter().map(|e| (e, e.target)));
+                }
+                if matches!(direction, Direction::Incoming | Direction::Both) {
+                    step.extend(self.incoming(node).into_iter().map(|e| (e, e.source)));
+                }
+                for (edge, other) in step {
+                    found.upsert_edge(edge.clone());
+                    if visited.insert(other) {
+                        next.push(other);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        found
+    }
+
+    /// Hop count of the shortest path between `a` and `b`, ignoring edge
+    /// direction — the same semantics as AGE's unweighted `shortestPath()`.
+    pub fn social_distance(&self, a: EntityId, b: EntityId) -> Option<usize> {
+        if a == b {
+            return Some(0);
+        }
+        let mut visited: HashSet<EntityId> = HashSet::from([a]);
+        let mut queue = VecDeque::from([(a, 0usize)]);
+        while let Some((node, dist)) = queue.pop_front() {
+            let neighbours = self
+                .outgoing
+                .get(&node)
+                .into_iter()
+                .chain(self.incoming.get(&node))
+                .flatten();
+            for &other in neighbours {
+                if other == b {
+                    return Some(dist + 1);
+                }
+                if visited.insert(other) {
+                    queue.push_back((other, dist + 1));
+                }
+            }
+        }
+        None
+    }
+
+    /// Who can exert leverage on `target` through at most `max_hops` links.
+    ///
+    /// Leverage flows against edge direction: if `target` depends on `b`
+    /// (edge `target→b`) and `b` depends on `a` (edge `b→a`), then `a` holds
+    /// leverage on `target` through `b`. Each holder appears once, with its
+    /// strongest chain; results are ordered strongest first.
+    pub fn leverage_on(&self, target: EntityId, max_hops: usize) -> Vec<LeveragePath> {
+        let mut best: HashMap<EntityId, LeveragePath> = HashMap::new();
+        let mut frontier = vec![LeveragePath {
+            holder: target,
+            path: vec![target],
+            strength: 1.0,
+        }];
+
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for chain in &frontier {
+                for edge in self.outgoing(chain.holder) {
+                    // Simple paths only — no leverage through a cycle.
+                    if chain.path.contains(&edge.target) {
+                        continue;
+                    }
+                    let strength = chain.strength * edge_leverage(edge);
+                    if strength <= 0.0 {
+                        continue;
+                    }
+                    let mut path = chain.path.clone();
+                    path.push(edge.target);
+                    let extended = LeveragePath {
+                        holder: edge.target,
+                        path,
+                        strength,
+                    };
+                    let improves = best.get(&edge.target).is_none_or(|b| strength > b.strength);
+                    if improves {
+                        best.insert(edge.target, extended.clone());
+                    }
+                    next.push(extended);
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        let mut paths: Vec<LeveragePath> = best.into_values().collect();
+        paths.sort_by(|a, b| {
+            b.strength
+                .total_cmp(&a.strength)
+                .then_with(|| a.hops().cmp(&b.hops()))
+                .then_with(|| a.holder.0.cmp(&b.holder.0))
+        });
+        paths
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Storage backends
+// ---------------------------------------------------------------------------
+
+/// Storage for the relational web of one story.
+///
+/// Backends implement edge storage and neighbourhood loading; the provided
+/// query methods run on the loaded [`RelationalGraph`], so every backend
+/// answers them the same way.
+#[async_trait::async_trait]
+pub trait RelationalWeb: Send + Sync {
+    /// Insert or replace the edge for `(edge.source, edge.target)`.
+    async fn upsert_edge(&self, edge: &DirectedEdge) -> StorytellerResult<()>;
+
+    /// The edge from `source` toward `target`, if any.
+    async fn edge(
+        &self,
+        source: EntityId,
+        target: EntityId,
+    ) -> StorytellerResult<Option<DirectedEdge>>;
+
+    /// Every directed edge with both endpoints in `cast`.
+    async fn cast_subgraph(&self, cast: &[EntityId]) -> StorytellerResult<RelationalGraph>;
+
+    /// Edges reachable from `entity` within `max_hops`, following `direction`.
+    async fn neighbourhood(
+        &self,
+        entity: EntityId,
+        max_hops: usize,
+        direction: Direction,
+    ) -> StorytellerResult<RelationalGraph>;
+
+    /// Hop count between `a` and `b` within `max_hops`, ignoring direction.
+    async fn social_distance(
+        &self,
+        a: EntityId,
+        b: EntityId,
+        max_hops: usize,
+    ) -> StorytellerResult<Option<usize>> {
+        let graph = self.neighbourhood(a, max_hops, Direction::Both).await?;
+        Ok(graph.social_distance(a, b).filter(|d| *d <= max_hops))
+    }
+
+    /// Who can exert leverage on `target` through at most `max_hops` links.
+    ///
+    /// See [`RelationalGraph::leverage_on`].
+    async fn leverage_on(
+        &self,
+        target: EntityId,
+        max_hops: usize,
+    ) -> StorytellerResult<Vec<LeveragePath>> {
+        let graph = self
+            .neighbourhood(target, max_hops, Direction::Outgoing)
+            .await?;
+        Ok(graph.leverage_on(target, max_hops))
+    }
+}
+
+/// In-process [`RelationalWeb`] — the fallback when AGE is unavailable.
+#[derive(Debug, Default)]
+pub struct InMemoryRelationalWeb {
+    graph: RwLock<RelationalGraph>,
+}
+
+impl InMemoryRelationalWeb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A copy of the full graph.
+    pub fn snapshot(&self) -> RelationalGraph {
+        self.graph.read().expect("graph lock poisoned").clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl RelationalWeb for InMemoryRelationalWeb {
+    async fn upsert_edge(&self, edge: &DirectedEdge) -> StorytellerResult<()> {
+        self.graph
+            .write()
+            .expect("graph lock poisoned")
+            .upsert_edge(edge.clone());
+        Ok(())
+    }
+
+    async fn edge(
+        &self,
+        source: EntityId,
+        target: EntityId,
+    ) -> StorytellerResult<Option<DirectedEdge>> {
+        Ok(self
+            .graph
+            .read()
+            .expect("graph lock poisoned")
+            .edge(source, target)
+            .cloned())
+    }
+
+    async fn cast_subgraph(&self, cast: &[EntityId]) -> StorytellerResult<RelationalGraph> {
+        Ok(self
+            .graph
+            .read()
+            .expect("graph lock poisoned")
+            .cast_subgraph(cast))
+    }
+
+    async fn neighbourhood(
+        &self,
+        entity: EntityId,
+        max_hops: usize,
+        direction: Direction,
+    ) -> StorytellerResult<RelationalGraph> {
+        Ok(self
+            .graph
+            .read()
+            .expect("graph lock poisoned")
+            .neighbourhood(entity, max_hops, direction))
+    }
+}
+
+/// AGE-backed [`RelationalWeb`] for one story.
+///
+/// Traversal runs in Cypher over `:RelEntity` / `:RELATES_TO`; the matched
+/// pairs are joined to `storykeeper.relational_edge_details` for the full
+/// edge record (the two-phase pattern from the AGE persistence design).
+/// Edge properties carry the substrate central tendencies so Cypher
+/// `WHERE` filters can use them.
+#[derive(Debug, Clone)]
+pub struct AgeRelationalWeb {
+    pool: PgPool,
+    story_id: Uuid,
+}
+
+impl AgeRelationalWeb {
+    pub fn new(pool: PgPool, story_id: Uuid) -> Self {
+        Self { pool, story_id }
+    }
+
+    /// Full edge records for the `(source, target)` pairs a Cypher `MATCH`
+    /// returns as `source` and `target`.
+    async fn edges_matching(&self, cypher: &str) -> StorytellerResult<Vec<DirectedEdge>> {
+        let sql = format!(
+            "WITH matched AS ( \
+                 SELECT * FROM cypher('{graph}', $$ {cypher} $$) \
+                 AS (source agtype, target agtype) \
+             ) \
+             SELECT d.edge \
+             FROM matched m \
+             JOIN storykeeper.relational_edge_details d \
+               ON d.story_id = $1 \
+              AND d.source_id = trim(both '\"' from m.source::text)::uuid \
+              AND d.target_id = trim(both '\"' from m.target::text)::uuid \
+             ORDER BY d.source_id, d.target_id",
+            graph = age::GRAPH_NAME,
+        );
+
+        let mut tx = age::begin(&self.pool).await?;
+        let rows: Vec<sqlx::types::Json<DirectedEdge>> = sqlx::query_scalar(&sql)
+            .bind(self.story_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(StorytellerError::Database)?;
+        tx.commit().await.map_err(StorytellerError::Database)?;
+
+        Ok(rows.into_iter().map(|j| j.0).collect())
+    }
+
+    /// One hop from `frontier` in `direction`.
+    async fn hop(
+        &self,
+        frontier: &[EntityId],
+        direction: Direction,
+    ) -> StorytellerResult<Vec<DirectedEdge>> {
+        let story = age::uuid_literal(self.story_id);
+        let ids = age::uuid_list(frontier.iter().map(|e| e.0));
+        let filter = match direction {
+            Direction::Outgoing => format!("a.pg_id IN {ids}"),
+            Direction::Incoming => format!("b.pg_id IN {ids}"),
+            Direction::Both => format!("(a.pg_id IN {ids} OR b.pg_id IN {ids})"),
+        };
+        self.edges_matching(&format!(
+            "MATCH (a:RelEntity)-[:RELATES_TO]->(b:RelEntity) \
+             WHERE a.story_id = {story} AND {filter} \
+             RETURN a.pg_id, b.pg_id"
+        ))
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl RelationalWeb for AgeRelationalWeb {
+    async fn upsert_edge(&self, edge: &DirectedEdge) -> StorytellerResult<()> {
+        let s = &edge.substrate;
+        let story = age::uuid_literal(self.story_id);
+        let cypher = format!(
+            "MERGE (a:RelEntity {{pg_id: {source}, story_id: {story}}}) \
+             MERGE (b:RelEntity {{pg_id: {target}, story_id: {story}}}) \
+             MERGE (a)-[r:RELATES_TO]->(b) \
+             SET r.trust_reliability = {tr}, r.trust_competence = {tc}, \
+                 r.trust_benevolence = {tb}, r.affection = {af}, r.debt = {debt} \
+             RETURN r",
+            source = age::uuid_literal(edge.source.0),
+            target = age::uuid_literal(edge.target.0),
+            tr = age::float_literal(s.trust_reliability.central_tendency),
+            tc = age::float_literal(s.trust_competence.central_tendency),
+            tb = age::float_literal(s.trust_benevolence.central_tendency),
+            af = age::float_literal(s.affection.central_tendency),
+            debt = age::float_literal(s.debt.central_tendency),
+        );
+
+        let mut tx = age::begin(&self.pool).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO storykeeper.relational_edge_details
+                (story_id, source_id, target_id, edge)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (story_id, source_id, target_id)
+            DO UPDATE SET edge = EXCLUDED.edge, updated_at = now()
+            "#,
+        )
+        .bind(self.story_id)
+        .bind(edge.source.0)
+        .bind(edge.target.0)
+        .bind(sqlx::types::Json(edge))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        sqlx::query(&format!(
+            "SELECT * FROM cypher('{graph}', $$ {cypher} $$) AS (r agtype)",
+            graph = age::GRAPH_NAME,
+        ))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        tx.commit().await.map_err(StorytellerError::Database)
+    }
+
+    async fn edge(
+        &self,
+        source: EntityId,
+        target: EntityId,
+    ) -> StorytellerResult<Option<DirectedEdge>> {
+        let story = age::uuid_literal(self.story_id);
+        let edges = self
+            .edges_matching(&format!(
+                "MATCH (a:RelEntity {{pg_id: {source}, story_id: {story}}}) \
+                       -[:RELATES_TO]->(b:RelEntity {{pg_id: {target}}}) \
+                 RETURN a.pg_id, b.pg_id",
+                source = age::uuid_literal(source.0),
+                target = age::uuid_literal(target.0),
+            ))
+            .await?;
+        Ok(edges.into_iter().next())
+    }
+
+    async fn cast_subgraph(&self, cast: &[EntityId]) -> StorytellerResult<RelationalGraph> {
+        if cast.is_empty() {
+            return Ok(RelationalGraph::new());
+        }
+        let story = age::uuid_literal(self.story_id);
+        let ids = age::uuid_list(cast.iter().map(|e| e.0));
+        let edges = self
+            .edges_matching(&format!(
+                "MATCH (a:RelEntity)-[:RELATES_TO]->(b:RelEntity) \
+                 WHERE a.story_id = {story} AND a.pg_id IN {ids} AND b.pg_id IN {ids} \
+                 RETURN a.pg_id, b.pg_id"
+            ))
+            .await?;
+        Ok(RelationalGraph::from_edges(edges))
+    }
+
+    async fn neighbourhood(
+        &self,
+        entity: EntityId,
+        max_hops: usize,
+        direction: Direction,
+    ) -> StorytellerResult<RelationalGraph> {
+        // Hop-by-hop frontier expansion keeps each Cypher query a bounded,
+        // indexed neighbourhood lookup rather than a variable-length match.
+        let mut found = RelationalGraph::new();
+        let mut visited: HashSet<EntityId> = HashSet::from([entity]);
+        let mut frontier = vec![entity];
+
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for edge in self.hop(&frontier, direction).await? {
+                let touches_source = frontier.contains(&edge.source);
+                let touches_target = frontier.contains(&edge.target);
+                let others = match direction {
+                    Direction::Outgoing => vec![edge.target],
+                    Direction::Incoming => vec![edge.source],
+                    Direction::Both => [
+                        touches_source.then_some(edge.target),
+                        touches_target.then_some(edge.source),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+                };
+                for other in others {
+                    if visited.insert(other) {
+                        next.push(other);
+                    }
+                }
+                found.upsert_edge(edge);
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::relational::{InformationState, RelationalSubstrate};
+    use crate::types::tensor::AxisValue;
+
+    fn axis(v: f32) -> AxisValue {
+        AxisValue {
+            central_tendency: v,
+            variance: 0.1,
+            range_low: (v - 0.2).max(-1.0),
+            range_high: (v + 0.2).min(1.0),
+        }
+    }
+
+    fn edge(source: EntityId, target: EntityId, debt: f32, affection: f32) -> DirectedEdge {
+        DirectedEdge {
+            source,
+            target,
+            substrate: RelationalSubstrate {
+                trust_reliability: axis(0.5),
+                trust_competence: axis(0.0),
+                trust_benevolence: axis(0.5),
+                affection: axis(affection),
+                debt: axis(debt),
+            },
+            information_state: InformationState {
+                known_facts: Vec::new(),
+                beliefs: Vec::new(),
+                blind_spots: Vec::new(),
+            },
+        }
+    }
+
+    /// sarah owes adam; adam owes the witch; tom loves sarah.
+    fn web() -> (RelationalGraph, [EntityId; 4]) {
+        let [sarah, adam, witch, tom] = [
+            EntityId::new(),
+            EntityId::new(),
+            EntityId::new(),
+            EntityId::new(),
+        ];
+        let graph = RelationalGraph::from_edges([
+            edge(sarah, adam, 0.8, 0.0),
+            edge(adam, witch, 1.0, 0.0),
+            edge(tom, sarah, 0.0, 1.0),
+        ]);
+        (graph, [sarah, adam, witch, tom])
+    }
+
+    #[test]
+    fn leverage_reaches_through_two_hops() {
+        let (graph, [sarah, adam, witch, tom]) = web();
+
+        let one_hop = graph.leverage_on(sarah, 1);
+        assert_eq!(one_hop.len(), 1);
+        assert_eq!(one_hop[0].holder, adam);
+
+        let two_hops = graph.leverage_on(sarah, 2);
+        let holders: Vec<EntityId> = two_hops.iter().map(|p| p.holder).collect();
+        assert_eq!(holders, vec![adam, witch], "strongest first");
+        assert_eq!(two_hops[1].path, vec![sarah, adam, witch]);
+        assert!((two_hops[1].strength - 0.4 * 0.5).abs() < 1e-6);
+
+        // Leverage follows dependency, not affection aimed at the target.
+        assert!(!holders.contains(&tom));
+    }
+
+    #[test]
+    fn leverage_ignores_cycles_and_keeps_strongest_chain() {
+        let (mut graph, [sarah, adam, witch, _]) = web();
+        graph.upsert_edge(edge(witch, sarah, 1.0, 0.0));
+        graph.upsert_edge(edge(sarah, witch, 0.2, 0.0));
+
+        let paths = graph.leverage_on(sarah, 3);
+        let witch_path = paths.iter().find(|p| p.holder == witch).unwrap();
+        assert_eq!(
+            witch_path.path,
+            vec![sarah, adam, witch],
+            "0.2 > 0.1 direct"
+        );
+        assert!(paths.iter().all(|p| p.holder != sarah));
+    }
+
+    #[test]
+    fn upsert_replaces_existing_pair() {
+        let (mut graph, [sarah, adam, _, _]) = web();
+        graph.upsert_edge(edge(sarah, adam, 0.0, 0.0));
+        assert_eq!(graph.len(), 3);
+        assert!(graph.leverage_on(sarah, 1).is_empty());
+    }
+
+    #[test]
+    fn social_distance_ignores_direction() {
+        let (graph, [sarah, _, witch, tom]) = web();
+        assert_eq!(graph.social_distance(tom, witch), Some(3));
+        assert_eq!(graph.social_distance(witch, sarah), Some(2));
+        assert_eq!(graph.social_distance(tom, EntityId::new()), None);
+    }
+
+    #[test]
+    fn cast_subgraph_keeps_internal_edges_only() {
+        let (graph, [sarah, adam, _, tom]) = web();
+        let cast = graph.cast_subgraph(&[sarah, adam, tom]);
+        assert_eq!(cast.len(), 2);
+        assert!(cast.edge(sarah, adam).is_some());
+        assert!(cast.edge(tom, sarah).is_some());
+    }
+
+    #[test]
+    fn neighbourhood_is_bounded_by_hops() {
+        let (graph, [sarah, adam, witch, tom]) = web();
+        let out = graph.neighbourhood(sarah, 1, Direction::Outgoing);
+        assert_eq!(out.len(), 1);
+        assert!(out.edge(sarah, adam).is_some());
+
+        let both = graph.neighbourhood(sarah, 1, Direction::Both);
+        assert_eq!(both.len(), 2);
+        assert!(both.edge(tom, sarah).is_some());
+        assert!(both.edge(adam, witch).is_none());
+    }
+
+    #[tokio::test]
+    async fn in_memory_web_answers_from_stored_edges() {
+        let (graph, [sarah, adam, witch, tom]) = web();
+        let store = InMemoryRelationalWeb::new();
+        for e in graph.edges() {
+            store.upsert_edge(e).await.unwrap();
+        }
+
+        assert!(store.edge(sarah, adam).await.unwrap().is_some());
+        assert!(store.edge(adam, sarah).await.unwrap().is_none());
+        assert_eq!(store.social_distance(tom, witch, 3).await.unwrap(), Some(3));
+        assert_eq!(store.social_distance(tom, witch, 2).await.unwrap(), None);
+        assert_eq!(
+            store.leverage_on(sarah, 2).await.unwrap(),
+            graph.leverage_on(sarah, 2)
+        );
+    }
+}
@@ -9,3 +9,741 @@
 //! Settings are re-enterable locations/contexts. Scenes happen *within* settings
 //! but are not the same thing — the same setting hosts different scenes at
 //! different times, and its state may change between visits.
+//!
+//! A [`SettingTopologyStore`] holds three things per story: authored
+//! [`SettingNode`]s, the [`SettingConnection`]s between them, and each
+//! setting's [`SettingState`]. [`place_scene`](SettingTopologyStore::place_scene)
+//! is how composition re-enters a setting: it records the visit and returns
+//! the authored setting with every accumulated change applied.
+//!
+//! As with the other graph modules, backends load bounded regions into an
+//! in-process [`SettingTopology`] and routing runs there:
+//!
+//! - [`AgeSettingTopology`] — `:Setting` vertices and `:CONNECTS_TO` edges
+//!   over rows of the baseline `settings` table, which holds the authored
+//!   setting, its exits, and its state
+//! - [`InMemorySettingTopology`] — the fallback when AGE is unavailable
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::RwLock;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::age;
+use crate::errors::{StorytellerError, StorytellerResult};
+use crate::types::character::SceneSetting;
+use crate::types::scene::SceneId;
+use crate::types::setting::{
+    SettingChange, SettingConnection, SettingId, SettingNode, SettingState,
+};
+
+/// A setting prepared for a new scene.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PlacedSetting {
+    /// The setting the scene is placed in.
+    pub setting_id: SettingId,
+    /// The setting's name.
+    pub name: String,
+    /// The authored setting with accumulated changes applied.
+    pub setting: SceneSetting,
+    /// Visits including this one.
+    pub visits: u32,
+}
+
+impl PlacedSetting {
+    /// Whether an earlier scene has already happened here.
+    pub fn is_return_visit(&self) -> bool {
+        self.visits > 1
+    }
+}
+
+/// The cheapest way between two settings.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SettingRoute {
+    /// Settings from start to destination, inclusive of both.
+    pub settings: Vec<SettingId>,
+    /// Sum of connection friction along the route.
+    pub friction: f32,
+}
+
+// ---------------------------------------------------------------------------
+// In-process topology
+// ---------------------------------------------------------------------------
+
+/// An in-process setting topology: settings and the connections between them.
+///
+/// At most one connection per ordered `(from, to)` pair.
+#[derive(Debug, Clone, Default)]
+pub struct SettingTopology {
+    settings: BTreeMap<SettingId, SettingNode>,
+    connections: BTreeMap<(SettingId, SettingId), SettingConnection>,
+}
+
+impl SettingTopology {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a topology from settings and connections.
+    pub fn from_parts(
+        settings: impl IntoIterator<Item = SettingNode>,
+        connections: impl IntoIterator<Item = SettingConnection>,
+    ) -> Self {
+        let mut topology = Self::new();
+        for setting in settings {
+            topology.upsert_setting(setting);
+        }
+        for connection in connections {
+            topology.connect(connection);
+        }
+        topology
+    }
+
+    /// Insert or replace a setting.
+    pub fn upsert_setting(&mut self, setting: SettingNode) {
+        self.settings.insert(setting.setting_id, setting);
+    }
+
+    /// Insert or replace the connection for `(from, to)`.
+    pub fn connect(&mut self, connection: SettingConnection) {
+        self.connections
+            .insert((connection.from, connection.to), connection);
+    }
+
+    /// A setting by id.
+    pub fn setting(&self, setting_id: SettingId) -> Option<&SettingNode> {
+        self.settings.get(&setting_id)
+    }
+
+    /// All settings, ordered by id.
+    pub fn settings(&self) -> impl Iterator<Item = &SettingNode> {
+        self.settings.values()
+    }
+
+    /// All connections, ordered by `(from, to)`.
+    pub fn connections(&self) -> impl Iterator<Item = &SettingConnection> {
+        self.connections.values()
+    }
+
+    /// Settings reachable from `setting_id` in one passage, honouring
+    /// direction, with the connection used.
+    pub fn adjacent(&self, setting_id: SettingId) -> Vec<(SettingId, &SettingConnection)> {
+        self.connections
+            .values()
+            .filter_map(|c| {
+                if c.from == setting_id {
+                    Some((c.to, c))
+                } else if c.to == setting_id && c.bidirectional {
+                    Some((c.from, c))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// The region within `max_hops` connections of `from`, ignoring
+    /// direction, including `from`.
+    pub fn neighbourhood(&self, from: SettingId, max_hops: usize) -> SettingTopology {
+        let mut seen: BTreeSet<SettingId> = BTreeSet::from([from]);
+        let mut frontier: BTreeSet<SettingId> = BTreeSet::from([from]);
+        let mut region = SettingTopology::new();
+
+        for _ in 0..max_hops {
+            let mut next = BTreeSet::new();
+            for c in self.connections.values() {
+                let touches = frontier.contains(&c.from) || frontier.contains(&c.to);
+                if !touches {
+                    continue;
+                }
+                region.connect(c.clone());
+                for id in [c.from, c.to] {
+                    if seen.insert(id) {
+                        next.insert(id);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        for id in seen {
+            if let Some(setting) = self.settings.get(&id) {
+                region.upsert_setting(setting.clone());
+            }
+        }
+        region
+    }
+
+    /// The lowest-friction route from `from` to `to`, honouring direction.
+    pub fn route(&self, from: SettingId, to: SettingId) -> Option<SettingRoute> {
+        if from == to {
+            return Some(SettingRoute {
+                settings: vec![from],
+                friction: 0.0,
+            });
+        }
+
+        // Dijkstra without a heap — setting topologies are small.
+        let mut dist: HashMap<SettingId, f32> = HashMap::from([(from, 0.0)]);
+        let mut prev: HashMap<SettingId, SettingId> = HashMap::new();
+        let mut done: BTreeSet<SettingId> = BTreeSet::new();
+
+        while let Some((&current, &d)) = dist
+            .iter()
+            .filter(|(id, _)| !done.contains(*id))
+            .min_by(|a, b| a.1.total_cmp(b.1).then_with(|| a.0.cmp(b.0)))
+        {
+            if current == to {
+                break;
+            }
+            done.insert(current);
+            for (next, c) in self.adjacent(current) {
+                let nd = d + c.friction.max(0.0);
+                if dist.get(&next).is_none_or(|&old| nd < old) {
+                    dist.insert(next, nd);
+                    prev.insert(next, current);
+                }
+            }
+        }
+
+        let friction = *dist.get(&to)?;
+        let mut settings = vec![to];
+        let mut cursor = to;
+        while let Some(&p) = prev.get(&cursor) {
+            settings.push(p);
+            cursor = p;
+        }
+        settings.reverse();
+        Some(SettingRoute { settings, friction })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Storage backends
+// ---------------------------------------------------------------------------
+
+/// Storage for the setting topology and setting state of one story.
+///
+/// State writes are read-modify-write; callers serialize them per setting
+/// (in practice one scene at a time touches a setting).
+#[async_trait::async_trait]
+pub trait SettingTopologyStore: Send + Sync + std::fmt::Debug {
+    /// Insert or replace an authored setting.
+    async fn upsert_setting(&self, setting: &SettingNode) -> StorytellerResult<()>;
+
+    /// Insert or replace the connection for `(from, to)`.
+    async fn connect(&self, connection: &SettingConnection) -> StorytellerResult<()>;
+
+    /// An authored setting by id.
+    async fn setting(&self, setting_id: SettingId) -> StorytellerResult<Option<SettingNode>>;
+
+    /// Persisted state, or `None` if the setting has never been visited or changed.
+    async fn state(&self, setting_id: SettingId) -> StorytellerResult<Option<SettingState>>;
+
+    /// Persist a setting's state, replacing what was there.
+    async fn save_state(&self, state: &SettingState) -> StorytellerResult<()>;
+
+    /// The region within `max_hops` connections of `from`, ignoring direction.
+    async fn neighbourhood(
+        &self,
+        from: SettingId,
+        max_hops: usize,
+    ) -> StorytellerResult<SettingTopology>;
+
+    /// Record a change made to a setting during `scene_id`.
+    async fn record_change(
+        &self,
+        setting_id: SettingId,
+        scene_id: SceneId,
+        change: SettingChange,
+    ) -> StorytellerResult<SettingState> {
+        let mut state = self
+            .state(setting_id)
+            .await?
+            .unwrap_or_else(|| SettingState::new(setting_id));
+        state.record_change(scene_id, change);
+        self.save_state(&state).await?;
+        Ok(state)
+    }
+
+    /// Place a new scene into an existing setting.
+    ///
+    /// Records the visit and returns the authored setting with every change
+    /// from earlier scenes applied. Errors if the setting does not exist.
+    async fn place_scene(
+        &self,
+        setting_id: SettingId,
+        scene_id: SceneId,
+    ) -> StorytellerResult<PlacedSetting> {
+        let node = self
+            .setting(setting_id)
+            .await?
+            .ok_or_else(|| unknown_setting(setting_id))?;
+        let mut state = self
+            .state(setting_id)
+            .await?
+            .unwrap_or_else(|| SettingState::new(setting_id));
+        state.record_visit(scene_id);
+        self.save_state(&state).await?;
+
+        Ok(PlacedSetting {
+            setting_id,
+            name: node.name,
+            setting: state.apply_to(&node.base),
+            visits: state.visits,
+        })
+    }
+
+    /// The lowest-friction route between two settings at most `max_hops` apart.
+    async fn route(
+        &self,
+        from: SettingId,
+        to: SettingId,
+        max_hops: usize,
+    ) -> StorytellerResult<Option<SettingRoute>> {
+        let region = self.neighbourhood(from, max_hops).await?;
+        Ok(region.route(from, to))
+    }
+}
+
+/// In-process [`SettingTopologyStore`] — the fallback when AGE is unavailable.
+#[derive(Debug, Default)]
+pub struct InMemorySettingTopology {
+    topology: RwLock<SettingTopology>,
+    states: RwLock<HashMap<SettingId, SettingState>>,
+}
+
+impl InMemorySettingTopology {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl SettingTopologyStore for InMemorySettingTopology {
+    async fn upsert_setting(&self, setting: &SettingNode) -> StorytellerResult<()> {
+        self.topology
+            .write()
+            .expect("topology lock poisoned")
+            .upsert_setting(setting.clone());
+        Ok(())
+    }
+
+    async fn connect(&self, connection: &SettingConnection) -> StorytellerResult<()> {
+        self.topology
+            .write()
+            .expect("topology lock poisoned")
+            .connect(connection.clone());
+        Ok(())
+    }
+
+    async fn setting(&self, setting_id: SettingId) -> StorytellerResult<Option<SettingNode>> {
+        Ok(self
+            .topology
+            .read()
+            .expect("topology lock poisoned")
+            .setting(setting_id)
+            .cloned())
+    }
+
+    async fn state(&self, setting_id: SettingId) -> StorytellerResult<Option<SettingState>> {
+        Ok(self
+            .states
+            .read()
+            .expect("state lock poisoned")
+            .get(&setting_id)
+            .cloned())
+    }
+
+    async fn save_state(&self, state: &SettingState) -> StorytellerResult<()> {
+        self.states
+            .write()
+            .expect("state lock poisoned")
+            .insert(state.setting_id, state.clone());
+        Ok(())
+    }
+
+    async fn neighbourhood(
+        &self,
+        from: SettingId,
+        max_hops: usize,
+    ) -> StorytellerResult<SettingTopology> {
+        Ok(self
+            .topology
+            .read()
+            .expect("topology lock poisoned")
+            .neighbourhood(from, max_hops))
+    }
+}
+
+/// AGE-backed [`SettingTopologyStore`] for one story.
+///
+/// `:Setting` vertices and `:CONNECTS_TO` edges (carrying `friction` and
+/// `bidirectional`) hold the topology. Records live in the baseline
+/// `settings` table, joined by id: the authored [`SceneSetting`] in
+/// `spatial_data.base`, outgoing connections in `spatial_data.exits`, and
+/// [`SettingState`] in `state`. Authored rows without a `base` are read as
+/// their description alone.
+#[derive(Debug, Clone)]
+pub struct AgeSettingTopology {
+    pool: PgPool,
+    story_id: Uuid,
+}
+
+impl AgeSettingTopology {
+    pub fn new(pool: PgPool, story_id: Uuid) -> Self {
+        Self { pool, story_id }
+    }
+
+    /// One undirected hop of connections touching `frontier`.
+    async fn hop(&self, frontier: &[SettingId]) -> StorytellerResult<Vec<SettingConnection>> {
+        let ids = age::uuid_list(frontier.iter().map(|s| s.0));
+        let cypher = format!(
+            "MATCH (a:Setting)-[:CONNECTS_TO]->(b:Setting) \
+             WHERE a.story_id = {story} AND (a.pg_id IN {ids} OR b.pg_id IN {ids}) \
+             RETURN a.pg_id, b.pg_id",
+            story = age::uuid_literal(self.story_id),
+        );
+        let sql = format!(
+            "WITH matched AS ( \
+                 SELECT * FROM cypher('{graph}', $$ {cypher} $$) \
+                 AS (source agtype, target agtype) \
+             ) \
+             SELECT e.exit \
+             FROM matched m \
+             JOIN settings s \
+               ON s.story_id = $1 \
+              AND s.id = trim(both '\"' from m.source::text)::uuid \
+             CROSS JOIN LATERAL jsonb_array_elements( \
+                 COALESCE(s.spatial_data->'exits', '[]'::jsonb)) AS e(exit) \
+             WHERE e.exit->>'to' = trim(both '\"' from m.target::text) \
+             ORDER BY s.id, e.exit->>'to'",
+            graph = age::GRAPH_NAME,
+        );
+
+        let mut tx = age::begin(&self.pool).await?;
+        let rows: Vec<sqlx::types::Json<SettingConnection>> = sqlx::query_scalar(&sql)
+            .bind(self.story_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(StorytellerError::Database)?;
+        tx.commit().await.map_err(StorytellerError::Database)?;
+        Ok(rows.into_iter().map(|j| j.0).collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl SettingTopologyStore for AgeSettingTopology {
+    async fn upsert_setting(&self, setting: &SettingNode) -> StorytellerResult<()> {
+        let mut tx = age::begin(&self.pool).await?;
+        age::register_story(&mut tx, self.story_id).await?;
+        let upserted = sqlx::query(
+            r#"
+            INSERT INTO settings (id, story_id, name, description, spatial_data)
+            VALUES ($1, $2, $3, $4, jsonb_build_object('base', $5::jsonb))
+            ON CONFLICT (id) DO UPDATE SET
+                name = EXCLUDED.name,
+                description = EXCLUDED.description,
+                spatial_data = COALESCE(settings.spatial_data, '{}'::jsonb)
+                    || EXCLUDED.spatial_data
+            WHERE settings.story_id = EXCLUDED.story_id
+            "#,
+        )
+        .bind(setting.setting_id.0)
+        .bind(self.story_id)
+        .bind(&setting.name)
+        .bind(&setting.base.description)
+        .bind(sqlx::types::Json(&setting.base))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        if upserted.rows_affected() == 0 {
+            return Err(StorytellerError::Graph(format!(
+                "setting {} belongs to another story",
+                setting.setting_id.0
+            )));
+        }
+
+        sqlx::query(&format!(
+            "SELECT * FROM cypher('{graph}', $$ \
+                 MERGE (s:Setting {{pg_id: {id}, story_id: {story}}}) RETURN s \
+             $$) AS (result agtype)",
+            graph = age::GRAPH_NAME,
+            id = age::uuid_literal(setting.setting_id.0),
+            story = age::uuid_literal(self.story_id),
+        ))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        tx.commit().await.map_err(StorytellerError::Database)
+    }
+
+    async fn connect(&self, connection: &SettingConnection) -> StorytellerResult<()> {
+        let mut tx = age::begin(&self.pool).await?;
+        let updated = sqlx::query(
+            r#"
+            UPDATE settings SET spatial_data = jsonb_set(
+                COALESCE(spatial_data, '{}'::jsonb),
+                '{exits}',
+                COALESCE(
+                    (SELECT jsonb_agg(e.exit)
+                     FROM jsonb_array_elements(COALESCE(spatial_data->'exits', '[]'::jsonb))
+                          AS e(exit)
+                     WHERE e.exit->>'to' <> $3::text),
+                    '[]'::jsonb
+                ) || jsonb_build_array($4::jsonb)
+            )
+            WHERE story_id = $1 AND id = $2
+            "#,
+        )
+        .bind(self.story_id)
+        .bind(connection.from.0)
+        .bind(connection.to.0.to_string())
+        .bind(sqlx::types::Json(connection))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        if updated.rows_affected() == 0 {
+            return Err(unknown_setting(connection.from));
+        }
+
+        let story = age::uuid_literal(self.story_id);
+        sqlx::query(&format!(
+            "SELECT * FROM cypher('{graph}', $$ \
+                 MERGE (a:Setting {{pg_id: {from}, story_id: {story}}}) \
+                 MERGE (b:Setting {{pg_id: {to}, story_id: {story}}}) \
+                 MERGE (a)-[c:CONNECTS_TO]->(b) \
+                 SET c.friction = {friction}, c.bidirectional = {bidirectional} \
+                 RETURN c \
+             $$) AS (result agtype)",
+            graph = age::GRAPH_NAME,
+            from = age::uuid_literal(connection.from.0),
+            to = age::uuid_literal(connection.to.0),
+            friction = age::float_literal(connection.friction),
+            bidirectional = connection.bidirectional,
+        ))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+        tx.commit().await.map_err(StorytellerError::Database)
+    }
+
+    async fn setting(&self, setting_id: SettingId) -> StorytellerResult<Option<SettingNode>> {
+        let row: Option<SettingRow> = sqlx::query_as(
+            "SELECT id, name, description, spatial_data FROM settings \
+             WHERE story_id = $1 AND id = $2",
+        )
+        .bind(self.story_id)
+        .bind(setting_id.0)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(StorytellerError::Database)?;
+        row.map(setting_node).transpose()
+    }
+
+    async fn state(&self, setting_id: SettingId) -> StorytellerResult<Option<SettingState>> {
+        let row: Option<Option<sqlx::types::Json<SettingState>>> =
+            sqlx::query_scalar("SELECT state FROM settings WHERE story_id = $1 AND id = $2")
+                .bind(self.story_id)
+                .bind(setting_id.0)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(StorytellerError::Database)?;
+        Ok(row.flatten().map(|j| j.0))
+    }
+
+    /// Errors if the setting is not in the story's `settings` rows.
+    async fn save_state(&self, state: &SettingState) -> StorytellerResult<()> {
+        let updated = sqlx::query("UPDATE settings SET state = $3 WHERE story_id = $1 AND id = $2")
+            .bind(self.story_id)
+            .bind(state.setting_id.0)
+            .bind(sqlx::types::Json(state))
+            .execute(&self.pool)
+            .await
+            .map_err(StorytellerError::Database)?;
+        if updated.rows_affected() == 0 {
+            return Err(unknown_setting(state.setting_id));
+        }
+        Ok(())
+    }
+
+    async fn neighbourhood(
+        &self,
+        from: SettingId,
+        max_hops: usize,
+    ) -> StorytellerResult<SettingTopology> {
+        let mut connections = Vec::new();
+        let mut seen: BTreeSet<SettingId> = BTreeSet::from([from]);
+        let mut frontier = vec![from];
+
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for c in self.hop(&frontier).await? {
+                for id in [c.from, c.to] {
+                    if seen.insert(id) {
+                        next.push(id);
+                    }
+                }
+                connections.push(c);
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        let ids: Vec<Uuid> = seen.iter().map(|s| s.0).collect();
+        let rows: Vec<SettingRow> = sqlx::query_as(
+            "SELECT id, name, description, spatial_data FROM settings \
+             WHERE story_id = $1 AND id = ANY($2)",
+        )
+        .bind(self.story_id)
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorytellerError::Database)?;
+        let settings = rows
+            .into_iter()
+            .map(setting_node)
+            .collect::<StorytellerResult<Vec<_>>>()?;
+
+        Ok(SettingTopology::from_parts(settings, connections))
+    }
+}
+
+/// `id, name, description, spatial_data` of a `settings` row.
+type SettingRow = (Uuid, String, String, Option<serde_json::Value>);
+
+/// An authored setting from its `settings` row; the `description` column is
+/// the description of record.
+fn setting_node(
+    (id, name, description, spatial_data): SettingRow,
+) -> StorytellerResult<SettingNode> {
+    let base = match spatial_data.as_ref().and_then(|d| d.get("base")) {
+        Some(base) => SceneSetting {
+            description,
+            ..serde_json::from_value(base.clone())?
+        },
+        None => SceneSetting {
+            description,
+            affordances: Vec::new(),
+            sensory_details: Vec::new(),
+            aesthetic_detail: String::new(),
+        },
+    };
+    Ok(SettingNode {
+        setting_id: SettingId(id),
+        name,
+        base,
+    })
+}
+
+fn unknown_setting(setting_id: SettingId) -> StorytellerError {
+    StorytellerError::Graph(format!("unknown setting {}", setting_id.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str) -> SettingNode {
+        SettingNode {
+            setting_id: SettingId::new(),
+            name: name.to_string(),
+            base: SceneSetting {
+                description: format!("{name}."),
+                affordances: Vec::new(),
+                sensory_details: Vec::new(),
+                aesthetic_detail: String::new(),
+            },
+        }
+    }
+
+    fn passage(
+        from: &SettingNode,
+        to: &SettingNode,
+        friction: f32,
+        bidirectional: bool,
+    ) -> SettingConnection {
+        SettingConnection {
+            from: from.setting_id,
+            to: to.setting_id,
+            label: format!("{} to {}", from.name, to.name),
+            friction,
+            bidirectional,
+        }
+    }
+
+    #[test]
+    fn route_prefers_low_friction_and_honours_direction() {
+        let [village, wood, ford, cottage] =
+            [node("Village"), node("Wood"), node("Ford"), node("Cottage")];
+        let topology = SettingTopology::from_parts(
+            [village.clone(), wood.clone(), ford.clone(), cottage.clone()],
+            [
+                passage(&village, &wood, 1.0, true),
+                passage(&wood, &cottage, 1.0, true),
+                passage(&village, &ford, 0.5, true),
+                // The flooded ford only carries you downstream.
+                passage(&ford, &cottage, 0.5, false),
+            ],
+        );
+
+        let there = topology
+            .route(village.setting_id, cottage.setting_id)
+            .unwrap();
+        assert_eq!(
+            there.settings,
+            vec![village.setting_id, ford.setting_id, cottage.setting_id]
+        );
+        assert!((there.friction - 1.0).abs() < 1e-6);
+
+        let back = topology
+            .route(cottage.setting_id, village.setting_id)
+            .unwrap();
+        assert_eq!(
+            back.settings,
+            vec![cottage.setting_id, wood.setting_id, village.setting_id]
+        );
+        assert!((back.friction - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn route_to_unconnected_setting_is_none() {
+        let [a, b] = [node("A"), node("B")];
+        let topology = SettingTopology::from_parts([a.clone(), b.clone()], []);
+        assert!(topology.route(a.setting_id, b.setting_id).is_none());
+        assert_eq!(
+            topology.route(a.setting_id, a.setting_id).unwrap().settings,
+            vec![a.setting_id]
+        );
+    }
+
+    #[test]
+    fn neighbourhood_is_bounded_and_undirected() {
+        let [a, b, c] = [node("A"), node("B"), node("C")];
+        let topology = SettingTopology::from_parts(
+            [a.clone(), b.clone(), c.clone()],
+            [passage(&b, &a, 1.0, false), passage(&b, &c, 1.0, false)],
+        );
+        let one = topology.neighbourhood(a.setting_id, 1);
+        assert_eq!(one.settings().count(), 2);
+        let two = topology.neighbourhood(a.setting_id, 2);
+        assert_eq!(two.settings().count(), 3);
+        assert_eq!(two.connections().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn place_unknown_setting_errors() {
+        let store = InMemorySettingTopology::new();
+        let err = store
+            .place_scene(SettingId::new(), SceneId::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StorytellerError::Graph(_)));
+    }
+}
@@ -15,6 +15,7 @@
 //! - [`types`] — Domain types: entities, tensors, events, scenes, relationships, narrative
 //! - [`traits`] — Shared traits (e.g., `LlmProvider`, `EmotionalGrammar`)
 //! - [`grammars`] — Emotional grammar implementations (e.g., `PlutchikWestern`)
+//! - [`game_design`] — Resolution mechanics (e.g., `GraduatedResolution`), selected per genre
 //! - [`promotion`] — Entity promotion logic (weight computation, tier determination, resolution)
 //! - [`errors`] — `StorytellerError` and `StorytellerResult`
 //! - [`config`] — Configuration loading and validation
@@ -24,6 +25,7 @@
 pub mod config;
 pub mod database;
 pub mod errors;
+pub mod game_design;
 pub mod grammars;
 pub mod graph;
 pub mod promotion;
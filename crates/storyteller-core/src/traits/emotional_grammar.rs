@@ -10,8 +10,12 @@
 //! opposition structure, intensity ranges, and composition rules. Different
 //! grammars can model different cultural or entity-type emotional vocabularies.
 //!
-//! The first grammar is `plutchik_western` (Plutchik-derived, 8 primaries).
-//! Future grammars: wu xing (TCM five-element), non-human, fey/mythic.
+//! The built-in grammar is `plutchik_western` (Plutchik-derived, 8
+//! primaries). Others — wu xing (TCM five-element), non-human, fey/mythic —
+//! are declared as data and loaded into a
+//! [`GrammarRegistry`](crate::grammars::GrammarRegistry).
+
+use serde::{Deserialize, Serialize};
 
 use crate::types::character::EmotionalState;
 
@@ -19,13 +23,16 @@ use crate::types::character::EmotionalState;
 ///
 /// This defines what a primary *is* in the grammar — not a character's
 /// current intensity (that's [`EmotionalPrimary`](crate::types::character::EmotionalPrimary)).
-#[derive(Debug, Clone)]
+/// Serializable so data-driven grammars can declare their primaries in
+/// TOML or JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PrimaryDef {
     /// Identifier used in [`EmotionalPrimary::primary_id`](crate::types::character::EmotionalPrimary).
     pub id: String,
     /// Human-readable display name.
     pub name: String,
     /// The opposite primary, if this grammar has opposition structure.
+    #[serde(default)]
     pub opposite_id: Option<String>,
     /// Label at the low end of the intensity gradient. E.g. "serenity" for joy.
     pub low_intensity_label: String,
@@ -40,7 +47,8 @@ pub struct PrimaryDef {
 ///
 /// Grammars are resolved by ID at runtime — [`CharacterSheet`](crate::types::character::CharacterSheet)
 /// stores `grammar_id: String`, and the agent architecture looks up the
-/// corresponding `dyn EmotionalGrammar` from a registry.
+/// corresponding `dyn EmotionalGrammar` from a
+/// [`GrammarRegistry`](crate::grammars::GrammarRegistry).
 pub trait EmotionalGrammar: Send + Sync + std::fmt::Debug {
     /// Unique grammar identifier. E.g. `"plutchik_western"`.
     fn id(&self) -> &str;
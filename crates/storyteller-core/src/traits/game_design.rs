@@ -10,10 +10,11 @@
 //! resolution mechanics. The Resolver uses whichever implementation is
 //! configured for the current story.
 //!
-//! For now, only one implementation will exist (a general-purpose fantasy
-//! resolution system). The trait exists to establish the extension point
-//! for future genre-specific mechanics (e.g., noir, sci-fi, literary fiction
-//! without combat).
+//! The reference implementation is `game_design::GraduatedResolution`, a
+//! general-purpose graduated-success system whose odds are tuned per genre
+//! through `game_design::GameDesignRegistry`. The trait remains the extension
+//! point for mechanics that differ in kind rather than in tuning (e.g.,
+//! literary fiction without contests).
 
 use crate::errors::StorytellerResult;
 use crate::types::prediction::CharacterPrediction;
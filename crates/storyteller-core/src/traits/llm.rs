@@ -57,13 +57,15 @@ pub struct CompletionResponse {
 
 /// Newtype for the receiving end of a narrator token stream.
 ///
-/// Bounded channel (capacity 64) per project convention.
+/// Bounded channel (capacity 64) per project convention. A backend that
+/// fails mid-stream sends an `Err` before closing, so a truncated response
+/// is distinguishable from a complete one.
 #[derive(Debug)]
-pub struct NarratorTokenStream(pub mpsc::Receiver<String>);
+pub struct NarratorTokenStream(pub mpsc::Receiver<StorytellerResult<String>>);
 
 /// Newtype for the sending end of a narrator token stream.
 #[derive(Debug, Clone)]
-pub struct NarratorTokenSender(pub mpsc::Sender<String>);
+pub struct NarratorTokenSender(pub mpsc::Sender<StorytellerResult<String>>);
 
 /// Create a bounded narrator token channel pair.
 pub fn narrator_token_channel() -> (NarratorTokenSender, NarratorTokenStream) {
@@ -82,7 +84,8 @@ pub trait LlmProvider: Send + Sync + std::fmt::Debug {
     /// Stream completion tokens.
     ///
     /// Default implementation calls `complete()` and sends the full response
-    /// as a single chunk. Override for real streaming (e.g., `ExternalServerProvider`).
+    /// as a single chunk. Override for real streaming (e.g., `ExternalServerProvider`,
+    /// `CloudLlmProvider`).
     async fn stream_complete(
         &self,
         request: CompletionRequest,
@@ -90,7 +93,7 @@ pub trait LlmProvider: Send + Sync + std::fmt::Debug {
         let response = self.complete(request).await?;
         let (sender, receiver) = narrator_token_channel();
         tokio::spawn(async move {
-            let _ = sender.0.send(response.content).await;
+            let _ = sender.0.send(Ok(response.content)).await;
         });
         Ok(receiver)
     }
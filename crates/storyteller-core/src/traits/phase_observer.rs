@@ -135,6 +135,32 @@ pub enum PhaseEventDetail {
         estimated_tokens: u32,
     },
 
+    // -- Arbitration events --
+    /// An ambiguous action verdict was settled by LLM adjudication.
+    ActionAdjudicated {
+        /// Final verdict — `"permitted"` or `"impossible"`.
+        verdict: String,
+        /// The adjudicator's confidence in the verdict (0.0–1.0).
+        confidence: f32,
+        /// Conditions attached to a permitted verdict.
+        condition_count: usize,
+        /// The adjudicator's explanation.
+        rationale: String,
+    },
+
+    // -- Commit events --
+    /// Committed events changed the scene's event dependency graph.
+    EventDependenciesUpdated {
+        /// Nodes that resolved.
+        resolved: Vec<String>,
+        /// Nodes whose dependencies are now met.
+        enabled: Vec<String>,
+        /// Nodes that became unreachable or orphaned.
+        foreclosed: Vec<String>,
+        /// Nodes still reachable after the update.
+        reachable: usize,
+    },
+
     // -- Narrator rendering events --
     /// Narrator prompt was constructed from assembled context.
     NarratorPromptBuilt {
@@ -255,6 +281,27 @@ impl fmt::Display for PhaseEventDetail {
                 f,
                 "Predictions enriched: {character_count} characters, {total_actions} actions, ~{estimated_tokens}t"
             ),
+            Self::ActionAdjudicated {
+                verdict,
+                confidence,
+                condition_count,
+                rationale,
+            } => write!(
+                f,
+                "Action adjudicated: {verdict} ({confidence:.2} confidence, {condition_count} conditions) — {rationale}"
+            ),
+            Self::EventDependenciesUpdated {
+                resolved,
+                enabled,
+                foreclosed,
+                reachable,
+            } => write!(
+                f,
+                "Event dependencies updated: {} resolved, {} enabled, {} foreclosed, {reachable} reachable",
+                resolved.len(),
+                enabled.len(),
+                foreclosed.len()
+            ),
             Self::NarratorPromptBuilt {
                 system_prompt_chars,
                 user_message_chars,
@@ -424,6 +471,19 @@ mod tests {
         assert!(display.contains("900t"));
     }
 
+    #[test]
+    fn action_adjudicated_display_shows_verdict() {
+        let detail = PhaseEventDetail::ActionAdjudicated {
+            verdict: "impossible".to_string(),
+            confidence: 0.9,
+            condition_count: 0,
+            rationale: "No focus to hand".to_string(),
+        };
+        let display = format!("{detail}");
+        assert!(display.contains("impossible"));
+        assert!(display.contains("0.90"));
+    }
+
     #[test]
     fn context_assembled_display_shows_trimmed() {
         let detail = PhaseEventDetail::ContextAssembled {
@@ -10,20 +10,22 @@
 //! define three interfaces:
 //! - [`StorykeeperQuery`] — reads: "what matters right now?"
 //! - [`StorykeeperCommit`] — writes: "what happened this turn?"
-//! - [`StorykeeperLifecycle`] — transitions: scene entry/exit, checkpoints
+//! - [`StorykeeperLifecycle`] — transitions: scene entry/exit, narrative
+//!   layers, checkpoints
 //!
-//! Two implementations are planned:
+//! Two implementations live in `storyteller-storykeeper`:
 //! - `InMemoryStorykeeper` — wraps current engine behavior for tests/prototype
-//! - `PostgresStorykeeper` — sqlx queries against the real schema (future)
+//! - `PostgresStorykeeper` — sqlx queries against the baseline session tables
 //!
 //! The engine interacts exclusively through these traits via a Bevy Resource
 //! wrapping `Arc<dyn Storykeeper>`.
 
 use crate::errors::StorytellerResult;
-use crate::types::character::CharacterSheet;
+use crate::types::character::{CharacterSheet, SceneData};
 use crate::types::entity::{EntityId, EntityRef};
 use crate::types::event::NarrativeEvent;
 use crate::types::message::PlayerInput;
+use crate::types::narrative_layer::{LayerId, NarrativeLayer, NarrativeStack};
 use crate::types::narrator_context::{NarratorContextInput, RetrievedContext, SceneJournal};
 use crate::types::resolver::ResolverOutput;
 use crate::types::scene::SceneId;
@@ -74,6 +76,10 @@ pub struct SessionContext {
     pub player_entity_id: EntityId,
     pub current_scene_id: Option<SceneId>,
     pub turn_number: u32,
+    /// Narrative layers entered from the current scene — frame stories,
+    /// memories, dreams. Empty in the main narrative.
+    #[serde(default)]
+    pub layers: NarrativeStack,
 }
 
 /// Scene data loaded at scene entry — everything needed to run a scene.
@@ -120,6 +126,24 @@ pub struct SceneExitResult {
     pub deferred_count: usize,
 }
 
+/// Result of entering a nested narrative layer.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LayerEntryResult {
+    /// The layer entered.
+    pub layer_id: LayerId,
+    /// Nesting depth after entry; 1 for a layer entered from the main narrative.
+    pub depth: usize,
+}
+
+/// Result of leaving a nested narrative layer.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LayerExitResult {
+    /// The layer left, with its journal — what happened inside it.
+    pub layer: NarrativeLayer,
+    /// Nesting depth after exit; 0 back in the main narrative.
+    pub depth: usize,
+}
+
 /// A completed turn ready for commitment.
 ///
 /// Bundles the player input, classification, predictions, and resolver output
@@ -187,6 +211,13 @@ pub struct CommandSourced {
 #[async_trait::async_trait]
 pub trait StorykeeperQuery: Send + Sync + std::fmt::Debug {
     /// Assemble the full three-tier context for the Narrator.
+    ///
+    /// The preamble describes the session's current scene as registered with
+    /// [`StorykeeperLifecycle::register_scene`], with the cast entered at
+    /// [`StorykeeperLifecycle::enter_scene`]; it errors outside a registered
+    /// scene. Narrator voice and anti-patterns are left to the engine.
+    /// Inside a narrative layer, the layer's journal stands in for `journal`
+    /// and the preamble carries the layer's frame.
     async fn assemble_narrator_context(
         &self,
         session: &SessionContext,
@@ -196,6 +227,8 @@ pub trait StorykeeperQuery: Send + Sync + std::fmt::Debug {
     ) -> StorytellerResult<NarratorContextInput>;
 
     /// What is relevant about this entity right now?
+    ///
+    /// Entities not present in the current narrative layer have no relevance.
     async fn query_entity_relevance(
         &self,
         entity: &EntityRef,
@@ -203,13 +236,24 @@ pub trait StorykeeperQuery: Send + Sync + std::fmt::Debug {
     ) -> StorytellerResult<EntityRelevance>;
 
     /// Which gates are close to triggering?
+    ///
+    /// Gate scenes reachable from the current scene that the session has not
+    /// completed or bypassed, scored against the committed truth set.
     async fn query_gate_proximity(
         &self,
         session: &SessionContext,
     ) -> StorytellerResult<Vec<GateProximity>>;
 
-    /// Is this entity permitted to know this fact?
-    fn check_information_boundary(&self, entity_id: &EntityId, fact: &str) -> BoundaryCheck;
+    /// Is this entity permitted to know this fact in this session?
+    ///
+    /// Boundaries are seeded from the cast at [`StorykeeperLifecycle::enter_scene`]
+    /// and lifted by committed information transfers.
+    async fn check_information_boundary(
+        &self,
+        session: &SessionContext,
+        entity_id: &EntityId,
+        fact: &str,
+    ) -> StorytellerResult<BoundaryCheck>;
 }
 
 // ---------------------------------------------------------------------------
@@ -244,20 +288,46 @@ pub trait StorykeeperCommit: Send + Sync + std::fmt::Debug {
 
 /// Scene transition and session lifecycle management.
 ///
-/// Manages the cold-to-hot state transitions at scene boundaries,
-/// checkpointing for crash recovery, and session lifecycle.
+/// Manages the cold-to-hot state transitions at scene boundaries, entry
+/// and exit of nested narrative layers, checkpointing for crash recovery,
+/// and session lifecycle.
 #[async_trait::async_trait]
 pub trait StorykeeperLifecycle: Send + Sync + std::fmt::Debug {
+    /// Record a scene's authored or composed data for the session's story.
+    ///
+    /// Context assembly describes the current scene from this record, so a
+    /// scene must be registered before narrator context is assembled in it.
+    /// Registering the same scene again replaces its data.
+    async fn register_scene(
+        &self,
+        scene: &SceneData,
+        session: &SessionContext,
+    ) -> StorytellerResult<()>;
+
     /// Load everything needed for a scene.
+    ///
+    /// `cast` is the composed cast; its sheets seed the session's
+    /// information boundaries and come back in the load result.
     async fn enter_scene(
         &self,
         scene_id: &SceneId,
+        cast: &[CharacterSheet],
         session: &SessionContext,
     ) -> StorytellerResult<SceneLoadResult>;
 
     /// Flush accumulated state and process deferred effects.
     async fn exit_scene(&self, session: &SessionContext) -> StorytellerResult<SceneExitResult>;
 
+    /// Enter a nested narrative layer above the session's current position.
+    async fn enter_layer(
+        &self,
+        layer: NarrativeLayer,
+        session: &mut SessionContext,
+    ) -> StorytellerResult<LayerEntryResult>;
+
+    /// Leave the innermost narrative layer. Errors in the main narrative.
+    async fn exit_layer(&self, session: &mut SessionContext) -> StorytellerResult<LayerExitResult>;
+
     /// Snapshot state for crash recovery.
     async fn write_checkpoint(&self, session: &SessionContext) -> StorytellerResult<CheckpointId>;
 
@@ -29,6 +29,34 @@ pub struct LexiconEntry {
 }
 
 impl LexiconEntry {
+    /// An entry with no terms yet — filled in at authoring time.
+    pub fn new(capability: impl Into<String>) -> Self {
+        Self {
+            capability: capability.into(),
+            synonyms: Vec::new(),
+            action_verbs: Vec::new(),
+            implied_objects: Vec::new(),
+            idiomatic_phrases: Vec::new(),
+        }
+    }
+
+    /// Fold another entry's terms into this one, skipping terms already
+    /// present (case-insensitively).
+    pub fn absorb(&mut self, other: &LexiconEntry) {
+        fn union(into: &mut Vec<String>, from: &[String]) {
+            for term in from {
+                let term = term.trim();
+                if !term.is_empty() && !into.iter().any(|t| t.eq_ignore_ascii_case(term)) {
+                    into.push(term.to_string());
+                }
+            }
+        }
+        union(&mut self.synonyms, &other.synonyms);
+        union(&mut self.action_verbs, &other.action_verbs);
+        union(&mut self.implied_objects, &other.implied_objects);
+        union(&mut self.idiomatic_phrases, &other.idiomatic_phrases);
+    }
+
     /// Check if a single token matches any term in this entry.
     pub fn matches_token(&self, token: &str) -> bool {
         let lower = token.to_lowercase();
@@ -79,6 +107,15 @@ impl CapabilityLexicon {
         self.entries.insert(entry.capability.clone(), entry);
     }
 
+    /// Add an entry, folding its terms into any existing entry for the
+    /// same capability.
+    pub fn merge(&mut self, entry: LexiconEntry) {
+        match self.entries.get_mut(&entry.capability) {
+            Some(existing) => existing.absorb(&entry),
+            None => self.add(entry),
+        }
+    }
+
     /// Find all capabilities that match tokens in the given text.
     pub fn match_text(&self, text: &str) -> Vec<String> {
         self.entries
@@ -130,6 +167,26 @@ mod tests {
         assert!(matches.is_empty());
     }
 
+    #[test]
+    fn merge_unions_terms_without_duplicates() {
+        let mut lexicon = CapabilityLexicon::new();
+        lexicon.add(LexiconEntry {
+            action_verbs: vec!["slash".to_string()],
+            ..LexiconEntry::new("swordsmanship")
+        });
+        lexicon.merge(LexiconEntry {
+            synonyms: vec!["fencing".to_string()],
+            action_verbs: vec!["Slash".to_string(), "parry".to_string(), " ".to_string()],
+            ..LexiconEntry::new("swordsmanship")
+        });
+        lexicon.merge(LexiconEntry::new("archery"));
+
+        let entry = &lexicon.entries["swordsmanship"];
+        assert_eq!(entry.synonyms, vec!["fencing"]);
+        assert_eq!(entry.action_verbs, vec!["slash", "parry"]);
+        assert_eq!(lexicon.entries.len(), 2);
+    }
+
     #[test]
     fn lexicon_serializes() {
         let mut lexicon = CapabilityLexicon::new();
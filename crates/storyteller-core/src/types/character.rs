@@ -24,8 +24,11 @@
 use std::collections::BTreeMap;
 
 use super::entity::EntityId;
+use super::event_dependency::EventDependencyGraph;
+use super::relational::CastRelationship;
 use super::scene::SceneId;
 use super::tensor::{AwarenessLevel, AxisValue, Provenance, TemporalLayer};
+use super::trigger::TriggerPredicate;
 use super::world_model::CapabilityProfile;
 
 /// A single entry in a character tensor — value, temporal layer, and provenance.
@@ -110,13 +113,17 @@ impl Default for CharacterTensor {
 
 /// A contextual trigger that shifts tensor axes when conditions are met.
 ///
-/// This is the simple prototype version — a human-readable trigger description
-/// paired with axis shifts. The full set-theoretic composition (All/Any/Not)
-/// is deferred.
+/// The description is for authors and the narrator. When `condition` is
+/// set, the engine evaluates it at each turn commit and applies the axis
+/// shifts to the live tensor while it holds; triggers without one are
+/// descriptive only. See [`TriggerPredicate`].
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ContextualTrigger {
     /// Human-readable description of what activates this trigger.
     pub description: String,
+    /// Machine-evaluable activation condition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<TriggerPredicate>,
     /// Which axes shift and by how much when triggered.
     pub axis_shifts: Vec<AxisShift>,
     /// Rough magnitude category for the overall trigger.
@@ -124,7 +131,7 @@ pub struct ContextualTrigger {
 }
 
 /// A single axis shift within a trigger.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct AxisShift {
     /// Name of the axis to shift (must match a key in CharacterTensor).
     pub axis: String,
@@ -282,6 +289,10 @@ pub struct CharacterSheet {
     /// Optional — characters without capabilities use defaults.
     #[serde(default)]
     pub capabilities: CapabilityProfile,
+    /// Relationships toward other cast members, from composed dynamics.
+    /// Empty for hand-authored characters without a relational web.
+    #[serde(default)]
+    pub relationships: Vec<CastRelationship>,
 }
 
 /// Extended scene representation with everything needed to run a scene.
@@ -308,6 +319,9 @@ pub struct SceneData {
     pub emotional_arc: Vec<String>,
     /// Evaluation criteria — what "success" looks like for this scene.
     pub evaluation_criteria: Vec<String>,
+    /// Authored event dependencies evaluated as turns commit.
+    #[serde(default, skip_serializing_if = "EventDependencyGraph::is_empty")]
+    pub event_dependencies: EventDependencyGraph,
 }
 
 /// Physical and sensory setting for a scene.
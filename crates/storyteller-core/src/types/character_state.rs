@@ -0,0 +1,611 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Live character state — per-session emotional and relational values that
+//! move as turns resolve.
+//!
+//! See: `docs/foundation/emotional-model.md`
+//!
+//! A [`CharacterSheet`] is the composed character entering the scene and
+//! never changes. [`CastState`] is the mutable layer on top: each committed
+//! turn first relaxes every emotional primary toward its composed baseline,
+//! then applies the turn's [`StateChange`]s. Intensities are clamped to the
+//! range of each character's own grammar and relational dimensions to
+//! `[-1.0, 1.0]`.
+//!
+//! Relational values don't decay — a broken promise stays broken until
+//! something repairs it.
+//!
+//! Contextual triggers with a [`TriggerPredicate`] are re-evaluated after
+//! the changes land. A trigger is active while its condition holds, and
+//! active triggers' axis shifts move the tensor within each axis's range.
+//!
+//! Consumers that read sheets (ML features, the narrator preamble) call
+//! [`CastState::overlay`] to get a sheet carrying the live values.
+//!
+//! The state is plain data so it can ride in session snapshots and be
+//! rebuilt by replaying committed turns.
+
+use super::character::{AxisShift, CharacterSheet, EmotionalPrimary};
+use super::entity::EntityId;
+use super::event_grammar::EventAtom;
+use super::resolver::StateChange;
+use super::tensor::AxisValue;
+use super::trigger::{TriggerContext, TriggerPredicate, MAX_PREDICATE_DEPTH};
+use crate::grammars::GrammarRegistry;
+use crate::traits::emotional_grammar::EmotionalGrammar;
+
+/// Fraction of the distance to baseline an emotional primary recovers per
+/// committed turn.
+pub const DEFAULT_EMOTIONAL_DECAY: f32 = 0.15;
+
+/// Mutable emotional and relational state for a scene's cast.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CastState {
+    /// One entry per cast member.
+    #[serde(default)]
+    pub characters: Vec<LiveCharacterState>,
+    /// Per-turn decay toward baseline. See [`DEFAULT_EMOTIONAL_DECAY`].
+    #[serde(default = "default_decay")]
+    pub decay: f32,
+}
+
+/// Live state for one character.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LiveCharacterState {
+    pub entity_id: EntityId,
+    /// Valid intensity range, captured from the character's grammar.
+    #[serde(default = "default_intensity_range")]
+    pub intensity_range: (f32, f32),
+    pub primaries: Vec<LivePrimary>,
+    pub edges: Vec<LiveEdge>,
+    /// The sheet's evaluable triggers, in sheet order.
+    #[serde(default)]
+    pub triggers: Vec<LiveTrigger>,
+}
+
+/// One emotional primary's current and composed intensity.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LivePrimary {
+    pub primary_id: String,
+    /// Intensity the character was composed with — the decay target.
+    pub baseline: f32,
+    /// Current intensity.
+    pub intensity: f32,
+}
+
+/// Current central tendencies of one directed edge's substrate.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LiveEdge {
+    pub target: EntityId,
+    pub trust_reliability: f32,
+    pub trust_competence: f32,
+    pub trust_benevolence: f32,
+    pub affection: f32,
+    pub debt: f32,
+}
+
+/// A contextual trigger with a condition, and whether it currently holds.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LiveTrigger {
+    pub description: String,
+    pub condition: TriggerPredicate,
+    pub axis_shifts: Vec<AxisShift>,
+    pub active: bool,
+}
+
+/// A trigger that became active on a commit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiredTrigger {
+    pub character_id: EntityId,
+    pub description: String,
+}
+
+fn default_intensity_range() -> (f32, f32) {
+    (0.0, 1.0)
+}
+
+fn default_decay() -> f32 {
+    DEFAULT_EMOTIONAL_DECAY
+}
+
+impl Default for CastState {
+    fn default() -> Self {
+        Self {
+            characters: Vec::new(),
+            decay: DEFAULT_EMOTIONAL_DECAY,
+        }
+    }
+}
+
+impl CastState {
+    /// Seed live state from the composed cast, clamping each character with
+    /// the intensity range of the grammar its sheet names.
+    pub fn new(characters: &[&CharacterSheet], grammars: &GrammarRegistry) -> Self {
+        Self {
+            characters: characters
+                .iter()
+                .map(|sheet| {
+                    LiveCharacterState::from_sheet(sheet, grammars.grammar_for(&sheet.grammar_id))
+                })
+                .collect(),
+            decay: DEFAULT_EMOTIONAL_DECAY,
+        }
+    }
+
+    /// Whether the state has been seeded. Sessions created before live
+    /// state existed carry an empty one.
+    pub fn is_empty(&self) -> bool {
+        self.characters.is_empty()
+    }
+
+    /// Look up a character's live state.
+    pub fn character(&self, entity_id: EntityId) -> Option<&LiveCharacterState> {
+        self.characters.iter().find(|c| c.entity_id == entity_id)
+    }
+
+    /// Commit one turn: apply `changes`, then re-evaluate triggers against
+    /// the committed events. Returns the triggers that became active.
+    pub fn commit(
+        &mut self,
+        changes: &[StateChange],
+        committed: &[&EventAtom],
+    ) -> Vec<FiredTrigger> {
+        self.apply(changes);
+        self.evaluate_triggers(committed)
+    }
+
+    /// Decay toward baseline, then apply `changes`.
+    ///
+    /// Shifts naming characters, primaries, or edges the state doesn't hold
+    /// are skipped. `WorldState` changes carry no character state.
+    pub fn apply(&mut self, changes: &[StateChange]) {
+        for character in &mut self.characters {
+            for primary in &mut character.primaries {
+                primary.intensity += (primary.baseline - primary.intensity) * self.decay;
+            }
+        }
+
+        for change in changes {
+            match change {
+                StateChange::EmotionalShift {
+                    character_id,
+                    primary_id,
+                    intensity_change,
+                } => {
+                    let primary = self
+                        .characters
+                        .iter_mut()
+                        .find(|c| c.entity_id == *character_id)
+                        .and_then(|c| {
+                            let range = c.intensity_range;
+                            c.primaries
+                                .iter_mut()
+                                .find(|p| p.primary_id == *primary_id)
+                                .map(|p| (p, range))
+                        });
+                    match primary {
+                        Some((primary, (low, high))) => {
+                            primary.intensity =
+                                (primary.intensity + intensity_change).clamp(low, high);
+                        }
+                        None => tracing::debug!(
+                            ?character_id,
+                            primary_id,
+                            "emotional shift for unknown character or primary, skipping"
+                        ),
+                    }
+                }
+                StateChange::RelationalShift {
+                    source,
+                    target,
+                    dimension,
+                    change,
+                } => {
+                    let value = self
+                        .characters
+                        .iter_mut()
+                        .find(|c| c.entity_id == *source)
+                        .and_then(|c| c.edges.iter_mut().find(|e| e.target == *target))
+                        .and_then(|edge| edge.dimension_mut(dimension));
+                    match value {
+                        Some(value) => *value = (*value + change).clamp(-1.0, 1.0),
+                        None => tracing::debug!(
+                            ?source,
+                            ?target,
+                            dimension,
+                            "relational shift for unknown edge or dimension, skipping"
+                        ),
+                    }
+                }
+                StateChange::WorldState { .. } => {}
+            }
+        }
+    }
+
+    /// Re-evaluate every trigger against the committed events and current
+    /// live state. Returns the triggers that became active.
+    pub fn evaluate_triggers(&mut self, committed: &[&EventAtom]) -> Vec<FiredTrigger> {
+        let holds: Vec<Vec<bool>> = self
+            .characters
+            .iter()
+            .map(|character| {
+                let ctx = TriggerContext {
+                    subject: character.entity_id,
+                    events: committed,
+                    cast: self,
+                };
+                character
+                    .triggers
+                    .iter()
+                    .map(|trigger| {
+                        if trigger.condition.depth() > MAX_PREDICATE_DEPTH {
+                            tracing::warn!(
+                                trigger = trigger.description,
+                                max = MAX_PREDICATE_DEPTH,
+                                "trigger condition nested too deeply, never fires"
+                            );
+                        }
+                        trigger.condition.matches(&ctx)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut fired = Vec::new();
+        for (character, holds) in self.characters.iter_mut().zip(holds) {
+            for (trigger, holds) in character.triggers.iter_mut().zip(holds) {
+                if holds && !trigger.active {
+                    tracing::debug!(
+                        character = ?character.entity_id,
+                        trigger = trigger.description,
+                        "contextual trigger fired"
+                    );
+                    fired.push(FiredTrigger {
+                        character_id: character.entity_id,
+                        description: trigger.description.clone(),
+                    });
+                }
+                trigger.active = holds;
+            }
+        }
+        fired
+    }
+
+    /// A copy of `sheet` carrying this state's live values.
+    ///
+    /// Characters without live state come back unchanged. Edge ranges widen
+    /// to contain the live central tendency; active trigger shifts stay
+    /// within each tensor axis's range.
+    pub fn overlay(&self, sheet: &CharacterSheet) -> CharacterSheet {
+        let mut sheet = sheet.clone();
+        let Some(live) = self.character(sheet.entity_id) else {
+            return sheet;
+        };
+
+        for primary in &mut sheet.emotional_state.primaries {
+            if let Some(current) = live
+                .primaries
+                .iter()
+                .find(|p| p.primary_id == primary.primary_id)
+            {
+                primary.intensity = current.intensity;
+            }
+        }
+
+        for relationship in &mut sheet.relationships {
+            let substrate = &mut relationship.edge.substrate;
+            if let Some(edge) = live
+                .edges
+                .iter()
+                .find(|e| e.target == relationship.edge.target)
+            {
+                set_tendency(&mut substrate.trust_reliability, edge.trust_reliability);
+                set_tendency(&mut substrate.trust_competence, edge.trust_competence);
+                set_tendency(&mut substrate.trust_benevolence, edge.trust_benevolence);
+                set_tendency(&mut substrate.affection, edge.affection);
+                set_tendency(&mut substrate.debt, edge.debt);
+            }
+        }
+
+        for shift in live
+            .triggers
+            .iter()
+            .filter(|t| t.active)
+            .flat_map(|t| &t.axis_shifts)
+        {
+            if let Some(entry) = sheet.tensor.axes.get_mut(&shift.axis) {
+                let axis = &mut entry.value;
+                axis.central_tendency =
+                    (axis.central_tendency + shift.shift).clamp(axis.range_low, axis.range_high);
+            }
+        }
+
+        sheet
+    }
+}
+
+impl LiveCharacterState {
+    /// Live state equal to the composed sheet, clamped to `grammar`'s
+    /// intensity range as it moves.
+    pub fn from_sheet(sheet: &CharacterSheet, grammar: &dyn EmotionalGrammar) -> Self {
+        Self {
+            entity_id: sheet.entity_id,
+            intensity_range: grammar.intensity_range(),
+            primaries: sheet
+                .emotional_state
+                .primaries
+                .iter()
+                .map(LivePrimary::from_primary)
+                .collect(),
+            edges: sheet
+                .relationships
+                .iter()
+                .map(|r| {
+                    let s = &r.edge.substrate;
+                    LiveEdge {
+                        target: r.edge.target,
+                        trust_reliability: s.trust_reliability.central_tendency,
+                        trust_competence: s.trust_competence.central_tendency,
+                        trust_benevolence: s.trust_benevolence.central_tendency,
+                        affection: s.affection.central_tendency,
+                        debt: s.debt.central_tendency,
+                    }
+                })
+                .collect(),
+            triggers: sheet
+                .triggers
+                .iter()
+                .filter_map(|t| {
+                    Some(LiveTrigger {
+                        description: t.description.clone(),
+                        condition: t.condition.clone()?,
+                        axis_shifts: t.axis_shifts.clone(),
+                        active: false,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Current intensity of a primary, if the character has it.
+    pub fn intensity(&self, primary_id: &str) -> Option<f32> {
+        self.primaries
+            .iter()
+            .find(|p| p.primary_id == primary_id)
+            .map(|p| p.intensity)
+    }
+}
+
+impl LivePrimary {
+    fn from_primary(primary: &EmotionalPrimary) -> Self {
+        Self {
+            primary_id: primary.primary_id.clone(),
+            baseline: primary.intensity,
+            intensity: primary.intensity,
+        }
+    }
+}
+
+impl LiveEdge {
+    /// The value for a substrate dimension by its field name.
+    pub fn dimension(&self, dimension: &str) -> Option<f32> {
+        match dimension {
+            "trust_reliability" => Some(self.trust_reliability),
+            "trust_competence" => Some(self.trust_competence),
+            "trust_benevolence" => Some(self.trust_benevolence),
+            "affection" => Some(self.affection),
+            "debt" => Some(self.debt),
+            _ => None,
+        }
+    }
+
+    /// The mutable value for a substrate dimension by its field name.
+    pub fn dimension_mut(&mut self, dimension: &str) -> Option<&mut f32> {
+        match dimension {
+            "trust_reliability" => Some(&mut self.trust_reliability),
+            "trust_competence" => Some(&mut self.trust_competence),
+            "trust_benevolence" => Some(&mut self.trust_benevolence),
+            "affection" => Some(&mut self.affection),
+            "debt" => Some(&mut self.debt),
+            _ => None,
+        }
+    }
+}
+
+fn set_tendency(axis: &mut AxisValue, value: f32) {
+    axis.central_tendency = value;
+    axis.range_low = axis.range_low.min(value);
+    axis.range_high = axis.range_high.max(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> (CastState, EntityId, EntityId) {
+        let a = EntityId::new();
+        let b = EntityId::new();
+        let cast = CastState {
+            characters: vec![LiveCharacterState {
+                entity_id: a,
+                intensity_range: (0.0, 1.0),
+                primaries: vec![LivePrimary {
+                    primary_id: "joy".to_string(),
+                    baseline: 0.2,
+                    intensity: 0.2,
+                }],
+                edges: vec![LiveEdge {
+                    target: b,
+                    trust_reliability: 0.0,
+                    trust_competence: 0.0,
+                    trust_benevolence: 0.5,
+                    affection: 0.9,
+                    debt: 0.0,
+                }],
+                triggers: vec![],
+            }],
+            ..CastState::default()
+        };
+        (cast, a, b)
+    }
+
+    fn joy(cast: &CastState, id: EntityId) -> f32 {
+        cast.character(id).unwrap().intensity("joy").unwrap()
+    }
+
+    #[test]
+    fn shifts_apply_and_clamp_to_range() {
+        let (mut cast, a, _) = state();
+        cast.apply(&[StateChange::EmotionalShift {
+            character_id: a,
+            primary_id: "joy".to_string(),
+            intensity_change: 0.5,
+        }]);
+        assert!((joy(&cast, a) - 0.7).abs() < 1e-6);
+
+        cast.apply(&[StateChange::EmotionalShift {
+            character_id: a,
+            primary_id: "joy".to_string(),
+            intensity_change: 2.0,
+        }]);
+        assert_eq!(joy(&cast, a), 1.0);
+    }
+
+    #[test]
+    fn each_character_clamps_to_its_own_grammar_range() {
+        let (mut cast, a, _) = state();
+        let mut wide = cast.characters[0].clone();
+        wide.entity_id = EntityId::new();
+        wide.intensity_range = (0.0, 2.0);
+        let w = wide.entity_id;
+        cast.characters.push(wide);
+
+        let surge = |id| StateChange::EmotionalShift {
+            character_id: id,
+            primary_id: "joy".to_string(),
+            intensity_change: 3.0,
+        };
+        cast.apply(&[surge(a), surge(w)]);
+        assert_eq!(joy(&cast, a), 1.0);
+        assert_eq!(joy(&cast, w), 2.0);
+    }
+
+    #[test]
+    fn intensity_decays_toward_baseline() {
+        let (mut cast, a, _) = state();
+        cast.apply(&[StateChange::EmotionalShift {
+            character_id: a,
+            primary_id: "joy".to_string(),
+            intensity_change: 0.6,
+        }]);
+        let peak = joy(&cast, a);
+        for _ in 0..5 {
+            cast.apply(&[]);
+        }
+        let later = joy(&cast, a);
+        assert!(later < peak);
+        assert!(later > 0.2);
+        // Each quiet turn closes the same fraction of the gap.
+        let expected = 0.2 + (peak - 0.2) * (1.0 - DEFAULT_EMOTIONAL_DECAY).powi(5);
+        assert!((later - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn relational_shift_clamps_and_persists() {
+        let (mut cast, a, b) = state();
+        cast.apply(&[StateChange::RelationalShift {
+            source: a,
+            target: b,
+            dimension: "affection".to_string(),
+            change: 0.3,
+        }]);
+        cast.apply(&[]);
+        let edge = &cast.character(a).unwrap().edges[0];
+        assert_eq!(edge.affection, 1.0);
+        assert_eq!(edge.trust_benevolence, 0.5);
+    }
+
+    #[test]
+    fn unknown_targets_are_skipped() {
+        let (mut cast, a, b) = state();
+        let before = cast.clone();
+        cast.apply(&[
+            StateChange::EmotionalShift {
+                character_id: EntityId::new(),
+                primary_id: "joy".to_string(),
+                intensity_change: 0.5,
+            },
+            StateChange::RelationalShift {
+                source: b,
+                target: a,
+                dimension: "affection".to_string(),
+                change: 0.3,
+            },
+            StateChange::RelationalShift {
+                source: a,
+                target: b,
+                dimension: "respect".to_string(),
+                change: 0.3,
+            },
+        ]);
+        assert_eq!(cast, before);
+    }
+
+    #[test]
+    fn triggers_fire_on_commit_and_track_their_condition() {
+        use crate::types::trigger::TriggerCondition;
+
+        let (mut cast, a, _) = state();
+        cast.characters[0].triggers.push(LiveTrigger {
+            description: "Joy breaks through".to_string(),
+            condition: TriggerPredicate::Atom(TriggerCondition::Emotion {
+                primary_id: "joy".to_string(),
+                at_least: Some(0.5),
+                at_most: None,
+            }),
+            axis_shifts: vec![AxisShift {
+                axis: "warmth".to_string(),
+                shift: 0.2,
+            }],
+            active: false,
+        });
+        let lift = StateChange::EmotionalShift {
+            character_id: a,
+            primary_id: "joy".to_string(),
+            intensity_change: 0.5,
+        };
+
+        let fired = cast.commit(std::slice::from_ref(&lift), &[]);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].character_id, a);
+        assert!(cast.characters[0].triggers[0].active);
+
+        // Still holding: active, but not fired again.
+        assert!(cast.commit(&[], &[]).is_empty());
+        assert!(cast.characters[0].triggers[0].active);
+
+        // Decay carries joy back under the threshold.
+        for _ in 0..10 {
+            cast.commit(&[], &[]);
+        }
+        assert!(!cast.characters[0].triggers[0].active);
+    }
+
+    #[test]
+    fn legacy_snapshot_deserializes_with_defaults() {
+        let cast: CastState = serde_json::from_str("{}").unwrap();
+        assert!(cast.is_empty());
+        assert_eq!(cast.decay, DEFAULT_EMOTIONAL_DECAY);
+
+        let (seeded, a, _) = state();
+        let mut json = serde_json::to_value(&seeded).unwrap();
+        json["characters"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("intensity_range");
+        let cast: CastState = serde_json::from_value(json).unwrap();
+        assert_eq!(cast.character(a).unwrap().intensity_range, (0.0, 1.0));
+    }
+}
@@ -0,0 +1,642 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Event dependency graph — authored "once X and Y have happened, Z becomes
+//! possible" structure over committed events.
+//!
+//! See: `docs/technical/event-dependency-graph.md`
+//!
+//! An [`EventDependencyGraph`] is authored content: nodes are event
+//! conditions, resolved when a [`TriggerPredicate`] matches the committed
+//! [`EventAtom`]s (or asserted directly, for authored preconditions), and
+//! edges say how nodes depend on one another:
+//!
+//! - **Requires** — the target cannot resolve until the source has.
+//! - **Enables** — a target with enabling edges is dormant until at least
+//!   one source resolves.
+//! - **Excludes** — the source resolving makes the target unreachable.
+//! - **Amplifies** — the source resolving multiplies the target's weight.
+//!
+//! Requires, Enables and Amplifies edges must form a DAG; Excludes edges
+//! may be mutual (either-or choices). [`EventDependencies`] carries the
+//! graph with each node's [`NodeStatus`] so session snapshots can rebuild it
+//! by replaying committed turns. Only unresolved nodes whose dependencies
+//! are met are evaluated on a commit.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::character_state::CastState;
+use super::entity::EntityId;
+use super::event_grammar::EventAtom;
+use super::trigger::{TriggerContext, TriggerPredicate};
+use crate::errors::{StorytellerError, StorytellerResult};
+
+/// An authored event condition.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventNode {
+    /// Authored identifier, unique within the graph.
+    pub id: String,
+    /// What the event is, in the designer's words.
+    pub description: String,
+    /// Resolution condition. Nodes without one are authored preconditions,
+    /// resolved only by [`EventDependencies::assert_resolved`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<TriggerPredicate>,
+    /// The character `Who::Me`, `Emotion` and `Relationship` conditions
+    /// refer to. Without one, those conditions never match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<EntityId>,
+}
+
+/// How a dependency edge relates its endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyKind {
+    Requires,
+    Enables,
+    Excludes,
+    Amplifies,
+}
+
+/// A directed edge: `to` depends on `from`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DependencyEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: DependencyKind,
+    /// Multiplier for `amplifies` edges; ignored by the other kinds.
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+/// Authored event-dependency nodes and edges.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventDependencyGraph {
+    #[serde(default)]
+    pub nodes: Vec<EventNode>,
+    #[serde(default)]
+    pub edges: Vec<DependencyEdge>,
+}
+
+impl EventDependencyGraph {
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Node indices in dependency order, rejecting graphs with duplicate
+    /// IDs, dangling or self-referencing edges, or cycles outside Excludes
+    /// edges.
+    pub fn topological_order(&self) -> StorytellerResult<Vec<usize>> {
+        let invalid =
+            |reason: String| StorytellerError::Config(format!("event dependency graph: {reason}"));
+
+        let mut index: HashMap<&str, usize> = HashMap::with_capacity(self.nodes.len());
+        for (i, node) in self.nodes.iter().enumerate() {
+            if index.insert(node.id.as_str(), i).is_some() {
+                return Err(invalid(format!("duplicate node '{}'", node.id)));
+            }
+        }
+
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        let mut pending = vec![0usize; self.nodes.len()];
+        for edge in &self.edges {
+            let (Some(&from), Some(&to)) =
+                (index.get(edge.from.as_str()), index.get(edge.to.as_str()))
+            else {
+                return Err(invalid(format!(
+                    "edge '{}' -> '{}' names an unknown node",
+                    edge.from, edge.to
+                )));
+            };
+            if from == to {
+                return Err(invalid(format!("node '{}' depends on itself", edge.from)));
+            }
+            if edge.kind != DependencyKind::Excludes {
+                dependents[from].push(to);
+                pending[to] += 1;
+            }
+        }
+
+        // Kahn's algorithm, seeded in authored order so the result is stable.
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut ready: Vec<usize> = (0..self.nodes.len()).filter(|&i| pending[i] == 0).collect();
+        ready.reverse();
+        while let Some(i) = ready.pop() {
+            order.push(i);
+            for &next in dependents[i].iter().rev() {
+                pending[next] -= 1;
+                if pending[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+
+        if order.len() < self.nodes.len() {
+            let cyclic: Vec<&str> = (0..self.nodes.len())
+                .filter(|&i| pending[i] > 0)
+                .map(|i| self.nodes[i].id.as_str())
+                .collect();
+            return Err(invalid(format!("cycle through {}", cyclic.join(", "))));
+        }
+        Ok(order)
+    }
+}
+
+/// Where an authored event stands in play.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeStatus {
+    /// Not met yet, and still possible.
+    #[default]
+    Unresolved,
+    /// Met.
+    Resolved,
+    /// Excluded by a resolved event, or foreclosed by the scene.
+    Unreachable,
+    /// Can never resolve because something it depends on is unreachable.
+    Orphaned,
+}
+
+impl NodeStatus {
+    /// Unreachable or orphaned — a road not taken.
+    pub fn is_foreclosed(self) -> bool {
+        matches!(self, Self::Unreachable | Self::Orphaned)
+    }
+}
+
+/// An authored graph with each node's status in the session.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventDependencies {
+    pub graph: EventDependencyGraph,
+    /// Node statuses by ID; absent nodes are unresolved.
+    #[serde(default)]
+    pub status: BTreeMap<String, NodeStatus>,
+}
+
+/// What one commit changed.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DependencyUpdate {
+    /// Nodes that resolved.
+    pub resolved: Vec<String>,
+    /// Unresolved nodes whose dependencies are now met.
+    pub enabled: Vec<String>,
+    /// Nodes that became unreachable or orphaned.
+    pub foreclosed: Vec<String>,
+}
+
+impl DependencyUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.resolved.is_empty() && self.enabled.is_empty() && self.foreclosed.is_empty()
+    }
+}
+
+impl EventDependencies {
+    /// Start tracking a validated graph, every node unresolved.
+    pub fn new(graph: EventDependencyGraph) -> StorytellerResult<Self> {
+        graph.topological_order()?;
+        Ok(Self {
+            graph,
+            status: BTreeMap::new(),
+        })
+    }
+
+    /// Whether there is anything to track. Scenes without authored
+    /// dependencies, and sessions from before tracking existed, are empty.
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    /// A node's status; `None` for IDs the graph doesn't hold.
+    pub fn status(&self, node_id: &str) -> Option<NodeStatus> {
+        self.graph
+            .nodes
+            .iter()
+            .any(|n| n.id == node_id)
+            .then(|| self.status_of(node_id))
+    }
+
+    /// Whether an unresolved node's dependencies are met: every Requires
+    /// source resolved and, if it has Enables sources, at least one of them.
+    pub fn is_enabled(&self, node_id: &str) -> bool {
+        if self.status(node_id) != Some(NodeStatus::Unresolved) {
+            return false;
+        }
+        let mut enablers = self.incoming(node_id, DependencyKind::Enables).peekable();
+        let enabled = enablers.peek().is_none()
+            || enablers.any(|from| self.status_of(from) == NodeStatus::Resolved);
+        enabled
+            && self
+                .incoming(node_id, DependencyKind::Requires)
+                .all(|from| self.status_of(from) == NodeStatus::Resolved)
+    }
+
+    /// Nodes that have not resolved and still can.
+    pub fn reachable(&self) -> impl Iterator<Item = &str> {
+        self.nodes_with(NodeStatus::Unresolved)
+    }
+
+    /// Nodes that are reachable and whose dependencies are met.
+    pub fn enabled(&self) -> impl Iterator<Item = &str> {
+        self.graph
+            .nodes
+            .iter()
+            .map(|n| n.id.as_str())
+            .filter(|id| self.is_enabled(id))
+    }
+
+    /// Nodes that can never resolve.
+    pub fn foreclosed(&self) -> impl Iterator<Item = &str> {
+        self.graph
+            .nodes
+            .iter()
+            .map(|n| n.id.as_str())
+            .filter(|id| self.status_of(id).is_foreclosed())
+    }
+
+    /// Weight multiplier from resolved Amplifies sources; 1.0 without any.
+    pub fn amplification(&self, node_id: &str) -> f32 {
+        self.graph
+            .edges
+            .iter()
+            .filter(|e| e.kind == DependencyKind::Amplifies && e.to == node_id)
+            .filter(|e| self.status_of(&e.from) == NodeStatus::Resolved)
+            .map(|e| e.weight)
+            .product()
+    }
+
+    /// Resolve every enabled node whose condition matches the committed
+    /// events, in dependency order so a chain can resolve on one commit,
+    /// then foreclose what the resolutions exclude.
+    pub fn evaluate(&mut self, committed: &[&EventAtom], cast: &CastState) -> DependencyUpdate {
+        let Ok(order) = self.graph.topological_order() else {
+            tracing::warn!("event dependency graph is invalid, skipping evaluation");
+            return DependencyUpdate::default();
+        };
+        let earlier = self.clone();
+
+        for i in order {
+            let node = &self.graph.nodes[i];
+            let Some(condition) = &node.condition else {
+                continue;
+            };
+            if !self.is_enabled(&node.id) {
+                continue;
+            }
+            let ctx = TriggerContext {
+                subject: node.subject.unwrap_or(EntityId(uuid::Uuid::nil())),
+                events: committed,
+                cast,
+            };
+            if condition.matches(&ctx) {
+                let id = node.id.clone();
+                self.resolve(id);
+            }
+        }
+        self.propagate_orphans();
+
+        self.changes_since(&earlier)
+    }
+
+    /// Resolve an authored precondition, e.g. on scene entry.
+    pub fn assert_resolved(&mut self, node_id: &str) -> DependencyUpdate {
+        self.set_directly(node_id, NodeStatus::Resolved)
+    }
+
+    /// Mark a node unreachable, e.g. when the only scene it could happen in
+    /// is left behind.
+    pub fn foreclose(&mut self, node_id: &str) -> DependencyUpdate {
+        self.set_directly(node_id, NodeStatus::Unreachable)
+    }
+
+    /// The difference between `earlier` and this state.
+    pub fn changes_since(&self, earlier: &EventDependencies) -> DependencyUpdate {
+        let ids = self.graph.nodes.iter().map(|n| n.id.as_str());
+        DependencyUpdate {
+            resolved: ids
+                .clone()
+                .filter(|id| {
+                    self.status_of(id) == NodeStatus::Resolved
+                        && earlier.status_of(id) != NodeStatus::Resolved
+                })
+                .map(str::to_string)
+                .collect(),
+            enabled: ids
+                .clone()
+                .filter(|id| self.is_enabled(id) && !earlier.is_enabled(id))
+                .map(str::to_string)
+                .collect(),
+            foreclosed: ids
+                .filter(|id| {
+                    self.status_of(id).is_foreclosed() && !earlier.status_of(id).is_foreclosed()
+                })
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    fn set_directly(&mut self, node_id: &str, status: NodeStatus) -> DependencyUpdate {
+        match self.status(node_id) {
+            Some(NodeStatus::Unresolved) => {}
+            Some(current) => {
+                tracing::debug!(node_id, ?current, "event node already settled, ignoring");
+                return DependencyUpdate::default();
+            }
+            None => {
+                tracing::debug!(node_id, "unknown event node, ignoring");
+                return DependencyUpdate::default();
+            }
+        }
+        let earlier = self.clone();
+        match status {
+            NodeStatus::Resolved => self.resolve(node_id.to_string()),
+            other => {
+                self.status.insert(node_id.to_string(), other);
+            }
+        }
+        self.propagate_orphans();
+        self.changes_since(&earlier)
+    }
+
+    fn resolve(&mut self, node_id: String) {
+        let excluded: Vec<String> = self
+            .graph
+            .edges
+            .iter()
+            .filter(|e| e.kind == DependencyKind::Excludes && e.from == node_id)
+            .map(|e| e.to.clone())
+            .collect();
+        tracing::debug!(node_id, "event node resolved");
+        self.status.insert(node_id, NodeStatus::Resolved);
+        for id in excluded {
+            if self.status_of(&id) == NodeStatus::Unresolved {
+                self.status.insert(id, NodeStatus::Unreachable);
+            }
+        }
+    }
+
+    /// Orphan unresolved nodes that a foreclosed Requires source, or only
+    /// foreclosed Enables sources, have cut off.
+    fn propagate_orphans(&mut self) {
+        let Ok(order) = self.graph.topological_order() else {
+            return;
+        };
+        for i in order {
+            let id = &self.graph.nodes[i].id;
+            if self.status_of(id) != NodeStatus::Unresolved {
+                continue;
+            }
+            let required_lost = self
+                .incoming(id, DependencyKind::Requires)
+                .any(|from| self.status_of(from).is_foreclosed());
+            let enablers_lost = {
+                let mut enablers = self.incoming(id, DependencyKind::Enables).peekable();
+                enablers.peek().is_some()
+                    && enablers.all(|from| self.status_of(from).is_foreclosed())
+            };
+            if required_lost || enablers_lost {
+                let id = id.clone();
+                self.status.insert(id, NodeStatus::Orphaned);
+            }
+        }
+    }
+
+    fn status_of(&self, node_id: &str) -> NodeStatus {
+        self.status.get(node_id).copied().unwrap_or_default()
+    }
+
+    fn incoming<'a>(
+        &'a self,
+        node_id: &'a str,
+        kind: DependencyKind,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.graph
+            .edges
+            .iter()
+            .filter(move |e| e.kind == kind && e.to == node_id)
+            .map(|e| e.from.as_str())
+    }
+
+    fn nodes_with(&self, status: NodeStatus) -> impl Iterator<Item = &str> {
+        self.graph
+            .nodes
+            .iter()
+            .map(|n| n.id.as_str())
+            .filter(move |id| self.status_of(id) == status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::event::{EventId, EventPriority};
+    use crate::types::event_grammar::{
+        ConfidenceEvidence, EventConfidence, EventKind, EventSource,
+    };
+    use crate::types::scene::SceneId;
+    use crate::types::trigger::{EventPattern, TriggerCondition};
+
+    fn node(id: &str, mentions: Option<&str>) -> EventNode {
+        EventNode {
+            id: id.to_string(),
+            description: id.to_string(),
+            condition: mentions.map(|text| {
+                TriggerPredicate::Atom(TriggerCondition::EventOccurred(EventPattern {
+                    mentions: Some(text.to_string()),
+                    ..EventPattern::default()
+                }))
+            }),
+            subject: None,
+        }
+    }
+
+    fn edge(from: &str, to: &str, kind: DependencyKind) -> DependencyEdge {
+        DependencyEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind,
+            weight: 1.0,
+        }
+    }
+
+    fn said(text: &str) -> EventAtom {
+        EventAtom {
+            id: EventId::new(),
+            timestamp: chrono::Utc::now(),
+            kind: EventKind::InformationTransfer {
+                content_summary: text.to_string(),
+            },
+            participants: vec![],
+            relational_implications: vec![],
+            source: EventSource::System {
+                component: "test".to_string(),
+            },
+            confidence: EventConfidence {
+                value: 1.0,
+                evidence: ConfidenceEvidence::SystemProduced,
+            },
+            priority: EventPriority::Normal,
+            scene_id: SceneId::new(),
+            turn_id: None,
+        }
+    }
+
+    /// secret + suspicion → discovery; discovery excludes estrangement;
+    /// estrangement enables bitterness.
+    fn nancy() -> EventDependencies {
+        EventDependencies::new(EventDependencyGraph {
+            nodes: vec![
+                node("pregnant", None),
+                node("suspicion", Some("suspects")),
+                node("extra_shifts", Some("shifts")),
+                node("discovery", Some("truth")),
+                node("devotion", Some("forever")),
+                node("estrangement", Some("leaves")),
+                node("bitterness", Some("bitter")),
+            ],
+            edges: vec![
+                edge("suspicion", "discovery", DependencyKind::Requires),
+                edge("extra_shifts", "discovery", DependencyKind::Requires),
+                edge("discovery", "devotion", DependencyKind::Requires),
+                edge("pregnant", "devotion", DependencyKind::Requires),
+                DependencyEdge {
+                    weight: 1.5,
+                    ..edge("suspicion", "devotion", DependencyKind::Amplifies)
+                },
+                edge("discovery", "estrangement", DependencyKind::Excludes),
+                edge("estrangement", "discovery", DependencyKind::Excludes),
+                edge("estrangement", "bitterness", DependencyKind::Enables),
+            ],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn dependencies_resolve_in_order_and_chain_on_one_commit() {
+        let mut deps = nancy();
+        let cast = CastState::default();
+        assert!(!deps.is_enabled("discovery"));
+        assert!(!deps.is_enabled("bitterness"));
+
+        // The truth mentioned before its dependencies resolve doesn't count…
+        let early = said("the truth is out");
+        let update = deps.evaluate(&[&early], &cast);
+        assert!(update.resolved.is_empty());
+        assert_eq!(deps.status("discovery"), Some(NodeStatus::Unresolved));
+
+        // …but once they have, the same ledger resolves the chain.
+        let suspicion = said("Nancy suspects Johnny");
+        let shifts = said("Johnny has been working extra shifts");
+        let update = deps.evaluate(&[&early, &suspicion, &shifts], &cast);
+        assert_eq!(
+            update.resolved,
+            vec!["suspicion", "extra_shifts", "discovery"]
+        );
+        assert_eq!(update.foreclosed, vec!["estrangement", "bitterness"]);
+        assert_eq!(deps.status("bitterness"), Some(NodeStatus::Orphaned));
+        // devotion still waits on the authored precondition.
+        assert!(!deps.is_enabled("devotion"));
+
+        let update = deps.assert_resolved("pregnant");
+        assert_eq!(update.resolved, vec!["pregnant"]);
+        assert_eq!(update.enabled, vec!["devotion"]);
+        assert!((deps.amplification("devotion") - 1.5).abs() < 1e-6);
+        assert_eq!(deps.reachable().collect::<Vec<_>>(), vec!["devotion"]);
+    }
+
+    #[test]
+    fn enables_wake_dormant_nodes_and_exclusion_is_mutual() {
+        let mut deps = nancy();
+        let cast = CastState::default();
+
+        let update = deps.evaluate(&[&said("she leaves without a word")], &cast);
+        assert_eq!(update.resolved, vec!["estrangement"]);
+        assert_eq!(update.enabled, vec!["bitterness"]);
+        assert_eq!(update.foreclosed, vec!["discovery", "devotion"]);
+        assert_eq!(deps.status("discovery"), Some(NodeStatus::Unreachable));
+        assert_eq!(deps.status("devotion"), Some(NodeStatus::Orphaned));
+
+        // Foreclosed nodes stay foreclosed whatever happens next.
+        deps.evaluate(
+            &[&said("the truth"), &said("suspects"), &said("shifts")],
+            &cast,
+        );
+        assert_eq!(deps.status("discovery"), Some(NodeStatus::Unreachable));
+        assert!(deps.assert_resolved("discovery").is_empty());
+    }
+
+    #[test]
+    fn foreclosing_a_scene_event_orphans_its_descendants() {
+        let mut deps = nancy();
+        let update = deps.foreclose("extra_shifts");
+        assert_eq!(
+            update.foreclosed,
+            vec!["extra_shifts", "discovery", "devotion"]
+        );
+        assert_eq!(deps.foreclosed().count(), 3);
+        assert!(deps.foreclose("no_such_node").is_empty());
+    }
+
+    #[test]
+    fn invalid_graphs_are_rejected() {
+        let cyclic = EventDependencyGraph {
+            nodes: vec![node("a", None), node("b", None)],
+            edges: vec![
+                edge("a", "b", DependencyKind::Requires),
+                edge("b", "a", DependencyKind::Enables),
+            ],
+        };
+        let err = EventDependencies::new(cyclic).unwrap_err();
+        assert!(err.to_string().contains("cycle through a, b"));
+
+        let dangling = EventDependencyGraph {
+            nodes: vec![node("a", None)],
+            edges: vec![edge("a", "ghost", DependencyKind::Requires)],
+        };
+        assert!(EventDependencies::new(dangling)
+            .unwrap_err()
+            .to_string()
+            .contains("unknown node"));
+
+        let duplicate = EventDependencyGraph {
+            nodes: vec![node("a", None), node("a", None)],
+            edges: vec![],
+        };
+        assert!(EventDependencies::new(duplicate).is_err());
+    }
+
+    #[test]
+    fn graphs_round_trip_through_authored_json() {
+        let graph: EventDependencyGraph = serde_json::from_str(
+            r#"{
+                "nodes": [
+                    {"id": "x", "description": "X happens"},
+                    {"id": "y", "description": "Y happens"},
+                    {"id": "z", "description": "Z becomes possible",
+                     "condition": {"atom": {"event_occurred": {"mentions": "z"}}}}
+                ],
+                "edges": [
+                    {"from": "x", "to": "z", "kind": "requires"},
+                    {"from": "y", "to": "z", "kind": "requires"},
+                    {"from": "x", "to": "z", "kind": "amplifies", "weight": 2.0}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut deps = EventDependencies::new(graph).unwrap();
+        deps.assert_resolved("x");
+        let update = deps.assert_resolved("y");
+        assert_eq!(update.enabled, vec!["z"]);
+        assert_eq!(deps.amplification("z"), 2.0);
+
+        let json = serde_json::to_string(&deps).unwrap();
+        let restored: EventDependencies = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, deps);
+        let legacy: EventDependencies = serde_json::from_str("{\"graph\": {}}").unwrap();
+        assert!(legacy.is_empty());
+    }
+}
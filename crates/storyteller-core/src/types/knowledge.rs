@@ -0,0 +1,340 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Knowledge model — which entity has learned which fact, and how.
+//!
+//! See: `docs/technical/storykeeper-api-contract.md` § Information Boundaries
+//!
+//! Information boundaries start from authored character sheets: `knows`
+//! seeds what each character has already learned, `does_not_know` seeds the
+//! facts guarded from them. As the story runs, `InformationTransfer` events
+//! teach their participants what was communicated, which can lift a guard.
+//! Every boundary check — the Storykeeper's, retrieval's, intent
+//! synthesis's — answers from this one model.
+//!
+//! Matching is keyword overlap against the guard text, not semantic
+//! similarity: good enough to catch a secret being restated, cheap enough to
+//! run on every retrieved item.
+
+use std::collections::HashMap;
+
+use crate::traits::storykeeper::BoundaryCheck;
+use crate::types::character::CharacterSheet;
+use crate::types::entity::EntityId;
+use crate::types::event::EventId;
+use crate::types::event_grammar::{EventAtom, EventKind, ParticipantRole};
+
+/// How an entity came to know a fact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum KnowledgeSource {
+    /// Authored — the character knew it when the story began.
+    Seeded,
+    /// Learned through a committed event.
+    Event(EventId),
+}
+
+/// A fact an entity has learned.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LearnedFact {
+    /// The fact, as stated by its source.
+    pub fact: String,
+    /// How the entity learned it.
+    pub source: KnowledgeSource,
+}
+
+/// Per-entity knowledge state: learned facts and guarded facts.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct KnowledgeModel {
+    learned: HashMap<EntityId, Vec<LearnedFact>>,
+    guarded: HashMap<EntityId, Vec<String>>,
+}
+
+impl KnowledgeModel {
+    /// An empty model — every fact is permitted to every entity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A model seeded from a cast's character sheets.
+    pub fn from_characters(characters: &[&CharacterSheet]) -> Self {
+        let mut model = Self::new();
+        for sheet in characters {
+            model.seed(sheet);
+        }
+        model
+    }
+
+    /// A model seeded from a cast and caught up on committed event atoms.
+    ///
+    /// Knowledge is a pure function of the authored cast and the ledger, so
+    /// callers holding a truth set can rebuild it rather than persist it.
+    pub fn replay<'a>(
+        characters: &[&CharacterSheet],
+        atoms: impl IntoIterator<Item = &'a EventAtom>,
+    ) -> Self {
+        let mut model = Self::from_characters(characters);
+        for atom in atoms {
+            model.observe(atom);
+        }
+        model
+    }
+
+    /// Seed an entity's knowledge from its sheet, replacing any earlier seed.
+    ///
+    /// Facts learned through events are kept.
+    pub fn seed(&mut self, sheet: &CharacterSheet) {
+        let learned = self.learned.entry(sheet.entity_id).or_default();
+        learned.retain(|f| f.source != KnowledgeSource::Seeded);
+        learned.extend(sheet.knows.iter().map(|fact| LearnedFact {
+            fact: fact.clone(),
+            source: KnowledgeSource::Seeded,
+        }));
+        self.guarded
+            .insert(sheet.entity_id, sheet.does_not_know.clone());
+    }
+
+    /// Record that an entity has learned a fact.
+    pub fn learn(&mut self, entity_id: EntityId, fact: impl Into<String>, source: KnowledgeSource) {
+        self.learned
+            .entry(entity_id)
+            .or_default()
+            .push(LearnedFact {
+                fact: fact.into(),
+                source,
+            });
+    }
+
+    /// Learn from a committed event atom.
+    ///
+    /// An `InformationTransfer` teaches its content to every resolved actor,
+    /// target, and witness. Other event kinds teach nothing.
+    pub fn observe(&mut self, atom: &EventAtom) {
+        let EventKind::InformationTransfer { content_summary } = &atom.kind else {
+            return;
+        };
+        for participant in &atom.participants {
+            if !matches!(
+                participant.role,
+                ParticipantRole::Actor | ParticipantRole::Target | ParticipantRole::Witness
+            ) {
+                continue;
+            }
+            if let Some(entity_id) = participant.entity.entity_id() {
+                self.learn(
+                    entity_id,
+                    content_summary.clone(),
+                    KnowledgeSource::Event(atom.id),
+                );
+            }
+        }
+    }
+
+    /// Facts the entity has learned, in the order learned.
+    pub fn learned(&self, entity_id: &EntityId) -> &[LearnedFact] {
+        self.learned.get(entity_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Guarded facts the entity has not yet learned.
+    pub fn withheld(&self, entity_id: &EntityId) -> Vec<&str> {
+        self.guarded
+            .get(entity_id)
+            .into_iter()
+            .flatten()
+            .filter(|guard| !self.has_learned(entity_id, guard))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Is this entity permitted to know this fact?
+    ///
+    /// A fact touching none of the entity's guards is permitted. A fact
+    /// touching guards is withheld until the entity learns what they cover;
+    /// when only some have been learned, those are the visible aspects.
+    pub fn check(&self, entity_id: &EntityId, fact: &str) -> BoundaryCheck {
+        let touched: Vec<&String> = self
+            .guarded
+            .get(entity_id)
+            .into_iter()
+            .flatten()
+            .filter(|guard| mentions(guard, fact))
+            .collect();
+        if touched.is_empty() {
+            return BoundaryCheck::Permitted;
+        }
+
+        let visible_aspects: Vec<String> = touched
+            .iter()
+            .filter(|guard| self.has_learned(entity_id, guard))
+            .map(|guard| guard_text(guard))
+            .collect();
+        if visible_aspects.is_empty() {
+            BoundaryCheck::Withheld
+        } else if visible_aspects.len() == touched.len() {
+            BoundaryCheck::Permitted
+        } else {
+            BoundaryCheck::PartiallyRevealed { visible_aspects }
+        }
+    }
+
+    /// Whether the entity learned a guarded fact through an event.
+    fn has_learned(&self, entity_id: &EntityId, guard: &str) -> bool {
+        self.learned(entity_id)
+            .iter()
+            .any(|f| f.source != KnowledgeSource::Seeded && mentions(guard, &f.fact))
+    }
+}
+
+/// The guard's statement, without its parenthetical awareness note.
+fn guard_text(guard: &str) -> String {
+    guard
+        .find('(')
+        .map_or(guard, |i| &guard[..i])
+        .trim()
+        .to_string()
+}
+
+/// Whether `text` restates a guarded fact.
+///
+/// At least half of the guard's significant keywords (longer than three
+/// characters, parenthetical notes stripped) must appear in the text; guards
+/// with fewer than two keywords never match.
+pub fn mentions(guard: &str, text: &str) -> bool {
+    let guard = guard_text(guard).to_lowercase();
+    let text = text.to_lowercase();
+    let keywords: Vec<&str> = guard.split_whitespace().filter(|w| w.len() > 3).collect();
+    let matches = keywords.iter().filter(|kw| text.contains(**kw)).count();
+    keywords.len() >= 2 && matches >= keywords.len() / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::entity::EntityRef;
+    use crate::types::event::EventPriority;
+    use crate::types::event_grammar::{
+        ConfidenceEvidence, EventConfidence, EventSource, Participant,
+    };
+    use crate::types::scene::SceneId;
+
+    const GUARD: &str = "That the ley line beneath the village is failing (senses unease)";
+
+    fn model_for(entity_id: EntityId) -> KnowledgeModel {
+        let mut model = KnowledgeModel::new();
+        model.guarded.insert(entity_id, vec![GUARD.to_string()]);
+        model
+    }
+
+    fn transfer(content: &str, participants: Vec<Participant>) -> EventAtom {
+        EventAtom {
+            id: EventId::new(),
+            timestamp: chrono::Utc::now(),
+            kind: EventKind::InformationTransfer {
+                content_summary: content.to_string(),
+            },
+            participants,
+            relational_implications: vec![],
+            source: EventSource::System {
+                component: "test".to_string(),
+            },
+            confidence: EventConfidence {
+                value: 1.0,
+                evidence: ConfidenceEvidence::SystemProduced,
+            },
+            priority: EventPriority::Normal,
+            scene_id: SceneId::new(),
+            turn_id: None,
+        }
+    }
+
+    #[test]
+    fn unguarded_facts_are_permitted() {
+        let entity = EntityId::new();
+        let model = model_for(entity);
+        assert_eq!(
+            model.check(&entity, "the fence is old"),
+            BoundaryCheck::Permitted
+        );
+        assert_eq!(
+            model.check(&EntityId::new(), "the ley line is failing"),
+            BoundaryCheck::Permitted
+        );
+    }
+
+    #[test]
+    fn guarded_facts_are_withheld_until_learned_through_an_event() {
+        let entity = EntityId::new();
+        let mut model = model_for(entity);
+        let fact = "The ley line under the village is failing fast";
+        assert_eq!(model.check(&entity, fact), BoundaryCheck::Withheld);
+        assert_eq!(model.withheld(&entity), vec![GUARD]);
+
+        let atom = transfer(
+            "the ley line beneath the village is failing",
+            vec![
+                Participant {
+                    entity: EntityRef::Resolved(EntityId::new()),
+                    role: ParticipantRole::Actor,
+                },
+                Participant {
+                    entity: EntityRef::Resolved(entity),
+                    role: ParticipantRole::Target,
+                },
+            ],
+        );
+        model.observe(&atom);
+
+        assert_eq!(model.check(&entity, fact), BoundaryCheck::Permitted);
+        assert!(model.withheld(&entity).is_empty());
+        assert_eq!(
+            model.learned(&entity)[0].source,
+            KnowledgeSource::Event(atom.id)
+        );
+    }
+
+    #[test]
+    fn subjects_of_a_transfer_learn_nothing() {
+        let entity = EntityId::new();
+        let mut model = model_for(entity);
+        model.observe(&transfer(
+            "the ley line beneath the village is failing",
+            vec![Participant {
+                entity: EntityRef::Resolved(entity),
+                role: ParticipantRole::Subject,
+            }],
+        ));
+        assert!(model.learned(&entity).is_empty());
+    }
+
+    #[test]
+    fn partially_learned_guards_reveal_visible_aspects() {
+        let entity = EntityId::new();
+        let mut model = model_for(entity);
+        model
+            .guarded
+            .get_mut(&entity)
+            .unwrap()
+            .push("That the village elders sealed the well".to_string());
+        model.learn(
+            entity,
+            "the ley line beneath the village is failing",
+            KnowledgeSource::Event(EventId::new()),
+        );
+
+        match model.check(&entity, "the village elders know the ley line is failing") {
+            BoundaryCheck::PartiallyRevealed { visible_aspects } => {
+                assert_eq!(
+                    visible_aspects,
+                    vec!["That the ley line beneath the village is failing"]
+                );
+            }
+            other => panic!("expected PartiallyRevealed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn short_guards_never_match() {
+        assert!(!mentions("Magic (unaware)", "magic everywhere"));
+        assert!(mentions(GUARD, "is the ley line failing?"));
+    }
+}
@@ -10,18 +10,24 @@
 pub mod bedrock;
 pub mod capability_lexicon;
 pub mod character;
+pub mod character_state;
 pub mod entity;
 pub mod event;
+pub mod event_dependency;
 pub mod event_grammar;
 pub mod health;
 pub mod implication;
+pub mod knowledge;
 pub mod message;
 pub mod narrative;
+pub mod narrative_layer;
 pub mod narrator_context;
 pub mod prediction;
 pub mod relational;
 pub mod resolver;
 pub mod scene;
+pub mod setting;
 pub mod tensor;
+pub mod trigger;
 pub mod turn_cycle;
 pub mod world_model;
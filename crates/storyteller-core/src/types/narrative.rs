@@ -10,6 +10,9 @@
 //! Scenes have mass that pulls the narrative; the player navigates attractor basins.
 //! Mass formula: authored_base + structural_modifiers + dynamic_adjustment(player_state).
 
+use super::entity::EntityId;
+use super::scene::{DepartureType, SceneId, SceneType};
+
 /// Narrative mass — how strongly a scene attracts the story toward it.
 ///
 /// Higher mass means the narrative "wants" to reach this scene.
@@ -45,3 +48,96 @@ pub struct ApproachVector {
     /// Thematic threads the player has been following.
     pub thematic_threads: Vec<String>,
 }
+
+/// A scene as a node in the narrative graph.
+///
+/// Carries only what graph traversal and pull scoring need; full scene
+/// content (cast, stakes, constraints) stays with the scene record.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NarrativeScene {
+    /// The scene this node stands for.
+    pub scene_id: SceneId,
+    /// Human-readable title.
+    pub title: String,
+    /// Scene classification — drives re-entry and structural mass.
+    pub scene_type: SceneType,
+    /// Authored mass. `dynamic_adjustment` is ignored here and recomputed
+    /// from the truth set when pull is scored.
+    pub mass: NarrativeMass,
+    /// Authored ways of arriving at this scene.
+    pub approach_vectors: Vec<SceneApproach>,
+}
+
+/// A directed transition between two scenes, typed by the departure that
+/// takes it.
+///
+/// The same pair of scenes may be joined by several transitions — one per
+/// departure type — e.g. completing a scene leads onward while abandoning it
+/// leads somewhere else.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SceneTransition {
+    /// Scene being departed.
+    pub from: SceneId,
+    /// Scene being entered.
+    pub to: SceneId,
+    /// The kind of departure that takes this transition.
+    pub departure: DepartureType,
+    /// How strongly the transition carries the narrative forward. Range: \[0.0, 1.0\].
+    pub weight: f32,
+    /// Authored label for the outcome this transition represents.
+    pub outcome_label: String,
+}
+
+/// An authored approach vector — one way a player can arrive at a scene,
+/// expressed as weighted predicates over the truth set.
+///
+/// Distinct from [`ApproachVector`], which records how a player actually
+/// arrived; this describes what a good arrival looks like.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SceneApproach {
+    /// Name of the approach, e.g. `exhausted_but_persisting`.
+    pub name: String,
+    /// Predicates and their relative weights.
+    pub predicates: Vec<WeightedPredicate>,
+}
+
+/// A truth-set predicate with its weight within an approach vector.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WeightedPredicate {
+    /// What must be true.
+    pub predicate: TruthPredicate,
+    /// Relative weight within the approach. Non-negative.
+    pub weight: f32,
+}
+
+/// A condition evaluated against the committed truth set.
+///
+/// Text matches are case-insensitive substring matches.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TruthPredicate {
+    /// A fact was asserted or revealed — matched against state assertions
+    /// and information transfers.
+    FactEstablished {
+        /// Text the assertion or transfer summary must contain.
+        fact: String,
+    },
+    /// An entity took part in committed events. Partially satisfied in
+    /// proportion to how many of `min_events` have happened.
+    EntityInvolved {
+        /// The entity.
+        entity_id: EntityId,
+        /// Events needed for full satisfaction.
+        min_events: u32,
+    },
+    /// An emotion was expressed on stage.
+    EmotionExpressed {
+        /// Text the emotion hint must contain.
+        emotion: String,
+    },
+    /// A scene has been entered.
+    SceneEntered {
+        /// The scene.
+        scene_id: SceneId,
+    },
+}
@@ -0,0 +1,419 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Narrative layers — frame stories, memories, and dreams nested inside a
+//! scene.
+//!
+//! See: `docs/technical/tales-within-tales.md` § Graph Addressing
+//!
+//! A session's position is a [`NarrativeStack`]: the main narrative at the
+//! bottom (implicit — it is the session's scene and journal) and any entered
+//! [`NarrativeLayer`]s above it. Entering a memory pushes a layer; waking
+//! from it pops one. Each layer keeps its own [`SceneJournal`], so the
+//! Narrator rendering a memory sees the memory's history, not the frame's.
+//!
+//! Entity visibility follows the document's resolution order. A layer
+//! declares which entities it holds:
+//!
+//! - **Shared** — the same entity as in the layer beneath, continuous.
+//! - **Projected** — a separate entity standing in for one beneath (the
+//!   younger self in a memory, the distorted figure in a dream).
+//! - **Discrete** — an entity that exists only within the layer.
+//!
+//! An entity from beneath is visible inside a layer only if every layer
+//! above its origin shares or projects it; a projection resolves to the
+//! projection's ID. Entities discrete to a layer vanish when it is popped.
+
+use super::entity::EntityId;
+use super::narrator_context::SceneJournal;
+use super::scene::SceneId;
+
+use uuid::Uuid;
+
+/// Unique identifier for an entered narrative layer.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct LayerId(pub Uuid);
+
+impl LayerId {
+    /// Create a new time-ordered layer ID (UUID v7).
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl Default for LayerId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What kind of tale a layer is — sets how the Narrator frames it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayerKind {
+    /// A character tells a story; the player plays through it.
+    FrameStory,
+    /// A character recalls or relives an experience.
+    Memory,
+    /// A surreal or prophetic experience.
+    Dream,
+    /// The same events from another character's perspective.
+    ParallelPov,
+    /// A document, fairy tale, or written work within the story.
+    EmbeddedText,
+    /// A what-if explored within the narrative.
+    AlternateTimeline,
+}
+
+impl LayerKind {
+    /// How the Narrator is told what it is rendering.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::FrameStory => "a story told within the story",
+            Self::Memory => "a memory",
+            Self::Dream => "a dream",
+            Self::ParallelPov => "the same events from another point of view",
+            Self::EmbeddedText => "a text read within the story",
+            Self::AlternateTimeline => "a path not taken",
+        }
+    }
+}
+
+/// How a layer holds an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case", tag = "mode")]
+pub enum EntitySharing {
+    /// The same entity as in the layer beneath.
+    Shared,
+    /// A stand-in for `source`, an entity in the layer beneath.
+    Projected { source: EntityId },
+    /// Exists only within this layer.
+    Discrete,
+}
+
+/// An entity a layer declares.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LayerEntity {
+    /// The entity's ID within the layer.
+    pub entity_id: EntityId,
+    pub sharing: EntitySharing,
+}
+
+/// An entered narrative layer with its own scene, entities, and journal.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NarrativeLayer {
+    pub id: LayerId,
+    pub kind: LayerKind,
+    /// Human-readable title ("The Winter Road").
+    pub title: String,
+    /// The scene being played inside the layer.
+    pub scene_id: SceneId,
+    /// Who is telling or remembering, if anyone.
+    #[serde(default)]
+    pub teller: Option<String>,
+    /// Voice guidance for the layer, overriding the scene's register.
+    #[serde(default)]
+    pub voice_note: Option<String>,
+    /// Entities present in the layer.
+    #[serde(default)]
+    pub entities: Vec<LayerEntity>,
+    /// The layer's own progressive journal.
+    pub journal: SceneJournal,
+}
+
+impl NarrativeLayer {
+    /// A layer with an empty journal and no entities.
+    pub fn new(kind: LayerKind, title: impl Into<String>, scene_id: SceneId) -> Self {
+        Self {
+            id: LayerId::new(),
+            kind,
+            title: title.into(),
+            scene_id,
+            teller: None,
+            voice_note: None,
+            entities: Vec::new(),
+            journal: SceneJournal::new(scene_id, 1200),
+        }
+    }
+
+    /// Set who is telling or remembering.
+    pub fn with_teller(mut self, teller: impl Into<String>) -> Self {
+        self.teller = Some(teller.into());
+        self
+    }
+
+    /// Set the layer's voice guidance.
+    pub fn with_voice_note(mut self, voice_note: impl Into<String>) -> Self {
+        self.voice_note = Some(voice_note.into());
+        self
+    }
+
+    /// Declare an entity present in the layer.
+    pub fn with_entity(mut self, entity_id: EntityId, sharing: EntitySharing) -> Self {
+        self.entities.push(LayerEntity { entity_id, sharing });
+        self
+    }
+
+    /// Whether `entity_id` originates in this layer (discrete or a
+    /// projection) rather than beneath it.
+    fn introduces(&self, entity_id: EntityId) -> bool {
+        self.entities
+            .iter()
+            .any(|e| e.entity_id == entity_id && e.sharing != EntitySharing::Shared)
+    }
+
+    /// What an entity from the layer beneath is called in this layer, if it
+    /// is present at all.
+    fn admit(&self, entity_id: EntityId) -> Option<EntityId> {
+        self.entities.iter().find_map(|e| match e.sharing {
+            EntitySharing::Shared if e.entity_id == entity_id => Some(entity_id),
+            EntitySharing::Projected { source } if source == entity_id => Some(e.entity_id),
+            _ => None,
+        })
+    }
+}
+
+/// What the Narrator needs to know about the layer it is rendering.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LayerFrame {
+    pub kind: LayerKind,
+    pub title: String,
+    /// Nesting depth; 1 for a layer entered from the main narrative.
+    pub depth: usize,
+    #[serde(default)]
+    pub teller: Option<String>,
+    #[serde(default)]
+    pub voice_note: Option<String>,
+}
+
+/// The session's position in nested narrative layers.
+///
+/// Empty means the main narrative.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct NarrativeStack {
+    #[serde(default)]
+    layers: Vec<NarrativeLayer>,
+}
+
+impl NarrativeStack {
+    /// Enter a layer above the current position. Returns the new depth.
+    pub fn push(&mut self, layer: NarrativeLayer) -> usize {
+        self.layers.push(layer);
+        self.layers.len()
+    }
+
+    /// Leave the innermost layer. `None` in the main narrative.
+    pub fn pop(&mut self) -> Option<NarrativeLayer> {
+        self.layers.pop()
+    }
+
+    /// Number of entered layers; 0 in the main narrative.
+    pub fn depth(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Whether the session is in the main narrative.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// The innermost layer, if any.
+    pub fn current(&self) -> Option<&NarrativeLayer> {
+        self.layers.last()
+    }
+
+    /// The innermost layer, if any, mutably.
+    pub fn current_mut(&mut self) -> Option<&mut NarrativeLayer> {
+        self.layers.last_mut()
+    }
+
+    /// Entered layers, outermost first.
+    pub fn layers(&self) -> &[NarrativeLayer] {
+        &self.layers
+    }
+
+    /// Every entered layer's journal, outermost first.
+    pub fn journals_mut(&mut self) -> impl Iterator<Item = &mut SceneJournal> {
+        self.layers.iter_mut().map(|layer| &mut layer.journal)
+    }
+
+    /// The journal turns are recorded in: the innermost layer's, or
+    /// `main` in the main narrative.
+    pub fn journal<'a>(&'a self, main: &'a SceneJournal) -> &'a SceneJournal {
+        self.current().map_or(main, |layer| &layer.journal)
+    }
+
+    /// Mutable counterpart of [`journal`](Self::journal).
+    pub fn journal_mut<'a>(&'a mut self, main: &'a mut SceneJournal) -> &'a mut SceneJournal {
+        match self.layers.last_mut() {
+            Some(layer) => &mut layer.journal,
+            None => main,
+        }
+    }
+
+    /// What `entity_id` is called at the current position, or `None` if it
+    /// is not present here.
+    ///
+    /// The entity is traced from the layer it originates in (the main
+    /// narrative unless a layer introduces it) up through each layer above,
+    /// which must share or project it.
+    pub fn resolve(&self, entity_id: EntityId) -> Option<EntityId> {
+        let origin = self
+            .layers
+            .iter()
+            .rposition(|layer| layer.introduces(entity_id))
+            .map_or(0, |i| i + 1);
+        self.layers[origin..]
+            .iter()
+            .try_fold(entity_id, |id, layer| layer.admit(id))
+    }
+
+    /// Whether `entity_id` is present at the current position, as itself or
+    /// through a projection.
+    pub fn is_visible(&self, entity_id: EntityId) -> bool {
+        self.resolve(entity_id).is_some()
+    }
+
+    /// The Narrator's frame for the current position; `None` in the main
+    /// narrative.
+    pub fn frame(&self) -> Option<LayerFrame> {
+        self.current().map(|layer| LayerFrame {
+            kind: layer.kind,
+            title: layer.title.clone(),
+            depth: self.depth(),
+            teller: layer.teller.clone(),
+            voice_note: layer.voice_note.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn main_narrative_sees_everything_and_has_no_frame() {
+        let stack = NarrativeStack::default();
+        let pyotir = EntityId::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.resolve(pyotir), Some(pyotir));
+        assert!(stack.frame().is_none());
+    }
+
+    #[test]
+    fn memory_projects_and_shares_entities_from_the_frame() {
+        let pyotir = EntityId::new();
+        let young_pyotir = EntityId::new();
+        let bramblehoof = EntityId::new();
+        let recruiter = EntityId::new();
+
+        let mut stack = NarrativeStack::default();
+        stack.push(
+            NarrativeLayer::new(LayerKind::Memory, "The Recruiter", SceneId::new())
+                .with_teller("Pyotir")
+                .with_entity(young_pyotir, EntitySharing::Projected { source: pyotir })
+                .with_entity(recruiter, EntitySharing::Discrete),
+        );
+
+        assert_eq!(stack.resolve(pyotir), Some(young_pyotir));
+        assert_eq!(stack.resolve(young_pyotir), Some(young_pyotir));
+        assert_eq!(stack.resolve(recruiter), Some(recruiter));
+        assert!(
+            !stack.is_visible(bramblehoof),
+            "the listener is not in the memory"
+        );
+
+        let frame = stack.frame().unwrap();
+        assert_eq!(frame.kind, LayerKind::Memory);
+        assert_eq!(frame.depth, 1);
+        assert_eq!(frame.teller.as_deref(), Some("Pyotir"));
+
+        let left = stack.pop().unwrap();
+        assert_eq!(left.title, "The Recruiter");
+        assert_eq!(stack.resolve(pyotir), Some(pyotir));
+        assert_eq!(
+            stack.resolve(recruiter),
+            Some(recruiter),
+            "the main narrative does not scope entities"
+        );
+        assert!(stack.pop().is_none());
+    }
+
+    #[test]
+    fn nested_layers_resolve_through_every_layer_above_the_origin() {
+        let pyotir = EntityId::new();
+        let young_pyotir = EntityId::new();
+        let wolf = EntityId::new();
+
+        let mut stack = NarrativeStack::default();
+        stack.push(
+            NarrativeLayer::new(LayerKind::Memory, "Childhood", SceneId::new())
+                .with_entity(young_pyotir, EntitySharing::Projected { source: pyotir })
+                .with_entity(wolf, EntitySharing::Discrete),
+        );
+        stack.push(
+            NarrativeLayer::new(LayerKind::FrameStory, "The Wolf's Tale", SceneId::new())
+                .with_entity(wolf, EntitySharing::Shared),
+        );
+
+        assert_eq!(stack.depth(), 2);
+        assert_eq!(
+            stack.resolve(wolf),
+            Some(wolf),
+            "shared down from the memory"
+        );
+        assert!(
+            !stack.is_visible(pyotir),
+            "projected into the memory but not carried into the tale"
+        );
+        assert!(!stack.is_visible(young_pyotir));
+        assert_eq!(stack.frame().unwrap().depth, 2);
+    }
+
+    #[test]
+    fn each_layer_keeps_its_own_journal() {
+        let mut main = SceneJournal::new(SceneId::new(), 1200);
+        let mut stack = NarrativeStack::default();
+        let memory_scene = SceneId::new();
+        stack.push(NarrativeLayer::new(
+            LayerKind::Memory,
+            "The Recruiter",
+            memory_scene,
+        ));
+
+        assert_eq!(stack.journal(&main).scene_id, memory_scene);
+        stack.journal_mut(&mut main).token_budget = 600;
+        assert_eq!(stack.current().unwrap().journal.token_budget, 600);
+        assert_eq!(main.token_budget, 1200);
+
+        stack.pop();
+        assert_eq!(stack.journal(&main).scene_id, main.scene_id);
+    }
+
+    #[test]
+    fn stacks_round_trip_through_serde() {
+        let pyotir = EntityId::new();
+        let mut stack = NarrativeStack::default();
+        stack.push(
+            NarrativeLayer::new(LayerKind::Dream, "Ash Orchard", SceneId::new())
+                .with_voice_note("Fragmentary, present tense")
+                .with_entity(EntityId::new(), EntitySharing::Projected { source: pyotir }),
+        );
+
+        let json = serde_json::to_value(&stack).unwrap();
+        assert_eq!(json["layers"][0]["kind"], "dream");
+        assert_eq!(
+            json["layers"][0]["entities"][0]["sharing"]["mode"],
+            "projected"
+        );
+        let restored: NarrativeStack = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.frame(), stack.frame());
+        assert!(restored.is_visible(pyotir));
+
+        let legacy: NarrativeStack = serde_json::from_str("{}").unwrap();
+        assert!(legacy.is_empty());
+    }
+}
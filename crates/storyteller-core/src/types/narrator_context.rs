@@ -21,8 +21,10 @@
 use chrono::{DateTime, Utc};
 
 use super::entity::EntityId;
+use super::narrative_layer::LayerFrame;
 use super::resolver::ResolverOutput;
 use super::scene::SceneId;
+use super::world_model::ActionPossibility;
 
 // ---------------------------------------------------------------------------
 // Tier 1: Persistent preamble
@@ -53,6 +55,14 @@ pub struct PersistentPreamble {
     /// Player-facing goal context. None when goals system is not active or player has no goals.
     #[serde(default)]
     pub player_context: Option<String>,
+    /// The nested narrative layer being rendered — a memory, frame story,
+    /// or dream. None in the main narrative.
+    #[serde(default)]
+    pub narrative_layer: Option<LayerFrame>,
+    /// Arbitration's verdict on the player's action this turn — whether it
+    /// can succeed at all, and on what conditions. None outside a turn.
+    #[serde(default)]
+    pub action_verdict: Option<ActionPossibility>,
 }
 
 /// Scene-level dramaturgical direction from goal system.
@@ -91,6 +101,10 @@ pub struct CastDescription {
     /// Whether this cast member is controlled by the player.
     #[serde(default)]
     pub is_player: bool,
+    /// How this cast member stands toward the others, one line per
+    /// relationship — e.g. "mentor to Ada (Mentor and Student): trusting, warm".
+    #[serde(default)]
+    pub relationships: Vec<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -111,6 +125,26 @@ pub enum CompressionLevel {
     Skeleton,
 }
 
+/// Compressed forms of a journal entry, produced from its full content.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct JournalCompression {
+    /// The `Summary`-level rendering of the turn.
+    pub summary: String,
+    /// The `Skeleton`-level rendering of the turn.
+    pub skeleton: String,
+}
+
+impl JournalCompression {
+    /// The rendering for `level`, or `None` for `Full`.
+    pub fn at(&self, level: CompressionLevel) -> Option<&str> {
+        match level {
+            CompressionLevel::Full => None,
+            CompressionLevel::Summary => Some(&self.summary),
+            CompressionLevel::Skeleton => Some(&self.skeleton),
+        }
+    }
+}
+
 /// A single entry in the scene journal — one turn's record.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct JournalEntry {
@@ -126,6 +160,10 @@ pub struct JournalEntry {
     pub referenced_entities: Vec<EntityId>,
     /// Emotional dynamics noted in this turn (for compression priority).
     pub emotional_markers: Vec<String>,
+    /// Compressed forms summarized from the full content between turns.
+    /// When present, compression uses these instead of the heuristic.
+    #[serde(default)]
+    pub cached_compression: Option<JournalCompression>,
 }
 
 /// The rolling scene journal — Tier 2 of the Narrator's context.
@@ -223,11 +261,14 @@ mod tests {
                 role: "Visitor, catalyst".to_string(),
                 voice_note: "Warm, reaches for metaphor".to_string(),
                 is_player: false,
+                relationships: Vec::new(),
             }],
             boundaries: vec!["Pyotir cannot leave".to_string()],
             scene_direction: None,
             character_drives: Vec::new(),
             player_context: None,
+            narrative_layer: None,
+            action_verdict: None,
         };
         assert_eq!(preamble.cast_descriptions.len(), 1);
         assert_eq!(preamble.anti_patterns.len(), 2);
@@ -245,6 +286,7 @@ mod tests {
             content: "Bramblehoof approaches the fence.".to_string(),
             referenced_entities: vec![EntityId::new()],
             emotional_markers: vec!["anticipation".to_string()],
+            cached_compression: None,
         });
         assert_eq!(journal.turn_count(), 1);
     }
@@ -263,6 +305,7 @@ mod tests {
             role: "protagonist".to_string(),
             voice_note: "Measured, clipped".to_string(),
             is_player: true,
+            relationships: Vec::new(),
         };
         assert!(cast.is_player);
     }
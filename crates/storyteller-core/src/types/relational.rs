@@ -71,3 +71,22 @@ pub enum TopologicalRole {
     /// Few connections — limited structural influence.
     Periphery,
 }
+
+/// A character's side of a relational dynamic with another cast member.
+///
+/// Scene composition instantiates these from the selected dynamics: the
+/// edge feeds ML feature encoding, the role labels feed the Narrator's cast
+/// descriptions.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CastRelationship {
+    /// This character's edge toward the other cast member.
+    pub edge: DirectedEdge,
+    /// The other cast member's structural role within the dynamic.
+    pub target_topology: TopologicalRole,
+    /// Display name of the dynamic, e.g. "Mentor and Student".
+    pub dynamic: String,
+    /// This character's role in the dynamic, e.g. "mentor".
+    pub role: String,
+    /// The other cast member's role in the dynamic, e.g. "student".
+    pub target_role: String,
+}
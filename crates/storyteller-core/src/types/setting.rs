@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Setting types — re-enterable places that scenes happen within.
+//!
+//! See: `docs/technical/scene-model.md` (scene-setting distinction)
+//!
+//! Design decision: a setting's authored description ([`SettingNode`]) is
+//! separate from what play has done to it ([`SettingState`]). State is an
+//! append-only list of changes, each attributed to the scene that made it,
+//! rendered over the authored [`SceneSetting`] whenever a new scene is placed
+//! there. A return visit sees the broken gate and the cold hearth.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::character::SceneSetting;
+use super::scene::SceneId;
+
+/// Unique identifier for a setting in the setting topology.
+///
+/// Uses UUID v7 (time-ordered) for efficient BTree indexing.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct SettingId(pub Uuid);
+
+impl SettingId {
+    /// Create a new time-ordered setting ID (UUID v7).
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl Default for SettingId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A setting as authored — the place before anything has happened in it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SettingNode {
+    /// The setting.
+    pub setting_id: SettingId,
+    /// Human-readable name.
+    pub name: String,
+    /// The authored physical and sensory setting.
+    pub base: SceneSetting,
+}
+
+/// A way to get from one setting to another.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SettingConnection {
+    /// Setting travelled from.
+    pub from: SettingId,
+    /// Setting travelled to.
+    pub to: SettingId,
+    /// Authored label, e.g. "the path along the stream".
+    pub label: String,
+    /// Cost of the passage — distance, danger, difficulty. Non-negative.
+    pub friction: f32,
+    /// Whether the passage can also be travelled from `to` to `from`.
+    pub bidirectional: bool,
+}
+
+/// One change play has made to a setting.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SettingChange {
+    /// The space now allows something it did not.
+    AffordanceAdded {
+        /// The new affordance.
+        affordance: String,
+    },
+    /// The space no longer allows something it did.
+    AffordanceRemoved {
+        /// The lost affordance — matched exactly against existing ones.
+        affordance: String,
+    },
+    /// A new sensory detail — smoke in the rafters, a smell of rain.
+    SensoryDetailAdded {
+        /// The detail.
+        detail: String,
+    },
+    /// A lasting alteration, appended to the setting's description.
+    Altered {
+        /// What is different now.
+        description: String,
+    },
+}
+
+/// A [`SettingChange`] attributed to the scene that made it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SettingChangeRecord {
+    /// The scene the change happened in.
+    pub scene_id: SceneId,
+    /// When the change was recorded.
+    pub recorded_at: DateTime<Utc>,
+    /// The change.
+    pub change: SettingChange,
+}
+
+/// Mutable state of a setting that outlives any one scene.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SettingState {
+    /// The setting.
+    pub setting_id: SettingId,
+    /// Scenes placed into this setting so far.
+    pub visits: u32,
+    /// The most recent scene placed here.
+    pub last_scene: Option<SceneId>,
+    /// Accumulated changes, oldest first.
+    pub changes: Vec<SettingChangeRecord>,
+}
+
+impl SettingState {
+    /// A setting nobody has visited yet.
+    pub fn new(setting_id: SettingId) -> Self {
+        Self {
+            setting_id,
+            visits: 0,
+            last_scene: None,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Record that `scene_id` has been placed into this setting.
+    pub fn record_visit(&mut self, scene_id: SceneId) {
+        self.visits += 1;
+        self.last_scene = Some(scene_id);
+    }
+
+    /// Record a change made during `scene_id`.
+    pub fn record_change(&mut self, scene_id: SceneId, change: SettingChange) {
+        self.changes.push(SettingChangeRecord {
+            scene_id,
+            recorded_at: Utc::now(),
+            change,
+        });
+    }
+
+    /// The authored setting with every accumulated change applied, in order.
+    pub fn apply_to(&self, base: &SceneSetting) -> SceneSetting {
+        let mut setting = base.clone();
+        for record in &self.changes {
+            match &record.change {
+                SettingChange::AffordanceAdded { affordance } => {
+                    if !setting.affordances.contains(affordance) {
+                        setting.affordances.push(affordance.clone());
+                    }
+                }
+                SettingChange::AffordanceRemoved { affordance } => {
+                    setting.affordances.retain(|a| a != affordance);
+                }
+                SettingChange::SensoryDetailAdded { detail } => {
+                    setting.sensory_details.push(detail.clone());
+                }
+                SettingChange::Altered { description } => {
+                    setting.description.push(' ');
+                    setting.description.push_str(description);
+                }
+            }
+        }
+        setting
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cottage() -> SceneSetting {
+        SceneSetting {
+            description: "A low cottage at the edge of the wood.".to_string(),
+            affordances: vec!["sit by the hearth".to_string()],
+            sensory_details: vec!["woodsmoke".to_string()],
+            aesthetic_detail: "a cracked blue bowl".to_string(),
+        }
+    }
+
+    #[test]
+    fn changes_render_over_the_authored_setting_in_order() {
+        let scene = SceneId::new();
+        let mut state = SettingState::new(SettingId::new());
+        state.record_change(
+            scene,
+            SettingChange::AffordanceRemoved {
+                affordance: "sit by the hearth".to_string(),
+            },
+        );
+        state.record_change(
+            scene,
+            SettingChange::Altered {
+                description: "The hearth has gone cold.".to_string(),
+            },
+        );
+        state.record_change(
+            scene,
+            SettingChange::AffordanceAdded {
+                affordance: "search the ashes".to_string(),
+            },
+        );
+
+        let rendered = state.apply_to(&cottage());
+        assert_eq!(
+            rendered.description,
+            "A low cottage at the edge of the wood. The hearth has gone cold."
+        );
+        assert_eq!(rendered.affordances, vec!["search the ashes".to_string()]);
+        assert_eq!(rendered.aesthetic_detail, "a cracked blue bowl");
+    }
+
+    #[test]
+    fn unvisited_state_renders_the_base_unchanged() {
+        let state = SettingState::new(SettingId::new());
+        let rendered = state.apply_to(&cottage());
+        assert_eq!(rendered.description, cottage().description);
+        assert_eq!(rendered.sensory_details, cottage().sensory_details);
+    }
+
+    #[test]
+    fn setting_change_serde_is_tagged() {
+        let change = SettingChange::SensoryDetailAdded {
+            detail: "wet ash".to_string(),
+        };
+        let json = serde_json::to_value(&change).unwrap();
+        assert_eq!(json["type"], "sensory_detail_added");
+        let back: SettingChange = serde_json::from_value(json).unwrap();
+        assert_eq!(back, change);
+    }
+}
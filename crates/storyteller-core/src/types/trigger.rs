@@ -0,0 +1,579 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Trigger predicates — the condition language for contextual triggers.
+//!
+//! See: `docs/technical/tensor-schema-spec.md` § Trigger Language
+//!
+//! Atomic conditions match against the session's truth: committed
+//! [`EventAtom`]s, the cast's live emotional state, and live relational
+//! edges. Atoms compose set-theoretically (All/Any/Not/Difference/Threshold)
+//! and temporally against commit order (After/Sequence).
+//!
+//! Evaluation is bounded: predicates nested deeper than
+//! [`MAX_PREDICATE_DEPTH`] never match. In a `Threshold`, an event atom
+//! contributes its confidence rather than a full match, so a 0.6-confidence
+//! event counts 0.6 toward `min_match`.
+
+use super::character_state::CastState;
+use super::entity::{EntityId, EntityRef};
+use super::event_grammar::{EventAtom, EventKind, ParticipantRole};
+use super::prediction::ActionType;
+
+/// Deepest nesting a predicate may have and still be evaluated.
+pub const MAX_PREDICATE_DEPTH: usize = 4;
+
+/// A composable trigger condition.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerPredicate {
+    /// A single condition.
+    Atom(TriggerCondition),
+    /// Intersection — every predicate matches.
+    All(Vec<TriggerPredicate>),
+    /// Union — at least one predicate matches.
+    Any(Vec<TriggerPredicate>),
+    /// Complement.
+    Not(Box<TriggerPredicate>),
+    /// `include` matches and `exclude` does not.
+    Difference {
+        include: Box<TriggerPredicate>,
+        exclude: Box<TriggerPredicate>,
+    },
+    /// At least `min_match` of the predicates match, confidence-weighted.
+    Threshold {
+        predicates: Vec<TriggerPredicate>,
+        min_match: u32,
+    },
+    /// `event` has been committed and `then` holds now.
+    After {
+        event: EventPattern,
+        then: Box<TriggerPredicate>,
+    },
+    /// Events matching each pattern were committed in this order.
+    Sequence(Vec<EventPattern>),
+}
+
+/// An atomic condition.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerCondition {
+    /// A committed event matches the pattern.
+    EventOccurred(EventPattern),
+    /// The character is part of the scene's cast.
+    CharacterPresent(EntityId),
+    /// The trigger owner's live intensity for a primary is within bounds.
+    Emotion {
+        primary_id: String,
+        #[serde(default)]
+        at_least: Option<f32>,
+        #[serde(default)]
+        at_most: Option<f32>,
+    },
+    /// The trigger owner's live edge toward `with` is within bounds on a
+    /// substrate dimension.
+    Relationship {
+        with: EntityId,
+        dimension: String,
+        #[serde(default)]
+        at_least: Option<f32>,
+        #[serde(default)]
+        at_most: Option<f32>,
+    },
+}
+
+/// Matches committed event atoms. Unset fields match anything.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventPattern {
+    #[serde(default)]
+    pub kind: Option<EventKindTag>,
+    /// Only meaningful with `kind: action_occurrence`.
+    #[serde(default)]
+    pub action_type: Option<ActionType>,
+    #[serde(default)]
+    pub participant: Option<ParticipantPattern>,
+    /// Case-insensitive text the event's content must contain.
+    #[serde(default)]
+    pub mentions: Option<String>,
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+}
+
+/// Matches one of an event's participants.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ParticipantPattern {
+    pub who: Who,
+    #[serde(default)]
+    pub role: Option<ParticipantRole>,
+}
+
+/// Which entity a participant pattern refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Who {
+    /// The character who owns the trigger.
+    Me,
+    /// A specific entity.
+    Entity(EntityId),
+    /// Any resolved or unresolved participant.
+    Anyone,
+}
+
+/// The variants of [`EventKind`], without their payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKindTag {
+    StateAssertion,
+    ActionOccurrence,
+    SpatialChange,
+    EmotionalExpression,
+    InformationTransfer,
+    SpeechAct,
+    RelationalShift,
+    EnvironmentalChange,
+    SceneLifecycle,
+    EntityLifecycle,
+}
+
+impl EventKindTag {
+    pub fn of(kind: &EventKind) -> Self {
+        match kind {
+            EventKind::StateAssertion { .. } => Self::StateAssertion,
+            EventKind::ActionOccurrence { .. } => Self::ActionOccurrence,
+            EventKind::SpatialChange { .. } => Self::SpatialChange,
+            EventKind::EmotionalExpression { .. } => Self::EmotionalExpression,
+            EventKind::InformationTransfer { .. } => Self::InformationTransfer,
+            EventKind::SpeechAct { .. } => Self::SpeechAct,
+            EventKind::RelationalShift { .. } => Self::RelationalShift,
+            EventKind::EnvironmentalChange { .. } => Self::EnvironmentalChange,
+            EventKind::SceneLifecycle { .. } => Self::SceneLifecycle,
+            EventKind::EntityLifecycle { .. } => Self::EntityLifecycle,
+        }
+    }
+}
+
+/// What a predicate is evaluated against.
+#[derive(Debug, Clone, Copy)]
+pub struct TriggerContext<'a> {
+    /// The character who owns the trigger.
+    pub subject: EntityId,
+    /// Committed event atoms, in commit order.
+    pub events: &'a [&'a EventAtom],
+    /// The cast's live state.
+    pub cast: &'a CastState,
+}
+
+impl TriggerPredicate {
+    /// Nesting depth; an atom is 0.
+    pub fn depth(&self) -> usize {
+        let children = |ps: &[TriggerPredicate]| ps.iter().map(Self::depth).max().unwrap_or(0);
+        match self {
+            Self::Atom(_) | Self::Sequence(_) => 0,
+            Self::All(ps) | Self::Any(ps) => 1 + children(ps),
+            Self::Threshold { predicates, .. } => 1 + children(predicates),
+            Self::Not(p) | Self::After { then: p, .. } => 1 + p.depth(),
+            Self::Difference { include, exclude } => 1 + include.depth().max(exclude.depth()),
+        }
+    }
+
+    /// Whether the predicate holds. Predicates deeper than
+    /// [`MAX_PREDICATE_DEPTH`] never do.
+    pub fn matches(&self, ctx: &TriggerContext<'_>) -> bool {
+        self.depth() <= MAX_PREDICATE_DEPTH && self.holds(ctx)
+    }
+
+    fn holds(&self, ctx: &TriggerContext<'_>) -> bool {
+        match self {
+            Self::Atom(condition) => condition.holds(ctx),
+            Self::All(ps) => ps.iter().all(|p| p.holds(ctx)),
+            Self::Any(ps) => ps.iter().any(|p| p.holds(ctx)),
+            Self::Not(p) => !p.holds(ctx),
+            Self::Difference { include, exclude } => include.holds(ctx) && !exclude.holds(ctx),
+            Self::Threshold {
+                predicates,
+                min_match,
+            } => {
+                let score: f32 = predicates.iter().map(|p| p.weight(ctx)).sum();
+                score >= *min_match as f32
+            }
+            Self::After { event, then } => {
+                ctx.events.iter().any(|e| event.matches(e, ctx.subject)) && then.holds(ctx)
+            }
+            Self::Sequence(patterns) => {
+                let mut events = ctx.events.iter();
+                patterns
+                    .iter()
+                    .all(|pattern| events.any(|e| pattern.matches(e, ctx.subject)))
+            }
+        }
+    }
+
+    /// Contribution toward a `Threshold`: the best matching event's
+    /// confidence for event atoms, otherwise 1.0 or 0.0.
+    fn weight(&self, ctx: &TriggerContext<'_>) -> f32 {
+        match self {
+            Self::Atom(TriggerCondition::EventOccurred(pattern)) => ctx
+                .events
+                .iter()
+                .filter(|e| pattern.matches(e, ctx.subject))
+                .map(|e| e.confidence.value.clamp(0.0, 1.0))
+                .fold(0.0, f32::max),
+            other => {
+                if other.holds(ctx) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+impl TriggerCondition {
+    fn holds(&self, ctx: &TriggerContext<'_>) -> bool {
+        match self {
+            Self::EventOccurred(pattern) => {
+                ctx.events.iter().any(|e| pattern.matches(e, ctx.subject))
+            }
+            Self::CharacterPresent(id) => ctx.cast.character(*id).is_some(),
+            Self::Emotion {
+                primary_id,
+                at_least,
+                at_most,
+            } => ctx
+                .cast
+                .character(ctx.subject)
+                .and_then(|c| c.intensity(primary_id))
+                .is_some_and(|v| within(v, *at_least, *at_most)),
+            Self::Relationship {
+                with,
+                dimension,
+                at_least,
+                at_most,
+            } => ctx
+                .cast
+                .character(ctx.subject)
+                .and_then(|c| c.edges.iter().find(|e| e.target == *with))
+                .and_then(|e| e.dimension(dimension))
+                .is_some_and(|v| within(v, *at_least, *at_most)),
+        }
+    }
+}
+
+impl EventPattern {
+    /// Whether `atom` matches, with `subject` standing in for [`Who::Me`].
+    pub fn matches(&self, atom: &EventAtom, subject: EntityId) -> bool {
+        if self.kind.is_some_and(|k| k != EventKindTag::of(&atom.kind)) {
+            return false;
+        }
+        if let Some(wanted) = self.action_type {
+            match &atom.kind {
+                EventKind::ActionOccurrence { action_type } if *action_type == wanted => {}
+                _ => return false,
+            }
+        }
+        if self
+            .min_confidence
+            .is_some_and(|min| atom.confidence.value < min)
+        {
+            return false;
+        }
+        if let Some(participant) = &self.participant {
+            let found = atom.participants.iter().any(|p| {
+                participant.role.is_none_or(|role| role == p.role)
+                    && match (participant.who, &p.entity) {
+                        (Who::Anyone, _) => true,
+                        (Who::Me, EntityRef::Resolved(id)) => *id == subject,
+                        (Who::Entity(wanted), EntityRef::Resolved(id)) => *id == wanted,
+                        _ => false,
+                    }
+            });
+            if !found {
+                return false;
+            }
+        }
+        if let Some(needle) = &self.mentions {
+            let needle = needle.to_lowercase();
+            if !event_text(atom).to_lowercase().contains(&needle) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// The searchable text of an event: its content plus participant mentions.
+fn event_text(atom: &EventAtom) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    match &atom.kind {
+        EventKind::StateAssertion { assertion } => parts.push(assertion),
+        EventKind::InformationTransfer { content_summary } => parts.push(content_summary),
+        EventKind::EnvironmentalChange { description } => parts.push(description),
+        EventKind::EmotionalExpression {
+            emotion_hint: Some(hint),
+            ..
+        } => parts.push(hint),
+        EventKind::SpatialChange { from, to } => {
+            parts.extend(from.as_deref());
+            parts.extend(to.as_deref());
+        }
+        _ => {}
+    }
+    for participant in &atom.participants {
+        match &participant.entity {
+            EntityRef::Unresolved { mention, .. } => parts.push(mention),
+            EntityRef::Implicit { implied_entity, .. } => parts.push(implied_entity),
+            EntityRef::Resolved(_) => {}
+        }
+    }
+    parts.join(" ")
+}
+
+fn within(value: f32, at_least: Option<f32>, at_most: Option<f32>) -> bool {
+    at_least.is_none_or(|min| value >= min) && at_most.is_none_or(|max| value <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::character_state::{LiveCharacterState, LiveEdge, LivePrimary};
+    use crate::types::event::{EventId, EventPriority};
+    use crate::types::event_grammar::{
+        ConfidenceEvidence, EventConfidence, EventSource, Participant,
+    };
+    use crate::types::scene::SceneId;
+
+    fn atom(kind: EventKind, participants: Vec<Participant>, confidence: f32) -> EventAtom {
+        EventAtom {
+            id: EventId::new(),
+            timestamp: chrono::Utc::now(),
+            kind,
+            participants,
+            relational_implications: vec![],
+            source: EventSource::System {
+                component: "test".to_string(),
+            },
+            confidence: EventConfidence {
+                value: confidence,
+                evidence: ConfidenceEvidence::SystemProduced,
+            },
+            priority: EventPriority::Normal,
+            scene_id: SceneId::new(),
+            turn_id: None,
+        }
+    }
+
+    fn said(text: &str) -> EventAtom {
+        atom(
+            EventKind::InformationTransfer {
+                content_summary: text.to_string(),
+            },
+            vec![],
+            1.0,
+        )
+    }
+
+    fn mentions(text: &str) -> EventPattern {
+        EventPattern {
+            mentions: Some(text.to_string()),
+            ..EventPattern::default()
+        }
+    }
+
+    fn occurred(text: &str) -> TriggerPredicate {
+        TriggerPredicate::Atom(TriggerCondition::EventOccurred(mentions(text)))
+    }
+
+    fn cast(subject: EntityId, other: EntityId) -> CastState {
+        CastState {
+            characters: vec![LiveCharacterState {
+                entity_id: subject,
+                intensity_range: (0.0, 1.0),
+                primaries: vec![LivePrimary {
+                    primary_id: "grief".to_string(),
+                    baseline: 0.4,
+                    intensity: 0.7,
+                }],
+                edges: vec![LiveEdge {
+                    target: other,
+                    trust_reliability: 0.0,
+                    trust_competence: 0.0,
+                    trust_benevolence: -0.4,
+                    affection: 0.2,
+                    debt: 0.0,
+                }],
+                triggers: vec![],
+            }],
+            ..CastState::default()
+        }
+    }
+
+    #[test]
+    fn set_operations_compose() {
+        let subject = EntityId::new();
+        let cast = cast(subject, EntityId::new());
+        let flute = said("He mentions the flute on the hook");
+        let events = [&flute];
+        let ctx = TriggerContext {
+            subject,
+            events: &events,
+            cast: &cast,
+        };
+
+        assert!(occurred("FLUTE").matches(&ctx));
+        assert!(TriggerPredicate::All(vec![occurred("flute"), occurred("hook")]).matches(&ctx));
+        assert!(!TriggerPredicate::All(vec![occurred("flute"), occurred("ley")]).matches(&ctx));
+        assert!(TriggerPredicate::Any(vec![occurred("ley"), occurred("hook")]).matches(&ctx));
+        assert!(TriggerPredicate::Not(Box::new(occurred("ley"))).matches(&ctx));
+        assert!(!TriggerPredicate::Difference {
+            include: Box::new(occurred("flute")),
+            exclude: Box::new(occurred("hook")),
+        }
+        .matches(&ctx));
+    }
+
+    #[test]
+    fn emotional_and_relational_thresholds_read_live_state() {
+        let subject = EntityId::new();
+        let other = EntityId::new();
+        let cast = cast(subject, other);
+        let ctx = TriggerContext {
+            subject,
+            events: &[],
+            cast: &cast,
+        };
+
+        let grief = |at_least| {
+            TriggerPredicate::Atom(TriggerCondition::Emotion {
+                primary_id: "grief".to_string(),
+                at_least: Some(at_least),
+                at_most: None,
+            })
+        };
+        assert!(grief(0.6).matches(&ctx));
+        assert!(!grief(0.8).matches(&ctx));
+
+        let distrust = TriggerPredicate::Atom(TriggerCondition::Relationship {
+            with: other,
+            dimension: "trust_benevolence".to_string(),
+            at_least: None,
+            at_most: Some(-0.3),
+        });
+        assert!(distrust.matches(&ctx));
+        assert!(TriggerPredicate::Atom(TriggerCondition::CharacterPresent(subject)).matches(&ctx));
+    }
+
+    #[test]
+    fn threshold_weights_events_by_confidence() {
+        let subject = EntityId::new();
+        let cast = cast(subject, EntityId::new());
+        let sure = said("the flute");
+        let mut unsure = said("the ley line");
+        unsure.confidence.value = 0.6;
+        let events = [&sure, &unsure];
+        let ctx = TriggerContext {
+            subject,
+            events: &events,
+            cast: &cast,
+        };
+
+        let threshold = |min_match| TriggerPredicate::Threshold {
+            predicates: vec![occurred("flute"), occurred("ley"), occurred("drought")],
+            min_match,
+        };
+        assert!(threshold(1).matches(&ctx));
+        assert!(!threshold(2).matches(&ctx), "1.0 + 0.6 falls short of 2");
+    }
+
+    #[test]
+    fn temporal_predicates_follow_commit_order() {
+        let subject = EntityId::new();
+        let cast = cast(subject, EntityId::new());
+        let first = said("the flute");
+        let second = said("the tools were sold");
+        let events = [&first, &second];
+        let ctx = TriggerContext {
+            subject,
+            events: &events,
+            cast: &cast,
+        };
+
+        assert!(
+            TriggerPredicate::Sequence(vec![mentions("flute"), mentions("tools")]).matches(&ctx)
+        );
+        assert!(
+            !TriggerPredicate::Sequence(vec![mentions("tools"), mentions("flute")]).matches(&ctx)
+        );
+        assert!(TriggerPredicate::After {
+            event: mentions("flute"),
+            then: Box::new(occurred("tools")),
+        }
+        .matches(&ctx));
+    }
+
+    #[test]
+    fn participant_patterns_resolve_me() {
+        let subject = EntityId::new();
+        let cast = cast(subject, EntityId::new());
+        let handed = atom(
+            EventKind::ActionOccurrence {
+                action_type: ActionType::Perform,
+            },
+            vec![Participant {
+                entity: EntityRef::Resolved(subject),
+                role: ParticipantRole::Target,
+            }],
+            1.0,
+        );
+        let events = [&handed];
+        let ctx = TriggerContext {
+            subject,
+            events: &events,
+            cast: &cast,
+        };
+
+        let pattern = |role| EventPattern {
+            kind: Some(EventKindTag::ActionOccurrence),
+            action_type: Some(ActionType::Perform),
+            participant: Some(ParticipantPattern { who: Who::Me, role }),
+            ..EventPattern::default()
+        };
+        let on_me = |role| TriggerPredicate::Atom(TriggerCondition::EventOccurred(pattern(role)));
+        assert!(on_me(Some(ParticipantRole::Target)).matches(&ctx));
+        assert!(!on_me(Some(ParticipantRole::Actor)).matches(&ctx));
+    }
+
+    #[test]
+    fn overly_deep_predicates_never_match() {
+        let subject = EntityId::new();
+        let cast = cast(subject, EntityId::new());
+        let ctx = TriggerContext {
+            subject,
+            events: &[],
+            cast: &cast,
+        };
+        let mut predicate = TriggerPredicate::Not(Box::new(occurred("flute")));
+        assert!(predicate.matches(&ctx));
+        for _ in 0..MAX_PREDICATE_DEPTH {
+            predicate = TriggerPredicate::All(vec![predicate]);
+        }
+        assert_eq!(predicate.depth(), MAX_PREDICATE_DEPTH + 1);
+        assert!(!predicate.matches(&ctx));
+    }
+
+    #[test]
+    fn predicates_round_trip_through_json() {
+        let json = serde_json::json!({
+            "all": [
+                { "atom": { "event_occurred": { "kind": "information_transfer", "mentions": "flute" } } },
+                { "not": { "atom": { "emotion": { "primary_id": "joy", "at_least": 0.5 } } } }
+            ]
+        });
+        let predicate: TriggerPredicate = serde_json::from_value(json).unwrap();
+        assert_eq!(predicate.depth(), 2);
+        let back: TriggerPredicate =
+            serde_json::from_value(serde_json::to_value(&predicate).unwrap()).unwrap();
+        assert_eq!(back, predicate);
+    }
+}
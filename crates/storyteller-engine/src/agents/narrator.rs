@@ -404,6 +404,7 @@ mod tests {
                         role: "Visitor, catalyst".to_string(),
                         voice_note: "Warm, reaches for metaphor".to_string(),
                         is_player: false,
+                        relationships: Vec::new(),
                     },
                     CastDescription {
                         entity_id: EntityId::new(),
@@ -411,12 +412,15 @@ mod tests {
                         role: "Resident, ground truth".to_string(),
                         voice_note: "Measured, practical".to_string(),
                         is_player: false,
+                        relationships: Vec::new(),
                     },
                 ],
                 boundaries: vec!["Pyotir cannot leave".to_string()],
                 scene_direction: None,
                 character_drives: Vec::new(),
                 player_context: None,
+                narrative_layer: None,
+                action_verdict: None,
             },
             journal: SceneJournal::new(SceneId::new(), 1200),
             retrieved: vec![RetrievedContext {
@@ -673,4 +677,32 @@ mod tests {
         assert!(message.contains("Pyotir"));
         assert!(!message.contains("## Character Intents"));
     }
+
+    // Exercises the real streaming path end to end with an in-process model.
+    // Requires STORYTELLER_LOCAL_LLM_PATH (see `inference::local`).
+    // Enable with: cargo test -p storyteller-engine --features test-local-llm
+    #[cfg(feature = "test-local-llm")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stream_render_with_local_model() {
+        use crate::inference::local::CandleLlmProvider;
+
+        let dir = std::env::var("STORYTELLER_LOCAL_LLM_PATH")
+            .expect("STORYTELLER_LOCAL_LLM_PATH must be set for test-local-llm");
+        let llm: Arc<dyn LlmProvider> =
+            Arc::new(CandleLlmProvider::from_dir(std::path::Path::new(&dir)).unwrap());
+        let narrator = NarratorAgent::new(&mock_context(), llm);
+
+        let mut stream = narrator
+            .stream_render(&mock_context(), &NoopObserver)
+            .await
+            .unwrap();
+        let mut prose = String::new();
+        let mut chunks = 0;
+        while let Some(token) = stream.0.recv().await {
+            prose.push_str(&token.unwrap());
+            chunks += 1;
+        }
+        assert!(chunks > 1, "narrator should stream incrementally");
+        assert!(!prose.trim().is_empty());
+    }
 }
@@ -14,6 +14,10 @@ use std::sync::Arc;
 use bevy_ecs::prelude::*;
 use chrono::{DateTime, Utc};
 
+use storyteller_core::database::checkpoint::TruthSet;
+use storyteller_core::database::ledger::EventLedger;
+use storyteller_core::types::character_state::CastState;
+use storyteller_core::types::event_dependency::EventDependencies;
 use storyteller_core::types::event_grammar::{CompoundEvent, EventAtom};
 use storyteller_core::types::message::NarratorRendering;
 use storyteller_core::types::narrator_context::{NarratorContextInput, SceneJournal};
@@ -23,6 +27,9 @@ use storyteller_core::types::turn_cycle::{EnrichmentPhase, TurnCycleStage};
 use storyteller_core::types::world_model::ActionPossibility;
 use storyteller_core::StorytellerResult;
 
+use storyteller_core::game_design::{GameDesignRegistry, GenreRules};
+use storyteller_core::traits::llm::LlmProvider;
+use storyteller_core::traits::storykeeper::SessionId;
 use storyteller_core::traits::structured_llm::StructuredLlmProvider;
 
 use crate::agents::narrator::NarratorAgent;
@@ -159,6 +166,49 @@ impl std::fmt::Debug for NarratorResource {
 #[derive(Debug, Resource)]
 pub struct JournalResource(pub SceneJournal);
 
+/// Bevy Resource: events the session has committed as having happened.
+///
+/// `commit_previous_system` adds each archived turn's atoms; deferred
+/// workflow results from tasker-core are merged at turn boundaries.
+#[derive(Debug, Default, Resource)]
+pub struct TruthSetResource(pub TruthSet);
+
+/// Bevy Resource: the session's event ledger.
+///
+/// Optional — when present, state changes made outside the turn pipeline
+/// (deferred workflow results) are ledgered before they are applied, so
+/// recovery replays them.
+#[derive(Resource, Clone)]
+pub struct LedgerResource {
+    /// Where entries are appended.
+    pub ledger: Arc<dyn EventLedger>,
+    /// The session the world is playing.
+    pub session_id: SessionId,
+}
+
+impl std::fmt::Debug for LedgerResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LedgerResource")
+            .field("session_id", &self.session_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Bevy Resource: the cast's live emotional and relational state.
+///
+/// `commit_previous_system` seeds it from the scene cast on the first
+/// commit and applies each archived turn's resolved state changes.
+/// Enrichment and context assembly read the cast through it.
+#[derive(Debug, Default, Resource)]
+pub struct CastStateResource(pub CastState);
+
+/// Bevy Resource: the scene's event dependency graph and its progress.
+///
+/// `commit_previous_system` seeds it from the scene's authored graph on the
+/// first commit and resolves nodes against each turn's committed events.
+#[derive(Debug, Default, Resource)]
+pub struct EventDependenciesResource(pub EventDependencies);
+
 /// Bevy Resource: tokio runtime handle for spawning async tasks.
 ///
 /// Bevy systems may not run on a tokio thread, so we cannot rely on
@@ -188,6 +238,50 @@ impl std::fmt::Debug for StructuredLlmResource {
     }
 }
 
+/// Bevy Resource: LLM provider for NPC intent synthesis.
+///
+/// Optional — when absent, the enrichment pipeline skips intent synthesis
+/// and the Narrator falls back to rendering raw predictions.
+#[derive(Resource, Clone)]
+pub struct IntentLlmResource(pub Arc<dyn LlmProvider>);
+
+impl std::fmt::Debug for IntentLlmResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IntentLlmResource").finish()
+    }
+}
+
+/// Bevy Resource: the game design system selection for this scene.
+///
+/// The genre picks the tuning from the registry; the seed (mixed with the
+/// turn number) makes resolution replayable.
+#[derive(Debug, Resource)]
+pub struct GameDesignResource {
+    /// Per-genre resolution tunings.
+    pub registry: GameDesignRegistry,
+    /// The scene's genre.
+    pub genre_id: String,
+    /// Session seed for resolution rolls.
+    pub seed: u64,
+}
+
+impl Default for GameDesignResource {
+    fn default() -> Self {
+        Self {
+            registry: GameDesignRegistry::builtin(),
+            genre_id: String::new(),
+            seed: 0,
+        }
+    }
+}
+
+/// Bevy Resource: the scene's genre rules for action arbitration.
+///
+/// Derived (and lexicon-expanded) once at composition; empty rules permit
+/// every action.
+#[derive(Debug, Default, Resource)]
+pub struct GenreRulesResource(pub GenreRules);
+
 // ---------------------------------------------------------------------------
 // Async narrator task tracking
 // ---------------------------------------------------------------------------
@@ -17,12 +17,21 @@
 //!
 //! Entries with emotional markers matching scene stakes resist one
 //! compression level (e.g., stay at Summary when they would become Skeleton).
+//!
+//! What an entry compresses *to* is pluggable. Compression itself is
+//! synchronous and runs as turns are added; a [`JournalCompressor`] can run
+//! asynchronously between turns to precompute an entry's Summary and
+//! Skeleton forms, which are cached on the entry and preferred over the
+//! [`HeuristicCompressor`] fallback.
 
 use chrono::Utc;
 
+use storyteller_core::errors::StorytellerResult;
 use storyteller_core::traits::phase_observer::{PhaseEvent, PhaseEventDetail, PhaseObserver};
 use storyteller_core::types::entity::EntityId;
-use storyteller_core::types::narrator_context::{CompressionLevel, JournalEntry, SceneJournal};
+use storyteller_core::types::narrator_context::{
+    CompressionLevel, JournalCompression, JournalEntry, SceneJournal,
+};
 use storyteller_core::types::turn_cycle::TurnCycleStage;
 
 use super::tokens::estimate_tokens;
@@ -46,6 +55,7 @@ pub fn add_turn(
         content: content.to_string(),
         referenced_entities,
         emotional_markers: emotional_markers.clone(),
+        cached_compression: None,
     };
 
     observer.emit(PhaseEvent {
@@ -102,9 +112,16 @@ pub fn compress_if_needed(journal: &mut SceneJournal, observer: &dyn PhaseObserv
         if actual_target > current {
             let from = current;
 
-            // Apply compression by transforming the content
-            journal.entries[i].content =
-                compress_content(&journal.entries[i].content, actual_target);
+            // Prefer the cached form; fall back to the heuristic
+            let entry = &mut journal.entries[i];
+            entry.content = match entry
+                .cached_compression
+                .as_ref()
+                .and_then(|c| c.at(actual_target))
+            {
+                Some(cached) => cached.to_string(),
+                None => compress_content(&entry.content, actual_target),
+            };
             journal.entries[i].compression = actual_target;
             entries_compressed += 1;
 
@@ -140,42 +157,126 @@ pub fn compress_if_needed(journal: &mut SceneJournal, observer: &dyn PhaseObserv
     }
 }
 
+/// Strategy for producing an entry's compressed forms from its full content.
+///
+/// Implementations may be slow (an LLM call); they run between turns via
+/// [`cache_compression`], never on the turn's critical path.
+#[async_trait::async_trait]
+pub trait JournalCompressor: std::fmt::Debug + Send + Sync {
+    /// Compress a full-detail entry to its Summary and Skeleton forms.
+    async fn compress(&self, entry: &JournalEntry) -> StorytellerResult<JournalCompression>;
+}
+
+/// Sentence-based compression with no model behind it — the default, and
+/// the fallback whenever no cached form is available.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicCompressor;
+
+#[async_trait::async_trait]
+impl JournalCompressor for HeuristicCompressor {
+    async fn compress(&self, entry: &JournalEntry) -> StorytellerResult<JournalCompression> {
+        Ok(JournalCompression {
+            summary: compress_content(&entry.content, CompressionLevel::Summary),
+            skeleton: compress_content(&entry.content, CompressionLevel::Skeleton),
+        })
+    }
+}
+
+/// Longest Summary the heuristic keeps, in characters.
+const SUMMARY_MAX_CHARS: usize = 160;
+/// Longest Skeleton the heuristic keeps, in characters.
+const SKELETON_MAX_CHARS: usize = 80;
+
 /// Compress content to the target level.
 ///
 /// - Full: unchanged
-/// - Summary: first sentence + emotional marker summary
-/// - Skeleton: very brief essence
+/// - Summary: the first sentence, up to 160 characters
+/// - Skeleton: the first sentence, up to 80 characters
+///
+/// Over-long sentences are cut at a word boundary and marked with `…`.
+/// Lengths count characters, so multibyte text is never split.
 fn compress_content(content: &str, target: CompressionLevel) -> String {
-    match target {
-        CompressionLevel::Full => content.to_string(),
-        CompressionLevel::Summary => {
-            // Take the first sentence (or first 100 chars) as summary
-            let first_sentence = content
-                .find(". ")
-                .map(|i| &content[..=i])
-                .unwrap_or_else(|| {
-                    if content.len() <= 100 {
-                        content
-                    } else {
-                        &content[..100]
-                    }
-                });
-            first_sentence.to_string()
-        }
-        CompressionLevel::Skeleton => {
-            // Very brief — first clause or first 50 chars
-            let skeleton = content
-                .find(", ")
-                .or_else(|| content.find(". "))
-                .map(|i| &content[..i])
-                .unwrap_or_else(|| {
-                    if content.len() <= 50 {
-                        content
-                    } else {
-                        &content[..50]
-                    }
-                });
-            skeleton.to_string()
+    let max_chars = match target {
+        CompressionLevel::Full => return content.to_string(),
+        CompressionLevel::Summary => SUMMARY_MAX_CHARS,
+        CompressionLevel::Skeleton => SKELETON_MAX_CHARS,
+    };
+    truncate_at_word(first_sentence(content), max_chars)
+}
+
+/// The first sentence of `content`, terminal punctuation included.
+fn first_sentence(content: &str) -> &str {
+    let content = content.trim();
+    content
+        .char_indices()
+        .find(|&(i, c)| {
+            matches!(c, '.' | '!' | '?')
+                && content[i + c.len_utf8()..]
+                    .chars()
+                    .next()
+                    .is_none_or(char::is_whitespace)
+        })
+        .map_or(content, |(i, c)| &content[..i + c.len_utf8()])
+}
+
+/// `text` if it fits in `max_chars`, otherwise its longest word-aligned
+/// prefix that does, followed by `…`.
+fn truncate_at_word(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    // Byte offset of the char at `max_chars`; always a char boundary.
+    let limit = text
+        .char_indices()
+        .nth(max_chars)
+        .map_or(text.len(), |(i, _)| i);
+    let cut = text[..limit].rfind(char::is_whitespace).unwrap_or(limit);
+    format!(
+        "{}…",
+        text[..cut].trim_end_matches(|c: char| c.is_whitespace() || c == ',')
+    )
+}
+
+/// Cache `compression` on the entry for `turn_number`.
+///
+/// An entry already compressed (by the heuristic, because the compressor
+/// finished after the next turn arrived) is re-rendered from the cache.
+/// Returns `false` if the journal has no such entry.
+pub fn cache_compression(
+    journal: &mut SceneJournal,
+    turn_number: u32,
+    compression: JournalCompression,
+) -> bool {
+    let Some(entry) = journal
+        .entries
+        .iter_mut()
+        .find(|e| e.turn_number == turn_number)
+    else {
+        return false;
+    };
+    if let Some(cached) = compression.at(entry.compression) {
+        entry.content = cached.to_string();
+    }
+    entry.cached_compression = Some(compression);
+    true
+}
+
+/// Carry compressions cached on `source` over to matching entries in
+/// `target` that lack one.
+///
+/// For publishing a snapshot built from an older copy of the journal
+/// without losing compressions that landed in the meantime.
+pub fn adopt_cached_compressions(target: &mut SceneJournal, source: &SceneJournal) {
+    for entry in &source.entries {
+        let Some(compression) = &entry.cached_compression else {
+            continue;
+        };
+        let missing = target
+            .entries
+            .iter()
+            .any(|e| e.turn_number == entry.turn_number && e.cached_compression.is_none());
+        if missing {
+            cache_compression(target, entry.turn_number, compression.clone());
         }
     }
 }
@@ -346,6 +447,114 @@ mod tests {
         assert!(skeleton.len() < summary.len());
     }
 
+    #[test]
+    fn compression_keeps_whole_sentences_and_char_boundaries() {
+        // The old skeleton cut at the first comma and dropped the clause
+        // that mattered.
+        let content = "Pyotir sets down the hoe, then hands over the flute. He says nothing.";
+        assert_eq!(
+            compress_content(content, CompressionLevel::Skeleton),
+            "Pyotir sets down the hoe, then hands over the flute."
+        );
+
+        // Multibyte text longer than the limit truncates on a char (and
+        // word) boundary rather than panicking mid-character.
+        let cyrillic = "Пётр ".repeat(30);
+        let skeleton = compress_content(&cyrillic, CompressionLevel::Skeleton);
+        assert!(skeleton.ends_with('…'));
+        assert!(skeleton.chars().count() <= SKELETON_MAX_CHARS + 1);
+        assert!(skeleton.trim_end_matches('…').ends_with("Пётр"));
+    }
+
+    #[test]
+    fn first_sentence_ignores_inner_periods() {
+        assert_eq!(
+            first_sentence("It cost 3.50 coins. Too much."),
+            "It cost 3.50 coins."
+        );
+        assert_eq!(
+            first_sentence("No terminal punctuation"),
+            "No terminal punctuation"
+        );
+    }
+
+    #[tokio::test]
+    async fn heuristic_compressor_matches_compress_content() {
+        let mut journal = make_journal();
+        add_turn(
+            &mut journal,
+            1,
+            "Bramblehoof approaches the fence. The light catches his horns.",
+            vec![],
+            vec![],
+            &storyteller_core::traits::NoopObserver,
+        );
+
+        let compression = HeuristicCompressor
+            .compress(&journal.entries[0])
+            .await
+            .unwrap();
+        assert_eq!(compression.summary, "Bramblehoof approaches the fence.");
+        assert_eq!(compression.skeleton, "Bramblehoof approaches the fence.");
+    }
+
+    fn cached(turn: u32) -> JournalCompression {
+        JournalCompression {
+            summary: format!("Summary of turn {turn}."),
+            skeleton: format!("Skeleton {turn}."),
+        }
+    }
+
+    #[test]
+    fn cached_compression_replaces_heuristic() {
+        let mut journal = make_journal();
+        let observer = storyteller_core::traits::NoopObserver;
+
+        add_turn(&mut journal, 1, "First. Detail.", vec![], vec![], &observer);
+        // Cached while the entry is still Full — content is untouched.
+        assert!(cache_compression(&mut journal, 1, cached(1)));
+        assert_eq!(journal.entries[0].content, "First. Detail.");
+
+        for i in 2..=4 {
+            add_turn(&mut journal, i, "Later. Detail.", vec![], vec![], &observer);
+        }
+        assert_eq!(journal.entries[0].compression, CompressionLevel::Skeleton);
+        assert_eq!(journal.entries[0].content, "Skeleton 1.");
+        // Uncached entries still use the heuristic.
+        assert_eq!(journal.entries[2].content, "Later.");
+    }
+
+    #[test]
+    fn late_cache_rerenders_compressed_entry() {
+        let mut journal = make_journal();
+        let observer = storyteller_core::traits::NoopObserver;
+
+        add_turn(&mut journal, 1, "First. Detail.", vec![], vec![], &observer);
+        add_turn(&mut journal, 2, "Second.", vec![], vec![], &observer);
+        assert_eq!(journal.entries[0].content, "First.");
+
+        assert!(cache_compression(&mut journal, 1, cached(1)));
+        assert_eq!(journal.entries[0].content, "Summary of turn 1.");
+        assert!(!cache_compression(&mut journal, 9, cached(9)));
+    }
+
+    #[test]
+    fn adopt_cached_compressions_carries_over() {
+        let observer = storyteller_core::traits::NoopObserver;
+        let mut live = make_journal();
+        add_turn(&mut live, 1, "First. Detail.", vec![], vec![], &observer);
+
+        // A turn was folded from a copy taken before the cache landed.
+        let mut next = live.clone();
+        add_turn(&mut next, 2, "Second.", vec![], vec![], &observer);
+        cache_compression(&mut live, 1, cached(1));
+
+        adopt_cached_compressions(&mut next, &live);
+        assert_eq!(next.entries[0].cached_compression, Some(cached(1)));
+        assert_eq!(next.entries[0].content, "Summary of turn 1.");
+        assert!(next.entries[1].cached_compression.is_none());
+    }
+
     #[test]
     fn journal_token_estimation() {
         let mut journal = make_journal();
@@ -28,9 +28,11 @@ use chrono::Utc;
 use storyteller_core::traits::phase_observer::{PhaseEvent, PhaseEventDetail, PhaseObserver};
 use storyteller_core::types::character::{CharacterSheet, SceneData};
 use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::knowledge::KnowledgeModel;
 use storyteller_core::types::narrator_context::{NarratorContextInput, SceneJournal};
 use storyteller_core::types::resolver::ResolverOutput;
 use storyteller_core::types::turn_cycle::TurnCycleStage;
+use storyteller_core::types::world_model::ActionPossibility;
 
 use self::preamble::{build_preamble, estimate_preamble_tokens};
 use self::retrieval::retrieve_context;
@@ -44,6 +46,14 @@ pub const DEFAULT_TOTAL_TOKEN_BUDGET: u32 = 2500;
 /// Budget trimming strategy: if the total exceeds the budget, trim Tier 3
 /// (retrieved context) first, then compress Tier 2 (journal) more aggressively.
 /// Tier 1 (preamble) is never trimmed — it's the narrator's identity.
+///
+/// Tier 3 retrieval is filtered through `knowledge`, so nothing a referenced
+/// character has not learned reaches the Narrator as that character's context.
+///
+/// `action_verdict` is arbitration's ruling on the player's action; it goes
+/// into the preamble so impossible attempts are narrated as failing and
+/// conditional ones with their conditions.
+#[allow(clippy::too_many_arguments)]
 pub fn assemble_narrator_context(
     scene: &SceneData,
     characters: &[&CharacterSheet],
@@ -51,13 +61,16 @@ pub fn assemble_narrator_context(
     resolver_output: &ResolverOutput,
     player_input: &str,
     referenced_entities: &[EntityId],
+    knowledge: &KnowledgeModel,
     total_budget: u32,
     observer: &dyn PhaseObserver,
     player_entity_id: Option<EntityId>,
     directive_context: Option<&str>,
+    action_verdict: Option<&ActionPossibility>,
 ) -> NarratorContextInput {
     // Tier 1: Preamble
     let mut preamble = build_preamble(scene, characters, observer, player_entity_id);
+    preamble.action_verdict = action_verdict.cloned();
     if let Some(directive) = directive_context {
         use storyteller_core::types::narrator_context::SceneDirection;
         match preamble.scene_direction.as_mut() {
@@ -84,7 +97,7 @@ pub fn assemble_narrator_context(
     let journal_tokens = journal::estimate_journal_tokens(journal);
 
     // Tier 3: Retrieved context
-    let retrieved = retrieve_context(referenced_entities, characters, scene, observer);
+    let retrieved = retrieve_context(referenced_entities, characters, scene, knowledge, observer);
     let mut retrieved_tokens: u32 = retrieved.iter().map(|r| estimate_tokens(&r.content)).sum();
 
     // Budget trimming
@@ -155,10 +168,12 @@ mod tests {
             &resolver,
             "I approach the fence slowly.",
             &[bramblehoof.entity_id, pyotir.entity_id],
+            &KnowledgeModel::from_characters(&characters),
             DEFAULT_TOTAL_TOKEN_BUDGET,
             &observer,
             None,
             None,
+            None,
         );
 
         // All three tiers present
@@ -203,10 +218,12 @@ mod tests {
             &resolver,
             "test",
             &[bramblehoof.entity_id],
+            &KnowledgeModel::from_characters(&characters),
             100, // absurdly low budget
             &observer,
             None,
             None,
+            None,
         );
 
         let events = observer.take_events();
@@ -15,8 +15,11 @@ use chrono::Utc;
 use storyteller_core::traits::phase_observer::{PhaseEvent, PhaseEventDetail, PhaseObserver};
 use storyteller_core::types::character::{CharacterSheet, SceneData};
 use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::narrative_layer::LayerFrame;
 use storyteller_core::types::narrator_context::{CastDescription, PersistentPreamble};
+use storyteller_core::types::relational::CastRelationship;
 use storyteller_core::types::turn_cycle::TurnCycleStage;
+use storyteller_core::types::world_model::ActionPossibility;
 
 use super::tokens::estimate_tokens;
 
@@ -63,15 +66,25 @@ pub fn build_preamble(
     }
 
     // Cast descriptions — match character sheets to cast entries by name,
-    // pulling voice notes from the sheet.
+    // pulling voice notes and relationships toward the rest of the cast
+    // from the sheet.
     let cast_descriptions: Vec<CastDescription> = scene
         .cast
         .iter()
         .map(|cast_entry| {
-            let voice_note = characters
-                .iter()
-                .find(|c| c.name == cast_entry.name)
-                .map(|c| c.voice.clone())
+            let sheet = characters.iter().find(|c| c.name == cast_entry.name);
+            let voice_note = sheet.map(|c| c.voice.clone()).unwrap_or_default();
+            let relationships = sheet
+                .map(|c| {
+                    c.relationships
+                        .iter()
+                        .filter_map(|r| {
+                            let target =
+                                characters.iter().find(|t| t.entity_id == r.edge.target)?;
+                            Some(describe_relationship(r, &target.name))
+                        })
+                        .collect()
+                })
                 .unwrap_or_default();
 
             CastDescription {
@@ -80,6 +93,7 @@ pub fn build_preamble(
                 role: cast_entry.role.clone(),
                 voice_note,
                 is_player: player_entity_id == Some(cast_entry.entity_id),
+                relationships,
             }
         })
         .collect();
@@ -96,6 +110,8 @@ pub fn build_preamble(
         scene_direction: None,
         character_drives: Vec::new(),
         player_context: None,
+        narrative_layer: None,
+        action_verdict: None,
     };
 
     // Emit observability event.
@@ -125,6 +141,9 @@ pub fn estimate_preamble_tokens(preamble: &PersistentPreamble) -> u32 {
         total += estimate_tokens(&cast.name);
         total += estimate_tokens(&cast.role);
         total += estimate_tokens(&cast.voice_note);
+        for relationship in &cast.relationships {
+            total += estimate_tokens(relationship);
+        }
     }
     for boundary in &preamble.boundaries {
         total += estimate_tokens(boundary);
@@ -142,9 +161,116 @@ pub fn estimate_preamble_tokens(preamble: &PersistentPreamble) -> u32 {
     if let Some(ref context) = preamble.player_context {
         total += estimate_tokens(context);
     }
+    if let Some(ref frame) = preamble.narrative_layer {
+        total += estimate_tokens(&render_layer_frame(frame));
+    }
+    if let Some(verdict) = preamble
+        .action_verdict
+        .as_ref()
+        .and_then(render_action_verdict)
+    {
+        total += estimate_tokens(&verdict);
+    }
     total
 }
 
+/// Tell the Narrator what arbitration decided about the player's action:
+/// an impossible attempt fails on the page, and a conditional one holds
+/// only while its conditions do. Unconditionally permitted actions need no
+/// note.
+fn render_action_verdict(verdict: &ActionPossibility) -> Option<String> {
+    match verdict {
+        ActionPossibility::Impossible { reason } => Some(format!(
+            "The player's attempt cannot succeed in this world: {}. Narrate it failing; \
+             it must not take effect.\n",
+            reason.description
+        )),
+        ActionPossibility::Permitted { conditions } if conditions.is_empty() => None,
+        ActionPossibility::Permitted { conditions } => {
+            let mut text = "The player's action succeeds only on these conditions:\n".to_string();
+            for condition in conditions {
+                text.push_str(&format!(
+                    "- {} ({})\n",
+                    condition.requirement, condition.impact
+                ));
+            }
+            Some(text)
+        }
+        ActionPossibility::Ambiguous {
+            known_constraints,
+            uncertainty,
+        } => {
+            let mut text = "Whether the player's action can work is uncertain; do not let it \
+                            succeed unless the scene meets what it requires:\n"
+                .to_string();
+            if known_constraints.is_empty() {
+                text.push_str(&format!("- {uncertainty}\n"));
+            }
+            for constraint in known_constraints {
+                text.push_str(&format!("- {}\n", constraint.description));
+            }
+            Some(text)
+        }
+    }
+}
+
+/// Describe the narrative layer being rendered, with its voice guidance.
+fn render_layer_frame(frame: &LayerFrame) -> String {
+    let mut line = format!(
+        "You are rendering {}: \"{}\"",
+        frame.kind.describe(),
+        frame.title
+    );
+    if let Some(ref teller) = frame.teller {
+        line.push_str(&format!(", as told by {teller}"));
+    }
+    if frame.depth > 1 {
+        line.push_str(&format!(" (nested {} layers deep)", frame.depth));
+    }
+    line.push_str(".\n");
+    if let Some(ref voice_note) = frame.voice_note {
+        line.push_str(&format!("Voice: {voice_note}\n"));
+    }
+    line
+}
+
+/// Describe a relationship in a line for the cast list.
+///
+/// Substrate values become words the Narrator can play — mean trust and
+/// affection bucketed into three registers each, debt noted only when it
+/// weighs.
+fn describe_relationship(relationship: &CastRelationship, target_name: &str) -> String {
+    let substrate = &relationship.edge.substrate;
+    let trust = (substrate.trust_reliability.central_tendency
+        + substrate.trust_competence.central_tendency
+        + substrate.trust_benevolence.central_tendency)
+        / 3.0;
+    let trust = if trust >= 0.4 {
+        "trusting"
+    } else if trust > -0.2 {
+        "wary"
+    } else {
+        "distrustful"
+    };
+    let affection = substrate.affection.central_tendency;
+    let affection = if affection >= 0.4 {
+        "warm"
+    } else if affection > -0.2 {
+        "reserved"
+    } else {
+        "hostile"
+    };
+
+    let mut line = format!(
+        "{} to {target_name} ({}): {trust}, {affection}",
+        relationship.role, relationship.dynamic
+    );
+    if substrate.debt.central_tendency >= 0.4 {
+        line.push_str(", indebted");
+    }
+    line
+}
+
 /// Render a preamble to a string suitable for the Narrator's system prompt.
 pub fn render_preamble(preamble: &PersistentPreamble) -> String {
     let mut output = String::new();
@@ -165,6 +291,13 @@ pub fn render_preamble(preamble: &PersistentPreamble) -> String {
     output.push_str(&preamble.setting_description);
     output.push_str("\n\n");
 
+    // Narrative Layer (frame stories, memories, dreams)
+    if let Some(ref frame) = preamble.narrative_layer {
+        output.push_str("## Narrative Layer\n");
+        output.push_str(&render_layer_frame(frame));
+        output.push('\n');
+    }
+
     output.push_str("## Cast\n");
     for cast in &preamble.cast_descriptions {
         if cast.is_player {
@@ -175,6 +308,9 @@ pub fn render_preamble(preamble: &PersistentPreamble) -> String {
         if !cast.voice_note.is_empty() {
             output.push_str(&format!("Voice: {}\n", cast.voice_note));
         }
+        for relationship in &cast.relationships {
+            output.push_str(&format!("- {relationship}\n"));
+        }
         output.push('\n');
     }
 
@@ -205,6 +341,16 @@ pub fn render_preamble(preamble: &PersistentPreamble) -> String {
         output.push('\n');
     }
 
+    // The Player's Action (from arbitration)
+    if let Some(verdict) = preamble
+        .action_verdict
+        .as_ref()
+        .and_then(render_action_verdict)
+    {
+        output.push_str("\n## The Player's Action\n");
+        output.push_str(&verdict);
+    }
+
     if !preamble.boundaries.is_empty() {
         output.push_str("## Boundaries\n");
         for boundary in &preamble.boundaries {
@@ -220,7 +366,57 @@ pub fn render_preamble(preamble: &PersistentPreamble) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use storyteller_core::traits::phase_observer::CollectingObserver;
+    use storyteller_core::traits::phase_observer::{CollectingObserver, NoopObserver};
+
+    #[test]
+    fn cast_descriptions_carry_relationships() {
+        use storyteller_core::types::relational::{
+            DirectedEdge, InformationState, RelationalSubstrate, TopologicalRole,
+        };
+        use storyteller_core::types::tensor::AxisValue;
+
+        let scene = crate::workshop::the_flute_kept::scene();
+        let mut bramblehoof = crate::workshop::the_flute_kept::bramblehoof();
+        let pyotir = crate::workshop::the_flute_kept::pyotir();
+        let axis = |v: f32| AxisValue {
+            central_tendency: v,
+            variance: 0.1,
+            range_low: v - 0.1,
+            range_high: v + 0.1,
+        };
+        bramblehoof.relationships.push(CastRelationship {
+            edge: DirectedEdge {
+                source: bramblehoof.entity_id,
+                target: pyotir.entity_id,
+                substrate: RelationalSubstrate {
+                    trust_reliability: axis(0.6),
+                    trust_competence: axis(0.5),
+                    trust_benevolence: axis(0.7),
+                    affection: axis(0.8),
+                    debt: axis(0.5),
+                },
+                information_state: InformationState {
+                    known_facts: vec![],
+                    beliefs: vec![],
+                    blind_spots: vec![],
+                },
+            },
+            target_topology: TopologicalRole::Periphery,
+            dynamic: "Mentor and Student".to_string(),
+            role: "mentor".to_string(),
+            target_role: "student".to_string(),
+        });
+        let characters: Vec<&CharacterSheet> = vec![&bramblehoof, &pyotir];
+
+        let preamble = build_preamble(&scene, &characters, &NoopObserver, None);
+        assert_eq!(
+            preamble.cast_descriptions[0].relationships,
+            vec!["mentor to Pyotir (Mentor and Student): trusting, warm, indebted"]
+        );
+        assert!(preamble.cast_descriptions[1].relationships.is_empty());
+        assert!(render_preamble(&preamble)
+            .contains("- mentor to Pyotir (Mentor and Student): trusting, warm, indebted\n"));
+    }
 
     #[test]
     fn preamble_from_workshop_scene() {
@@ -409,6 +605,8 @@ mod tests {
                 behavioral_stance: "Polite deflection masking quiet urgency.".to_string(),
             }],
             player_context: Some("You sense Arthur is here for more than tea.".to_string()),
+            narrative_layer: None,
+            action_verdict: None,
         };
 
         let rendered = render_preamble(&preamble);
@@ -445,6 +643,8 @@ mod tests {
             scene_direction: None,
             character_drives: Vec::new(),
             player_context: None,
+            narrative_layer: None,
+            action_verdict: None,
         };
 
         let rendered = render_preamble(&preamble);
@@ -460,5 +660,87 @@ mod tests {
             !rendered.contains("## Player Context"),
             "should not have player context"
         );
+        assert!(
+            !rendered.contains("## Narrative Layer"),
+            "should not have a narrative layer"
+        );
+    }
+
+    #[test]
+    fn preamble_renders_narrative_layer() {
+        use storyteller_core::types::narrative_layer::LayerKind;
+
+        let mut preamble = PersistentPreamble {
+            narrator_identity: "Literary fiction narrator".to_string(),
+            anti_patterns: Vec::new(),
+            setting_description: "A quiet rectory".to_string(),
+            cast_descriptions: Vec::new(),
+            boundaries: Vec::new(),
+            scene_direction: None,
+            character_drives: Vec::new(),
+            player_context: None,
+            narrative_layer: None,
+            action_verdict: None,
+        };
+        let baseline = estimate_preamble_tokens(&preamble);
+        preamble.narrative_layer = Some(LayerFrame {
+            kind: LayerKind::Memory,
+            title: "The Winter Road".to_string(),
+            depth: 1,
+            teller: Some("Margaret".to_string()),
+            voice_note: Some("Past tense, softened at the edges.".to_string()),
+        });
+
+        let rendered = render_preamble(&preamble);
+        assert!(rendered.contains("## Narrative Layer"));
+        assert!(rendered
+            .contains("You are rendering a memory: \"The Winter Road\", as told by Margaret."));
+        assert!(rendered.contains("Voice: Past tense, softened at the edges."));
+        assert!(estimate_preamble_tokens(&preamble) > baseline);
+    }
+
+    #[test]
+    fn preamble_renders_action_verdict() {
+        use storyteller_core::types::world_model::{ConstraintViolation, EnvironmentalConstraint};
+
+        let mut preamble = PersistentPreamble {
+            narrator_identity: "Literary fiction narrator".to_string(),
+            anti_patterns: Vec::new(),
+            setting_description: "A quiet rectory".to_string(),
+            cast_descriptions: Vec::new(),
+            boundaries: Vec::new(),
+            scene_direction: None,
+            character_drives: Vec::new(),
+            player_context: None,
+            narrative_layer: None,
+            action_verdict: Some(ActionPossibility::Permitted {
+                conditions: Vec::new(),
+            }),
+        };
+        let baseline = estimate_preamble_tokens(&preamble);
+        assert!(!render_preamble(&preamble).contains("## The Player's Action"));
+
+        preamble.action_verdict = Some(ActionPossibility::Impossible {
+            reason: ConstraintViolation {
+                constraint_name: "forbidden:overt_magic".to_string(),
+                description: "Overt magic does not exist here".to_string(),
+            },
+        });
+        let rendered = render_preamble(&preamble);
+        assert!(rendered.contains("## The Player's Action"));
+        assert!(rendered.contains("cannot succeed in this world: Overt magic does not exist here"));
+        assert!(estimate_preamble_tokens(&preamble) > baseline);
+
+        preamble.action_verdict = Some(ActionPossibility::Ambiguous {
+            known_constraints: vec![EnvironmentalConstraint {
+                name: "conditional:magic".to_string(),
+                description: "requires a focus".to_string(),
+                affected_action_types: vec!["magic".to_string()],
+            }],
+            uncertainty: "Capability 'magic' requires conditions".to_string(),
+        });
+        let rendered = render_preamble(&preamble);
+        assert!(rendered.contains("uncertain"));
+        assert!(rendered.contains("- requires a focus"));
     }
 }
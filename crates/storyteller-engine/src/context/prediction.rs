@@ -17,6 +17,7 @@
 //!    as a markdown section for the Narrator's context window, following
 //!    the same pattern as `preamble::render_preamble()`.
 
+use storyteller_core::grammars::GrammarRegistry;
 use storyteller_core::traits::emotional_grammar::EmotionalGrammar;
 use storyteller_core::types::character::{CharacterSheet, SceneData};
 use storyteller_core::types::prediction::{
@@ -25,7 +26,9 @@ use storyteller_core::types::prediction::{
     SpeechRegister, ThoughtPrediction,
 };
 use storyteller_core::types::tensor::AwarenessLevel;
-use storyteller_ml::feature_schema::{EventFeatureInput, PredictionInput, SceneFeatureInput};
+use storyteller_ml::feature_schema::{
+    relational_inputs, EventFeatureInput, PredictionInput, SceneFeatureInput,
+};
 
 use crate::inference::event_decomposition::EventDecomposition;
 use crate::inference::frame::CharacterPredictor;
@@ -37,7 +40,8 @@ use super::tokens::estimate_tokens;
 ///
 /// Steps:
 /// 1. Build scene features from scene data
-/// 2. Build prediction input for each character using provided event features
+/// 2. Build prediction input for each character using provided event features,
+///    with the emotional grammar its sheet names
 /// 3. Run batch inference via the ONNX model
 /// 4. Enrich each raw prediction with narrative descriptions
 ///
@@ -48,7 +52,7 @@ pub fn predict_character_behaviors(
     characters: &[&CharacterSheet],
     scene: &SceneData,
     _player_input: &str,
-    grammar: &dyn EmotionalGrammar,
+    grammars: &GrammarRegistry,
     event_features: EventFeatureInput,
     history: &std::collections::HashMap<
         storyteller_core::types::entity::EntityId,
@@ -57,6 +61,10 @@ pub fn predict_character_behaviors(
 ) -> Vec<CharacterPrediction> {
     let event = event_features;
     let scene_features = build_scene_features(scene, characters.len());
+    let relational: Vec<_> = characters
+        .iter()
+        .map(|sheet| relational_inputs(sheet))
+        .collect();
 
     let batch: Vec<(
         PredictionInput<'_>,
@@ -65,17 +73,19 @@ pub fn predict_character_behaviors(
         f32,
     )> = characters
         .iter()
-        .map(|sheet| {
+        .zip(relational.iter())
+        .map(|(sheet, (edges, target_roles))| {
             let input = PredictionInput {
                 character: sheet,
-                edges: &[],
-                target_roles: &[],
+                edges,
+                target_roles,
                 scene: scene_features,
                 event,
                 history: history
                     .get(&sheet.entity_id)
                     .map(|v| v.as_slice())
                     .unwrap_or(&[]),
+                grammar: Some(grammars.grammar_for(&sheet.grammar_id)),
             };
             let axis_count = sheet
                 .tensor
@@ -93,7 +103,12 @@ pub fn predict_character_behaviors(
         .into_iter()
         .zip(characters.iter())
         .filter_map(|(result, sheet)| match result {
-            Ok(raw) => Some(enrich_prediction(&raw, sheet, scene, grammar)),
+            Ok(raw) => Some(enrich_prediction(
+                &raw,
+                sheet,
+                scene,
+                grammars.grammar_for(&sheet.grammar_id),
+            )),
             Err(e) => {
                 tracing::warn!(character = %sheet.name, error = %e, "prediction failed");
                 None
@@ -985,6 +1000,7 @@ mod with_model {
             knows: vec![],
             does_not_know: vec![],
             capabilities: CapabilityProfile::default(),
+            relationships: Vec::new(),
         }
     }
 
@@ -1005,6 +1021,7 @@ mod with_model {
                 target_count: 1,
             },
             history: &[],
+            grammar: None,
         }
     }
 
@@ -1111,7 +1128,7 @@ mod with_model {
         use storyteller_ml::prediction_history::PredictionHistory;
 
         let predictor = CharacterPredictor::load(&model_path()).expect("model should load");
-        let grammar = PlutchikWestern::new();
+        let grammars = GrammarRegistry::builtin();
         let scene = crate::workshop::the_flute_kept::scene();
         let sheet = test_character_sheet("Bramblehoof");
         let characters: Vec<&CharacterSheet> = vec![&sheet];
@@ -1129,7 +1146,7 @@ mod with_model {
             &characters,
             &scene,
             "Hello there",
-            &grammar,
+            &grammars,
             default_features,
             &HashMap::new(),
         );
@@ -1151,7 +1168,7 @@ mod with_model {
             &characters,
             &scene,
             "Hello again",
-            &grammar,
+            &grammars,
             default_features2,
             history.as_map(),
         );
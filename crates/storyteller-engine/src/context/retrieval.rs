@@ -17,8 +17,10 @@
 use chrono::Utc;
 
 use storyteller_core::traits::phase_observer::{PhaseEvent, PhaseEventDetail, PhaseObserver};
+use storyteller_core::traits::storykeeper::BoundaryCheck;
 use storyteller_core::types::character::{CharacterSheet, SceneData};
 use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::knowledge::KnowledgeModel;
 use storyteller_core::types::narrator_context::RetrievedContext;
 use storyteller_core::types::turn_cycle::TurnCycleStage;
 
@@ -28,14 +30,15 @@ use super::tokens::estimate_tokens;
 ///
 /// For each referenced entity, this pulls relevant backstory, emotional
 /// context, and relational information from character sheets. Information
-/// boundaries are enforced — only information the characters would know
-/// or that has been revealed is included.
+/// boundaries are enforced through `knowledge` — only information the
+/// characters would know or that has been revealed is included.
 ///
 /// Emits `ContextRetrieved` and `InformationBoundaryApplied` events.
 pub fn retrieve_context(
     referenced_entities: &[EntityId],
     characters: &[&CharacterSheet],
     scene: &SceneData,
+    knowledge: &KnowledgeModel,
     observer: &dyn PhaseObserver,
 ) -> Vec<RetrievedContext> {
     let mut results = Vec::new();
@@ -46,9 +49,9 @@ pub fn retrieve_context(
             let mut items = retrieve_for_character(sheet, scene);
 
             // Information boundary: filter out items that reference
-            // things the character explicitly does_not_know
+            // things the character has not learned
             let available = items.len();
-            items.retain(|item| !is_boundary_violation(item, sheet));
+            items.retain(|item| !is_boundary_violation(item, entity_id, knowledge));
             let permitted = items.len();
 
             if available != permitted {
@@ -184,51 +187,26 @@ fn retrieve_for_character(sheet: &CharacterSheet, scene: &SceneData) -> Vec<Retr
 
 /// Check if a retrieved context item violates information boundaries.
 ///
-/// An item violates boundaries if its content matches something in the
-/// character's `does_not_know` list. This is a simple substring check
-/// for the prototype; production would use semantic matching.
-fn is_boundary_violation(item: &RetrievedContext, sheet: &CharacterSheet) -> bool {
+/// An item violates boundaries if the knowledge model does not fully
+/// permit its content to the character — a guarded fact the character
+/// has not learned, even in part.
+fn is_boundary_violation(
+    item: &RetrievedContext,
+    entity_id: EntityId,
+    knowledge: &KnowledgeModel,
+) -> bool {
     // Items flagged as revealed are always permitted
     if item.revealed {
         return false;
     }
-
-    // Check if the content matches anything in does_not_know
-    let content_lower = item.content.to_lowercase();
-    for boundary in &sheet.does_not_know {
-        // Extract keywords from the boundary description
-        // (the parenthetical awareness notes are stripped)
-        let boundary_text = boundary
-            .find('(')
-            .map(|i| &boundary[..i])
-            .unwrap_or(boundary)
-            .trim()
-            .to_lowercase();
-
-        // Check for significant keyword overlap
-        let keywords: Vec<&str> = boundary_text
-            .split_whitespace()
-            .filter(|w| w.len() > 3) // skip short words
-            .collect();
-
-        let matches = keywords
-            .iter()
-            .filter(|kw| content_lower.contains(**kw))
-            .count();
-
-        // If more than half the significant keywords match, it's a boundary violation
-        if keywords.len() >= 2 && matches >= keywords.len() / 2 {
-            return true;
-        }
-    }
-
-    false
+    knowledge.check(&entity_id, &item.content) != BoundaryCheck::Permitted
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use storyteller_core::traits::phase_observer::CollectingObserver;
+    use storyteller_core::types::knowledge::KnowledgeSource;
 
     #[test]
     fn retrieve_for_known_character() {
@@ -237,8 +215,15 @@ mod tests {
         let pyotir = crate::workshop::the_flute_kept::pyotir();
         let characters: Vec<&CharacterSheet> = vec![&bramblehoof, &pyotir];
 
+        let knowledge = KnowledgeModel::from_characters(&characters);
         let observer = CollectingObserver::new();
-        let context = retrieve_context(&[bramblehoof.entity_id], &characters, &scene, &observer);
+        let context = retrieve_context(
+            &[bramblehoof.entity_id],
+            &characters,
+            &scene,
+            &knowledge,
+            &observer,
+        );
 
         // Should have backstory, knows items, performance notes, self-edge pattern, stakes
         assert!(
@@ -267,11 +252,13 @@ mod tests {
         let pyotir = crate::workshop::the_flute_kept::pyotir();
         let characters: Vec<&CharacterSheet> = vec![&bramblehoof, &pyotir];
 
+        let knowledge = KnowledgeModel::from_characters(&characters);
         let observer = storyteller_core::traits::NoopObserver;
         let context = retrieve_context(
             &[bramblehoof.entity_id, pyotir.entity_id],
             &characters,
             &scene,
+            &knowledge,
             &observer,
         );
 
@@ -288,7 +275,8 @@ mod tests {
 
         let observer = storyteller_core::traits::NoopObserver;
         let unknown_id = EntityId::new();
-        let context = retrieve_context(&[unknown_id], &characters, &scene, &observer);
+        let knowledge = KnowledgeModel::from_characters(&characters);
+        let context = retrieve_context(&[unknown_id], &characters, &scene, &knowledge, &observer);
 
         // No matching character sheet — empty result
         assert!(context.is_empty());
@@ -312,14 +300,31 @@ mod tests {
             source_entities: vec![],
         };
 
+        let mut knowledge = KnowledgeModel::from_characters(&[&bramblehoof, &pyotir]);
         assert!(
-            is_boundary_violation(&ley_line_item, &pyotir),
+            is_boundary_violation(&ley_line_item, pyotir.entity_id, &knowledge),
             "Pyotir should not see ley line information"
         );
         assert!(
-            !is_boundary_violation(&ley_line_item, &bramblehoof),
+            !is_boundary_violation(&ley_line_item, bramblehoof.entity_id, &knowledge),
             "Bramblehoof CAN see ley line information"
         );
+
+        // Once Pyotir is told, the boundary lifts.
+        let guard = pyotir
+            .does_not_know
+            .iter()
+            .find(|s| s.contains("ley line"))
+            .unwrap();
+        knowledge.learn(
+            pyotir.entity_id,
+            guard.clone(),
+            KnowledgeSource::Event(storyteller_core::types::event::EventId::new()),
+        );
+        assert!(
+            !is_boundary_violation(&ley_line_item, pyotir.entity_id, &knowledge),
+            "Pyotir learned about the ley line"
+        );
     }
 
     #[test]
@@ -334,8 +339,9 @@ mod tests {
             source_entities: vec![],
         };
 
+        let knowledge = KnowledgeModel::from_characters(&[&pyotir]);
         assert!(
-            !is_boundary_violation(&revealed_item, &pyotir),
+            !is_boundary_violation(&revealed_item, pyotir.entity_id, &knowledge),
             "Revealed items should bypass information boundaries"
         );
     }
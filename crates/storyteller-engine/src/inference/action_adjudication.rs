@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Small-LLM adjudication of ambiguous action verdicts.
+//!
+//! See: `docs/plans/2026-03-09-event-classification-and-action-arbitration-design.md`
+//!
+//! Deterministic arbitration returns `Ambiguous` when a player attempts a
+//! conditional capability ("magic requires a focus") — the lexicon can tell
+//! the capability was invoked, but not whether its conditions hold. The
+//! adjudicator hands the input, the unverified conditions, and the scene
+//! state to the structured LLM and turns its answer into a final
+//! `Permitted` (with conditions) or `Impossible` verdict.
+
+use serde::Deserialize;
+
+use storyteller_core::errors::StorytellerResult;
+use storyteller_core::traits::structured_llm::{StructuredLlmProvider, StructuredRequest};
+use storyteller_core::types::world_model::{
+    ActionCondition, ActionPossibility, ConstraintViolation, EnvironmentalConstraint,
+};
+
+/// The adjudication as returned by the LLM.
+#[derive(Debug, Clone, Deserialize)]
+struct RawAdjudication {
+    verdict: String,
+    #[serde(default)]
+    conditions: Vec<ActionCondition>,
+    #[serde(default)]
+    reason: String,
+    confidence: f32,
+}
+
+/// A final verdict for an action the rules engine could not decide.
+#[derive(Debug, Clone)]
+pub struct Adjudication {
+    /// `Permitted` or `Impossible` — never `Ambiguous`.
+    pub verdict: ActionPossibility,
+    /// The LLM's confidence in the verdict, clamped to [0.0, 1.0].
+    pub confidence: f32,
+    /// The LLM's explanation, for observability.
+    pub rationale: String,
+}
+
+/// Returns the JSON schema for adjudication output.
+pub fn action_adjudication_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "required": ["verdict", "conditions", "reason", "confidence"],
+        "properties": {
+            "verdict": { "type": "string", "enum": ["permitted", "impossible"] },
+            "conditions": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["requirement", "impact"],
+                    "properties": {
+                        "requirement": { "type": "string" },
+                        "impact": { "type": "string" }
+                    }
+                }
+            },
+            "reason": { "type": "string" },
+            "confidence": { "type": "number", "minimum": 0.0, "maximum": 1.0 }
+        }
+    })
+}
+
+/// Returns the system prompt for adjudication.
+pub fn action_adjudication_system_prompt() -> String {
+    "You adjudicate player actions for an interactive fiction rules engine. The player has \
+attempted something the world allows only under certain conditions. You receive the player's \
+input, the conditions the world requires, and the current scene state.
+
+Decide whether the conditions are met:
+- verdict \"permitted\" if the scene state or the input shows the conditions hold, or they \
+plausibly can be met as part of the action
+- verdict \"impossible\" if a required condition is clearly absent
+- conditions: for a permitted action, what must still happen for it to succeed and how that \
+shapes the outcome; empty for an impossible action
+- reason: one sentence explaining the verdict, suitable for a narrator
+- confidence: 0.0 to 1.0
+
+Judge only against the stated conditions and scene state. Do not invent new rules."
+        .to_string()
+}
+
+/// Adjudicate an ambiguous action verdict using a small LLM.
+///
+/// `known_constraints` and `uncertainty` come from the `Ambiguous` verdict;
+/// `scene_state` is a plain-text description of the scene.
+///
+/// # Errors
+///
+/// Returns `StorytellerError::Inference` if the provider call fails or the
+/// response cannot be parsed into a permitted or impossible verdict.
+pub async fn adjudicate_action(
+    provider: &dyn StructuredLlmProvider,
+    player_input: &str,
+    known_constraints: &[EnvironmentalConstraint],
+    uncertainty: &str,
+    scene_state: &str,
+) -> StorytellerResult<Adjudication> {
+    let conditions = known_constraints
+        .iter()
+        .map(|c| format!("- {}: {}", c.name, c.description))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let request = StructuredRequest {
+        system: action_adjudication_system_prompt(),
+        input: format!(
+            "Player input: {player_input}\n\nRequired conditions:\n{conditions}\n\n\
+             Uncertainty: {uncertainty}\n\nScene state:\n{scene_state}"
+        ),
+        output_schema: action_adjudication_schema(),
+        temperature: 0.1,
+    };
+
+    let json = provider.extract(request).await?;
+    let raw: RawAdjudication = serde_json::from_value(json).map_err(|e| {
+        storyteller_core::StorytellerError::Inference(format!(
+            "failed to parse action adjudication: {e}"
+        ))
+    })?;
+
+    let verdict = match raw.verdict.to_lowercase().as_str() {
+        "permitted" => ActionPossibility::Permitted {
+            conditions: raw.conditions,
+        },
+        "impossible" => ActionPossibility::Impossible {
+            reason: ConstraintViolation {
+                constraint_name: known_constraints
+                    .first()
+                    .map(|c| c.name.clone())
+                    .unwrap_or_else(|| "adjudicated".to_string()),
+                description: raw.reason.clone(),
+            },
+        },
+        other => {
+            return Err(storyteller_core::StorytellerError::Inference(format!(
+                "unknown adjudication verdict: {other}"
+            )))
+        }
+    };
+
+    Ok(Adjudication {
+        verdict,
+        confidence: raw.confidence.clamp(0.0, 1.0),
+        rationale: raw.reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct CannedProvider(serde_json::Value);
+
+    #[async_trait::async_trait]
+    impl StructuredLlmProvider for CannedProvider {
+        async fn extract(
+            &self,
+            _request: StructuredRequest,
+        ) -> StorytellerResult<serde_json::Value> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn focus_required() -> Vec<EnvironmentalConstraint> {
+        vec![EnvironmentalConstraint {
+            name: "conditional:magic".to_string(),
+            description: "a focus".to_string(),
+            affected_action_types: vec!["magic".to_string()],
+        }]
+    }
+
+    #[tokio::test]
+    async fn permitted_verdict_carries_conditions() {
+        let provider = CannedProvider(serde_json::json!({
+            "verdict": "permitted",
+            "conditions": [{"requirement": "grip the rowan staff", "impact": "strain on failure"}],
+            "reason": "The rowan staff by the hearth serves as a focus.",
+            "confidence": 0.8
+        }));
+        let adjudication = adjudicate_action(
+            &provider,
+            "I take up the staff and work a charm",
+            &focus_required(),
+            "magic requires a focus",
+            "A rowan staff leans by the hearth.",
+        )
+        .await
+        .unwrap();
+
+        match adjudication.verdict {
+            ActionPossibility::Permitted { conditions } => {
+                assert_eq!(conditions.len(), 1);
+                assert_eq!(conditions[0].requirement, "grip the rowan staff");
+            }
+            other => panic!("expected Permitted, got {other:?}"),
+        }
+        assert!((adjudication.confidence - 0.8).abs() < f32::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn impossible_verdict_names_the_unmet_condition() {
+        let provider = CannedProvider(serde_json::json!({
+            "verdict": "Impossible",
+            "conditions": [],
+            "reason": "There is no focus to hand.",
+            "confidence": 1.4
+        }));
+        let adjudication = adjudicate_action(
+            &provider,
+            "I work a charm",
+            &focus_required(),
+            "magic requires a focus",
+            "An empty room.",
+        )
+        .await
+        .unwrap();
+
+        match adjudication.verdict {
+            ActionPossibility::Impossible { reason } => {
+                assert_eq!(reason.constraint_name, "conditional:magic");
+                assert_eq!(reason.description, "There is no focus to hand.");
+            }
+            other => panic!("expected Impossible, got {other:?}"),
+        }
+        assert!((adjudication.confidence - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn unknown_verdict_errors() {
+        let provider = CannedProvider(serde_json::json!({
+            "verdict": "maybe",
+            "conditions": [],
+            "reason": "",
+            "confidence": 0.5
+        }));
+        let result = adjudicate_action(&provider, "", &focus_required(), "", "").await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn schema_restricts_verdicts() {
+        let schema = action_adjudication_schema();
+        assert_eq!(
+            schema["properties"]["verdict"]["enum"],
+            serde_json::json!(["permitted", "impossible"])
+        );
+        assert!(action_adjudication_system_prompt().contains("Do not invent new rules"));
+    }
+}
@@ -6,23 +6,692 @@
 //!
 //! See: `docs/technical/technical-stack.md`
 //!
-//! Implements `LlmProvider` for cloud APIs (Claude, GPT, etc.) via reqwest.
-//! Deferred — the prototype uses `ExternalServerProvider` (Ollama) instead.
+//! Implements `LlmProvider` for hosted chat APIs via reqwest. Two wire
+//! formats are supported, selected by [`CloudApiStyle`]:
+//!
+//! - **Messages** (`POST /v1/messages`) — Anthropic-style: top-level `system`,
+//!   `x-api-key` auth, SSE `content_block_delta` events when streaming.
+//! - **Chat Completions** (`POST /v1/chat/completions`) — OpenAI-style:
+//!   system prompt as the first message, bearer auth, SSE `choices[].delta`
+//!   chunks terminated by `data: [DONE]`.
+//!
+//! HTTP 429 maps to [`StorytellerError::LlmRateLimited`] (with `retry-after`
+//! when present); 502/503/529 and `overloaded_error` bodies map to
+//! [`StorytellerError::LlmOverloaded`]. Everything else is
+//! [`StorytellerError::Llm`].
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use storyteller_core::errors::StorytellerError;
+use storyteller_core::traits::llm::{
+    narrator_token_channel, CompletionRequest, CompletionResponse, LlmProvider, MessageRole,
+    NarratorTokenStream,
+};
+use tokio_stream::StreamExt as _;
+use tracing::instrument;
+
+/// Wire format of a hosted chat API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloudApiStyle {
+    /// Anthropic-style Messages API.
+    Messages,
+    /// OpenAI-style Chat Completions API.
+    ChatCompletions,
+}
+
+impl CloudApiStyle {
+    fn path(self) -> &'static str {
+        match self {
+            Self::Messages => "/v1/messages",
+            Self::ChatCompletions => "/v1/chat/completions",
+        }
+    }
+}
+
+/// Default `anthropic-version` header for the Messages API.
+pub const DEFAULT_MESSAGES_API_VERSION: &str = "2023-06-01";
+
+/// Configuration for a hosted LLM API.
+#[derive(Clone)]
+pub struct CloudLlmConfig {
+    /// Wire format to speak.
+    pub style: CloudApiStyle,
+    /// Base URL without the API path (e.g., "https://api.anthropic.com").
+    pub base_url: String,
+    /// API key sent with every request.
+    pub api_key: String,
+    /// Model name (e.g., "claude-sonnet-4-5", "gpt-4o").
+    pub model: String,
+    /// Request timeout.
+    pub timeout: Duration,
+    /// `anthropic-version` header value (Messages style only).
+    pub api_version: String,
+}
 
-use storyteller_core::traits::llm::{CompletionRequest, CompletionResponse, LlmProvider};
+impl CloudLlmConfig {
+    /// Messages-style API at the Anthropic endpoint.
+    pub fn anthropic(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            style: CloudApiStyle::Messages,
+            base_url: "https://api.anthropic.com".to_string(),
+            api_key: api_key.into(),
+            model: model.into(),
+            timeout: Duration::from_secs(120),
+            api_version: DEFAULT_MESSAGES_API_VERSION.to_string(),
+        }
+    }
+
+    /// Chat-Completions-style API at the OpenAI endpoint.
+    pub fn openai(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            style: CloudApiStyle::ChatCompletions,
+            base_url: "https://api.openai.com".to_string(),
+            api_key: api_key.into(),
+            model: model.into(),
+            timeout: Duration::from_secs(120),
+            api_version: DEFAULT_MESSAGES_API_VERSION.to_string(),
+        }
+    }
+
+    /// Point at a different host speaking the same wire format (proxies,
+    /// compatible providers, test servers).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl std::fmt::Debug for CloudLlmConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CloudLlmConfig")
+            .field("style", &self.style)
+            .field("base_url", &self.base_url)
+            .field("model", &self.model)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
 
 /// Cloud-based LLM provider using HTTP APIs.
 #[derive(Debug)]
 pub struct CloudLlmProvider {
-    _client: reqwest::Client,
+    config: CloudLlmConfig,
+    client: reqwest::Client,
+}
+
+impl CloudLlmProvider {
+    /// Create a new provider with the given configuration.
+    pub fn new(config: CloudLlmConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("failed to build HTTP client");
+        Self { config, client }
+    }
+
+    /// Request body for the configured wire format.
+    fn request_body(&self, request: &CompletionRequest, stream: bool) -> Value {
+        match self.config.style {
+            CloudApiStyle::Messages => {
+                // Messages API takes system text top-level; fold any
+                // system-role turns into it.
+                let mut system = request.system_prompt.clone();
+                let mut messages = Vec::with_capacity(request.messages.len());
+                for msg in &request.messages {
+                    match msg.role {
+                        MessageRole::System => {
+                            if !system.is_empty() {
+                                system.push_str("\n\n");
+                            }
+                            system.push_str(&msg.content);
+                        }
+                        MessageRole::User => {
+                            messages.push(json!({"role": "user", "content": msg.content}))
+                        }
+                        MessageRole::Assistant => {
+                            messages.push(json!({"role": "assistant", "content": msg.content}))
+                        }
+                    }
+                }
+                json!({
+                    "model": self.config.model,
+                    "system": system,
+                    "messages": messages,
+                    "max_tokens": request.max_tokens,
+                    "temperature": request.temperature,
+                    "stream": stream,
+                })
+            }
+            CloudApiStyle::ChatCompletions => {
+                let mut messages = Vec::with_capacity(request.messages.len() + 1);
+                messages.push(json!({"role": "system", "content": request.system_prompt}));
+                for msg in &request.messages {
+                    let role = match msg.role {
+                        MessageRole::System => "system",
+                        MessageRole::User => "user",
+                        MessageRole::Assistant => "assistant",
+                    };
+                    messages.push(json!({"role": role, "content": msg.content}));
+                }
+                json!({
+                    "model": self.config.model,
+                    "messages": messages,
+                    "max_tokens": request.max_tokens,
+                    "temperature": request.temperature,
+                    "stream": stream,
+                })
+            }
+        }
+    }
+
+    async fn send(
+        &self,
+        request: &CompletionRequest,
+        stream: bool,
+    ) -> storyteller_core::StorytellerResult<reqwest::Response> {
+        let url = format!("{}{}", self.config.base_url, self.config.style.path());
+        let builder = self
+            .client
+            .post(&url)
+            .json(&self.request_body(request, stream));
+        let builder = match self.config.style {
+            CloudApiStyle::Messages => builder
+                .header("x-api-key", &self.config.api_key)
+                .header("anthropic-version", &self.config.api_version),
+            CloudApiStyle::ChatCompletions => builder.bearer_auth(&self.config.api_key),
+        };
+
+        tracing::debug!(stream, "sending request to {url}");
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| StorytellerError::Llm(format!("cloud LLM request failed: {e}")))?;
+
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "failed to read body".to_string());
+        Err(classify_error(status, retry_after, &body))
+    }
+}
+
+/// Map a non-success response into a `StorytellerError`.
+fn classify_error(status: u16, retry_after: Option<Duration>, body: &str) -> StorytellerError {
+    let parsed: Option<Value> = serde_json::from_str(body).ok();
+    let error_type = parsed
+        .as_ref()
+        .and_then(|v| v.pointer("/error/type"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let message = parsed
+        .as_ref()
+        .and_then(|v| v.pointer("/error/message"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| body.to_string());
+
+    match (status, error_type) {
+        (429, _) | (_, "rate_limit_error") => StorytellerError::LlmRateLimited {
+            message: format!("{status}: {message}"),
+            retry_after,
+        },
+        (502 | 503 | 529, _) | (_, "overloaded_error") => {
+            StorytellerError::LlmOverloaded(format!("{status}: {message}"))
+        }
+        _ => StorytellerError::Llm(format!("cloud LLM returned {status}: {message}")),
+    }
+}
+
+/// What one SSE `data:` payload contributes to the token stream.
+#[derive(Debug, PartialEq, Eq)]
+enum StreamItem {
+    Token(String),
+    Done,
+    Error(String),
+    Skip,
+}
+
+fn parse_stream_data(style: CloudApiStyle, data: &str) -> StreamItem {
+    if style == CloudApiStyle::ChatCompletions && data == "[DONE]" {
+        return StreamItem::Done;
+    }
+    let Ok(value) = serde_json::from_str::<Value>(data) else {
+        return StreamItem::Skip;
+    };
+    if let Some(error) = value.get("error") {
+        let message = error
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown stream error");
+        return StreamItem::Error(message.to_string());
+    }
+    match style {
+        CloudApiStyle::Messages => match value.get("type").and_then(Value::as_str) {
+            Some("content_block_delta") => value
+                .pointer("/delta/text")
+                .and_then(Value::as_str)
+                .map(|t| StreamItem::Token(t.to_string()))
+                .unwrap_or(StreamItem::Skip),
+            Some("message_stop") => StreamItem::Done,
+            _ => StreamItem::Skip,
+        },
+        CloudApiStyle::ChatCompletions => value
+            .pointer("/choices/0/delta/content")
+            .and_then(Value::as_str)
+            .filter(|t| !t.is_empty())
+            .map(|t| StreamItem::Token(t.to_string()))
+            .unwrap_or(StreamItem::Skip),
+    }
+}
+
+/// Pop complete lines off `buffer`, returning the payloads of `data:` lines.
+///
+/// Works on bytes so multi-byte characters split across chunks survive.
+fn drain_sse_data(buffer: &mut Vec<u8>) -> Vec<String> {
+    let mut data = Vec::new();
+    while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+        let line: Vec<u8> = buffer.drain(..=pos).collect();
+        let line = String::from_utf8_lossy(&line);
+        if let Some(payload) = line.trim_end().strip_prefix("data:") {
+            data.push(payload.trim_start().to_string());
+        }
+    }
+    data
 }
 
 #[async_trait::async_trait]
 impl LlmProvider for CloudLlmProvider {
+    #[instrument(skip(self, request), fields(model = %self.config.model))]
     async fn complete(
         &self,
-        _request: CompletionRequest,
+        request: CompletionRequest,
     ) -> storyteller_core::StorytellerResult<CompletionResponse> {
-        todo!("Cloud LLM provider not yet implemented")
+        let response = self.send(&request, false).await?;
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| StorytellerError::Llm(format!("failed to parse cloud response: {e}")))?;
+
+        let (content, tokens_used) = match self.config.style {
+            CloudApiStyle::Messages => {
+                let content = body
+                    .get("content")
+                    .and_then(Value::as_array)
+                    .map(|blocks| {
+                        blocks
+                            .iter()
+                            .filter(|b| b.get("type").and_then(Value::as_str) == Some("text"))
+                            .filter_map(|b| b.get("text").and_then(Value::as_str))
+                            .collect::<String>()
+                    })
+                    .ok_or_else(|| {
+                        StorytellerError::Llm("cloud response missing content".to_string())
+                    })?;
+                let usage = |key: &str| {
+                    body.pointer(&format!("/usage/{key}"))
+                        .and_then(Value::as_u64)
+                        .unwrap_or(0)
+                };
+                (content, usage("input_tokens") + usage("output_tokens"))
+            }
+            CloudApiStyle::ChatCompletions => {
+                let content = body
+                    .pointer("/choices/0/message/content")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| {
+                        StorytellerError::Llm("cloud response missing choices".to_string())
+                    })?
+                    .to_string();
+                let tokens = body
+                    .pointer("/usage/total_tokens")
+                    .and_then(Value::as_u64)
+                    .unwrap_or(0);
+                (content, tokens)
+            }
+        };
+
+        tracing::debug!(
+            tokens_used,
+            content_len = content.len(),
+            "cloud response received"
+        );
+
+        Ok(CompletionResponse {
+            content,
+            tokens_used: u32::try_from(tokens_used).unwrap_or(u32::MAX),
+        })
+    }
+
+    #[instrument(skip(self, request), fields(model = %self.config.model))]
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> storyteller_core::StorytellerResult<NarratorTokenStream> {
+        let response = self.send(&request, true).await?;
+        let style = self.config.style;
+
+        let (sender, receiver) = narrator_token_channel();
+        let mut stream = response.bytes_stream();
+
+        tokio::spawn(async move {
+            let mut buffer = Vec::new();
+
+            while let Some(chunk) = stream.next().await {
+                match chunk {
+                    Ok(bytes) => {
+                        buffer.extend_from_slice(&bytes);
+                        for data in drain_sse_data(&mut buffer) {
+                            match parse_stream_data(style, &data) {
+                                StreamItem::Token(token) => {
+                                    if sender.0.send(Ok(token)).await.is_err() {
+                                        return; // receiver dropped — caller cancelled
+                                    }
+                                }
+                                StreamItem::Done => return,
+                                StreamItem::Error(message) => {
+                                    tracing::error!("cloud LLM stream error: {message}");
+                                    let _ = sender
+                                        .0
+                                        .send(Err(StorytellerError::Llm(format!(
+                                            "cloud stream error: {message}"
+                                        ))))
+                                        .await;
+                                    return;
+                                }
+                                StreamItem::Skip => {}
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("cloud LLM stream error: {e}");
+                        let _ = sender
+                            .0
+                            .send(Err(StorytellerError::Llm(format!(
+                                "cloud stream interrupted: {e}"
+                            ))))
+                            .await;
+                        return;
+                    }
+                }
+            }
+            // Sender drops here, closing the channel naturally.
+        });
+
+        Ok(receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inference::external::single_turn_request;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve one canned HTTP response and hand back the raw request text.
+    async fn mock_server(
+        status: &'static str,
+        headers: &'static str,
+        body: String,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            // Read headers, then the Content-Length body.
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text
+                        .lines()
+                        .find_map(|l| {
+                            l.to_ascii_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|v| v.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+                if n == 0 {
+                    break;
+                }
+            }
+            let response = format!(
+                "HTTP/1.1 {status}\r\n{headers}content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.ok();
+            String::from_utf8_lossy(&request).to_string()
+        });
+        (url, handle)
+    }
+
+    fn provider(style: CloudApiStyle, url: &str) -> CloudLlmProvider {
+        let config = match style {
+            CloudApiStyle::Messages => CloudLlmConfig::anthropic("test-key", "test-model"),
+            CloudApiStyle::ChatCompletions => CloudLlmConfig::openai("test-key", "test-model"),
+        };
+        CloudLlmProvider::new(config.with_base_url(url))
+    }
+
+    async fn collect(mut stream: NarratorTokenStream) -> Vec<String> {
+        let mut tokens = Vec::new();
+        while let Some(token) = stream.0.recv().await {
+            tokens.push(token.unwrap());
+        }
+        tokens
+    }
+
+    #[tokio::test]
+    async fn messages_complete_sends_system_top_level() {
+        let body = json!({
+            "content": [{"type": "text", "text": "The fog lifts."}],
+            "usage": {"input_tokens": 12, "output_tokens": 4}
+        })
+        .to_string();
+        let (url, server) = mock_server("200 OK", "content-type: application/json\r\n", body).await;
+
+        let response = provider(CloudApiStyle::Messages, &url)
+            .complete(single_turn_request("You narrate.", "Go on.", 100, 0.5))
+            .await
+            .unwrap();
+        assert_eq!(response.content, "The fog lifts.");
+        assert_eq!(response.tokens_used, 16);
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1/messages"));
+        assert!(request.contains("x-api-key: test-key"));
+        assert!(request.contains("anthropic-version: 2023-06-01"));
+        assert!(request.contains(r#""system":"You narrate.""#));
+    }
+
+    #[tokio::test]
+    async fn chat_completions_complete_uses_bearer_auth() {
+        let body = json!({
+            "choices": [{"message": {"role": "assistant", "content": "Rain falls."}}],
+            "usage": {"total_tokens": 30}
+        })
+        .to_string();
+        let (url, server) = mock_server("200 OK", "content-type: application/json\r\n", body).await;
+
+        let response = provider(CloudApiStyle::ChatCompletions, &url)
+            .complete(single_turn_request("You narrate.", "Go on.", 100, 0.5))
+            .await
+            .unwrap();
+        assert_eq!(response.content, "Rain falls.");
+        assert_eq!(response.tokens_used, 30);
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions"));
+        assert!(request
+            .to_ascii_lowercase()
+            .contains("authorization: bearer test-key"));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_maps_to_retryable_error() {
+        let body =
+            json!({"error": {"type": "rate_limit_error", "message": "slow down"}}).to_string();
+        let (url, _server) = mock_server("429 Too Many Requests", "retry-after: 7\r\n", body).await;
+
+        let err = provider(CloudApiStyle::Messages, &url)
+            .complete(single_turn_request("s", "u", 10, 0.0))
+            .await
+            .unwrap_err();
+        assert!(err.is_retryable());
+        match err {
+            StorytellerError::LlmRateLimited {
+                message,
+                retry_after,
+            } => {
+                assert!(message.contains("slow down"));
+                assert_eq!(retry_after, Some(Duration::from_secs(7)));
+            }
+            other => panic!("expected rate limit, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn overload_maps_to_overloaded_error() {
+        let body =
+            json!({"error": {"type": "overloaded_error", "message": "Overloaded"}}).to_string();
+        let (url, _server) = mock_server("529 Overloaded", "", body).await;
+
+        let err = provider(CloudApiStyle::Messages, &url)
+            .complete(single_turn_request("s", "u", 10, 0.0))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StorytellerError::LlmOverloaded(_)));
+    }
+
+    #[tokio::test]
+    async fn messages_stream_yields_text_deltas() {
+        let body = [
+            r#"event: message_start"#,
+            r#"data: {"type":"message_start","message":{}}"#,
+            "",
+            r#"event: content_block_delta"#,
+            r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"The "}}"#,
+            "",
+            r#"event: content_block_delta"#,
+            r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"door opens."}}"#,
+            "",
+            r#"event: message_stop"#,
+            r#"data: {"type":"message_stop"}"#,
+            "",
+            "",
+        ]
+        .join("\n");
+        let (url, _server) =
+            mock_server("200 OK", "content-type: text/event-stream\r\n", body).await;
+
+        let stream = provider(CloudApiStyle::Messages, &url)
+            .stream_complete(single_turn_request("s", "u", 10, 0.0))
+            .await
+            .unwrap();
+        assert_eq!(collect(stream).await, vec!["The ", "door opens."]);
+    }
+
+    #[tokio::test]
+    async fn chat_completions_stream_stops_at_done() {
+        let body = [
+            r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#,
+            "",
+            r#"data: {"choices":[{"delta":{"content":"Wind "}}]}"#,
+            "",
+            r#"data: {"choices":[{"delta":{"content":"howls."}}]}"#,
+            "",
+            "data: [DONE]",
+            "",
+            r#"data: {"choices":[{"delta":{"content":"ignored"}}]}"#,
+            "",
+            "",
+        ]
+        .join("\n");
+        let (url, _server) =
+            mock_server("200 OK", "content-type: text/event-stream\r\n", body).await;
+
+        let stream = provider(CloudApiStyle::ChatCompletions, &url)
+            .stream_complete(single_turn_request("s", "u", 10, 0.0))
+            .await
+            .unwrap();
+        assert_eq!(collect(stream).await, vec!["Wind ", "howls."]);
+    }
+
+    #[tokio::test]
+    async fn stream_error_mid_response_reaches_the_receiver() {
+        let body = [
+            r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"The "}}"#,
+            "",
+            r#"data: {"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#,
+            "",
+            "",
+        ]
+        .join("\n");
+        let (url, _server) =
+            mock_server("200 OK", "content-type: text/event-stream\r\n", body).await;
+
+        let mut stream = provider(CloudApiStyle::Messages, &url)
+            .stream_complete(single_turn_request("s", "u", 10, 0.0))
+            .await
+            .unwrap();
+        assert_eq!(stream.0.recv().await.unwrap().unwrap(), "The ");
+        let err = stream.0.recv().await.unwrap().unwrap_err();
+        assert!(err.to_string().contains("Overloaded"), "{err}");
+        assert!(stream.0.recv().await.is_none());
+    }
+
+    #[test]
+    fn sse_lines_split_across_chunks_reassemble() {
+        let mut buffer = b"data: {\"a\"".to_vec();
+        assert!(drain_sse_data(&mut buffer).is_empty());
+        buffer.extend_from_slice(b":1}\n\nevent: x\ndata: [DONE]\n");
+        assert_eq!(drain_sse_data(&mut buffer), vec![r#"{"a":1}"#, "[DONE]"]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn stream_error_events_surface() {
+        let item = parse_stream_data(
+            CloudApiStyle::Messages,
+            r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#,
+        );
+        assert_eq!(item, StreamItem::Error("Overloaded".to_string()));
+    }
+
+    #[test]
+    fn system_role_messages_fold_into_messages_system_prompt() {
+        let provider = CloudLlmProvider::new(CloudLlmConfig::anthropic("k", "m"));
+        let mut request = single_turn_request("Base.", "Hi", 10, 0.0);
+        request.messages.insert(
+            0,
+            storyteller_core::traits::llm::Message {
+                role: MessageRole::System,
+                content: "Extra.".to_string(),
+            },
+        );
+        let body = provider.request_body(&request, false);
+        assert_eq!(body["system"], "Base.\n\nExtra.");
+        assert_eq!(body["messages"].as_array().unwrap().len(), 1);
     }
 }
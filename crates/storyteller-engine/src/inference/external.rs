@@ -274,7 +274,7 @@ impl LlmProvider for ExternalServerProvider {
                                 Ok(chunk) => {
                                     let token = &chunk.message.content;
                                     if !token.is_empty()
-                                        && sender.0.send(token.clone()).await.is_err()
+                                        && sender.0.send(Ok(token.clone())).await.is_err()
                                     {
                                         return; // receiver dropped — caller cancelled
                                     }
@@ -290,7 +290,13 @@ impl LlmProvider for ExternalServerProvider {
                     }
                     Err(e) => {
                         tracing::error!("Ollama stream error: {e}");
-                        break;
+                        let _ = sender
+                            .0
+                            .send(Err(StorytellerError::Llm(format!(
+                                "Ollama stream interrupted: {e}"
+                            ))))
+                            .await;
+                        return;
                     }
                 }
             }
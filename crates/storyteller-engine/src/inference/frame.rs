@@ -137,13 +137,17 @@ impl CharacterPredictor {
             )));
         }
 
-        // 5. Decode into structured prediction
-        feature_schema::decode_outputs(
+        // 5. Decode into structured prediction, fitted to the grammar
+        let mut prediction = feature_schema::decode_outputs(
             &flat,
             character_id,
             activated_axis_indices,
             frame_confidence,
-        )
+        )?;
+        if let Some(grammar) = input.grammar {
+            feature_schema::fit_to_grammar(&mut prediction, grammar);
+        }
+        Ok(prediction)
     }
 
     /// Run inference for multiple characters in parallel via rayon.
@@ -310,6 +314,7 @@ mod tests {
                 knows: vec![],
                 does_not_know: vec![],
                 capabilities: CapabilityProfile::default(),
+                relationships: Vec::new(),
             }
         }
 
@@ -330,6 +335,7 @@ mod tests {
                     target_count: 1,
                 },
                 history: &[],
+                grammar: None,
             }
         }
 
@@ -14,6 +14,7 @@
 use storyteller_core::traits::llm::{CompletionRequest, LlmProvider, Message, MessageRole};
 use storyteller_core::types::character::{CharacterSheet, SceneData};
 use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::knowledge::KnowledgeModel;
 use storyteller_core::types::prediction::{ActionType, CharacterPrediction, SpeechRegister};
 
 use super::intention_generation::GeneratedIntentions;
@@ -43,6 +44,31 @@ pub fn format_scene_objectives(intentions: &GeneratedIntentions) -> String {
     text
 }
 
+/// Format what each non-player character has not learned as a prompt section.
+///
+/// Returns `None` when no character has anything withheld.
+pub fn format_information_boundaries(
+    characters: &[&CharacterSheet],
+    knowledge: &KnowledgeModel,
+    player_entity_id: Option<EntityId>,
+) -> Option<String> {
+    let lines: Vec<String> = characters
+        .iter()
+        .filter(|c| player_entity_id != Some(c.entity_id))
+        .filter_map(|c| {
+            let withheld = knowledge.withheld(&c.entity_id);
+            (!withheld.is_empty()).then(|| format!("{}: {}", c.name, withheld.join("; ")))
+        })
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "## Information Boundaries\nWhat each character does NOT know:\n{}",
+        lines.join("\n")
+    ))
+}
+
 /// Returns the system prompt for the intent synthesizer.
 pub fn intent_synthesis_system_prompt() -> String {
     "\
@@ -52,6 +78,7 @@ You receive:
 - Character data: personality traits, emotional state, relationships
 - ML predictions: what a behavior model predicts each character will do
 - Scene objectives: dramatic tension and per-character objectives for this scene
+- Information boundaries: what each character does not know
 - Recent scene history: what just happened
 - Player input: what the player character just did or said
 
@@ -75,6 +102,7 @@ Rules:
 - Do NOT write dialogue. The narrator writes all dialogue.
 - Do NOT narrate the scene. You are briefing the narrator, not writing prose.
 - Do NOT name personality traits directly. Show them through behavior.
+- Do NOT have a character reference, hint at, or act on anything listed under their information boundaries.
 - Use **CharacterName** headers for all characters (no other labels or markers)
 
 Examples of good directives:
@@ -390,9 +418,11 @@ fn build_scene_context(scene: &SceneData) -> String {
 /// Synthesizes character intent directives for the narrator.
 ///
 /// Calls the provided LLM with character data, ML predictions, scene history,
-/// and player input. Returns `Some(directives)` on success, `None` on failure.
+/// player input, and each character's information boundaries from
+/// `knowledge`. Returns `Some(directives)` on success, `None` on failure.
 ///
 /// Uses `max_tokens: 400`, `temperature: 0.3` for concise, deterministic output.
+#[allow(clippy::too_many_arguments)]
 pub async fn synthesize_intents(
     llm: &dyn LlmProvider,
     characters: &[&CharacterSheet],
@@ -402,6 +432,7 @@ pub async fn synthesize_intents(
     scene: &SceneData,
     player_entity_id: Option<EntityId>,
     intentions: Option<&GeneratedIntentions>,
+    knowledge: &KnowledgeModel,
 ) -> Option<String> {
     let (char_summary, pred_summary) = build_summaries(
         characters,
@@ -412,7 +443,7 @@ pub async fn synthesize_intents(
     let scene_context = build_scene_context(scene);
     let scene_objectives = intentions.map(format_scene_objectives);
 
-    let user_prompt = build_intent_user_prompt(
+    let mut user_prompt = build_intent_user_prompt(
         &char_summary,
         &pred_summary,
         journal_tail,
@@ -420,6 +451,11 @@ pub async fn synthesize_intents(
         &scene_context,
         scene_objectives.as_deref(),
     );
+    if let Some(boundaries) = format_information_boundaries(characters, knowledge, player_entity_id)
+    {
+        user_prompt.push_str("\n\n");
+        user_prompt.push_str(&boundaries);
+    }
 
     let request = CompletionRequest {
         system_prompt: intent_synthesis_system_prompt(),
@@ -615,6 +651,23 @@ mod tests {
         assert!(char_summary.contains("Pyotir"));
     }
 
+    #[test]
+    fn information_boundaries_list_npc_secrets_only() {
+        let bramblehoof = crate::workshop::the_flute_kept::bramblehoof();
+        let pyotir = crate::workshop::the_flute_kept::pyotir();
+        let characters: Vec<&CharacterSheet> = vec![&bramblehoof, &pyotir];
+        let knowledge = KnowledgeModel::from_characters(&characters);
+
+        let section =
+            format_information_boundaries(&characters, &knowledge, Some(bramblehoof.entity_id))
+                .expect("Pyotir has guarded facts");
+        assert!(section.contains("Pyotir:"));
+        assert!(section.contains("ley line"));
+        assert!(!section.contains("Bramblehoof:"));
+
+        assert!(format_information_boundaries(&[], &knowledge, None).is_none());
+    }
+
     #[test]
     fn summarize_character_includes_top_axes() {
         let bramblehoof = crate::workshop::the_flute_kept::bramblehoof();
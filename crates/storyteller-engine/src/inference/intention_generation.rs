@@ -321,6 +321,7 @@ mod tests {
             },
             emotional_arc: Vec::new(),
             evaluation_criteria: Vec::new(),
+            event_dependencies: Default::default(),
         };
 
         let composed = ComposedGoals::default();
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Small-LLM summarization of scene journal entries.
+//!
+//! See: `docs/technical/narrator-architecture.md` § Rolling Scene Journal
+//!
+//! The heuristic compressor keeps the first sentence of a turn, which loses
+//! whatever the turn established after it — often the plot-critical part.
+//! This compressor asks the structured LLM for a Summary that keeps every
+//! concrete fact (who did what, what was revealed, what changed hands) and
+//! a one-sentence Skeleton, so older turns stay useful to the narrator once
+//! they fall out of the recent window.
+
+use serde::Deserialize;
+
+use storyteller_core::errors::StorytellerResult;
+use storyteller_core::traits::structured_llm::{StructuredLlmProvider, StructuredRequest};
+use storyteller_core::types::narrator_context::{JournalCompression, JournalEntry};
+use storyteller_core::StorytellerError;
+
+use crate::context::journal::JournalCompressor;
+
+/// The compression as returned by the LLM.
+#[derive(Debug, Clone, Deserialize)]
+struct RawCompression {
+    summary: String,
+    skeleton: String,
+}
+
+/// Returns the JSON schema for journal compression output.
+pub fn journal_compression_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "required": ["summary", "skeleton"],
+        "properties": {
+            "summary": { "type": "string" },
+            "skeleton": { "type": "string" }
+        }
+    })
+}
+
+/// Returns the system prompt for journal compression.
+pub fn journal_compression_system_prompt() -> String {
+    "You compress turns of an interactive fiction scene for the narrator's running journal. \
+You receive the narrator's prose for one turn and the emotional dynamics noted in it.
+
+Produce two compressions:
+- summary: two or three plain sentences keeping every concrete fact a later turn could \
+depend on — who acted, what was said or revealed, objects that moved, promises, injuries, \
+changes in who knows what. Drop description and atmosphere first.
+- skeleton: one short sentence with the single most consequential fact.
+
+Write in past tense, third person. Do not add anything the prose does not state."
+        .to_string()
+}
+
+/// [`JournalCompressor`] backed by a [`StructuredLlmProvider`].
+#[derive(Debug)]
+pub struct LlmJournalCompressor<'a> {
+    provider: &'a dyn StructuredLlmProvider,
+}
+
+impl<'a> LlmJournalCompressor<'a> {
+    pub fn new(provider: &'a dyn StructuredLlmProvider) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait::async_trait]
+impl JournalCompressor for LlmJournalCompressor<'_> {
+    /// # Errors
+    ///
+    /// Returns `StorytellerError::Inference` if the provider call fails or
+    /// the response is missing either compression.
+    async fn compress(&self, entry: &JournalEntry) -> StorytellerResult<JournalCompression> {
+        let markers = if entry.emotional_markers.is_empty() {
+            "none".to_string()
+        } else {
+            entry.emotional_markers.join(", ")
+        };
+        let request = StructuredRequest {
+            system: journal_compression_system_prompt(),
+            input: format!(
+                "Turn {} prose:\n{}\n\nEmotional dynamics: {markers}",
+                entry.turn_number, entry.content
+            ),
+            output_schema: journal_compression_schema(),
+            temperature: 0.1,
+        };
+
+        let json = self.provider.extract(request).await?;
+        let raw: RawCompression = serde_json::from_value(json).map_err(|e| {
+            StorytellerError::Inference(format!("failed to parse journal compression: {e}"))
+        })?;
+
+        let summary = raw.summary.trim().to_string();
+        let skeleton = raw.skeleton.trim().to_string();
+        if summary.is_empty() || skeleton.is_empty() {
+            return Err(StorytellerError::Inference(
+                "journal compression returned an empty summary or skeleton".to_string(),
+            ));
+        }
+        Ok(JournalCompression { summary, skeleton })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::sync::Mutex;
+    use storyteller_core::types::narrator_context::CompressionLevel;
+
+    #[derive(Debug)]
+    struct CannedProvider {
+        response: serde_json::Value,
+        seen_input: Mutex<Option<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl StructuredLlmProvider for CannedProvider {
+        async fn extract(
+            &self,
+            request: StructuredRequest,
+        ) -> StorytellerResult<serde_json::Value> {
+            *self.seen_input.lock().unwrap() = Some(request.input);
+            Ok(self.response.clone())
+        }
+    }
+
+    fn entry() -> JournalEntry {
+        JournalEntry {
+            turn_number: 4,
+            timestamp: Utc::now(),
+            compression: CompressionLevel::Full,
+            content: "The evening light slants across the fence. Pyotir finally takes the \
+                      flute and admits he sold his father's tools to pay the healer."
+                .to_string(),
+            referenced_entities: vec![],
+            emotional_markers: vec!["grief".to_string()],
+            cached_compression: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn compress_returns_both_forms() {
+        let provider = CannedProvider {
+            response: serde_json::json!({
+                "summary": " Pyotir took the flute and admitted selling his father's tools to pay the healer. ",
+                "skeleton": "Pyotir sold his father's tools."
+            }),
+            seen_input: Mutex::new(None),
+        };
+
+        let compression = LlmJournalCompressor::new(&provider)
+            .compress(&entry())
+            .await
+            .unwrap();
+
+        assert!(compression.summary.starts_with("Pyotir took the flute"));
+        assert!(compression.summary.ends_with("healer."));
+        assert_eq!(compression.skeleton, "Pyotir sold his father's tools.");
+
+        let input = provider.seen_input.lock().unwrap().clone().unwrap();
+        assert!(input.contains("Turn 4 prose"));
+        assert!(input.contains("Emotional dynamics: grief"));
+    }
+
+    #[tokio::test]
+    async fn empty_form_is_an_error() {
+        let provider = CannedProvider {
+            response: serde_json::json!({ "summary": "Something happened.", "skeleton": "  " }),
+            seen_input: Mutex::new(None),
+        };
+
+        let result = LlmJournalCompressor::new(&provider)
+            .compress(&entry())
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn malformed_response_is_an_error() {
+        let provider = CannedProvider {
+            response: serde_json::json!({ "summary": "Only a summary." }),
+            seen_input: Mutex::new(None),
+        };
+
+        let err = LlmJournalCompressor::new(&provider)
+            .compress(&entry())
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("failed to parse journal compression"));
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Authoring-time capability lexicon expansion via small-LLM structured output.
+//!
+//! See: `docs/plans/2026-03-09-event-classification-and-action-arbitration-design.md`
+//!
+//! Genre rules arrive with a hand-seeded lexicon for each constrained
+//! capability. Expansion asks the structured LLM for more synonyms, action
+//! verbs, implied objects, and idiomatic phrases, and folds them into the
+//! seed. It runs once per composition — arbitration at runtime is still
+//! plain string matching against the expanded sets.
+
+use serde::Deserialize;
+
+use storyteller_core::errors::StorytellerResult;
+use storyteller_core::traits::structured_llm::{StructuredLlmProvider, StructuredRequest};
+use storyteller_core::types::capability_lexicon::{CapabilityLexicon, LexiconEntry};
+
+/// Shortest term kept from LLM output.
+///
+/// Lexicon matching is substring-based; very short terms ("gun", "car")
+/// hide inside ordinary words and would trip constraints on innocent input.
+const MIN_TERM_LEN: usize = 4;
+
+/// The expansion result from the LLM.
+#[derive(Debug, Clone, Deserialize)]
+struct LexiconExpansion {
+    entries: Vec<LexiconEntry>,
+}
+
+/// Returns the JSON schema for lexicon expansion output.
+pub fn lexicon_expansion_schema() -> serde_json::Value {
+    let terms = serde_json::json!({ "type": "array", "items": { "type": "string" } });
+    serde_json::json!({
+        "type": "object",
+        "required": ["entries"],
+        "properties": {
+            "entries": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": [
+                        "capability", "synonyms", "action_verbs",
+                        "implied_objects", "idiomatic_phrases"
+                    ],
+                    "properties": {
+                        "capability": { "type": "string" },
+                        "synonyms": terms,
+                        "action_verbs": terms,
+                        "implied_objects": terms,
+                        "idiomatic_phrases": terms
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Returns the system prompt for lexicon expansion.
+pub fn lexicon_expansion_system_prompt() -> String {
+    "You expand a capability lexicon for an interactive fiction rules engine. You receive a \
+genre description and a list of capabilities, each with its existing terms.
+
+For each capability, add the words a player might type when attempting it:
+- synonyms: other names for the capability itself
+- action_verbs: verbs for performing it
+- implied_objects: things whose use implies it
+- idiomatic_phrases: multi-word phrases that describe doing it
+
+Rules:
+- Return one entry per capability, using the capability name exactly as given
+- Do not add new capabilities
+- Prefer specific terms; avoid short or everyday words that commonly mean something else
+- Terms are matched as lowercase substrings of player input"
+        .to_string()
+}
+
+/// Expand a capability lexicon using a small LLM.
+///
+/// Only capabilities already in `lexicon` are expanded; LLM output for
+/// other capabilities, and terms shorter than four characters, are dropped.
+///
+/// # Errors
+///
+/// Returns `StorytellerError::Inference` if the provider call fails or the
+/// response cannot be parsed.
+pub async fn expand_lexicon(
+    provider: &dyn StructuredLlmProvider,
+    lexicon: &CapabilityLexicon,
+    genre_description: &str,
+) -> StorytellerResult<CapabilityLexicon> {
+    if lexicon.entries.is_empty() {
+        return Ok(lexicon.clone());
+    }
+
+    let capabilities = lexicon
+        .entries
+        .values()
+        .map(|e| {
+            let existing = e
+                .synonyms
+                .iter()
+                .chain(&e.action_verbs)
+                .chain(&e.implied_objects)
+                .chain(&e.idiomatic_phrases)
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("- {}: {existing}", e.capability)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let request = StructuredRequest {
+        system: lexicon_expansion_system_prompt(),
+        input: format!("Genre: {genre_description}\n\nCapabilities:\n{capabilities}"),
+        output_schema: lexicon_expansion_schema(),
+        temperature: 0.3,
+    };
+
+    let json = provider.extract(request).await?;
+    let expansion: LexiconExpansion = serde_json::from_value(json).map_err(|e| {
+        storyteller_core::StorytellerError::Inference(format!(
+            "failed to parse lexicon expansion: {e}"
+        ))
+    })?;
+
+    let mut expanded = lexicon.clone();
+    for mut entry in expansion.entries {
+        if !expanded.entries.contains_key(&entry.capability) {
+            tracing::debug!(capability = %entry.capability, "Dropping unrequested capability");
+            continue;
+        }
+        for terms in [
+            &mut entry.synonyms,
+            &mut entry.action_verbs,
+            &mut entry.implied_objects,
+            &mut entry.idiomatic_phrases,
+        ] {
+            terms.retain(|t| t.trim().chars().count() >= MIN_TERM_LEN);
+        }
+        expanded.merge(entry);
+    }
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct CannedProvider(serde_json::Value);
+
+    #[async_trait::async_trait]
+    impl StructuredLlmProvider for CannedProvider {
+        async fn extract(
+            &self,
+            _request: StructuredRequest,
+        ) -> StorytellerResult<serde_json::Value> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn seed() -> CapabilityLexicon {
+        let mut lexicon = CapabilityLexicon::new();
+        lexicon.add(LexiconEntry {
+            implied_objects: vec!["fireball".to_string()],
+            ..LexiconEntry::new("overt_magic")
+        });
+        lexicon
+    }
+
+    #[tokio::test]
+    async fn expansion_merges_requested_capabilities_only() {
+        let provider = CannedProvider(serde_json::json!({
+            "entries": [
+                {
+                    "capability": "overt_magic",
+                    "synonyms": ["pyromancy"],
+                    "action_verbs": ["immolate", "zap"],
+                    "implied_objects": ["Fireball", "staff of flame"],
+                    "idiomatic_phrases": ["rain fire"]
+                },
+                {
+                    "capability": "flight",
+                    "synonyms": ["soaring"],
+                    "action_verbs": [],
+                    "implied_objects": [],
+                    "idiomatic_phrases": []
+                }
+            ]
+        }));
+        let expanded = expand_lexicon(&provider, &seed(), "low fantasy folklore")
+            .await
+            .unwrap();
+
+        assert_eq!(expanded.entries.len(), 1);
+        let entry = &expanded.entries["overt_magic"];
+        assert_eq!(entry.synonyms, vec!["pyromancy"]);
+        assert_eq!(entry.action_verbs, vec!["immolate"]); // "zap" too short
+        assert_eq!(entry.implied_objects, vec!["fireball", "staff of flame"]);
+        assert_eq!(
+            expanded.match_text("I make it rain fire on them"),
+            vec!["overt_magic"]
+        );
+    }
+
+    #[tokio::test]
+    async fn unparseable_expansion_errors() {
+        let provider = CannedProvider(serde_json::json!({"nope": true}));
+        assert!(expand_lexicon(&provider, &seed(), "").await.is_err());
+    }
+
+    #[test]
+    fn schema_requires_entries() {
+        let schema = lexicon_expansion_schema();
+        assert_eq!(schema["required"][0], "entries");
+        assert!(lexicon_expansion_system_prompt().contains("Do not add new capabilities"));
+    }
+}
@@ -9,4 +9,508 @@
 //! Implements `LlmProvider` for local model inference via candle.
 //! Feature-gated behind `local-llm` (disabled by default).
 //!
+//! Runs a Llama-architecture chat model on the CPU with no external daemon.
+//! Two on-disk layouts are accepted (see [`LocalModelFiles::discover`]):
+//!
+//! - **Quantized GGUF** — `model.gguf` (or any single `*.gguf`) plus
+//!   `tokenizer.json`
+//! - **Safetensors** — `model.safetensors`, `config.json`, `tokenizer.json`
+//!
+//! Generation runs on a blocking thread; each decoded text fragment is sent
+//! through a `NarratorTokenSender` as soon as it is complete, so
+//! `stream_complete` streams real tokens. A small model such as
+//! SmolLM2-135M-Instruct is enough for CI (`test-local-llm` feature).
+//!
 //! Future direction: `burn` as all-Rust replacement for the Python→ONNX path.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use candle_core::quantized::gguf_file;
+use candle_core::{DType, Device, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::generation::LogitsProcessor;
+use candle_transformers::models::{llama, quantized_llama};
+use storyteller_core::errors::StorytellerError;
+use storyteller_core::traits::llm::{
+    narrator_token_channel, CompletionRequest, CompletionResponse, LlmProvider, MessageRole,
+    NarratorTokenSender, NarratorTokenStream,
+};
+use storyteller_core::StorytellerResult;
+use tokenizers::Tokenizer;
+
+/// Prompt format the model was fine-tuned on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatTemplate {
+    /// `<|im_start|>role\n…<|im_end|>` (Qwen, SmolLM, many fine-tunes).
+    ChatMl,
+    /// `<|start_header_id|>role<|end_header_id|>\n\n…<|eot_id|>` (Llama 3).
+    Llama3,
+}
+
+impl ChatTemplate {
+    /// Render a request as a prompt ending at the assistant's turn.
+    pub fn render(self, request: &CompletionRequest) -> String {
+        let turns = std::iter::once(("system", request.system_prompt.as_str())).chain(
+            request.messages.iter().map(|m| {
+                let role = match m.role {
+                    MessageRole::System => "system",
+                    MessageRole::User => "user",
+                    MessageRole::Assistant => "assistant",
+                };
+                (role, m.content.as_str())
+            }),
+        );
+
+        let mut prompt = String::new();
+        match self {
+            Self::ChatMl => {
+                for (role, content) in turns {
+                    prompt.push_str(&format!("<|im_start|>{role}\n{content}<|im_end|>\n"));
+                }
+                prompt.push_str("<|im_start|>assistant\n");
+            }
+            Self::Llama3 => {
+                prompt.push_str("<|begin_of_text|>");
+                for (role, content) in turns {
+                    prompt.push_str(&format!(
+                        "<|start_header_id|>{role}<|end_header_id|>\n\n{content}<|eot_id|>"
+                    ));
+                }
+                prompt.push_str("<|start_header_id|>assistant<|end_header_id|>\n\n");
+            }
+        }
+        prompt
+    }
+
+    /// Special tokens that end the assistant's turn.
+    fn stop_tokens(self) -> &'static [&'static str] {
+        match self {
+            Self::ChatMl => &["<|im_end|>", "<|endoftext|>"],
+            Self::Llama3 => &["<|eot_id|>", "<|end_of_text|>"],
+        }
+    }
+}
+
+/// Model weights on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalModelFiles {
+    Gguf {
+        weights: PathBuf,
+        tokenizer: PathBuf,
+    },
+    Safetensors {
+        weights: PathBuf,
+        config: PathBuf,
+        tokenizer: PathBuf,
+    },
+}
+
+impl LocalModelFiles {
+    /// Find model files in `dir`. GGUF is preferred when both are present.
+    pub fn discover(dir: &Path) -> StorytellerResult<Self> {
+        let tokenizer = dir.join("tokenizer.json");
+        if !tokenizer.is_file() {
+            return Err(StorytellerError::Config(format!(
+                "no tokenizer.json in {}",
+                dir.display()
+            )));
+        }
+
+        let gguf = std::fs::read_dir(dir)
+            .map_err(|e| StorytellerError::Config(format!("read {}: {e}", dir.display())))?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "gguf"))
+            .min();
+        if let Some(weights) = gguf {
+            return Ok(Self::Gguf { weights, tokenizer });
+        }
+
+        let weights = dir.join("model.safetensors");
+        let config = dir.join("config.json");
+        if weights.is_file() && config.is_file() {
+            return Ok(Self::Safetensors {
+                weights,
+                config,
+                tokenizer,
+            });
+        }
+
+        Err(StorytellerError::Config(format!(
+            "no *.gguf or model.safetensors + config.json in {}",
+            dir.display()
+        )))
+    }
+
+    fn tokenizer(&self) -> &Path {
+        match self {
+            Self::Gguf { tokenizer, .. } | Self::Safetensors { tokenizer, .. } => tokenizer,
+        }
+    }
+}
+
+/// Configuration for a local model.
+#[derive(Debug, Clone)]
+pub struct LocalLlmConfig {
+    pub files: LocalModelFiles,
+    pub template: ChatTemplate,
+    /// Sampling seed; fixed so runs are reproducible.
+    pub seed: u64,
+    /// Nucleus sampling cutoff; `None` samples the full distribution.
+    pub top_p: Option<f64>,
+}
+
+impl LocalLlmConfig {
+    /// Configuration for the model files in `dir`, using ChatML.
+    pub fn from_dir(dir: &Path) -> StorytellerResult<Self> {
+        Ok(Self {
+            files: LocalModelFiles::discover(dir)?,
+            template: ChatTemplate::ChatMl,
+            seed: 299_792_458,
+            top_p: Some(0.9),
+        })
+    }
+}
+
+/// Loaded weights for either layout.
+enum LocalModel {
+    Quantized(quantized_llama::ModelWeights),
+    Full {
+        model: llama::Llama,
+        config: llama::Config,
+    },
+}
+
+impl LocalModel {
+    fn load(files: &LocalModelFiles, device: &Device) -> StorytellerResult<Self> {
+        match files {
+            LocalModelFiles::Gguf { weights, .. } => {
+                let mut file = std::fs::File::open(weights).map_err(|e| {
+                    StorytellerError::Inference(format!("open {}: {e}", weights.display()))
+                })?;
+                let content = gguf_file::Content::read(&mut file).map_err(candle_err)?;
+                let model = quantized_llama::ModelWeights::from_gguf(content, &mut file, device)
+                    .map_err(candle_err)?;
+                Ok(Self::Quantized(model))
+            }
+            LocalModelFiles::Safetensors {
+                weights, config, ..
+            } => {
+                let raw = std::fs::read_to_string(config).map_err(|e| {
+                    StorytellerError::Inference(format!("read {}: {e}", config.display()))
+                })?;
+                let config: llama::LlamaConfig = serde_json::from_str(&raw)?;
+                let config = config.into_config(false);
+                // SAFETY: the weights file is memory-mapped read-only and is
+                // not modified while the model is loaded.
+                let vb = unsafe {
+                    VarBuilder::from_mmaped_safetensors(
+                        std::slice::from_ref(weights),
+                        DType::F32,
+                        device,
+                    )
+                }
+                .map_err(candle_err)?;
+                let model = llama::Llama::load(vb, &config).map_err(candle_err)?;
+                Ok(Self::Full { model, config })
+            }
+        }
+    }
+
+    /// Per-generation state; the quantized model keeps its KV cache
+    /// internally and resets it at position 0.
+    fn new_cache(&self, device: &Device) -> StorytellerResult<Option<llama::Cache>> {
+        match self {
+            Self::Quantized(_) => Ok(None),
+            Self::Full { config, .. } => llama::Cache::new(true, DType::F32, config, device)
+                .map(Some)
+                .map_err(candle_err),
+        }
+    }
+
+    /// Logits for the token after `input`, which starts at `position`.
+    fn forward(
+        &mut self,
+        input: &Tensor,
+        position: usize,
+        cache: Option<&mut llama::Cache>,
+    ) -> candle_core::Result<Tensor> {
+        let logits = match (self, cache) {
+            (Self::Quantized(model), _) => model.forward(input, position)?,
+            (Self::Full { model, .. }, Some(cache)) => model.forward(input, position, cache)?,
+            (Self::Full { .. }, None) => candle_core::bail!("missing KV cache"),
+        };
+        logits.squeeze(0)
+    }
+}
+
+fn candle_err(e: candle_core::Error) -> StorytellerError {
+    StorytellerError::Inference(format!("candle: {e}"))
+}
+
+/// Turns a growing list of token ids into text deltas.
+///
+/// Decoding ids one at a time breaks multi-byte characters and
+/// SentencePiece spacing, so the whole tail is re-decoded and only the new
+/// suffix is emitted once it no longer ends in a partial character.
+struct IncrementalDecoder {
+    ids: Vec<u32>,
+    emitted: usize,
+}
+
+impl IncrementalDecoder {
+    fn new() -> Self {
+        Self {
+            ids: Vec::new(),
+            emitted: 0,
+        }
+    }
+
+    fn push(&mut self, tokenizer: &Tokenizer, id: u32) -> StorytellerResult<Option<String>> {
+        self.ids.push(id);
+        let text = tokenizer
+            .decode(&self.ids, true)
+            .map_err(|e| StorytellerError::Inference(format!("decode: {e}")))?;
+        if text.len() <= self.emitted || text.ends_with('\u{FFFD}') {
+            return Ok(None);
+        }
+        let delta = text[self.emitted..].to_string();
+        self.emitted = text.len();
+        Ok(Some(delta))
+    }
+}
+
+/// LLM provider that runs a chat model in-process with candle.
+pub struct CandleLlmProvider {
+    model: Arc<Mutex<LocalModel>>,
+    tokenizer: Arc<Tokenizer>,
+    device: Device,
+    config: LocalLlmConfig,
+    stop_ids: Vec<u32>,
+}
+
+impl std::fmt::Debug for CandleLlmProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CandleLlmProvider")
+            .field("config", &self.config)
+            .field("stop_ids", &self.stop_ids)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CandleLlmProvider {
+    /// Load the model and tokenizer onto the CPU.
+    pub fn load(config: LocalLlmConfig) -> StorytellerResult<Self> {
+        let device = Device::Cpu;
+        let tokenizer = Tokenizer::from_file(config.files.tokenizer())
+            .map_err(|e| StorytellerError::Inference(format!("load tokenizer: {e}")))?;
+        let model = LocalModel::load(&config.files, &device)?;
+
+        let stop_ids: Vec<u32> = config
+            .template
+            .stop_tokens()
+            .iter()
+            .filter_map(|t| tokenizer.token_to_id(t))
+            .collect();
+        if stop_ids.is_empty() {
+            tracing::warn!(
+                template = ?config.template,
+                "tokenizer has none of the template's stop tokens; generation runs to max_tokens"
+            );
+        }
+
+        tracing::info!(files = ?config.files, "local LLM loaded");
+
+        Ok(Self {
+            model: Arc::new(Mutex::new(model)),
+            tokenizer: Arc::new(tokenizer),
+            device,
+            config,
+            stop_ids,
+        })
+    }
+
+    /// Load from a model directory with default settings.
+    pub fn from_dir(dir: &Path) -> StorytellerResult<Self> {
+        Self::load(LocalLlmConfig::from_dir(dir)?)
+    }
+
+    /// Spawn generation on a blocking thread, streaming text deltas to
+    /// `sender`. Resolves to the number of tokens generated.
+    fn spawn_generation(
+        &self,
+        request: CompletionRequest,
+        sender: NarratorTokenSender,
+    ) -> tokio::task::JoinHandle<StorytellerResult<u32>> {
+        let model = Arc::clone(&self.model);
+        let tokenizer = Arc::clone(&self.tokenizer);
+        let device = self.device.clone();
+        let prompt = self.config.template.render(&request);
+        let stop_ids = self.stop_ids.clone();
+        let temperature = (request.temperature > 0.0).then_some(f64::from(request.temperature));
+        let mut sampler = LogitsProcessor::new(self.config.seed, temperature, self.config.top_p);
+
+        tokio::task::spawn_blocking(move || {
+            let encoding = tokenizer
+                .encode(prompt, false)
+                .map_err(|e| StorytellerError::Inference(format!("encode prompt: {e}")))?;
+            let prompt_ids = encoding.get_ids().to_vec();
+
+            // One generation at a time per loaded model.
+            let mut model = model
+                .lock()
+                .map_err(|_| StorytellerError::Inference("local model lock poisoned".into()))?;
+            let mut cache = model.new_cache(&device)?;
+            let mut decoder = IncrementalDecoder::new();
+
+            let mut input = prompt_ids;
+            let mut position = 0;
+            let mut generated = 0u32;
+            while generated < request.max_tokens {
+                let tensor = Tensor::new(input.as_slice(), &device)
+                    .and_then(|t| t.unsqueeze(0))
+                    .map_err(candle_err)?;
+                let logits = model
+                    .forward(&tensor, position, cache.as_mut())
+                    .map_err(candle_err)?;
+                position += input.len();
+
+                let next = sampler.sample(&logits).map_err(candle_err)?;
+                generated += 1;
+                if stop_ids.contains(&next) {
+                    break;
+                }
+                if let Some(delta) = decoder.push(&tokenizer, next)? {
+                    if sender.0.blocking_send(Ok(delta)).is_err() {
+                        break; // receiver dropped — caller cancelled
+                    }
+                }
+                input = vec![next];
+            }
+            Ok(generated)
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for CandleLlmProvider {
+    async fn complete(&self, request: CompletionRequest) -> StorytellerResult<CompletionResponse> {
+        let (sender, mut receiver) = narrator_token_channel();
+        let handle = self.spawn_generation(request, sender);
+
+        let mut content = String::new();
+        while let Some(delta) = receiver.0.recv().await {
+            content.push_str(&delta?);
+        }
+        let tokens_used = handle
+            .await
+            .map_err(|e| StorytellerError::Inference(format!("generation task: {e}")))??;
+
+        Ok(CompletionResponse {
+            content,
+            tokens_used,
+        })
+    }
+
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> StorytellerResult<NarratorTokenStream> {
+        let (sender, receiver) = narrator_token_channel();
+        let handle = self.spawn_generation(request, sender.clone());
+        tokio::spawn(async move {
+            let failure = match handle.await {
+                Ok(Ok(tokens)) => {
+                    tracing::debug!(tokens, "local generation complete");
+                    return;
+                }
+                Ok(Err(e)) => e,
+                Err(e) => StorytellerError::Inference(format!("generation task: {e}")),
+            };
+            tracing::error!("local generation failed: {failure}");
+            let _ = sender.0.send(Err(failure)).await;
+        });
+        Ok(receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inference::external::single_turn_request;
+
+    #[test]
+    fn chatml_prompt_ends_at_assistant_turn() {
+        let request = single_turn_request("You narrate.", "Go on.", 10, 0.0);
+        let prompt = ChatTemplate::ChatMl.render(&request);
+        assert_eq!(
+            prompt,
+            "<|im_start|>system\nYou narrate.<|im_end|>\n\
+             <|im_start|>user\nGo on.<|im_end|>\n\
+             <|im_start|>assistant\n"
+        );
+    }
+
+    #[test]
+    fn llama3_prompt_ends_at_assistant_header() {
+        let request = single_turn_request("You narrate.", "Go on.", 10, 0.0);
+        let prompt = ChatTemplate::Llama3.render(&request);
+        assert!(prompt.starts_with("<|begin_of_text|><|start_header_id|>system"));
+        assert!(prompt.ends_with("<|start_header_id|>assistant<|end_header_id|>\n\n"));
+    }
+
+    #[test]
+    fn discover_requires_tokenizer_and_weights() {
+        let dir = std::env::temp_dir().join(format!("st-local-llm-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(LocalModelFiles::discover(&dir).is_err());
+
+        std::fs::write(dir.join("tokenizer.json"), "{}").unwrap();
+        assert!(LocalModelFiles::discover(&dir).is_err());
+
+        std::fs::write(dir.join("model.safetensors"), "").unwrap();
+        std::fs::write(dir.join("config.json"), "{}").unwrap();
+        assert!(matches!(
+            LocalModelFiles::discover(&dir).unwrap(),
+            LocalModelFiles::Safetensors { .. }
+        ));
+
+        std::fs::write(dir.join("smol-q8.gguf"), "").unwrap();
+        assert!(matches!(
+            LocalModelFiles::discover(&dir).unwrap(),
+            LocalModelFiles::Gguf { .. }
+        ));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    // Requires a real model: STORYTELLER_LOCAL_LLM_PATH pointing at a
+    // directory for `LocalModelFiles::discover`.
+    // Enable with: cargo test -p storyteller-engine --features test-local-llm
+    #[cfg(feature = "test-local-llm")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn local_model_streams_tokens() {
+        let dir = std::env::var("STORYTELLER_LOCAL_LLM_PATH")
+            .expect("STORYTELLER_LOCAL_LLM_PATH must be set for test-local-llm");
+        let provider = CandleLlmProvider::from_dir(Path::new(&dir)).unwrap();
+
+        let mut stream = provider
+            .stream_complete(single_turn_request(
+                "You are a narrator.",
+                "Describe a quiet farmhouse.",
+                24,
+                0.7,
+            ))
+            .await
+            .unwrap();
+        let mut chunks = Vec::new();
+        while let Some(chunk) = stream.0.recv().await {
+            chunks.push(chunk.unwrap());
+        }
+        assert!(
+            chunks.len() > 1,
+            "expected incremental tokens, got {chunks:?}"
+        );
+        assert!(!chunks.concat().trim().is_empty());
+    }
+}
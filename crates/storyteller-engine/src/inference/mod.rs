@@ -12,6 +12,7 @@
 //! typed events and entities from natural language; LLM providers handle
 //! natural language generation.
 
+pub mod action_adjudication;
 pub mod cloud;
 pub mod event_classifier;
 pub mod event_decomposition;
@@ -19,6 +20,8 @@ pub mod external;
 pub mod frame;
 pub mod intent_synthesis;
 pub mod intention_generation;
+pub mod journal_summarization;
+pub mod lexicon_expansion;
 pub mod structured;
 
 #[cfg(feature = "local-llm")]
@@ -19,12 +19,14 @@
 //! - [`context`] — Three-tier Narrator context assembly (preamble, journal, retrieval)
 //! - [`inference`] — ML inference integration (frame computation, LLM providers)
 //! - [`messaging`] — RabbitMQ integration for tasker-core workflow dispatch
+//! - [`pipeline`] — Turn stage functions shared by the server and the Bevy turn cycle
 
 pub mod agents;
 pub mod components;
 pub mod context;
 pub mod inference;
 pub mod messaging;
+pub mod pipeline;
 pub mod plugin;
 pub mod scene_composer;
 pub mod systems;
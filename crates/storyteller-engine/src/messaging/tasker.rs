@@ -9,3 +9,861 @@
 //! Events classified as `Deferred` priority are dispatched to tasker-core
 //! for asynchronous workflow processing. Results flow back via RabbitMQ
 //! and are integrated into the truth set on arrival.
+//!
+//! The flow:
+//!
+//! 1. [`TaskerDispatcher::dispatch`] stamps a [`DeferredRequest`] with a
+//!    [`CorrelationId`], records it as pending, and publishes it on a
+//!    [`TaskerTransport`].
+//! 2. tasker-core runs the workflow (world reactions, off-screen
+//!    consequences) and replies with a [`DeferredResult`] carrying the same
+//!    correlation id.
+//! 3. [`TaskerTransport::consume`] forwards results for requests this
+//!    process is still waiting on into a channel the Bevy side drains;
+//!    [`merge_deferred_results_system`] ledgers them and merges them into
+//!    the truth set at the next turn boundary, never mid-turn.
+//!
+//! Two transports exist: [`AmqpTransport`] for RabbitMQ and
+//! [`ChannelTransport`] — an in-process pair whose [`ChannelWorker`] half
+//! stands in for tasker-core in tests.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, Utc};
+use crossbeam::channel::{Receiver, Sender};
+use lapin::options::{
+    BasicAckOptions, BasicConsumeOptions, BasicNackOptions, BasicPublishOptions,
+    QueueDeclareOptions,
+};
+use lapin::types::FieldTable;
+use lapin::{BasicProperties, Channel, Connection, ConnectionProperties};
+use tokio_stream::StreamExt;
+use uuid::Uuid;
+
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::types::event::{EventPriority, NarrativeEvent};
+use storyteller_core::types::event_grammar::EventPayload;
+
+use crate::components::turn::{LedgerResource, TokioRuntime, TruthSetResource, TurnHistory};
+
+// ---------------------------------------------------------------------------
+// Wire types
+// ---------------------------------------------------------------------------
+
+/// Correlates a deferred request with its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct CorrelationId(pub Uuid);
+
+impl CorrelationId {
+    /// Create a new time-ordered correlation ID (UUID v7).
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl Default for CorrelationId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A deferred event sent to tasker-core.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeferredRequest {
+    /// Echoed back on the result.
+    pub correlation_id: CorrelationId,
+    /// Turn the event was committed in.
+    pub turn_number: u32,
+    /// The deferred event.
+    pub event: NarrativeEvent,
+    /// When the request was dispatched.
+    pub dispatched_at: DateTime<Utc>,
+}
+
+/// What tasker-core produced for a deferred request.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeferredResult {
+    /// The request this answers.
+    pub correlation_id: CorrelationId,
+    /// Events the workflow produced, to be committed to the truth set.
+    #[serde(default)]
+    pub events: Vec<NarrativeEvent>,
+    /// Set when the workflow failed; `events` is then empty.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// When the workflow finished.
+    pub completed_at: DateTime<Utc>,
+}
+
+// ---------------------------------------------------------------------------
+// Transport
+// ---------------------------------------------------------------------------
+
+/// How deferred requests reach tasker-core and results come back.
+#[async_trait::async_trait]
+pub trait TaskerTransport: Send + Sync + std::fmt::Debug {
+    /// Publish one request.
+    async fn publish(&self, request: &DeferredRequest) -> StorytellerResult<()>;
+
+    /// Forward arriving results into `results` until the transport closes.
+    ///
+    /// Only results whose correlation id `is_pending` accepts are forwarded;
+    /// the rest are rejected. Runs for the life of the session; spawn it on
+    /// the Tokio runtime.
+    async fn consume(
+        &self,
+        results: Sender<DeferredResult>,
+        is_pending: &(dyn Fn(CorrelationId) -> bool + Send + Sync),
+    ) -> StorytellerResult<()>;
+}
+
+/// Queue name and broker address for [`AmqpTransport`].
+#[derive(Debug, Clone)]
+pub struct AmqpConfig {
+    /// Broker URI, e.g. `amqp://localhost:5672/%2f`.
+    pub uri: String,
+    /// Queue tasker-core consumes deferred requests from.
+    pub request_queue: String,
+}
+
+impl Default for AmqpConfig {
+    fn default() -> Self {
+        Self {
+            uri: "amqp://localhost:5672/%2f".to_string(),
+            request_queue: "storyteller.deferred.requests".to_string(),
+        }
+    }
+}
+
+fn amqp_error(e: lapin::Error) -> StorytellerError {
+    StorytellerError::Messaging(e.to_string())
+}
+
+/// RabbitMQ transport. Requests and results are JSON, published through
+/// the default exchange, with the correlation id carried in the AMQP
+/// `correlation_id` property as well as the body.
+///
+/// Requests go persistent to the shared, durable request queue. Each
+/// transport declares its own exclusive, broker-named reply queue and names
+/// it in every request's `reply_to`, so a result only ever reaches the
+/// process that is waiting for it.
+pub struct AmqpTransport {
+    // Held so the connection outlives the channel.
+    _connection: Connection,
+    channel: Channel,
+    config: AmqpConfig,
+    reply_queue: String,
+}
+
+impl AmqpTransport {
+    /// Connect, declare the request queue, and declare this process's
+    /// reply queue.
+    pub async fn connect(config: AmqpConfig) -> StorytellerResult<Self> {
+        let connection = Connection::connect(&config.uri, ConnectionProperties::default())
+            .await
+            .map_err(amqp_error)?;
+        let channel = connection.create_channel().await.map_err(amqp_error)?;
+
+        channel
+            .queue_declare(
+                config.request_queue.as_str(),
+                QueueDeclareOptions {
+                    durable: true,
+                    ..QueueDeclareOptions::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .map_err(amqp_error)?;
+        // Empty name: the broker picks one. Exclusive queues are deleted
+        // with the connection.
+        let reply_queue = channel
+            .queue_declare(
+                "",
+                QueueDeclareOptions {
+                    exclusive: true,
+                    auto_delete: true,
+                    ..QueueDeclareOptions::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .map_err(amqp_error)?
+            .name()
+            .as_str()
+            .to_string();
+
+        Ok(Self {
+            _connection: connection,
+            channel,
+            config,
+            reply_queue,
+        })
+    }
+}
+
+impl std::fmt::Debug for AmqpTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AmqpTransport")
+            .field("config", &self.config)
+            .field("reply_queue", &self.reply_queue)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskerTransport for AmqpTransport {
+    async fn publish(&self, request: &DeferredRequest) -> StorytellerResult<()> {
+        let body = serde_json::to_vec(request)?;
+        let properties = BasicProperties::default()
+            .with_content_type("application/json".into())
+            .with_correlation_id(request.correlation_id.to_string().into())
+            .with_reply_to(self.reply_queue.as_str().into())
+            .with_delivery_mode(2);
+
+        self.channel
+            .basic_publish(
+                "",
+                self.config.request_queue.as_str(),
+                BasicPublishOptions::default(),
+                &body,
+                properties,
+            )
+            .await
+            .map_err(amqp_error)?
+            .await
+            .map_err(amqp_error)?;
+        Ok(())
+    }
+
+    async fn consume(
+        &self,
+        results: Sender<DeferredResult>,
+        is_pending: &(dyn Fn(CorrelationId) -> bool + Send + Sync),
+    ) -> StorytellerResult<()> {
+        let consumer_tag = format!("storyteller-deferred-{}", Uuid::now_v7());
+        let mut consumer = self
+            .channel
+            .basic_consume(
+                &self.reply_queue,
+                &consumer_tag,
+                BasicConsumeOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .map_err(amqp_error)?;
+
+        while let Some(delivery) = consumer.next().await {
+            let delivery = delivery.map_err(amqp_error)?;
+            let result = match serde_json::from_slice::<DeferredResult>(&delivery.data) {
+                Ok(result) if is_pending(result.correlation_id) => result,
+                Ok(result) => {
+                    // The reply queue is ours alone, so requeueing would only
+                    // hand it back to us. Reject it — a dead-letter policy on
+                    // the broker keeps it for inspection.
+                    tracing::warn!(
+                        correlation_id = %result.correlation_id,
+                        "tasker: rejecting result with unknown correlation id"
+                    );
+                    reject(&delivery).await?;
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("tasker: rejecting malformed deferred result: {e}");
+                    reject(&delivery).await?;
+                    continue;
+                }
+            };
+            if results.send(result).is_err() {
+                // Receiver gone — the session ended. Requeue so the result
+                // is not acked unseen.
+                delivery
+                    .nack(BasicNackOptions {
+                        requeue: true,
+                        ..BasicNackOptions::default()
+                    })
+                    .await
+                    .map_err(amqp_error)?;
+                return Ok(());
+            }
+            delivery
+                .ack(BasicAckOptions::default())
+                .await
+                .map_err(amqp_error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Negatively acknowledge a delivery without requeueing it.
+async fn reject(delivery: &lapin::message::Delivery) -> StorytellerResult<()> {
+    delivery
+        .nack(BasicNackOptions::default())
+        .await
+        .map_err(amqp_error)?;
+    Ok(())
+}
+
+/// In-process transport for tests and single-process deployments.
+///
+/// Create with [`ChannelTransport::pair`]; the [`ChannelWorker`] half plays
+/// tasker-core.
+#[derive(Debug)]
+pub struct ChannelTransport {
+    requests: tokio::sync::mpsc::UnboundedSender<DeferredRequest>,
+    results: tokio::sync::Mutex<tokio::sync::mpsc::UnboundedReceiver<DeferredResult>>,
+}
+
+/// The tasker-core side of a [`ChannelTransport`].
+#[derive(Debug)]
+pub struct ChannelWorker {
+    requests: tokio::sync::mpsc::UnboundedReceiver<DeferredRequest>,
+    results: tokio::sync::mpsc::UnboundedSender<DeferredResult>,
+}
+
+impl ChannelTransport {
+    /// A connected transport and worker.
+    pub fn pair() -> (Self, ChannelWorker) {
+        let (request_tx, request_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (result_tx, result_rx) = tokio::sync::mpsc::unbounded_channel();
+        (
+            Self {
+                requests: request_tx,
+                results: tokio::sync::Mutex::new(result_rx),
+            },
+            ChannelWorker {
+                requests: request_rx,
+                results: result_tx,
+            },
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskerTransport for ChannelTransport {
+    async fn publish(&self, request: &DeferredRequest) -> StorytellerResult<()> {
+        self.requests
+            .send(request.clone())
+            .map_err(|_| StorytellerError::Messaging("channel worker dropped".to_string()))
+    }
+
+    async fn consume(
+        &self,
+        results: Sender<DeferredResult>,
+        is_pending: &(dyn Fn(CorrelationId) -> bool + Send + Sync),
+    ) -> StorytellerResult<()> {
+        let mut inbox = self.results.lock().await;
+        while let Some(result) = inbox.recv().await {
+            if !is_pending(result.correlation_id) {
+                tracing::warn!(
+                    correlation_id = %result.correlation_id,
+                    "tasker: dropping result with unknown correlation id"
+                );
+                continue;
+            }
+            if results.send(result).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ChannelWorker {
+    /// The next published request, or `None` once the transport is dropped.
+    pub async fn recv(&mut self) -> Option<DeferredRequest> {
+        self.requests.recv().await
+    }
+
+    /// A published request if one is waiting.
+    pub fn try_recv(&mut self) -> Option<DeferredRequest> {
+        self.requests.try_recv().ok()
+    }
+
+    /// Reply to a request.
+    pub fn respond(&self, result: DeferredResult) -> StorytellerResult<()> {
+        self.results
+            .send(result)
+            .map_err(|_| StorytellerError::Messaging("channel transport dropped".to_string()))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Dispatcher
+// ---------------------------------------------------------------------------
+
+/// Dispatches deferred events and matches results to what was sent.
+#[derive(Debug)]
+pub struct TaskerDispatcher {
+    transport: Arc<dyn TaskerTransport>,
+    pending: Mutex<HashMap<CorrelationId, DeferredRequest>>,
+}
+
+impl TaskerDispatcher {
+    pub fn new(transport: Arc<dyn TaskerTransport>) -> Self {
+        Self {
+            transport,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The transport requests go out on.
+    pub fn transport(&self) -> Arc<dyn TaskerTransport> {
+        Arc::clone(&self.transport)
+    }
+
+    /// Stamp and record a request without publishing it.
+    ///
+    /// Returns `None` for events that are not `Deferred` priority. Recording
+    /// before publishing means a fast result can never beat its own
+    /// registration.
+    pub fn prepare(&self, turn_number: u32, event: &NarrativeEvent) -> Option<DeferredRequest> {
+        if event.priority != EventPriority::Deferred {
+            return None;
+        }
+        let request = DeferredRequest {
+            correlation_id: CorrelationId::new(),
+            turn_number,
+            event: event.clone(),
+            dispatched_at: Utc::now(),
+        };
+        self.pending
+            .lock()
+            .expect("pending lock poisoned")
+            .insert(request.correlation_id, request.clone());
+        Some(request)
+    }
+
+    /// Publish a prepared request. On failure the request is forgotten.
+    pub async fn publish(&self, request: &DeferredRequest) -> StorytellerResult<()> {
+        let published = self.transport.publish(request).await;
+        if published.is_err() {
+            self.pending
+                .lock()
+                .expect("pending lock poisoned")
+                .remove(&request.correlation_id);
+        }
+        published
+    }
+
+    /// Prepare and publish. `Ok(None)` when the event is not deferred.
+    pub async fn dispatch(
+        &self,
+        turn_number: u32,
+        event: &NarrativeEvent,
+    ) -> StorytellerResult<Option<CorrelationId>> {
+        let Some(request) = self.prepare(turn_number, event) else {
+            return Ok(None);
+        };
+        self.publish(&request).await?;
+        Ok(Some(request.correlation_id))
+    }
+
+    /// Whether a request with this correlation id is awaiting its result.
+    pub fn is_pending(&self, correlation_id: CorrelationId) -> bool {
+        self.pending
+            .lock()
+            .expect("pending lock poisoned")
+            .contains_key(&correlation_id)
+    }
+
+    /// Match an arriving result to its request, consuming the pending entry.
+    ///
+    /// Returns `None` for unknown correlation ids — duplicates, or results
+    /// for requests from an earlier process.
+    pub fn accept(&self, result: &DeferredResult) -> Option<DeferredRequest> {
+        self.pending
+            .lock()
+            .expect("pending lock poisoned")
+            .remove(&result.correlation_id)
+    }
+
+    /// Requests still awaiting a result.
+    pub fn pending_count(&self) -> usize {
+        self.pending.lock().expect("pending lock poisoned").len()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bevy integration
+// ---------------------------------------------------------------------------
+
+/// Bevy Resource: the dispatcher and the inbox its transport feeds.
+///
+/// Optional — without it, deferred events are not dispatched and the
+/// tasker systems do nothing.
+#[derive(Resource)]
+pub struct TaskerResource {
+    /// Shared with the async tasks that publish requests.
+    pub dispatcher: Arc<TaskerDispatcher>,
+    /// Results forwarded by [`TaskerTransport::consume`].
+    pub inbox: Receiver<DeferredResult>,
+    /// Last turn whose deferred events were dispatched.
+    pub last_dispatched_turn: u32,
+}
+
+impl TaskerResource {
+    /// Wire a dispatcher to a fresh inbox and start consuming results.
+    pub fn start(dispatcher: Arc<TaskerDispatcher>, runtime: &tokio::runtime::Handle) -> Self {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        let transport = dispatcher.transport();
+        let pending = Arc::clone(&dispatcher);
+        runtime.spawn(async move {
+            let is_pending = move |id| pending.is_pending(id);
+            if let Err(e) = transport.consume(tx, &is_pending).await {
+                tracing::error!("tasker: result consumer stopped: {e}");
+            }
+        });
+        Self {
+            dispatcher,
+            inbox: rx,
+            last_dispatched_turn: 0,
+        }
+    }
+}
+
+impl std::fmt::Debug for TaskerResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskerResource")
+            .field("pending", &self.dispatcher.pending_count())
+            .field("last_dispatched_turn", &self.last_dispatched_turn)
+            .finish()
+    }
+}
+
+/// Merge arrived deferred results into the truth set.
+///
+/// Runs at the turn boundary — the `CommittingPrevious` stage, before the
+/// previous turn is archived — so results never change the truth set while
+/// a turn is being processed. With a [`LedgerResource`], each result is
+/// ledgered first and only merged once recorded, so replaying the ledger
+/// reproduces the live truth set.
+pub fn merge_deferred_results_system(
+    tasker: Option<Res<TaskerResource>>,
+    truth: Option<ResMut<TruthSetResource>>,
+    ledger: Option<Res<LedgerResource>>,
+    runtime: Option<Res<TokioRuntime>>,
+) {
+    let (Some(tasker), Some(mut truth)) = (tasker, truth) else {
+        return;
+    };
+
+    for result in tasker.inbox.try_iter() {
+        let Some(request) = tasker.dispatcher.accept(&result) else {
+            tracing::debug!(
+                correlation_id = %result.correlation_id,
+                "tasker: ignoring result with unknown correlation id"
+            );
+            continue;
+        };
+        if let Some(error) = &result.error {
+            tracing::warn!(
+                correlation_id = %result.correlation_id,
+                turn_number = request.turn_number,
+                "tasker: deferred workflow failed: {error}"
+            );
+            continue;
+        }
+        if let (Some(ledger), Some(runtime)) = (&ledger, &runtime) {
+            let recorded = runtime.0.block_on(ledger.ledger.record_deferred_result(
+                &ledger.session_id,
+                request.turn_number,
+                result.correlation_id.0,
+                &result.events,
+            ));
+            if let Err(e) = recorded {
+                tracing::error!(
+                    correlation_id = %result.correlation_id,
+                    "tasker: failed to ledger deferred result; not merged: {e}"
+                );
+                continue;
+            }
+        }
+        let merged = truth.0.merge_committed(result.events);
+        tracing::debug!(
+            correlation_id = %result.correlation_id,
+            turn_number = request.turn_number,
+            merged,
+            "tasker: merged deferred result"
+        );
+    }
+}
+
+/// Dispatch the just-committed turn's `Deferred` atoms to tasker-core.
+///
+/// Idempotent per turn: tracks the last dispatched turn number. Publishing
+/// is spawned on the Tokio runtime; requests are registered before the
+/// spawn so results cannot race them.
+pub fn dispatch_deferred_system(
+    tasker: Option<ResMut<TaskerResource>>,
+    history: Res<TurnHistory>,
+    runtime: Option<Res<TokioRuntime>>,
+) {
+    let (Some(mut tasker), Some(runtime)) = (tasker, runtime) else {
+        return;
+    };
+    let Some(turn) = history.turns.last() else {
+        return;
+    };
+    if turn.turn_number <= tasker.last_dispatched_turn {
+        return;
+    }
+    tasker.last_dispatched_turn = turn.turn_number;
+
+    let requests: Vec<DeferredRequest> = turn
+        .committed_atoms
+        .iter()
+        .filter(|atom| atom.priority == EventPriority::Deferred)
+        .filter_map(|atom| {
+            let event = NarrativeEvent {
+                id: atom.id,
+                timestamp: atom.timestamp,
+                priority: atom.priority,
+                payload: EventPayload::Atom(atom.clone()),
+            };
+            tasker.dispatcher.prepare(turn.turn_number, &event)
+        })
+        .collect();
+    if requests.is_empty() {
+        return;
+    }
+
+    tracing::debug!(
+        turn_number = turn.turn_number,
+        count = requests.len(),
+        "tasker: dispatching deferred events"
+    );
+    let dispatcher = Arc::clone(&tasker.dispatcher);
+    runtime.0.spawn(async move {
+        for request in requests {
+            if let Err(e) = dispatcher.publish(&request).await {
+                tracing::warn!(
+                    correlation_id = %request.correlation_id,
+                    "tasker: failed to publish deferred request: {e}"
+                );
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::prelude::*;
+    use storyteller_core::types::event::EventId;
+
+    fn event(priority: EventPriority) -> NarrativeEvent {
+        NarrativeEvent {
+            id: EventId::new(),
+            timestamp: Utc::now(),
+            priority,
+            payload: EventPayload::Untyped(serde_json::json!({"kind": "test"})),
+        }
+    }
+
+    fn reply(request: &DeferredRequest, events: Vec<NarrativeEvent>) -> DeferredResult {
+        DeferredResult {
+            correlation_id: request.correlation_id,
+            events,
+            error: None,
+            completed_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn only_deferred_events_are_dispatched() {
+        let (transport, mut worker) = ChannelTransport::pair();
+        let dispatcher = TaskerDispatcher::new(Arc::new(transport));
+
+        assert!(dispatcher
+            .dispatch(1, &event(EventPriority::Normal))
+            .await
+            .unwrap()
+            .is_none());
+        let id = dispatcher
+            .dispatch(1, &event(EventPriority::Deferred))
+            .await
+            .unwrap()
+            .expect("deferred event dispatched");
+
+        let request = worker.recv().await.unwrap();
+        assert_eq!(request.correlation_id, id);
+        assert_eq!(request.turn_number, 1);
+        assert!(worker.try_recv().is_none());
+        assert_eq!(dispatcher.pending_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn results_round_trip_with_correlation() {
+        let (transport, mut worker) = ChannelTransport::pair();
+        let dispatcher = TaskerDispatcher::new(Arc::new(transport));
+        dispatcher
+            .dispatch(3, &event(EventPriority::Deferred))
+            .await
+            .unwrap();
+
+        let request = worker.recv().await.unwrap();
+        let reaction = event(EventPriority::Normal);
+        worker
+            .respond(reply(&request, vec![reaction.clone()]))
+            .unwrap();
+        drop(worker);
+
+        let (tx, rx) = crossbeam::channel::unbounded();
+        dispatcher
+            .transport()
+            .consume(tx, &|id| dispatcher.is_pending(id))
+            .await
+            .unwrap();
+        let result = rx.try_recv().unwrap();
+
+        let matched = dispatcher.accept(&result).expect("known correlation id");
+        assert_eq!(matched.turn_number, 3);
+        assert_eq!(result.events[0].id, reaction.id);
+        assert!(dispatcher.accept(&result).is_none(), "accepted once");
+    }
+
+    #[tokio::test]
+    async fn consume_drops_results_nobody_is_waiting_for() {
+        let (transport, mut worker) = ChannelTransport::pair();
+        let dispatcher = TaskerDispatcher::new(Arc::new(transport));
+        dispatcher
+            .dispatch(1, &event(EventPriority::Deferred))
+            .await
+            .unwrap();
+        let request = worker.recv().await.unwrap();
+
+        worker
+            .respond(DeferredResult {
+                correlation_id: CorrelationId::new(),
+                events: vec![event(EventPriority::Normal)],
+                error: None,
+                completed_at: Utc::now(),
+            })
+            .unwrap();
+        worker.respond(reply(&request, vec![])).unwrap();
+        drop(worker);
+
+        let (tx, rx) = crossbeam::channel::unbounded();
+        dispatcher
+            .transport()
+            .consume(tx, &|id| dispatcher.is_pending(id))
+            .await
+            .unwrap();
+        let forwarded: Vec<_> = rx.try_iter().collect();
+        assert_eq!(forwarded.len(), 1);
+        assert_eq!(forwarded[0].correlation_id, request.correlation_id);
+    }
+
+    #[tokio::test]
+    async fn failed_publish_forgets_the_request() {
+        let (transport, worker) = ChannelTransport::pair();
+        drop(worker);
+        let dispatcher = TaskerDispatcher::new(Arc::new(transport));
+        assert!(dispatcher
+            .dispatch(1, &event(EventPriority::Deferred))
+            .await
+            .is_err());
+        assert_eq!(dispatcher.pending_count(), 0);
+    }
+
+    #[test]
+    fn merge_system_commits_known_results_at_turn_boundary() {
+        let (transport, _worker) = ChannelTransport::pair();
+        let dispatcher = Arc::new(TaskerDispatcher::new(Arc::new(transport)));
+        let request = dispatcher
+            .prepare(2, &event(EventPriority::Deferred))
+            .unwrap();
+
+        let (tx, rx) = crossbeam::channel::unbounded();
+        let reaction = event(EventPriority::Normal);
+        tx.send(reply(&request, vec![reaction.clone()])).unwrap();
+        // A stray result for a request this process never made.
+        tx.send(DeferredResult {
+            correlation_id: CorrelationId::new(),
+            events: vec![event(EventPriority::Normal)],
+            error: None,
+            completed_at: Utc::now(),
+        })
+        .unwrap();
+
+        let mut app = App::new();
+        app.insert_resource(TaskerResource {
+            dispatcher,
+            inbox: rx,
+            last_dispatched_turn: 0,
+        })
+        .init_resource::<TruthSetResource>()
+        .add_systems(Update, merge_deferred_results_system);
+        app.update();
+
+        let truth = &app.world().resource::<TruthSetResource>().0;
+        assert_eq!(truth.len(), 1);
+        assert_eq!(truth.events[0].id, reaction.id);
+    }
+
+    #[test]
+    fn merged_results_are_ledgered_for_replay() {
+        use storyteller_core::database::checkpoint::{replay, TruthSet};
+        use storyteller_core::database::ledger::{EventLedger, InMemoryLedger};
+        use storyteller_core::traits::storykeeper::SessionId;
+
+        let (transport, _worker) = ChannelTransport::pair();
+        let dispatcher = Arc::new(TaskerDispatcher::new(Arc::new(transport)));
+        let request = dispatcher
+            .prepare(2, &event(EventPriority::Deferred))
+            .unwrap();
+        let (tx, rx) = crossbeam::channel::unbounded();
+        tx.send(reply(&request, vec![event(EventPriority::Normal)]))
+            .unwrap();
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let ledger = Arc::new(InMemoryLedger::new());
+        let session_id = SessionId::new();
+
+        let mut app = App::new();
+        app.insert_resource(TaskerResource {
+            dispatcher,
+            inbox: rx,
+            last_dispatched_turn: 0,
+        })
+        .insert_resource(LedgerResource {
+            ledger: Arc::clone(&ledger) as Arc<dyn EventLedger>,
+            session_id,
+        })
+        .insert_resource(TokioRuntime(runtime.handle().clone()))
+        .init_resource::<TruthSetResource>()
+        .add_systems(Update, merge_deferred_results_system);
+        app.update();
+
+        let entries = runtime
+            .block_on(ledger.entries_since(&session_id, None))
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].turn_number, 2);
+        let mut replayed = TruthSet::default();
+        replay(&mut replayed, &entries).unwrap();
+
+        let live = &app.world().resource::<TruthSetResource>().0;
+        assert_eq!(live.len(), 1);
+        assert_eq!(replayed.events[0].id, live.events[0].id);
+    }
+
+    #[test]
+    fn tasker_systems_are_inert_without_resources() {
+        let mut app = App::new();
+        app.init_resource::<TurnHistory>().add_systems(
+            Update,
+            (merge_deferred_results_system, dispatch_deferred_system),
+        );
+        app.update();
+    }
+}
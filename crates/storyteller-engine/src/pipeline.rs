@@ -0,0 +1,850 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Turn stage library — one implementation of each turn stage, shared by
+//! every driver of the turn pipeline.
+//!
+//! See: `docs/technical/turn-cycle-architecture.md`
+//!
+//! The gRPC `submit_input` handler and the Bevy turn-cycle systems both run
+//! decomposition, prediction, arbitration, resolution, intent synthesis, and
+//! context assembly. Each stage lives here exactly once: it takes the turn's
+//! inputs, does the work, and returns a stage value carrying its output and
+//! timing. Drivers own sequencing and side effects — the server emits an
+//! `EngineEvent` per stage and persists what it needs, the Bevy systems write
+//! into `TurnContext` — so a fix to a stage lands in both paths.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use chrono::Utc;
+use storyteller_core::game_design::GenreRules;
+use storyteller_core::grammars::GrammarRegistry;
+use storyteller_core::traits::llm::LlmProvider;
+use storyteller_core::traits::phase_observer::{PhaseEvent, PhaseEventDetail, PhaseObserver};
+use storyteller_core::traits::structured_llm::{StructuredLlmProvider, StructuredRequest};
+use storyteller_core::traits::GameDesignSystem;
+use storyteller_core::types::character::{CharacterSheet, SceneData};
+use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::knowledge::KnowledgeModel;
+use storyteller_core::types::narrative_layer::LayerFrame;
+use storyteller_core::types::narrator_context::{NarratorContextInput, SceneJournal};
+use storyteller_core::types::prediction::{CharacterPrediction, EmotionalRegister, EventType};
+use storyteller_core::types::resolver::ResolverOutput;
+use storyteller_core::types::turn_cycle::TurnCycleStage;
+use storyteller_core::types::world_model::{ActionPossibility, WorldModel};
+use storyteller_ml::feature_schema::{EventFeatureInput, HistoryEntry};
+
+use crate::context::prediction::{decomposition_to_event_features, predict_character_behaviors};
+use crate::context::{assemble_narrator_context, DEFAULT_TOTAL_TOKEN_BUDGET};
+use crate::inference::action_adjudication::{adjudicate_action, Adjudication};
+use crate::inference::event_classifier::ClassificationOutput;
+use crate::inference::event_decomposition::{
+    event_decomposition_schema, event_decomposition_system_prompt, EventDecomposition,
+};
+use crate::inference::frame::CharacterPredictor;
+use crate::inference::intent_synthesis::synthesize_intents;
+use crate::inference::intention_generation::{intentions_to_preamble, GeneratedIntentions};
+use crate::systems::arbitration::check_action_possibility;
+
+/// Everything a turn's stages read about the scene and the player's move.
+#[derive(Debug, Clone, Copy)]
+pub struct TurnInputs<'a> {
+    /// The scene being played.
+    pub scene: &'a SceneData,
+    /// The scene's cast.
+    pub characters: &'a [&'a CharacterSheet],
+    /// The scene journal as of the previous turn.
+    pub journal: &'a SceneJournal,
+    /// The turn being played; 0 for the scene opening.
+    pub turn_number: u32,
+    /// The player's raw input for this turn.
+    pub player_input: &'a str,
+    /// The player's character, if known.
+    pub player_entity_id: Option<EntityId>,
+    /// Composition-time goal intentions, if the scene was composed with them.
+    pub intentions: Option<&'a GeneratedIntentions>,
+    /// Who knows what — retrieval and intent synthesis filter through it.
+    pub knowledge: &'a KnowledgeModel,
+    /// The nested narrative layer being played; `None` in the main narrative.
+    pub narrative_layer: Option<&'a LayerFrame>,
+}
+
+fn elapsed_ms(start: Instant) -> u64 {
+    start.elapsed().as_millis() as u64
+}
+
+// ---------------------------------------------------------------------------
+// Decomposition
+// ---------------------------------------------------------------------------
+
+/// Output of the event decomposition stage.
+#[derive(Debug, Clone, Default)]
+pub struct DecompositionStage {
+    /// Parsed decomposition, when the LLM produced usable output.
+    pub decomposition: Option<EventDecomposition>,
+    /// The LLM's raw JSON response, when it produced any.
+    pub raw: Option<serde_json::Value>,
+    /// Whether a structured LLM was available to ask.
+    pub attempted: bool,
+    /// Wall-clock time spent in the stage.
+    pub timing_ms: u64,
+}
+
+impl DecompositionStage {
+    /// JSON to persist for the stage — the raw response, or a skip marker.
+    pub fn persisted_json(&self) -> serde_json::Value {
+        self.raw
+            .clone()
+            .unwrap_or_else(|| serde_json::json!({"status": "skipped"}))
+    }
+
+    /// Error to report, when decomposition was attempted and failed.
+    pub fn error(&self) -> Option<String> {
+        (self.attempted && self.decomposition.is_none())
+            .then(|| "Decomposition failed or produced unparseable output".to_string())
+    }
+
+    /// The decomposition as classifier output, for event atoms.
+    pub fn classification(&self) -> Option<ClassificationOutput> {
+        self.decomposition
+            .as_ref()
+            .map(EventDecomposition::to_classification_output)
+    }
+
+    /// ML event features for prediction, falling back to a neutral
+    /// interaction aimed at the rest of the cast.
+    pub fn event_features(&self, cast_size: usize) -> EventFeatureInput {
+        match self.decomposition {
+            Some(ref decomposition) => decomposition_to_event_features(decomposition),
+            None => EventFeatureInput {
+                event_type: EventType::Interaction,
+                emotional_register: EmotionalRegister::Neutral,
+                confidence: 0.5,
+                target_count: cast_size.saturating_sub(1) as u8,
+            },
+        }
+    }
+}
+
+/// Decompose the player's input into events and entities.
+///
+/// The last journal entry is prepended as narrator context so the model can
+/// resolve pronouns. Without a structured LLM the stage is skipped.
+pub async fn decompose(
+    turn: &TurnInputs<'_>,
+    structured_llm: Option<&dyn StructuredLlmProvider>,
+) -> DecompositionStage {
+    let start = Instant::now();
+    let Some(structured_llm) = structured_llm else {
+        return DecompositionStage::default();
+    };
+
+    let input = match turn.journal.entries.last() {
+        Some(last_entry) => format!(
+            "[Narrator]\n{}\n\n[Player]\n{}",
+            last_entry.content, turn.player_input
+        ),
+        None => turn.player_input.to_string(),
+    };
+    let request = StructuredRequest {
+        system: event_decomposition_system_prompt(),
+        input,
+        output_schema: event_decomposition_schema(),
+        temperature: 0.1,
+    };
+
+    let mut stage = DecompositionStage {
+        attempted: true,
+        ..DecompositionStage::default()
+    };
+    match structured_llm.extract(request).await {
+        Ok(raw_json) => {
+            tracing::debug!(
+                raw = %serde_json::to_string(&raw_json).unwrap_or_default(),
+                "Event decomposition raw response"
+            );
+            stage.decomposition = EventDecomposition::from_json(&raw_json).ok();
+            stage.raw = Some(raw_json);
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Event decomposition LLM call failed");
+        }
+    }
+    stage.timing_ms = elapsed_ms(start);
+    stage
+}
+
+// ---------------------------------------------------------------------------
+// Prediction
+// ---------------------------------------------------------------------------
+
+/// Output of the ML prediction stage.
+#[derive(Debug, Clone, Default)]
+pub struct PredictionStage {
+    /// One prediction per cast member, empty without a predictor.
+    pub predictions: Vec<CharacterPrediction>,
+    /// Whether the ML predictor was available.
+    pub model_loaded: bool,
+    /// Wall-clock time spent in the stage.
+    pub timing_ms: u64,
+}
+
+/// Predict character behavior for the whole cast.
+pub fn predict(
+    turn: &TurnInputs<'_>,
+    predictor: Option<&CharacterPredictor>,
+    grammars: &GrammarRegistry,
+    event_features: EventFeatureInput,
+    history: &HashMap<EntityId, Vec<HistoryEntry>>,
+) -> PredictionStage {
+    let start = Instant::now();
+    let Some(predictor) = predictor else {
+        return PredictionStage::default();
+    };
+    let predictions = predict_character_behaviors(
+        predictor,
+        turn.characters,
+        turn.scene,
+        turn.player_input,
+        grammars,
+        event_features,
+        history,
+    );
+    PredictionStage {
+        predictions,
+        model_loaded: true,
+        timing_ms: elapsed_ms(start),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resolution
+// ---------------------------------------------------------------------------
+
+/// The world model the game design system resolves against.
+///
+/// Composed scenes carry no typed environmental constraints or spatial zones
+/// yet, so the hard constraints ride along as genre physics and resolution
+/// runs unpenalized.
+pub fn scene_world_model(scene: &SceneData) -> WorldModel {
+    WorldModel {
+        genre_physics: scene.constraints.hard.clone(),
+        spatial_zones: vec![],
+        environmental_constraints: vec![],
+    }
+}
+
+/// Resolve predictions through a game design system.
+///
+/// Runs after arbitration: when `verdict` rules the player's action
+/// impossible nothing is resolved, so the attempt produces no outcomes or
+/// state changes. On that, or on resolution failure, the predictions pass
+/// through unresolved, so the narrator still has them.
+pub fn resolve(
+    system: &dyn GameDesignSystem,
+    prediction: PredictionStage,
+    world_model: &WorldModel,
+    verdict: Option<&ActionPossibility>,
+) -> ResolverOutput {
+    let scene_dynamics = if prediction.model_loaded {
+        "ML-predicted character behavior"
+    } else {
+        "Character behavior (ML predictor not available)"
+    };
+    let mut output = ResolverOutput {
+        sequenced_actions: vec![],
+        original_predictions: prediction.predictions,
+        scene_dynamics: scene_dynamics.to_string(),
+        conflicts: vec![],
+        intent_statements: None,
+    };
+
+    if let Some(ActionPossibility::Impossible { reason }) = verdict {
+        output.scene_dynamics = format!(
+            "{scene_dynamics}. The player's action cannot succeed ({}); nothing was resolved",
+            reason.description
+        );
+        return output;
+    }
+
+    match system.resolve(&output.original_predictions, world_model) {
+        Ok(resolved) => {
+            output.sequenced_actions = resolved.sequenced_actions;
+            output.conflicts = resolved.conflicts;
+            output.scene_dynamics = format!("{scene_dynamics}. {}", resolved.scene_dynamics);
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, system = system.name(), "Action resolution failed");
+        }
+    }
+    output
+}
+
+// ---------------------------------------------------------------------------
+// Arbitration
+// ---------------------------------------------------------------------------
+
+/// Output of the action arbitration stage.
+#[derive(Debug, Clone)]
+pub struct ArbitrationStage {
+    /// Whether the player's action is possible.
+    pub result: ActionPossibility,
+    /// The LLM adjudication that settled an `Ambiguous` verdict, if any.
+    pub adjudication: Option<Adjudication>,
+    /// Wall-clock time spent in the stage.
+    pub timing_ms: u64,
+}
+
+/// Check whether the player's action is possible under the genre's rules.
+pub fn arbitrate(player_input: &str, rules: &GenreRules) -> ArbitrationStage {
+    let start = Instant::now();
+    let result = check_action_possibility(
+        player_input,
+        &rules.constraints,
+        &rules.lexicon,
+        None, // actor_zone — will come from spatial tracking later
+    );
+    ArbitrationStage {
+        result,
+        adjudication: None,
+        timing_ms: elapsed_ms(start),
+    }
+}
+
+/// Settle an `Ambiguous` arbitration verdict with the structured LLM.
+///
+/// Conditional capabilities ("magic requires a focus") arbitrate as
+/// `Ambiguous`; the adjudicator decides from the scene state whether the
+/// conditions hold and replaces the verdict with `Permitted` or
+/// `Impossible`, emitting an `ActionAdjudicated` phase event. Decided
+/// verdicts, a missing provider, or a failed call leave the stage as is.
+pub async fn adjudicate(
+    turn: &TurnInputs<'_>,
+    mut stage: ArbitrationStage,
+    structured_llm: Option<&dyn StructuredLlmProvider>,
+    observer: &dyn PhaseObserver,
+) -> ArbitrationStage {
+    let ActionPossibility::Ambiguous {
+        ref known_constraints,
+        ref uncertainty,
+    } = stage.result
+    else {
+        return stage;
+    };
+    let Some(provider) = structured_llm else {
+        return stage;
+    };
+
+    let start = Instant::now();
+    match adjudicate_action(
+        provider,
+        turn.player_input,
+        known_constraints,
+        uncertainty,
+        &scene_state(turn),
+    )
+    .await
+    {
+        Ok(adjudication) => {
+            let (verdict, condition_count) = match adjudication.verdict {
+                ActionPossibility::Permitted { ref conditions } => ("permitted", conditions.len()),
+                _ => ("impossible", 0),
+            };
+            observer.emit(PhaseEvent {
+                timestamp: Utc::now(),
+                turn_number: turn.turn_number,
+                stage: TurnCycleStage::Enriching,
+                detail: PhaseEventDetail::ActionAdjudicated {
+                    verdict: verdict.to_string(),
+                    confidence: adjudication.confidence,
+                    condition_count,
+                    rationale: adjudication.rationale.clone(),
+                },
+            });
+            stage.result = adjudication.verdict.clone();
+            stage.adjudication = Some(adjudication);
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Action adjudication failed; verdict stays ambiguous");
+        }
+    }
+    stage.timing_ms += elapsed_ms(start);
+    stage
+}
+
+/// Plain-text scene state for the adjudicator: setting, affordances,
+/// constraints, cast, and the most recent journal entry.
+fn scene_state(turn: &TurnInputs<'_>) -> String {
+    let scene = turn.scene;
+    let mut lines = vec![
+        format!("Scene: {}", scene.title),
+        format!("Setting: {}", scene.setting.description),
+    ];
+    if !scene.setting.affordances.is_empty() {
+        lines.push(format!(
+            "Affordances: {}",
+            scene.setting.affordances.join("; ")
+        ));
+    }
+    if !scene.constraints.hard.is_empty() {
+        lines.push(format!(
+            "Hard constraints: {}",
+            scene.constraints.hard.join("; ")
+        ));
+    }
+    if !scene.constraints.soft.is_empty() {
+        lines.push(format!(
+            "Soft constraints: {}",
+            scene.constraints.soft.join("; ")
+        ));
+    }
+    let cast = scene
+        .cast
+        .iter()
+        .map(|c| format!("{} ({})", c.name, c.role))
+        .collect::<Vec<_>>();
+    if !cast.is_empty() {
+        lines.push(format!("Cast: {}", cast.join(", ")));
+    }
+    if let Some(entry) = turn.journal.entries.last() {
+        lines.push(format!("Last turn: {}", entry.content));
+    }
+    lines.join("\n")
+}
+
+// ---------------------------------------------------------------------------
+// Intent synthesis
+// ---------------------------------------------------------------------------
+
+/// Output of the intent synthesis stage.
+#[derive(Debug, Clone, Default)]
+pub struct IntentStage {
+    /// Narrator-facing intent statements, when synthesis ran and succeeded.
+    pub statements: Option<String>,
+    /// Wall-clock time spent in the stage.
+    pub timing_ms: u64,
+}
+
+/// Synthesize character intent statements for the narrator from the last
+/// two journal entries and this turn's predictions.
+pub async fn synthesize(
+    turn: &TurnInputs<'_>,
+    intent_llm: Option<&dyn LlmProvider>,
+    predictions: &[CharacterPrediction],
+) -> IntentStage {
+    let start = Instant::now();
+    let Some(intent_llm) = intent_llm else {
+        return IntentStage::default();
+    };
+
+    let journal_tail = turn
+        .journal
+        .entries
+        .iter()
+        .rev()
+        .take(2)
+        .rev()
+        .map(|e| e.content.as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let statements = synthesize_intents(
+        intent_llm,
+        turn.characters,
+        predictions,
+        &journal_tail,
+        turn.player_input,
+        turn.scene,
+        turn.player_entity_id,
+        turn.intentions,
+        turn.knowledge,
+    )
+    .await;
+    IntentStage {
+        statements,
+        timing_ms: elapsed_ms(start),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Context assembly
+// ---------------------------------------------------------------------------
+
+/// Output of the context assembly stage.
+#[derive(Debug, Clone)]
+pub struct ContextStage {
+    /// The three-tier narrator context.
+    pub context: NarratorContextInput,
+    /// Wall-clock time spent in the stage.
+    pub timing_ms: u64,
+}
+
+/// Assemble the narrator's three-tier context, with composition-time goal
+/// intentions and the arbitration verdict on the player's action injected
+/// into the preamble.
+pub fn assemble(
+    turn: &TurnInputs<'_>,
+    resolver_output: &ResolverOutput,
+    referenced_entities: &[EntityId],
+    observer: &dyn PhaseObserver,
+    directive_context: Option<&str>,
+    action_verdict: Option<&ActionPossibility>,
+) -> ContextStage {
+    let start = Instant::now();
+    let mut context = assemble_narrator_context(
+        turn.scene,
+        turn.characters,
+        turn.journal,
+        resolver_output,
+        turn.player_input,
+        referenced_entities,
+        turn.knowledge,
+        DEFAULT_TOTAL_TOKEN_BUDGET,
+        observer,
+        turn.player_entity_id,
+        directive_context,
+        action_verdict,
+    );
+    if let Some(intentions) = turn.intentions {
+        let (scene_direction, character_drives) = intentions_to_preamble(intentions);
+        context.preamble.scene_direction = Some(scene_direction);
+        context.preamble.character_drives = character_drives;
+    }
+    context.preamble.narrative_layer = turn.narrative_layer.cloned();
+    ContextStage {
+        context,
+        timing_ms: elapsed_ms(start),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use storyteller_core::game_design::GraduatedResolution;
+    use storyteller_core::traits::NoopObserver;
+    use storyteller_core::types::scene::SceneId;
+
+    fn with_turn<R>(input: &str, f: impl FnOnce(&TurnInputs<'_>) -> R) -> R {
+        let scene = crate::workshop::the_flute_kept::scene();
+        let bramblehoof = crate::workshop::the_flute_kept::bramblehoof();
+        let pyotir = crate::workshop::the_flute_kept::pyotir();
+        let characters = [&bramblehoof, &pyotir];
+        let journal = SceneJournal::new(SceneId::new(), 1200);
+        let knowledge = KnowledgeModel::from_characters(&characters);
+        let turn = TurnInputs {
+            scene: &scene,
+            characters: &characters,
+            journal: &journal,
+            turn_number: 1,
+            player_input: input,
+            player_entity_id: None,
+            intentions: None,
+            knowledge: &knowledge,
+            narrative_layer: None,
+        };
+        f(&turn)
+    }
+
+    #[test]
+    fn skipped_decomposition_falls_back_to_neutral_features() {
+        let stage = DecompositionStage::default();
+        assert!(stage.error().is_none());
+        assert_eq!(stage.persisted_json()["status"], "skipped");
+        let features = stage.event_features(3);
+        assert_eq!(features.event_type, EventType::Interaction);
+        assert_eq!(features.target_count, 2);
+
+        let failed = DecompositionStage {
+            attempted: true,
+            ..DecompositionStage::default()
+        };
+        assert!(failed.error().is_some());
+    }
+
+    #[tokio::test]
+    async fn llm_stages_skip_without_providers() {
+        let scene = crate::workshop::the_flute_kept::scene();
+        let journal = SceneJournal::new(SceneId::new(), 1200);
+        let knowledge = KnowledgeModel::new();
+        let turn = TurnInputs {
+            scene: &scene,
+            characters: &[],
+            journal: &journal,
+            turn_number: 1,
+            player_input: "I wave",
+            player_entity_id: None,
+            intentions: None,
+            knowledge: &knowledge,
+            narrative_layer: None,
+        };
+        let decomposition = decompose(&turn, None).await;
+        assert!(!decomposition.attempted);
+        let intents = synthesize(&turn, None, &[]).await;
+        assert!(intents.statements.is_none());
+    }
+
+    #[test]
+    fn arbitration_applies_genre_rules() {
+        let rules = GenreRules::derive(
+            &storyteller_core::game_design::WorldAffordances::builtin("low_fantasy_folklore")
+                .unwrap(),
+            &[],
+        );
+        assert!(arbitrate("I hurl a fireball at the wolf", &rules)
+            .result
+            .is_impossible());
+        assert!(arbitrate("I bewitch the miller's cow", &rules)
+            .result
+            .is_ambiguous());
+        assert!(arbitrate("I sit down beside the fence", &rules)
+            .result
+            .is_permitted());
+    }
+
+    /// Resolves every turn to a marked, empty outcome.
+    #[derive(Debug)]
+    struct MarkingSystem;
+
+    impl GameDesignSystem for MarkingSystem {
+        fn name(&self) -> &str {
+            "marking"
+        }
+
+        fn resolve(
+            &self,
+            predictions: &[CharacterPrediction],
+            _world_model: &WorldModel,
+        ) -> storyteller_core::errors::StorytellerResult<ResolverOutput> {
+            Ok(ResolverOutput {
+                sequenced_actions: vec![],
+                original_predictions: predictions.to_vec(),
+                scene_dynamics: "marked".to_string(),
+                conflicts: vec![],
+                intent_statements: None,
+            })
+        }
+
+        fn resolve_single(
+            &self,
+            prediction: &CharacterPrediction,
+            _world_model: &WorldModel,
+        ) -> storyteller_core::errors::StorytellerResult<
+            storyteller_core::types::resolver::ResolvedCharacterAction,
+        > {
+            Ok(storyteller_core::types::resolver::ResolvedCharacterAction {
+                character_id: prediction.character_id,
+                character_name: prediction.character_name.clone(),
+                outcomes: vec![],
+            })
+        }
+    }
+
+    #[test]
+    fn impossible_actions_are_not_resolved() {
+        let rules = GenreRules::derive(
+            &storyteller_core::game_design::WorldAffordances::builtin("low_fantasy_folklore")
+                .unwrap(),
+            &[],
+        );
+        let world = WorldModel {
+            genre_physics: vec![],
+            spatial_zones: vec![],
+            environmental_constraints: vec![],
+        };
+
+        let fireball = arbitrate("I hurl a fireball at the wolf", &rules);
+        let output = resolve(
+            &MarkingSystem,
+            PredictionStage::default(),
+            &world,
+            Some(&fireball.result),
+        );
+        assert!(output.sequenced_actions.is_empty());
+        assert!(!output.scene_dynamics.ends_with("marked"));
+        assert!(output.scene_dynamics.contains("cannot succeed"));
+
+        let sitting = arbitrate("I sit down beside the fence", &rules);
+        let output = resolve(
+            &MarkingSystem,
+            PredictionStage::default(),
+            &world,
+            Some(&sitting.result),
+        );
+        assert!(output.scene_dynamics.ends_with("marked"));
+    }
+
+    #[derive(Debug)]
+    struct CannedProvider(serde_json::Value);
+
+    #[async_trait::async_trait]
+    impl StructuredLlmProvider for CannedProvider {
+        async fn extract(
+            &self,
+            _request: StructuredRequest,
+        ) -> storyteller_core::errors::StorytellerResult<serde_json::Value> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn adjudication_settles_ambiguous_verdicts() {
+        let scene = crate::workshop::the_flute_kept::scene();
+        let journal = SceneJournal::new(SceneId::new(), 1200);
+        let knowledge = KnowledgeModel::new();
+        let turn = TurnInputs {
+            scene: &scene,
+            characters: &[],
+            journal: &journal,
+            turn_number: 3,
+            player_input: "I bewitch the miller's cow",
+            player_entity_id: None,
+            intentions: None,
+            knowledge: &knowledge,
+            narrative_layer: None,
+        };
+        let rules = GenreRules::derive(
+            &storyteller_core::game_design::WorldAffordances::builtin("low_fantasy_folklore")
+                .unwrap(),
+            &[],
+        );
+        let provider = CannedProvider(serde_json::json!({
+            "verdict": "impossible",
+            "conditions": [],
+            "reason": "Nothing here can serve as a focus.",
+            "confidence": 0.7
+        }));
+        let observer = storyteller_core::traits::phase_observer::CollectingObserver::new();
+
+        let stage = adjudicate(
+            &turn,
+            arbitrate(turn.player_input, &rules),
+            Some(&provider),
+            &observer,
+        )
+        .await;
+        assert!(stage.result.is_impossible());
+        assert!(stage.adjudication.is_some());
+        let events = observer.take_events();
+        let adjudicated = events
+            .iter()
+            .find(|e| matches!(e.detail, PhaseEventDetail::ActionAdjudicated { .. }))
+            .expect("adjudication event");
+        assert_eq!(adjudicated.turn_number, 3);
+
+        // Decided verdicts and a missing provider are left alone.
+        let permitted = adjudicate(
+            &turn,
+            arbitrate("I sit down beside the fence", &rules),
+            Some(&provider),
+            &observer,
+        )
+        .await;
+        assert!(permitted.result.is_permitted());
+        let unprovided =
+            adjudicate(&turn, arbitrate(turn.player_input, &rules), None, &observer).await;
+        assert!(unprovided.result.is_ambiguous());
+        assert!(observer.take_events().is_empty());
+    }
+
+    #[test]
+    fn stages_run_without_a_predictor() {
+        with_turn("I sit down beside the fence", |turn| {
+            let grammars = GrammarRegistry::builtin();
+            let prediction = predict(
+                turn,
+                None,
+                &grammars,
+                DecompositionStage::default().event_features(turn.characters.len()),
+                &HashMap::new(),
+            );
+            assert!(!prediction.model_loaded);
+
+            let resolver_output = resolve(
+                &GraduatedResolution::default(),
+                prediction,
+                &scene_world_model(turn.scene),
+                None,
+            );
+            assert!(resolver_output.original_predictions.is_empty());
+            assert!(resolver_output
+                .scene_dynamics
+                .starts_with("Character behavior (ML predictor not available)"));
+
+            let arbitration = arbitrate(turn.player_input, &GenreRules::default());
+            assert!(!arbitration.result.is_impossible());
+
+            let context = assemble(turn, &resolver_output, &[], &NoopObserver, None, None);
+            assert!(context.context.estimated_tokens > 0);
+            assert!(context.context.preamble.narrative_layer.is_none());
+        });
+    }
+
+    #[test]
+    fn impossible_verdict_reaches_the_narrator() {
+        use crate::context::preamble::render_preamble;
+
+        with_turn("I hurl a fireball at the wolf", |turn| {
+            let rules = GenreRules::derive(
+                &storyteller_core::game_design::WorldAffordances::builtin("low_fantasy_folklore")
+                    .unwrap(),
+                &[],
+            );
+            let arbitration = arbitrate(turn.player_input, &rules);
+            assert!(arbitration.result.is_impossible());
+            let resolver_output = resolve(
+                &GraduatedResolution::default(),
+                PredictionStage::default(),
+                &scene_world_model(turn.scene),
+                Some(&arbitration.result),
+            );
+
+            let unruled = assemble(turn, &resolver_output, &[], &NoopObserver, None, None);
+            let ruled = assemble(
+                turn,
+                &resolver_output,
+                &[],
+                &NoopObserver,
+                None,
+                Some(&arbitration.result),
+            );
+            assert!(ruled.context.preamble.action_verdict.is_some());
+            let preamble = render_preamble(&ruled.context.preamble);
+            assert!(preamble.contains("## The Player's Action"));
+            assert!(preamble.contains("Narrate it failing"));
+            assert!(!render_preamble(&unruled.context.preamble).contains("## The Player's Action"));
+            assert!(ruled.context.estimated_tokens > unruled.context.estimated_tokens);
+        });
+    }
+
+    #[test]
+    fn assembly_frames_the_narrative_layer() {
+        use storyteller_core::types::narrative_layer::{LayerKind, NarrativeLayer, NarrativeStack};
+
+        with_turn("I tell them about the winter road", |turn| {
+            let mut layers = NarrativeStack::default();
+            layers.push(
+                NarrativeLayer::new(LayerKind::Memory, "The Winter Road", turn.scene.scene_id)
+                    .with_teller("Pyotir"),
+            );
+            let frame = layers.frame();
+            let framed = TurnInputs {
+                narrative_layer: frame.as_ref(),
+                ..*turn
+            };
+            let resolver_output = ResolverOutput {
+                sequenced_actions: vec![],
+                original_predictions: vec![],
+                scene_dynamics: String::new(),
+                conflicts: vec![],
+                intent_statements: None,
+            };
+
+            let context = assemble(&framed, &resolver_output, &[], &NoopObserver, None, None);
+            let frame = context.context.preamble.narrative_layer.unwrap();
+            assert_eq!(frame.title, "The Winter Road");
+            assert_eq!(frame.teller.as_deref(), Some("Pyotir"));
+        });
+    }
+}
@@ -12,8 +12,11 @@ use bevy_ecs::prelude::IntoSystemConfigs;
 use bevy_ecs::schedule::IntoSystemSetConfigs;
 
 use crate::components::turn::{
-    ActiveTurnStage, EnrichmentState, NarratorTask, PendingInput, TurnContext, TurnHistory,
+    ActiveTurnStage, CastStateResource, EnrichmentState, EventDependenciesResource,
+    GameDesignResource, GenreRulesResource, NarratorTask, PendingInput, TruthSetResource,
+    TurnContext, TurnHistory,
 };
+use crate::messaging::tasker::{dispatch_deferred_system, merge_deferred_results_system};
 use crate::systems::rendering::rendering_system;
 use crate::systems::turn_cycle::{
     assemble_context_system, commit_previous_system, enrichment_system, in_stage, TurnCycleSets,
@@ -35,7 +38,12 @@ impl Plugin for StorytellerEnginePlugin {
             .init_resource::<NarratorTask>()
             .init_resource::<TurnHistory>()
             .init_resource::<PendingInput>()
-            .init_resource::<EnrichmentState>();
+            .init_resource::<EnrichmentState>()
+            .init_resource::<TruthSetResource>()
+            .init_resource::<CastStateResource>()
+            .init_resource::<EventDependenciesResource>()
+            .init_resource::<GameDesignResource>()
+            .init_resource::<GenreRulesResource>();
 
         // System set ordering — sequential pipeline within a single frame
         app.configure_sets(
@@ -53,9 +61,18 @@ impl Plugin for StorytellerEnginePlugin {
         app.add_systems(
             Update,
             (
+                merge_deferred_results_system
+                    .run_if(in_stage(TurnCycleStage::CommittingPrevious))
+                    .before(commit_previous_system)
+                    .in_set(TurnCycleSets::CommittingPrevious),
                 commit_previous_system
                     .run_if(in_stage(TurnCycleStage::CommittingPrevious))
                     .in_set(TurnCycleSets::CommittingPrevious),
+                // Ungated: commit_previous has already advanced the stage.
+                // Idempotent per turn, and inert without a TaskerResource.
+                dispatch_deferred_system
+                    .after(commit_previous_system)
+                    .in_set(TurnCycleSets::CommittingPrevious),
                 enrichment_system
                     .run_if(in_stage(TurnCycleStage::Enriching))
                     .in_set(TurnCycleSets::Enrichment),
@@ -165,6 +165,8 @@ mod tests {
                 scene_direction: None,
                 character_drives: Vec::new(),
                 player_context: None,
+                narrative_layer: None,
+                action_verdict: None,
             },
             journal: SceneJournal::new(SceneId::new(), 1200),
             retrieved: vec![],
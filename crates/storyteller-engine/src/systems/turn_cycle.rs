@@ -14,6 +14,10 @@
 //! - `commit_previous_system`: archives previous turn, moves PendingInput
 //! - `enrichment_system`: unified sub-pipeline (classification, prediction,
 //!   arbitration, intent synthesis) managed by EnrichmentPhase
+//!
+//! The stage work itself lives in [`crate::pipeline`], shared with the
+//! server's gRPC turn handler; these systems only move data between
+//! resources and the stage functions.
 //! - `assemble_context_system`: three-tier Narrator context assembly
 //!
 //! The async narrator rendering system lives in [`super::rendering`].
@@ -21,14 +25,26 @@
 use bevy_ecs::prelude::*;
 use bevy_ecs::schedule::SystemSet;
 
+use storyteller_core::game_design::{turn_seed, GenreRules};
 use storyteller_core::traits::NoopObserver;
+use storyteller_core::types::character::CharacterSheet;
+use storyteller_core::types::character_state::CastState;
+use storyteller_core::types::event::NarrativeEvent;
+use storyteller_core::types::event_dependency::EventDependencies;
+use storyteller_core::types::event_grammar::{EventAtom, EventPayload};
+use storyteller_core::types::knowledge::KnowledgeModel;
+use storyteller_core::types::narrator_context::SceneJournal;
 use storyteller_core::types::resolver::ResolverOutput;
+use storyteller_core::types::scene::SceneId;
 use storyteller_core::types::turn_cycle::{EnrichmentPhase, TurnCycleStage};
+use storyteller_core::types::world_model::WorldModel;
 
 use crate::components::turn::{
-    ActiveTurnStage, CompletedTurn, EnrichmentState, JournalResource, PendingInput,
-    StructuredLlmResource, TokioRuntime, TurnContext, TurnHistory,
+    ActiveTurnStage, CastStateResource, CompletedTurn, EnrichmentState, EventDependenciesResource,
+    GameDesignResource, GenreRulesResource, IntentLlmResource, JournalResource, PendingInput,
+    StructuredLlmResource, TokioRuntime, TruthSetResource, TurnContext, TurnHistory,
 };
+use crate::pipeline;
 
 // ---------------------------------------------------------------------------
 // SystemSet — declared ordering for the turn pipeline
@@ -68,55 +84,87 @@ pub fn in_stage(target: TurnCycleStage) -> impl Fn(Res<ActiveTurnStage>) -> bool
 
 /// Unified enrichment system managing the sub-pipeline internally.
 ///
-/// Loops through all `EnrichmentPhase` stages in a single Bevy update:
-/// - EventClassification: pass-through (decomposition at server layer via 3b-instruct)
+/// Loops through all `EnrichmentPhase` stages in a single Bevy update,
+/// running each through the shared [`crate::pipeline`] stage functions the
+/// server also uses:
+/// - EventClassification: LLM event decomposition (needs a structured LLM and runtime)
 /// - BehaviorPrediction: ML character prediction (parallel across cast)
-/// - GameSystemArbitration: action arbitration + wrap predictions into ResolverOutput
-/// - IntentSynthesis: pass-through placeholder
+/// - GameSystemArbitration: action arbitration (LLM adjudication for ambiguous verdicts)
+///   + game design resolution into ResolverOutput
+/// - IntentSynthesis: NPC intent statements (needs an intent LLM and runtime)
 /// - Complete: reset EnrichmentState, advance top-level stage to AssemblingContext
+///
+/// Stages whose resources are missing are skipped, never failed.
+#[allow(clippy::too_many_arguments)]
 pub fn enrichment_system(
     mut stage: ResMut<ActiveTurnStage>,
     mut enrichment: ResMut<EnrichmentState>,
     mut turn_ctx: ResMut<TurnContext>,
+    history: Res<TurnHistory>,
     predictor: Option<Res<PredictorResource>>,
     scene_res: Option<Res<SceneResource>>,
     grammar_res: Option<Res<GrammarResource>>,
+    journal_res: Option<Res<JournalResource>>,
+    game_design: Option<Res<GameDesignResource>>,
+    genre_rules: Option<Res<GenreRulesResource>>,
+    structured_llm: Option<Res<StructuredLlmResource>>,
+    intent_llm: Option<Res<IntentLlmResource>>,
+    runtime: Option<Res<TokioRuntime>>,
+    truth: Option<Res<TruthSetResource>>,
+    cast_state: Option<Res<CastStateResource>>,
 ) {
+    let player_input = turn_ctx.player_input.clone().unwrap_or_default();
+    let live_cast = scene_res
+        .as_ref()
+        .map(|s| live_characters(s, cast_state.as_deref()))
+        .unwrap_or_default();
+    let characters: Vec<&CharacterSheet> = live_cast.iter().collect();
+    let empty_journal = SceneJournal::new(SceneId::new(), 1200);
+    let journal = journal_res.as_ref().map(|j| &j.0).unwrap_or(&empty_journal);
+    let knowledge = replay_knowledge(&characters, truth.as_deref());
+    let turn = scene_res.as_ref().map(|scene_res| pipeline::TurnInputs {
+        scene: &scene_res.scene,
+        characters: &characters,
+        journal,
+        turn_number: history.next_turn_number(),
+        player_input: &player_input,
+        player_entity_id: None, // Bevy path doesn't track the player entity yet
+        intentions: None,
+        knowledge: &knowledge,
+        narrative_layer: None,
+    });
+    let mut decomposition = pipeline::DecompositionStage::default();
+
     loop {
         match enrichment.0 {
             EnrichmentPhase::EventClassification => {
-                // Pass-through: event decomposition happens at server layer via
-                // structured 3b-instruct LLM, not in the Bevy pipeline.
-                // Future: may migrate decomposition here.
-                tracing::debug!("enrichment_system: EventClassification (pass-through)");
+                if let (Some(turn), Some(slm), Some(rt)) = (&turn, &structured_llm, &runtime) {
+                    decomposition =
+                        rt.0.block_on(pipeline::decompose(turn, Some(slm.0.as_ref())));
+                    turn_ctx.classification = decomposition.classification();
+                    tracing::debug!(
+                        decomposed = decomposition.decomposition.is_some(),
+                        "enrichment_system: event decomposition"
+                    );
+                } else {
+                    tracing::debug!(
+                        "enrichment_system: EventClassification skipped (missing resources)"
+                    );
+                }
                 enrichment.0 = enrichment.0.next();
             }
             EnrichmentPhase::BehaviorPrediction => {
-                // ML character prediction (logic from former predict_system)
-                if let (Some(ref predictor), Some(ref scene_res), Some(ref grammar_res)) =
-                    (&predictor, &scene_res, &grammar_res)
+                if let (Some(turn), Some(ref predictor), Some(ref grammar_res)) =
+                    (&turn, &predictor, &grammar_res)
                 {
-                    let input = turn_ctx.player_input.as_deref().unwrap_or("");
-                    let characters: Vec<&storyteller_core::types::character::CharacterSheet> =
-                        scene_res.characters.iter().collect();
-
-                    let event_features = storyteller_ml::feature_schema::EventFeatureInput {
-                        event_type: storyteller_core::types::prediction::EventType::Interaction,
-                        emotional_register:
-                            storyteller_core::types::prediction::EmotionalRegister::Neutral,
-                        confidence: 0.5,
-                        target_count: characters.len().saturating_sub(1) as u8,
-                    };
-                    let predictions = crate::context::prediction::predict_character_behaviors(
-                        &predictor.0,
-                        &characters,
-                        &scene_res.scene,
-                        input,
-                        grammar_res.0.as_ref(),
-                        event_features,
+                    let prediction = pipeline::predict(
+                        turn,
+                        Some(&predictor.0),
+                        &grammar_res.0,
+                        decomposition.event_features(turn.characters.len()),
                         &std::collections::HashMap::new(),
                     );
-                    turn_ctx.predictions = Some(predictions);
+                    turn_ctx.predictions = Some(prediction.predictions);
                 } else {
                     tracing::debug!(
                         "enrichment_system: BehaviorPrediction skipped (missing resources)"
@@ -125,45 +173,78 @@ pub fn enrichment_system(
                 enrichment.0 = enrichment.0.next();
             }
             EnrichmentPhase::GameSystemArbitration => {
-                // Action arbitration + wrap predictions into ResolverOutput
-                // (logic from former resolve_system)
                 if let Some(ref input) = turn_ctx.player_input {
-                    let result = crate::systems::arbitration::check_action_possibility(
-                        input,
-                        &[], // genre_constraints — will come from scene resource later
-                        &storyteller_core::types::capability_lexicon::CapabilityLexicon::new(),
-                        None, // actor_zone — will come from spatial tracking later
-                    );
+                    let fallback_rules = GenreRules::default();
+                    let rules = genre_rules.as_deref().map_or(&fallback_rules, |r| &r.0);
+                    let mut arbitration = pipeline::arbitrate(input, rules);
+                    if let (Some(turn), Some(slm), Some(rt)) = (&turn, &structured_llm, &runtime) {
+                        arbitration = rt.0.block_on(pipeline::adjudicate(
+                            turn,
+                            arbitration,
+                            Some(slm.0.as_ref()),
+                            &NoopObserver,
+                        ));
+                    }
 
                     tracing::debug!(
-                        permitted = result.is_permitted(),
-                        impossible = result.is_impossible(),
-                        ambiguous = result.is_ambiguous(),
+                        permitted = arbitration.result.is_permitted(),
+                        impossible = arbitration.result.is_impossible(),
+                        ambiguous = arbitration.result.is_ambiguous(),
                         "enrichment_system: action arbitration check"
                     );
 
-                    turn_ctx.arbitration = Some(result);
+                    turn_ctx.arbitration = Some(arbitration.result);
                 }
 
-                let predictions = turn_ctx.predictions.clone().unwrap_or_default();
-                let resolver_output = ResolverOutput {
-                    sequenced_actions: vec![],
-                    original_predictions: predictions,
-                    scene_dynamics: String::new(),
-                    conflicts: vec![],
-                    intent_statements: None,
+                let fallback_design = GameDesignResource::default();
+                let design = game_design.as_deref().unwrap_or(&fallback_design);
+                let system = design.registry.system_for(
+                    &design.genre_id,
+                    &live_cast,
+                    turn_seed(design.seed, history.next_turn_number()),
+                );
+                let world_model = match turn {
+                    Some(ref turn) => pipeline::scene_world_model(turn.scene),
+                    None => WorldModel {
+                        genre_physics: vec![],
+                        spatial_zones: vec![],
+                        environmental_constraints: vec![],
+                    },
+                };
+                let prediction = pipeline::PredictionStage {
+                    predictions: turn_ctx.predictions.clone().unwrap_or_default(),
+                    model_loaded: predictor.is_some(),
+                    timing_ms: 0,
                 };
-                turn_ctx.resolver_output = Some(resolver_output);
+                turn_ctx.resolver_output = Some(pipeline::resolve(
+                    system.as_ref(),
+                    prediction,
+                    &world_model,
+                    turn_ctx.arbitration.as_ref(),
+                ));
 
                 tracing::debug!(
-                    "enrichment_system: GameSystemArbitration wrapped predictions into ResolverOutput"
+                    system = system.name(),
+                    "enrichment_system: GameSystemArbitration resolved predictions"
                 );
                 enrichment.0 = enrichment.0.next();
             }
             EnrichmentPhase::IntentSynthesis => {
-                // Pass-through placeholder: intent synthesis will be implemented
-                // in a future task.
-                tracing::debug!("enrichment_system: IntentSynthesis (pass-through)");
+                if let (Some(turn), Some(llm), Some(rt)) = (&turn, &intent_llm, &runtime) {
+                    let predictions = turn_ctx.predictions.clone().unwrap_or_default();
+                    let intents = rt.0.block_on(pipeline::synthesize(
+                        turn,
+                        Some(llm.0.as_ref()),
+                        &predictions,
+                    ));
+                    if let Some(ref mut resolver_output) = turn_ctx.resolver_output {
+                        resolver_output.intent_statements = intents.statements;
+                    }
+                } else {
+                    tracing::debug!(
+                        "enrichment_system: IntentSynthesis skipped (missing resources)"
+                    );
+                }
                 enrichment.0 = enrichment.0.next();
             }
             EnrichmentPhase::Complete => {
@@ -195,17 +276,10 @@ pub struct SceneResource {
     pub characters: Vec<storyteller_core::types::character::CharacterSheet>,
 }
 
-/// Bevy Resource wrapping an emotional grammar implementation.
-#[derive(Resource)]
-pub struct GrammarResource(
-    pub Box<dyn storyteller_core::traits::emotional_grammar::EmotionalGrammar>,
-);
-
-impl std::fmt::Debug for GrammarResource {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("GrammarResource").finish()
-    }
-}
+/// Bevy Resource wrapping the emotional grammar registry. Each sheet's
+/// grammar is resolved from it by `grammar_id`.
+#[derive(Debug, Resource)]
+pub struct GrammarResource(pub storyteller_core::grammars::GrammarRegistry);
 
 /// Assemble three-tier Narrator context from scene data, journal, and resolver output.
 ///
@@ -214,8 +288,11 @@ impl std::fmt::Debug for GrammarResource {
 pub fn assemble_context_system(
     mut stage: ResMut<ActiveTurnStage>,
     mut turn_ctx: ResMut<TurnContext>,
+    history: Res<TurnHistory>,
     scene_res: Option<Res<SceneResource>>,
     journal_res: Option<Res<JournalResource>>,
+    truth: Option<Res<TruthSetResource>>,
+    cast_state: Option<Res<CastStateResource>>,
 ) {
     let Some(ref scene_res) = scene_res else {
         tracing::warn!("assemble_context_system: no SceneResource — skipping");
@@ -231,29 +308,35 @@ pub fn assemble_context_system(
         intent_statements: None,
     });
 
-    let characters: Vec<&storyteller_core::types::character::CharacterSheet> =
-        scene_res.characters.iter().collect();
+    let live_cast = live_characters(scene_res, cast_state.as_deref());
+    let characters: Vec<&CharacterSheet> = live_cast.iter().collect();
 
-    let empty_journal = storyteller_core::types::narrator_context::SceneJournal::new(
-        storyteller_core::types::scene::SceneId::new(),
-        1200,
-    );
+    let empty_journal = SceneJournal::new(SceneId::new(), 1200);
     let journal = journal_res.as_ref().map(|j| &j.0).unwrap_or(&empty_journal);
 
-    let player_input = turn_ctx.player_input.as_deref().unwrap_or("");
-
-    let context = crate::context::assemble_narrator_context(
-        &scene_res.scene,
-        &characters,
+    let player_input = turn_ctx.player_input.clone().unwrap_or_default();
+    let knowledge = replay_knowledge(&characters, truth.as_deref());
+    let turn = pipeline::TurnInputs {
+        scene: &scene_res.scene,
+        characters: &characters,
         journal,
+        turn_number: history.next_turn_number(),
+        player_input: &player_input,
+        player_entity_id: None, // Bevy system doesn't track player entity yet
+        intentions: None,
+        knowledge: &knowledge,
+        narrative_layer: None,
+    };
+
+    let context = pipeline::assemble(
+        &turn,
         &resolver_output,
-        player_input,
         &[], // referenced_entities — empty for now
-        crate::context::DEFAULT_TOTAL_TOKEN_BUDGET,
         &NoopObserver,
-        None, // player_entity_id — Bevy system doesn't track player entity yet
         None, // directive_context — Bevy path has no directive store access yet
-    );
+        turn_ctx.arbitration.as_ref(),
+    )
+    .context;
 
     tracing::debug!(
         estimated_tokens = context.estimated_tokens,
@@ -264,6 +347,29 @@ pub fn assemble_context_system(
     stage.0 = stage.0.next();
 }
 
+/// The scene cast carrying its live emotional and relational state.
+fn live_characters(
+    scene_res: &SceneResource,
+    cast_state: Option<&CastStateResource>,
+) -> Vec<CharacterSheet> {
+    match cast_state {
+        Some(cast) => scene_res
+            .characters
+            .iter()
+            .map(|c| cast.0.overlay(c))
+            .collect(),
+        None => scene_res.characters.clone(),
+    }
+}
+
+/// Rebuild the cast's knowledge from their sheets and the committed truth set.
+fn replay_knowledge(
+    characters: &[&CharacterSheet],
+    truth: Option<&TruthSetResource>,
+) -> KnowledgeModel {
+    KnowledgeModel::replay(characters, truth.into_iter().flat_map(|t| t.0.atoms()))
+}
+
 /// Build the combined text for committed-turn classification (D.3).
 ///
 /// Concatenates narrator rendering text and player input with a newline
@@ -286,12 +392,18 @@ fn build_committed_text(rendering: Option<&str>, player_input: Option<&str>) ->
 /// 1. If TurnContext has previous data (rendering or classification), archive
 ///    as a `CompletedTurn` and add rendering text to the journal.
 /// 2. Run committed-turn classification on the combined narrator + player text (D.3).
-/// 3. Reset TurnContext for the new turn.
-/// 4. Move `PendingInput` → `TurnContext.player_input`.
-/// 5. Advance to `Enriching`.
+/// 3. Apply the turn's resolved state changes to the live cast state,
+///    seeding it from the scene cast on first use, and re-evaluate
+///    contextual triggers against the committed events.
+/// 4. Resolve the scene's event dependencies against the committed events,
+///    seeding them from the scene's authored graph on first use.
+/// 5. Reset TurnContext for the new turn.
+/// 6. Move `PendingInput` → `TurnContext.player_input`.
+/// 7. Advance to `Enriching`.
 ///
 /// On the first turn of a scene, there is no previous data to archive —
 /// the system simply moves PendingInput and advances.
+#[allow(clippy::too_many_arguments)]
 pub fn commit_previous_system(
     mut stage: ResMut<ActiveTurnStage>,
     mut turn_ctx: ResMut<TurnContext>,
@@ -300,6 +412,11 @@ pub fn commit_previous_system(
     journal: Option<ResMut<JournalResource>>,
     structured_llm: Option<Res<StructuredLlmResource>>,
     runtime: Option<Res<TokioRuntime>>,
+    mut truth: Option<ResMut<TruthSetResource>>,
+    mut cast_state: Option<ResMut<CastStateResource>>,
+    scene_res: Option<Res<SceneResource>>,
+    grammar: Option<Res<GrammarResource>>,
+    dependencies: Option<ResMut<EventDependenciesResource>>,
 ) {
     let has_previous_data = turn_ctx.rendering.is_some() || turn_ctx.classification.is_some();
 
@@ -406,6 +523,71 @@ pub fn commit_previous_system(
             "commit_previous_system: archived turn"
         );
 
+        if let Some(truth) = truth.as_mut() {
+            truth
+                .0
+                .merge_committed(completed.committed_atoms.iter().map(|atom| NarrativeEvent {
+                    id: atom.id,
+                    timestamp: atom.timestamp,
+                    priority: atom.priority,
+                    payload: EventPayload::Atom(atom.clone()),
+                }));
+        }
+
+        let committed: Vec<&EventAtom> = match &truth {
+            Some(truth) => truth.0.atoms().collect(),
+            None => completed.committed_atoms.iter().collect(),
+        };
+
+        if let Some(cast) = cast_state.as_mut() {
+            if cast.0.is_empty() {
+                if let (Some(scene_res), Some(grammar)) = (&scene_res, &grammar) {
+                    let composed: Vec<&CharacterSheet> = scene_res.characters.iter().collect();
+                    cast.0 = CastState::new(&composed, &grammar.0);
+                }
+            }
+            let changes: Vec<_> = turn_ctx
+                .resolver_output
+                .iter()
+                .flat_map(|r| r.sequenced_actions.iter())
+                .flat_map(|r| r.outcomes.iter())
+                .flat_map(|o| o.state_changes.iter().cloned())
+                .collect();
+            let fired = cast.0.commit(&changes, &committed);
+            if !fired.is_empty() {
+                tracing::debug!(
+                    turn_number,
+                    fired = fired.len(),
+                    "commit_previous_system: contextual triggers fired"
+                );
+            }
+        }
+
+        if let Some(mut deps) = dependencies {
+            if deps.0.is_empty() {
+                if let Some(scene_res) = &scene_res {
+                    match EventDependencies::new(scene_res.scene.event_dependencies.clone()) {
+                        Ok(seeded) => deps.0 = seeded,
+                        Err(e) => {
+                            tracing::warn!("commit_previous_system: scene event dependencies: {e}")
+                        }
+                    }
+                }
+            }
+            let empty = CastState::default();
+            let cast = cast_state.as_deref().map_or(&empty, |c| &c.0);
+            let update = deps.0.evaluate(&committed, cast);
+            if !update.is_empty() {
+                tracing::debug!(
+                    turn_number,
+                    resolved = ?update.resolved,
+                    enabled = ?update.enabled,
+                    foreclosed = ?update.foreclosed,
+                    "commit_previous_system: event dependencies updated"
+                );
+            }
+        }
+
         history.turns.push(completed);
     } else {
         tracing::debug!("commit_previous_system: first turn — no previous data to archive");
@@ -498,6 +680,30 @@ mod tests {
         assert_eq!(stage.0, TurnCycleStage::AssemblingContext);
     }
 
+    #[test]
+    fn enrichment_arbitrates_against_genre_rules() {
+        use storyteller_core::game_design::WorldAffordances;
+
+        let mut app = test_app();
+        app.world_mut().resource_mut::<ActiveTurnStage>().0 = TurnCycleStage::Enriching;
+        app.world_mut().resource_mut::<TurnContext>().player_input =
+            Some("I cast a fireball at the well".to_string());
+        app.insert_resource(GenreRulesResource(GenreRules::derive(
+            &WorldAffordances::builtin("low_fantasy_folklore").unwrap(),
+            &[],
+        )));
+
+        app.add_systems(Update, enrichment_system);
+        app.update();
+
+        let ctx = app.world().resource::<TurnContext>();
+        assert!(ctx.arbitration.as_ref().unwrap().is_impossible());
+        // The impossible attempt is never resolved into outcomes.
+        let resolver_output = ctx.resolver_output.as_ref().unwrap();
+        assert!(resolver_output.sequenced_actions.is_empty());
+        assert!(resolver_output.scene_dynamics.contains("cannot succeed"));
+    }
+
     #[test]
     fn enrichment_skips_arbitration_without_player_input() {
         let mut app = test_app();
@@ -713,6 +919,198 @@ mod tests {
         assert!(journal.0.entries[0].content.contains("The scene unfolds"));
     }
 
+    #[test]
+    fn commit_previous_applies_state_changes_to_live_cast() {
+        use storyteller_core::grammars::GrammarRegistry;
+        use storyteller_core::types::prediction::{ActionPrediction, ActionType};
+        use storyteller_core::types::resolver::{
+            ActionOutcome, ResolvedCharacterAction, StateChange, SuccessDegree,
+        };
+
+        let mut app = test_app();
+        let bramblehoof = crate::workshop::the_flute_kept::bramblehoof();
+        let id = bramblehoof.entity_id;
+        let primary = bramblehoof.emotional_state.primaries[0].clone();
+        app.world_mut().insert_resource(SceneResource {
+            scene: crate::workshop::the_flute_kept::scene(),
+            characters: vec![bramblehoof],
+        });
+        app.world_mut()
+            .insert_resource(GrammarResource(GrammarRegistry::builtin()));
+        app.init_resource::<CastStateResource>();
+
+        app.world_mut().resource_mut::<ActiveTurnStage>().0 = TurnCycleStage::CommittingPrevious;
+        {
+            let mut ctx = app.world_mut().resource_mut::<TurnContext>();
+            ctx.rendering = Some(NarratorRendering {
+                text: "The satyr laughs.".to_string(),
+                stage_directions: None,
+            });
+            ctx.resolver_output = Some(ResolverOutput {
+                sequenced_actions: vec![ResolvedCharacterAction {
+                    character_id: id,
+                    character_name: "Bramblehoof".to_string(),
+                    outcomes: vec![ActionOutcome {
+                        action: ActionPrediction {
+                            description: "Laughs".to_string(),
+                            confidence: 0.8,
+                            action_type: ActionType::Perform,
+                            target: None,
+                        },
+                        success: SuccessDegree::FullSuccess,
+                        consequences: vec![],
+                        state_changes: vec![StateChange::EmotionalShift {
+                            character_id: id,
+                            primary_id: primary.primary_id.clone(),
+                            intensity_change: 5.0,
+                        }],
+                    }],
+                }],
+                original_predictions: vec![],
+                scene_dynamics: String::new(),
+                conflicts: vec![],
+                intent_statements: None,
+            });
+        }
+
+        app.add_systems(Update, commit_previous_system);
+        app.update();
+
+        let cast = &app.world().resource::<CastStateResource>().0;
+        let live = cast.character(id).expect("seeded from the scene cast");
+        assert_eq!(live.intensity(&primary.primary_id), Some(1.0));
+
+        let scene = app.world().resource::<SceneResource>();
+        let overlaid = live_characters(scene, Some(app.world().resource::<CastStateResource>()));
+        assert_eq!(overlaid[0].emotional_state.primaries[0].intensity, 1.0);
+        assert_eq!(
+            scene.characters[0].emotional_state.primaries[0].intensity, primary.intensity,
+            "the composed sheet is untouched"
+        );
+    }
+
+    #[test]
+    fn commit_previous_fires_authored_triggers_into_the_tensor() {
+        use storyteller_core::grammars::GrammarRegistry;
+        use storyteller_core::types::event::{EventId, EventPriority};
+        use storyteller_core::types::event_grammar::{
+            ConfidenceEvidence, EventConfidence, EventKind, EventSource,
+        };
+
+        let mut app = test_app();
+        let bramblehoof = crate::workshop::the_flute_kept::bramblehoof();
+        let composed_grief = bramblehoof.tensor.get("grief").unwrap().value;
+        app.world_mut().insert_resource(SceneResource {
+            scene: crate::workshop::the_flute_kept::scene(),
+            characters: vec![bramblehoof],
+        });
+        app.world_mut()
+            .insert_resource(GrammarResource(GrammarRegistry::builtin()));
+        app.init_resource::<CastStateResource>();
+
+        let flute = EventAtom {
+            id: EventId::new(),
+            timestamp: chrono::Utc::now(),
+            kind: EventKind::StateAssertion {
+                assertion: "The old flute hangs on its hook".to_string(),
+            },
+            participants: vec![],
+            relational_implications: vec![],
+            source: EventSource::System {
+                component: "test".to_string(),
+            },
+            confidence: EventConfidence {
+                value: 1.0,
+                evidence: ConfidenceEvidence::SystemProduced,
+            },
+            priority: EventPriority::Normal,
+            scene_id: SceneId::new(),
+            turn_id: None,
+        };
+        let mut truth = TruthSetResource::default();
+        truth.0.merge_committed([NarrativeEvent {
+            id: flute.id,
+            timestamp: flute.timestamp,
+            priority: flute.priority,
+            payload: EventPayload::Atom(flute),
+        }]);
+        app.world_mut().insert_resource(truth);
+
+        app.world_mut().resource_mut::<ActiveTurnStage>().0 = TurnCycleStage::CommittingPrevious;
+        app.world_mut().resource_mut::<TurnContext>().rendering = Some(NarratorRendering {
+            text: "Bramblehoof's eyes find the hook by the door.".to_string(),
+            stage_directions: None,
+        });
+
+        app.add_systems(Update, commit_previous_system);
+        app.update();
+
+        let cast = app.world().resource::<CastStateResource>();
+        let scene = app.world().resource::<SceneResource>();
+        let live = &live_characters(scene, Some(cast))[0];
+        let grief = &live.tensor.get("grief").unwrap().value;
+        assert!(grief.central_tendency > composed_grief.central_tendency);
+        assert!(grief.central_tendency <= composed_grief.range_high);
+    }
+
+    #[test]
+    fn commit_previous_resolves_scene_event_dependencies() {
+        use storyteller_core::types::event::{EventId, EventPriority};
+        use storyteller_core::types::event_dependency::NodeStatus;
+        use storyteller_core::types::event_grammar::{
+            ConfidenceEvidence, EventConfidence, EventKind, EventSource,
+        };
+
+        let mut app = test_app();
+        app.world_mut().insert_resource(SceneResource {
+            scene: crate::workshop::the_flute_kept::scene(),
+            characters: vec![],
+        });
+        app.init_resource::<EventDependenciesResource>();
+
+        let flute = EventAtom {
+            id: EventId::new(),
+            timestamp: chrono::Utc::now(),
+            kind: EventKind::StateAssertion {
+                assertion: "The old flute hangs on its hook".to_string(),
+            },
+            participants: vec![],
+            relational_implications: vec![],
+            source: EventSource::System {
+                component: "test".to_string(),
+            },
+            confidence: EventConfidence {
+                value: 1.0,
+                evidence: ConfidenceEvidence::SystemProduced,
+            },
+            priority: EventPriority::Normal,
+            scene_id: SceneId::new(),
+            turn_id: None,
+        };
+        let mut truth = TruthSetResource::default();
+        truth.0.merge_committed([NarrativeEvent {
+            id: flute.id,
+            timestamp: flute.timestamp,
+            priority: flute.priority,
+            payload: EventPayload::Atom(flute),
+        }]);
+        app.world_mut().insert_resource(truth);
+
+        app.world_mut().resource_mut::<ActiveTurnStage>().0 = TurnCycleStage::CommittingPrevious;
+        app.world_mut().resource_mut::<TurnContext>().rendering = Some(NarratorRendering {
+            text: "Bramblehoof's eyes find the hook by the door.".to_string(),
+            stage_directions: None,
+        });
+
+        app.add_systems(Update, commit_previous_system);
+        app.update();
+
+        let deps = &app.world().resource::<EventDependenciesResource>().0;
+        assert_eq!(deps.status("flute_noticed"), Some(NodeStatus::Resolved));
+        assert_eq!(deps.status("flute_played"), Some(NodeStatus::Unresolved));
+        assert!(!deps.is_enabled("flute_played"));
+    }
+
     // -----------------------------------------------------------------------
     // assemble_context_system
     // -----------------------------------------------------------------------
@@ -29,8 +29,12 @@ use storyteller_core::types::character::{
     SelfKnowledge, TriggerMagnitude,
 };
 use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::event_dependency::{
+    DependencyEdge, DependencyKind, EventDependencyGraph, EventNode,
+};
 use storyteller_core::types::scene::{SceneId, SceneType};
 use storyteller_core::types::tensor::{AwarenessLevel, AxisValue, Provenance, TemporalLayer};
+use storyteller_core::types::trigger::{EventPattern, TriggerCondition, TriggerPredicate};
 use storyteller_core::types::world_model::{Attribute, CapabilityProfile, Skill};
 
 /// Build the complete scene data for "The Flute Kept".
@@ -123,6 +127,55 @@ pub fn scene() -> SceneData {
             "The shift: something changes in Bramblehoof's understanding.".to_string(),
             "Narrative restraint: no resolution, no promises, just departure.".to_string(),
         ],
+        event_dependencies: event_dependencies(),
+    }
+}
+
+/// The scene's shape as event dependencies: the music question opens the
+/// gap, the impulse to fix it needs the gap, and a promise forecloses the
+/// quiet departure the scene is after (and vice versa).
+fn event_dependencies() -> EventDependencyGraph {
+    let node = |id: &str, description: &str, mentions: &str| EventNode {
+        id: id.to_string(),
+        description: description.to_string(),
+        condition: Some(mentioned(mentions)),
+        subject: None,
+    };
+    let edge = |from: &str, to: &str, kind: DependencyKind| DependencyEdge {
+        from: from.to_string(),
+        to: to.to_string(),
+        kind,
+        weight: 1.0,
+    };
+
+    EventDependencyGraph {
+        nodes: vec![
+            node("flute_noticed", "The flute on its hook is noticed", "flute"),
+            node("music_asked", "Bramblehoof asks about the music", "music"),
+            node("impulse_to_fix", "Bramblehoof offers to fix things", "fix"),
+            node("flute_played", "The flute comes down and is played", "play"),
+            node(
+                "promise_made",
+                "Someone promises to return or to change things",
+                "promise",
+            ),
+            node(
+                "quiet_departure",
+                "Bramblehoof leaves with nothing resolved",
+                "leave",
+            ),
+        ],
+        edges: vec![
+            edge("music_asked", "impulse_to_fix", DependencyKind::Requires),
+            edge("flute_noticed", "flute_played", DependencyKind::Requires),
+            edge("music_asked", "flute_played", DependencyKind::Requires),
+            DependencyEdge {
+                weight: 1.5,
+                ..edge("impulse_to_fix", "promise_made", DependencyKind::Amplifies)
+            },
+            edge("promise_made", "quiet_departure", DependencyKind::Excludes),
+            edge("quiet_departure", "promise_made", DependencyKind::Excludes),
+        ],
     }
 }
 
@@ -284,6 +337,7 @@ pub fn bramblehoof() -> CharacterSheet {
     let triggers = vec![
         ContextualTrigger {
             description: "Seeing the flute on the hook".to_string(),
+            condition: Some(mentioned("flute")),
             axis_shifts: vec![
                 AxisShift {
                     axis: "grief".to_string(),
@@ -298,6 +352,7 @@ pub fn bramblehoof() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Hearing Pyotir speak about his family without self-pity".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "empathy".to_string(),
@@ -312,6 +367,7 @@ pub fn bramblehoof() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Realizing Pyotir manages distance deliberately".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "narrative_framing".to_string(),
@@ -326,6 +382,7 @@ pub fn bramblehoof() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Sensing the faintly tainted ley line".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "pattern_recognition".to_string(),
@@ -340,6 +397,7 @@ pub fn bramblehoof() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Noticing the aesthetic detail Pyotir maintains".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "creative_receptivity".to_string(),
@@ -354,6 +412,7 @@ pub fn bramblehoof() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "The impulse to offer rescue being met with dignity".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "protective_impulse".to_string(),
@@ -504,6 +563,7 @@ pub fn bramblehoof() -> CharacterSheet {
             "Whether Pyotir wants to be found by someone from that part of his life".to_string(),
         ],
         capabilities: bramblehoof_capabilities(),
+        relationships: Vec::new(),
     }
 }
 
@@ -688,6 +748,7 @@ pub fn pyotir() -> CharacterSheet {
         ContextualTrigger {
             description: "Bramblehoof's arrival — someone from the music-life appearing"
                 .to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "warmth".to_string(),
@@ -706,6 +767,10 @@ pub fn pyotir() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Being asked about the music / the flute directly".to_string(),
+            condition: Some(TriggerPredicate::Any(vec![
+                mentioned("flute"),
+                mentioned("music"),
+            ])),
             axis_shifts: vec![
                 AxisShift {
                     axis: "longing".to_string(),
@@ -724,6 +789,7 @@ pub fn pyotir() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Being treated as someone to be saved or pitied".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "distance_management".to_string(),
@@ -742,6 +808,7 @@ pub fn pyotir() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Being treated with genuine respect for his choices".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "trust_bramblehoof".to_string(),
@@ -760,6 +827,7 @@ pub fn pyotir() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Bramblehoof showing real interest in his current life".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "warmth".to_string(),
@@ -774,6 +842,7 @@ pub fn pyotir() -> CharacterSheet {
         },
         ContextualTrigger {
             description: "Hearing about the wider world from Bramblehoof".to_string(),
+            condition: None,
             axis_shifts: vec![
                 AxisShift {
                     axis: "longing".to_string(),
@@ -939,6 +1008,7 @@ pub fn pyotir() -> CharacterSheet {
             "How the encounter will end or what it means".to_string(),
         ],
         capabilities: pyotir_capabilities(),
+        relationships: Vec::new(),
     }
 }
 
@@ -1025,6 +1095,14 @@ fn bramblehoof_capabilities() -> CapabilityProfile {
     cap
 }
 
+/// Trigger condition: a committed event mentions `text`.
+fn mentioned(text: &str) -> TriggerPredicate {
+    TriggerPredicate::Atom(TriggerCondition::EventOccurred(EventPattern {
+        mentions: Some(text.to_string()),
+        ..EventPattern::default()
+    }))
+}
+
 /// Build Pyotir's capability profile — attributes and skills for the Resolver.
 fn pyotir_capabilities() -> CapabilityProfile {
     let mut cap = CapabilityProfile::default();
@@ -1140,6 +1218,28 @@ mod tests {
         assert!(!scene.evaluation_criteria.is_empty());
     }
 
+    #[test]
+    fn scene_event_dependencies_are_valid() {
+        use storyteller_core::types::event_dependency::{EventDependencies, NodeStatus};
+
+        let mut deps = EventDependencies::new(scene().event_dependencies).unwrap();
+        assert_eq!(
+            deps.enabled().collect::<Vec<_>>(),
+            vec![
+                "flute_noticed",
+                "music_asked",
+                "promise_made",
+                "quiet_departure"
+            ]
+        );
+
+        deps.assert_resolved("promise_made");
+        assert_eq!(
+            deps.status("quiet_departure"),
+            Some(NodeStatus::Unreachable)
+        );
+    }
+
     #[test]
     fn bramblehoof_sheet_has_expected_axes() {
         let sheet = bramblehoof();
@@ -136,6 +136,7 @@ fn main() {
             scene: skeleton.scene,
             event: skeleton.event,
             history: &[],
+            grammar: None,
         };
         let features = feature_schema::encode_features(&input);
         let labels = feature_schema::encode_labels(&prediction);
@@ -25,11 +25,17 @@
 //! ## Conventions
 //!
 //! - One-hot encodings use the enum's discriminant order (first variant = index 0).
-//! - Padded regions (axes, edges) use all-zero vectors for empty slots.
+//! - Padded regions (axes, edges, primaries) use all-zero vectors for empty slots.
 //! - Float values are used as-is (no normalization in this layer).
+//! - Emotional primaries occupy [`NUM_PRIMARIES`] slots in the order of the
+//!   character's emotional grammar. Grammars with fewer primaries leave the
+//!   tail slots zeroed; grammars with more lose the primaries past the last
+//!   slot. Decoded predictions are fitted back to the grammar with
+//!   [`fit_to_grammar`].
 
 use serde::{Deserialize, Serialize};
 
+use storyteller_core::traits::EmotionalGrammar;
 use storyteller_core::types::character::{CharacterSheet, EmotionalPrimary, SelfEdge};
 use storyteller_core::types::prediction::{
     ActionContext, ActionType, EmotionalRegister, EventType, RawActionPrediction,
@@ -57,7 +63,8 @@ pub const MAX_EDGES: usize = 5;
 /// Features per relational edge: 5 substrate dimensions × 4 (AxisValue) + 4 (TopologicalRole one-hot).
 pub const FEATURES_PER_EDGE: usize = 5 * 4 + 4; // = 24
 
-/// Number of Plutchik primaries in the default grammar.
+/// Number of emotional primary slots — the size of the Plutchik grammar.
+/// Other grammars are fitted to these slots; see the module conventions.
 pub const NUM_PRIMARIES: usize = 8;
 
 /// Features per emotional primary: 1 (intensity) + 5 (AwarenessLevel one-hot).
@@ -172,6 +179,19 @@ pub struct PredictionInput<'a> {
     pub event: EventFeatureInput,
     /// Recent history (up to `HISTORY_DEPTH` entries, most recent first).
     pub history: &'a [HistoryEntry],
+    /// The character's emotional grammar. When set, primaries are slotted
+    /// in grammar order; otherwise in the order the sheet lists them.
+    pub grammar: Option<&'a dyn EmotionalGrammar>,
+}
+
+/// Split a character's relationships into the parallel edge and target-role
+/// slices [`PredictionInput`] expects.
+pub fn relational_inputs(character: &CharacterSheet) -> (Vec<DirectedEdge>, Vec<TopologicalRole>) {
+    character
+        .relationships
+        .iter()
+        .map(|r| (r.edge.clone(), r.target_topology))
+        .unzip()
 }
 
 // ===========================================================================
@@ -309,10 +329,21 @@ fn encode_self_edge(se: &SelfEdge, out: &mut Vec<f32>) {
     out.push(se.projection_accuracy);
 }
 
-fn encode_emotional_primaries(primaries: &[EmotionalPrimary], out: &mut Vec<f32>) {
+fn encode_emotional_primaries(
+    primaries: &[EmotionalPrimary],
+    grammar: Option<&dyn EmotionalGrammar>,
+    out: &mut Vec<f32>,
+) {
     // Encode exactly NUM_PRIMARIES slots. If we have fewer, zero-pad.
     for i in 0..NUM_PRIMARIES {
-        if let Some(p) = primaries.get(i) {
+        let primary = match grammar {
+            Some(grammar) => grammar
+                .primaries()
+                .get(i)
+                .and_then(|def| primaries.iter().find(|p| p.primary_id == def.id)),
+            None => primaries.get(i),
+        };
+        if let Some(p) = primary {
             out.push(p.intensity);
             out.extend_from_slice(&one_hot_awareness(p.awareness));
         } else {
@@ -320,6 +351,17 @@ fn encode_emotional_primaries(primaries: &[EmotionalPrimary], out: &mut Vec<f32>
             out.extend_from_slice(&[0.0; FEATURES_PER_PRIMARY]);
         }
     }
+
+    if let Some(grammar) = grammar {
+        if grammar.primaries().len() > NUM_PRIMARIES {
+            tracing::debug!(
+                grammar_id = grammar.id(),
+                primaries = grammar.primaries().len(),
+                slots = NUM_PRIMARIES,
+                "grammar has more primaries than feature slots, dropping the excess"
+            );
+        }
+    }
 }
 
 // ===========================================================================
@@ -347,7 +389,11 @@ pub fn encode_features(input: &PredictionInput<'_>) -> Vec<f32> {
     }
 
     // --- Region 2: Emotional state (NUM_PRIMARIES × FEATURES_PER_PRIMARY) ---
-    encode_emotional_primaries(&input.character.emotional_state.primaries, &mut features);
+    encode_emotional_primaries(
+        &input.character.emotional_state.primaries,
+        input.grammar,
+        &mut features,
+    );
 
     // --- Region 3: Self-edge (SELF_EDGE_FEATURES) ---
     encode_self_edge(&input.character.self_edge, &mut features);
@@ -460,13 +506,14 @@ pub fn decode_outputs(
     let awareness_level = argmax_to_awareness_level(&output[offset..offset + NUM_AWARENESS_LEVELS]);
     offset += NUM_AWARENESS_LEVELS;
     let raw_index = output[offset].round() as u8;
-    if raw_index > 7 {
+    let last_slot = (NUM_PRIMARIES - 1) as u8;
+    if raw_index > last_slot {
         tracing::warn!(
             raw_index,
-            "dominant_emotion_index out of Plutchik range, clamping to 7"
+            "dominant_emotion_index out of primary slot range, clamping to {last_slot}"
         );
     }
-    let dominant_emotion_index = raw_index.min(7);
+    let dominant_emotion_index = raw_index.min(last_slot);
     offset += 1;
 
     // --- Emotion head ---
@@ -522,6 +569,34 @@ pub fn decode_outputs(
     })
 }
 
+/// Fit a decoded prediction to a grammar with fewer than [`NUM_PRIMARIES`]
+/// primaries.
+///
+/// The model always predicts over every slot; slots past the grammar's last
+/// primary were zero-padded on the way in and mean nothing on the way out.
+/// Deltas for those slots are dropped and the dominant emotion is clamped to
+/// the grammar's last primary.
+pub fn fit_to_grammar(prediction: &mut RawCharacterPrediction, grammar: &dyn EmotionalGrammar) {
+    let primary_count = grammar.primaries().len().min(NUM_PRIMARIES);
+    let Some(last) = primary_count.checked_sub(1) else {
+        prediction.emotional_deltas.clear();
+        return;
+    };
+
+    let dominant = &mut prediction.thought.dominant_emotion_index;
+    if usize::from(*dominant) > last {
+        tracing::debug!(
+            grammar_id = grammar.id(),
+            dominant = *dominant,
+            "dominant emotion past the grammar's primaries, clamping"
+        );
+        *dominant = last as u8;
+    }
+    prediction
+        .emotional_deltas
+        .retain(|delta| usize::from(delta.primary_index) < primary_count);
+}
+
 // ===========================================================================
 // Public API: encode labels (for training data generation)
 // ===========================================================================
@@ -687,7 +762,7 @@ pub fn schema_metadata() -> SchemaMetadata {
     add_input(
         "emotional_state",
         NUM_PRIMARIES * FEATURES_PER_PRIMARY,
-        "Emotional primaries (intensity[1] + awareness[5]) × 8",
+        "Emotional primaries (intensity[1] + awareness[5]) × 8 slots, in grammar order",
     );
     add_input(
         "self_edge",
@@ -764,6 +839,7 @@ mod tests {
         CharacterTensor, EmotionalState, SelfEdgeTrust, SelfKnowledge,
     };
     use storyteller_core::types::entity::EntityId;
+    use storyteller_core::types::relational::{CastRelationship, InformationState};
     use storyteller_core::types::world_model::CapabilityProfile;
 
     fn test_character_sheet() -> CharacterSheet {
@@ -866,6 +942,7 @@ mod tests {
             knows: vec![],
             does_not_know: vec![],
             capabilities: CapabilityProfile::default(),
+            relationships: Vec::new(),
         }
     }
 
@@ -886,6 +963,7 @@ mod tests {
                 target_count: 1,
             },
             history: &[],
+            grammar: None,
         }
     }
 
@@ -982,6 +1060,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn relationships_encode_into_edge_region() {
+        let mut sheet = test_character_sheet();
+        let axis = |v: f32| AxisValue {
+            central_tendency: v,
+            variance: 0.1,
+            range_low: v - 0.1,
+            range_high: v + 0.1,
+        };
+        let target = EntityId::new();
+        sheet.relationships.push(CastRelationship {
+            edge: DirectedEdge {
+                source: sheet.entity_id,
+                target,
+                substrate: RelationalSubstrate {
+                    trust_reliability: axis(-0.5),
+                    trust_competence: axis(0.2),
+                    trust_benevolence: axis(-0.3),
+                    affection: axis(-0.4),
+                    debt: axis(0.0),
+                },
+                information_state: InformationState {
+                    known_facts: vec![],
+                    beliefs: vec![],
+                    blind_spots: vec![],
+                },
+            },
+            target_topology: TopologicalRole::Gate,
+            dynamic: "Rivals".to_string(),
+            role: "challenger".to_string(),
+            target_role: "incumbent".to_string(),
+        });
+
+        let (edges, roles) = relational_inputs(&sheet);
+        let mut input = test_prediction_input(&sheet);
+        input.edges = &edges;
+        input.target_roles = &roles;
+        let features = encode_features(&input);
+
+        let edge_start = MAX_TENSOR_AXES * FEATURES_PER_AXIS
+            + NUM_PRIMARIES * FEATURES_PER_PRIMARY
+            + SELF_EDGE_FEATURES;
+        assert!((features[edge_start] + 0.5).abs() < f32::EPSILON);
+        let role_start = edge_start + FEATURES_PER_EDGE - 4;
+        assert_eq!(&features[role_start..role_start + 4], &[1.0, 0.0, 0.0, 0.0]);
+        // The second edge slot stays empty.
+        assert!(features[edge_start + FEATURES_PER_EDGE].abs() < f32::EPSILON);
+    }
+
     #[test]
     fn empty_history_produces_zeros() {
         let sheet = test_character_sheet();
@@ -1154,6 +1281,7 @@ mod tests {
                 target_count: 1,
             },
             history: &[],
+            grammar: None,
         };
 
         let features = encode_features(&input);
@@ -1202,6 +1330,84 @@ mod tests {
         assert_eq!(pred.thought.dominant_emotion_index, 3);
     }
 
+    fn five_primary_grammar() -> storyteller_core::grammars::DataGrammar {
+        use storyteller_core::grammars::{DataGrammar, GrammarDefinition};
+        use storyteller_core::traits::emotional_grammar::PrimaryDef;
+
+        let primaries = ["joy", "anger", "worry", "grief", "fear"]
+            .iter()
+            .map(|id| PrimaryDef {
+                id: id.to_string(),
+                name: id.to_string(),
+                opposite_id: None,
+                low_intensity_label: "low".to_string(),
+                high_intensity_label: "high".to_string(),
+            })
+            .collect();
+        DataGrammar::new(GrammarDefinition {
+            id: "five".to_string(),
+            name: "Five".to_string(),
+            intensity_range: (0.0, 1.0),
+            primaries,
+        })
+        .expect("valid grammar")
+    }
+
+    #[test]
+    fn grammar_order_slots_primaries_and_pads_small_grammars() {
+        let grammar = five_primary_grammar();
+        let mut sheet = test_character_sheet();
+        sheet.emotional_state.grammar_id = "five".to_string();
+        // Listed out of grammar order on the sheet.
+        sheet.emotional_state.primaries = ["fear", "grief", "worry", "anger", "joy"]
+            .iter()
+            .enumerate()
+            .map(|(i, id)| EmotionalPrimary {
+                primary_id: id.to_string(),
+                intensity: 0.1 * (i + 1) as f32,
+                awareness: AwarenessLevel::Articulate,
+            })
+            .collect();
+
+        let mut input = test_prediction_input(&sheet);
+        input.grammar = Some(&grammar);
+        let features = encode_features(&input);
+        assert_eq!(features.len(), TOTAL_INPUT_FEATURES);
+
+        let region = MAX_TENSOR_AXES * FEATURES_PER_AXIS;
+        let intensity = |slot: usize| features[region + slot * FEATURES_PER_PRIMARY];
+        // joy was listed last (0.5), fear first (0.1).
+        assert!((intensity(0) - 0.5).abs() < 1e-6);
+        assert!((intensity(4) - 0.1).abs() < 1e-6);
+        for slot in 5..NUM_PRIMARIES {
+            let start = region + slot * FEATURES_PER_PRIMARY;
+            assert!(features[start..start + FEATURES_PER_PRIMARY]
+                .iter()
+                .all(|&f| f == 0.0));
+        }
+    }
+
+    #[test]
+    fn fit_to_grammar_drops_slots_past_the_grammar() {
+        let grammar = five_primary_grammar();
+        let mut output = vec![0.0f32; TOTAL_OUTPUT_FEATURES];
+        output[0] = 1.0;
+        let emotion_index_offset = 6 + 1 + 1 + 1 + 5 + 1 + 4 + 1 + 5;
+        output[emotion_index_offset] = 7.0;
+        let deltas_offset = emotion_index_offset + 1;
+        output[deltas_offset + 2] = 0.3;
+        output[deltas_offset + 6] = -0.4;
+
+        let mut pred =
+            decode_outputs(&output, EntityId::new(), vec![], 0.8).expect("decode should succeed");
+        assert_eq!(pred.emotional_deltas.len(), 2);
+
+        fit_to_grammar(&mut pred, &grammar);
+        assert_eq!(pred.thought.dominant_emotion_index, 4);
+        assert_eq!(pred.emotional_deltas.len(), 1);
+        assert_eq!(pred.emotional_deltas[0].primary_index, 2);
+    }
+
     #[test]
     fn encode_with_history() {
         let sheet = test_character_sheet();
@@ -1236,6 +1442,7 @@ mod tests {
                 target_count: 1,
             },
             history: &history,
+            grammar: None,
         };
 
         let features = encode_features(&input);
@@ -119,6 +119,7 @@ pub fn instantiate_character(
         knows: vec![],
         does_not_know: vec![],
         capabilities: CapabilityProfile::default(),
+        relationships: Vec::new(),
     }
 }
 
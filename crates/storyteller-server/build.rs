@@ -6,6 +6,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     tonic_prost_build::configure()
         .build_server(true)
         .build_client(true) // client stubs for integration tests
+        // JSON for the REST/SSE surface — same messages, snake_case oneof tags
+        .type_attribute(
+            ".storyteller.v1",
+            "#[derive(serde::Serialize, serde::Deserialize)]",
+        )
+        .message_attribute(".storyteller.v1", "#[serde(default)]")
+        .enum_attribute(".storyteller.v1", "#[serde(rename_all = \"snake_case\")]")
         .compile_protos(
             &[
                 "../../proto/storyteller/v1/engine.proto",
@@ -2,7 +2,7 @@
 // Copyright (c) 2026 Tasker Systems. All rights reserved.
 // See LICENSING.md for details.
 
-//! Standalone binary entry point for the storyteller gRPC server.
+//! Standalone binary entry point for the storyteller gRPC and HTTP server.
 
 use storyteller_server::server::{run_server, ServerConfig};
 use tracing_subscriber::prelude::*;
@@ -30,7 +30,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         ))
         .init();
 
-    let config = ServerConfig::from_env();
+    // Fail before binding anything if the layered config is unusable; the
+    // error names the offending key.
+    let config = ServerConfig::load().map_err(|e| format!("invalid server configuration: {e}"))?;
+    tracing::info!(
+        environment = %storyteller_core::config::ConfigLoader::detect_environment(),
+        grpc_port = config.grpc_port,
+        http_port = config.http_port,
+        "Configuration loaded"
+    );
     run_server(config, Some(log_broadcast)).await?;
 
     Ok(())
@@ -10,7 +10,9 @@
 
 use std::sync::Arc;
 
-use storyteller_core::grammars::PlutchikWestern;
+use storyteller_core::config::StorytellerConfig;
+use storyteller_core::game_design::GameDesignRegistry;
+use storyteller_core::grammars::GrammarRegistry;
 use storyteller_core::traits::llm::LlmProvider;
 use storyteller_core::traits::structured_llm::StructuredLlmProvider;
 use storyteller_engine::inference::frame::CharacterPredictor;
@@ -26,18 +28,23 @@ use storyteller_engine::inference::frame::CharacterPredictor;
 /// - `structured_llm` — small fast model (e.g. qwen2.5:3b-instruct) for JSON extraction
 /// - `intent_llm` — small model for NPC intent synthesis (3b-instruct via Ollama)
 /// - `predictor` — optional ONNX character predictor (absent if model not on disk)
-/// - `grammar` — emotion grammar used by prediction enrichment
+/// - `grammars` — emotion grammars, resolved per character by `grammar_id`
+/// - `game_design` — per-genre resolution mechanics applied to predictions
+/// - `config` — the layered configuration the server started with, for the
+///   `GetEffectiveConfig` debug RPC (absent for in-process test servers)
 #[derive(Clone)]
 pub struct EngineProviders {
     pub narrator_llm: Arc<dyn LlmProvider>,
     pub structured_llm: Option<Arc<dyn StructuredLlmProvider>>,
     pub intent_llm: Option<Arc<dyn LlmProvider>>,
     pub predictor: Option<Arc<CharacterPredictor>>,
-    pub grammar: Arc<PlutchikWestern>,
+    pub grammars: Arc<GrammarRegistry>,
+    pub game_design: Arc<GameDesignRegistry>,
     /// Model name for the narrator LLM (for observability/debug inspector).
     pub narrator_model: String,
     /// Model name for the decomposition LLM (for observability/debug inspector).
     pub decomposition_model: String,
+    pub config: Option<Arc<StorytellerConfig>>,
 }
 
 impl std::fmt::Debug for EngineProviders {
@@ -49,6 +56,7 @@ impl std::fmt::Debug for EngineProviders {
             .field("predictor", &self.predictor.is_some())
             .field("narrator_model", &self.narrator_model)
             .field("decomposition_model", &self.decomposition_model)
+            .field("config", &self.config.is_some())
             .finish()
     }
 }
@@ -86,9 +94,11 @@ mod tests {
             structured_llm: None,
             intent_llm: None,
             predictor: None,
-            grammar: Arc::new(PlutchikWestern::new()),
+            grammars: Arc::new(GrammarRegistry::builtin()),
+            game_design: Arc::new(GameDesignRegistry::builtin()),
             narrator_model: "test-model".to_string(),
             decomposition_model: "test-decomp-model".to_string(),
+            config: None,
         };
 
         let debug_str = format!("{providers:?}");
@@ -107,14 +117,16 @@ mod tests {
             structured_llm: None,
             intent_llm: None,
             predictor: None,
-            grammar: Arc::new(PlutchikWestern::new()),
+            grammars: Arc::new(GrammarRegistry::builtin()),
+            game_design: Arc::new(GameDesignRegistry::builtin()),
             narrator_model: "test-model".to_string(),
             decomposition_model: "test-decomp-model".to_string(),
+            config: None,
         };
 
         let cloned = providers.clone();
         // Same Arc pointer
         assert!(Arc::ptr_eq(&providers.narrator_llm, &cloned.narrator_llm));
-        assert!(Arc::ptr_eq(&providers.grammar, &cloned.grammar));
+        assert!(Arc::ptr_eq(&providers.grammars, &cloned.grammars));
     }
 }
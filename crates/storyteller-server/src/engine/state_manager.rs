@@ -141,6 +141,7 @@ mod tests {
             characters: vec![],
             goals: None,
             intentions: None,
+            genre_rules: None,
             selections: serde_json::json!({}),
         }
     }
@@ -8,10 +8,24 @@
 //! coupling to domain types at the state layer — deserialized on demand by the
 //! pipeline. `RuntimeSnapshot` uses typed domain objects so the pipeline can
 //! work directly without per-turn deserialization overhead.
+//!
+//! `RuntimeSnapshot` is also the checkpointed state: after turn 0 it only
+//! changes by folding ledger entries through [`LedgerReplay`], so the live
+//! turn path and crash recovery produce the same snapshot.
 
+use storyteller_core::database::checkpoint::{LedgerReplay, TruthSet};
+use storyteller_core::database::ledger::{LedgerEntry, LedgerEntryKind, LedgerSequence};
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::NoopObserver;
+use storyteller_core::types::character_state::CastState;
 use storyteller_core::types::entity::EntityId;
-use storyteller_core::types::narrator_context::SceneJournal;
+use storyteller_core::types::event_dependency::EventDependencies;
+use storyteller_core::types::narrative_layer::NarrativeStack;
+use storyteller_core::types::narrator_context::{JournalCompression, SceneJournal};
 use storyteller_core::types::scene::SceneId;
+use storyteller_engine::context::journal::{
+    add_turn, adopt_cached_compressions, cache_compression,
+};
 use storyteller_ml::prediction_history::PredictionHistory;
 
 /// Immutable composition data — created once per session.
@@ -25,13 +39,16 @@ pub struct Composition {
     pub goals: Option<serde_json::Value>,
     pub intentions: Option<serde_json::Value>,
     pub selections: serde_json::Value,
+    /// Arbitration rules (`GenreRules`), derived and lexicon-expanded once
+    /// at composition.
+    pub genre_rules: Option<serde_json::Value>,
 }
 
 /// Mutable runtime state — published as snapshots via ArcSwap.
 ///
 /// Readers get a cheap `Arc` clone; writers publish a new `Arc` at each
 /// pipeline phase boundary. No reader ever blocks a writer.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RuntimeSnapshot {
     /// Current turn number (0 = opening, incremented each player turn).
     pub turn_count: u32,
@@ -41,6 +58,22 @@ pub struct RuntimeSnapshot {
     pub journal: SceneJournal,
     /// Per-character prediction history — ring buffer for ML feature Region 7.
     pub prediction_history: PredictionHistory,
+    /// Classified events committed so far this session.
+    pub truth_set: TruthSet,
+    /// Live emotional and relational state of the cast, seeded from the
+    /// composition and moved by each turn's resolved state changes.
+    #[serde(default)]
+    pub cast_state: CastState,
+    /// The scene's event dependency graph, seeded from the composition and
+    /// resolved against each turn's committed events.
+    #[serde(default)]
+    pub event_dependencies: EventDependencies,
+    /// Nested narrative layers the session is playing inside; empty in the
+    /// main narrative. Turns are journaled in the innermost layer.
+    #[serde(default)]
+    pub layers: NarrativeStack,
+    /// Last ledger entry folded into this snapshot; `None` before the first turn.
+    pub ledger_sequence: Option<LedgerSequence>,
 }
 
 impl Default for RuntimeSnapshot {
@@ -50,13 +83,119 @@ impl Default for RuntimeSnapshot {
             player_entity_id: None,
             journal: SceneJournal::new(SceneId::default(), 1200),
             prediction_history: PredictionHistory::default(),
+            truth_set: TruthSet::default(),
+            cast_state: CastState::default(),
+            event_dependencies: EventDependencies::default(),
+            layers: NarrativeStack::default(),
+            ledger_sequence: None,
+        }
+    }
+}
+
+impl RuntimeSnapshot {
+    /// The journal turns are recorded in: the innermost layer's, or the
+    /// scene's in the main narrative.
+    pub fn current_journal(&self) -> &SceneJournal {
+        self.layers.journal(&self.journal)
+    }
+
+    /// Cache `compression` on whichever journal holds the entry for
+    /// `turn_number`. A summary for a layer since left is dropped.
+    pub fn cache_compression(&mut self, turn_number: u32, compression: JournalCompression) {
+        let journals = std::iter::once(&mut self.journal).chain(self.layers.journals_mut());
+        for journal in journals {
+            if cache_compression(journal, turn_number, compression.clone()) {
+                return;
+            }
+        }
+    }
+
+    /// Carry compressions cached anywhere in `source` over to matching
+    /// entries here that lack one. Turn numbers are unique across the main
+    /// journal and every layer's, so entries are matched by turn alone.
+    pub fn adopt_cached_compressions(&mut self, source: &RuntimeSnapshot) {
+        let sources: Vec<&SceneJournal> = std::iter::once(&source.journal)
+            .chain(source.layers.layers().iter().map(|layer| &layer.journal))
+            .collect();
+        let targets = std::iter::once(&mut self.journal).chain(self.layers.journals_mut());
+        for target in targets {
+            for source in &sources {
+                adopt_cached_compressions(target, source);
+            }
         }
     }
 }
 
+impl LedgerReplay for RuntimeSnapshot {
+    fn apply_entry(&mut self, entry: &LedgerEntry) -> StorytellerResult<()> {
+        self.truth_set.apply_entry(entry)?;
+
+        if let LedgerEntryKind::TurnCommit { commit } = &entry.kind {
+            self.turn_count = entry.turn_number;
+            if commit.player_entity_id.is_some() {
+                self.player_entity_id = commit.player_entity_id;
+            }
+
+            let journal = self.layers.journal_mut(&mut self.journal);
+            add_turn(
+                journal,
+                entry.turn_number,
+                &commit.narrator_prose,
+                commit.referenced_entities.clone(),
+                commit.emotional_markers.clone(),
+                &NoopObserver,
+            );
+            // The journal stamps entries with the clock; pin to the ledger
+            // so replay reproduces the entry exactly.
+            if let Some(added) = journal.entries.last_mut() {
+                added.timestamp = entry.recorded_at;
+            }
+
+            for prediction in &commit.predictions {
+                self.prediction_history.push_from_prediction(prediction);
+            }
+            let committed: Vec<_> = self.truth_set.atoms().collect();
+            self.cast_state.commit(&commit.state_changes, &committed);
+            self.event_dependencies
+                .evaluate(&committed, &self.cast_state);
+        }
+        match &entry.kind {
+            LedgerEntryKind::JournalCompressed { compression } => {
+                self.cache_compression(entry.turn_number, compression.clone());
+            }
+            LedgerEntryKind::LayerEntered { layer } => {
+                self.layers.push(layer.clone());
+            }
+            LedgerEntryKind::LayerExited { layer_id } => {
+                if self.layers.current().map(|layer| layer.id) != Some(*layer_id) {
+                    return Err(StorytellerError::Scene(format!(
+                        "ledger exits layer {} but it is not the innermost layer",
+                        layer_id.0
+                    )));
+                }
+                self.layers.pop();
+            }
+            _ => {}
+        }
+
+        self.ledger_sequence = Some(entry.sequence);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use storyteller_core::database::checkpoint::{
+        recover, replay, Checkpoint, CheckpointStore, InMemoryCheckpointStore,
+    };
+    use storyteller_core::database::ledger::{EventLedger, InMemoryLedger, TurnCommitRecord};
+    use storyteller_core::traits::storykeeper::SessionId;
+    use storyteller_core::types::character_state::{LiveCharacterState, LivePrimary};
+    use storyteller_core::types::event::{EventId, EventPriority, NarrativeEvent};
+    use storyteller_core::types::event_grammar::EventPayload;
+    use storyteller_core::types::message::PlayerInput;
+    use storyteller_core::types::resolver::StateChange;
 
     #[test]
     fn runtime_snapshot_default_is_empty() {
@@ -65,6 +204,8 @@ mod tests {
         assert!(snap.journal.entries.is_empty());
         assert!(snap.player_entity_id.is_none());
         assert!(snap.prediction_history.as_map().is_empty());
+        assert!(snap.truth_set.is_empty());
+        assert!(snap.ledger_sequence.is_none());
     }
 
     #[test]
@@ -74,10 +215,376 @@ mod tests {
             characters: vec![serde_json::json!({"name": "Alice"})],
             goals: None,
             intentions: None,
+            genre_rules: None,
             selections: serde_json::json!({}),
         };
         let cloned = comp.clone();
         assert_eq!(comp.scene, cloned.scene);
         assert_eq!(comp.characters.len(), cloned.characters.len());
     }
+
+    async fn commit_turn(
+        ledger: &InMemoryLedger,
+        session_id: &SessionId,
+        turn_number: u32,
+        player: EntityId,
+    ) {
+        let sourced = ledger
+            .source_command(
+                session_id,
+                &PlayerInput {
+                    text: format!("I wait, turn {turn_number}."),
+                    turn_number,
+                },
+            )
+            .await
+            .unwrap();
+        let event = NarrativeEvent {
+            id: EventId::new(),
+            timestamp: chrono::Utc::now(),
+            priority: EventPriority::Normal,
+            payload: EventPayload::Untyped(serde_json::json!({"turn": turn_number})),
+        };
+        ledger
+            .append_event(session_id, &sourced, &event)
+            .await
+            .unwrap();
+        ledger
+            .commit_turn(
+                session_id,
+                &sourced,
+                &TurnCommitRecord {
+                    event_ids: vec![event.id],
+                    narrator_prose: format!("The rain keeps falling. ({turn_number})"),
+                    player_entity_id: Some(player),
+                    referenced_entities: vec![player],
+                    emotional_markers: vec!["unease".to_string()],
+                    predictions: Vec::new(),
+                    state_changes: vec![StateChange::EmotionalShift {
+                        character_id: player,
+                        primary_id: "fear".to_string(),
+                        intensity_change: 0.1,
+                    }],
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Fold every ledger entry the snapshot has not seen yet, as the live
+    /// turn path does after each commit.
+    async fn catch_up(ledger: &InMemoryLedger, session_id: &SessionId, snap: &mut RuntimeSnapshot) {
+        let tail = ledger
+            .entries_since(session_id, snap.ledger_sequence)
+            .await
+            .unwrap();
+        replay(snap, &tail).unwrap();
+    }
+
+    #[tokio::test]
+    async fn recovered_snapshot_matches_live_snapshot() {
+        let ledger = InMemoryLedger::new();
+        let store = InMemoryCheckpointStore::new();
+        let session_id = SessionId::new();
+        let player = EntityId::new();
+
+        let mut live = RuntimeSnapshot::default();
+        live.cast_state.characters.push(LiveCharacterState {
+            entity_id: player,
+            intensity_range: (0.0, 1.0),
+            primaries: vec![LivePrimary {
+                primary_id: "fear".to_string(),
+                baseline: 0.2,
+                intensity: 0.2,
+            }],
+            edges: Vec::new(),
+            triggers: Vec::new(),
+        });
+        store
+            .write(&Checkpoint::new(session_id, 0, None, live.clone()))
+            .await
+            .unwrap();
+
+        for turn in 1..=2 {
+            commit_turn(&ledger, &session_id, turn, player).await;
+            catch_up(&ledger, &session_id, &mut live).await;
+        }
+        store
+            .write(&Checkpoint::new(
+                session_id,
+                live.turn_count,
+                live.ledger_sequence,
+                live.clone(),
+            ))
+            .await
+            .unwrap();
+        for turn in 3..=5 {
+            commit_turn(&ledger, &session_id, turn, player).await;
+            catch_up(&ledger, &session_id, &mut live).await;
+        }
+
+        let recovered = recover(&store, &ledger, &session_id)
+            .await
+            .unwrap()
+            .expect("checkpoint written");
+
+        assert_eq!(recovered.replayed, 9, "three turns after the checkpoint");
+        assert_eq!(recovered.state.turn_count, 5);
+        assert_eq!(recovered.state.player_entity_id, Some(player));
+        assert_eq!(recovered.state.truth_set.len(), 5);
+        let fear = recovered
+            .state
+            .cast_state
+            .character(player)
+            .and_then(|c| c.intensity("fear"))
+            .unwrap();
+        assert!(fear > 0.2, "committed shifts moved the live state");
+        assert_eq!(
+            serde_json::to_value(&recovered.state).unwrap(),
+            serde_json::to_value(&live).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn recovered_journal_keeps_ledgered_summaries() {
+        use storyteller_core::types::narrator_context::JournalCompression;
+
+        let ledger = InMemoryLedger::new();
+        let store = InMemoryCheckpointStore::new();
+        let session_id = SessionId::new();
+        let player = EntityId::new();
+        let mut live = RuntimeSnapshot::default();
+        store
+            .write(&Checkpoint::new(session_id, 0, None, live.clone()))
+            .await
+            .unwrap();
+
+        commit_turn(&ledger, &session_id, 1, player).await;
+        catch_up(&ledger, &session_id, &mut live).await;
+        // The summary lands off the turn path: ledgered, then cached live.
+        let compression = JournalCompression {
+            summary: "Rain.".to_string(),
+            skeleton: "rain".to_string(),
+        };
+        ledger
+            .record_journal_compression(&session_id, 1, &compression)
+            .await
+            .unwrap();
+        cache_compression(&mut live.journal, 1, compression.clone());
+        for turn in 2..=4 {
+            commit_turn(&ledger, &session_id, turn, player).await;
+            catch_up(&ledger, &session_id, &mut live).await;
+        }
+
+        let recovered = recover(&store, &ledger, &session_id)
+            .await
+            .unwrap()
+            .expect("checkpoint written");
+        assert_eq!(
+            recovered.state.journal.entries[0].cached_compression,
+            Some(compression)
+        );
+        assert_eq!(
+            serde_json::to_value(&recovered.state.journal).unwrap(),
+            serde_json::to_value(&live.journal).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn recovered_snapshot_keeps_layer_journals() {
+        use storyteller_core::types::narrative_layer::{LayerKind, NarrativeLayer};
+        use storyteller_core::types::scene::SceneId;
+
+        let ledger = InMemoryLedger::new();
+        let store = InMemoryCheckpointStore::new();
+        let session_id = SessionId::new();
+        let player = EntityId::new();
+        let mut live = RuntimeSnapshot::default();
+        store
+            .write(&Checkpoint::new(session_id, 0, None, live.clone()))
+            .await
+            .unwrap();
+
+        let layer = NarrativeLayer::new(LayerKind::Memory, "The Frozen River", SceneId::new());
+        ledger
+            .record_layer_entry(&session_id, 0, &layer)
+            .await
+            .unwrap();
+        commit_turn(&ledger, &session_id, 1, player).await;
+        catch_up(&ledger, &session_id, &mut live).await;
+        // A checkpoint taken inside the layer carries the layer with it.
+        store
+            .write(&Checkpoint::new(
+                session_id,
+                live.turn_count,
+                live.ledger_sequence,
+                live.clone(),
+            ))
+            .await
+            .unwrap();
+        commit_turn(&ledger, &session_id, 2, player).await;
+        ledger
+            .record_layer_exit(&session_id, 2, layer.id)
+            .await
+            .unwrap();
+        commit_turn(&ledger, &session_id, 3, player).await;
+        catch_up(&ledger, &session_id, &mut live).await;
+
+        assert_eq!(live.layers.depth(), 0);
+        let turns: Vec<u32> = live.journal.entries.iter().map(|e| e.turn_number).collect();
+        assert_eq!(turns, vec![3], "layer turns stay out of the main journal");
+
+        let recovered = recover(&store, &ledger, &session_id)
+            .await
+            .unwrap()
+            .expect("checkpoint written");
+        assert_eq!(
+            serde_json::to_value(&recovered.state).unwrap(),
+            serde_json::to_value(&live).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn replay_resolves_event_dependencies() {
+        use storyteller_core::types::event_dependency::{
+            DependencyEdge, DependencyKind, EventDependencyGraph, EventNode, NodeStatus,
+        };
+        use storyteller_core::types::event_grammar::{
+            ConfidenceEvidence, EventAtom, EventConfidence, EventKind, EventSource,
+        };
+        use storyteller_core::types::trigger::{EventPattern, TriggerCondition, TriggerPredicate};
+
+        let node = |id: &str, mentions: &str| EventNode {
+            id: id.to_string(),
+            description: id.to_string(),
+            condition: Some(TriggerPredicate::Atom(TriggerCondition::EventOccurred(
+                EventPattern {
+                    mentions: Some(mentions.to_string()),
+                    ..EventPattern::default()
+                },
+            ))),
+            subject: None,
+        };
+        let mut live = RuntimeSnapshot {
+            event_dependencies: EventDependencies::new(EventDependencyGraph {
+                nodes: vec![
+                    node("door_opened", "door"),
+                    node("stranger_enters", "stranger"),
+                ],
+                edges: vec![DependencyEdge {
+                    from: "door_opened".to_string(),
+                    to: "stranger_enters".to_string(),
+                    kind: DependencyKind::Requires,
+                    weight: 1.0,
+                }],
+            })
+            .unwrap(),
+            ..Default::default()
+        };
+
+        let ledger = InMemoryLedger::new();
+        let session_id = SessionId::new();
+        let sourced = ledger
+            .source_command(
+                &session_id,
+                &PlayerInput {
+                    text: "I open the door.".to_string(),
+                    turn_number: 1,
+                },
+            )
+            .await
+            .unwrap();
+        let atom = EventAtom {
+            id: EventId::new(),
+            timestamp: chrono::Utc::now(),
+            kind: EventKind::StateAssertion {
+                assertion: "The door swings open".to_string(),
+            },
+            participants: vec![],
+            relational_implications: vec![],
+            source: EventSource::System {
+                component: "test".to_string(),
+            },
+            confidence: EventConfidence {
+                value: 1.0,
+                evidence: ConfidenceEvidence::SystemProduced,
+            },
+            priority: EventPriority::Normal,
+            scene_id: SceneId::new(),
+            turn_id: None,
+        };
+        let event = NarrativeEvent {
+            id: atom.id,
+            timestamp: atom.timestamp,
+            priority: atom.priority,
+            payload: EventPayload::Atom(atom),
+        };
+        ledger
+            .append_event(&session_id, &sourced, &event)
+            .await
+            .unwrap();
+        ledger
+            .commit_turn(
+                &session_id,
+                &sourced,
+                &TurnCommitRecord {
+                    event_ids: vec![event.id],
+                    narrator_prose: "The door swings open.".to_string(),
+                    player_entity_id: None,
+                    referenced_entities: Vec::new(),
+                    emotional_markers: Vec::new(),
+                    predictions: Vec::new(),
+                    state_changes: Vec::new(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let before = live.event_dependencies.clone();
+        catch_up(&ledger, &session_id, &mut live).await;
+
+        let update = live.event_dependencies.changes_since(&before);
+        assert_eq!(update.resolved, vec!["door_opened"]);
+        assert_eq!(update.enabled, vec!["stranger_enters"]);
+        assert_eq!(
+            live.event_dependencies.status("stranger_enters"),
+            Some(NodeStatus::Unresolved)
+        );
+    }
+
+    #[tokio::test]
+    async fn uncommitted_turn_is_not_recovered() {
+        let ledger = InMemoryLedger::new();
+        let store = InMemoryCheckpointStore::new();
+        let session_id = SessionId::new();
+        let player = EntityId::new();
+
+        store
+            .write(&Checkpoint::new(
+                session_id,
+                0,
+                None,
+                RuntimeSnapshot::default(),
+            ))
+            .await
+            .unwrap();
+        commit_turn(&ledger, &session_id, 1, player).await;
+        ledger
+            .source_command(
+                &session_id,
+                &PlayerInput {
+                    text: "I run.".to_string(),
+                    turn_number: 2,
+                },
+            )
+            .await
+            .unwrap();
+
+        let recovered = recover(&store, &ledger, &session_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(recovered.state.turn_count, 1);
+        assert_eq!(recovered.state.journal.entries.len(), 1);
+    }
 }
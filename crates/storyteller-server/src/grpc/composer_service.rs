@@ -23,7 +23,9 @@ use crate::proto::{
 /// gRPC implementation of the `ComposerService` proto service.
 ///
 /// All RPCs are read-only catalog queries delegated to [`SceneComposer`].
-/// The composer is shared via `Arc` — no per-request allocation.
+/// The composer is shared via `Arc` — no per-request allocation. The
+/// server builds it from bedrock when a database is configured (see
+/// [`SceneComposer::from_bedrock`]), otherwise from descriptor files.
 #[derive(Debug)]
 pub struct ComposerServiceImpl {
     composer: Arc<SceneComposer>,
@@ -15,48 +15,58 @@ use uuid::Uuid;
 
 use crate::engine::{Composition, EngineProviders, EngineStateManager, RuntimeSnapshot};
 use crate::logging::LogBroadcast;
+use crate::middleware::auth::PlayerIdentity;
 use crate::persistence::SessionStore;
 use crate::proto::storyteller_engine_server::StorytellerEngine;
 use crate::proto::*;
 
 use storyteller_composer::{
-    compose::{CastSelection, DynamicSelection, SceneSelections},
+    compose::{CastSelection, ComposedScene, DynamicSelection, SceneSelections},
     goals::ComposedGoals,
     SceneComposer,
 };
 use storyteller_core::{
+    database::checkpoint::{recover, replay, Checkpoint, CheckpointStore},
+    database::ledger::{EventLedger, TurnCommitRecord},
+    game_design::{turn_seed, GenreRules},
+    graph::settings::SettingTopologyStore,
     traits::{
         llm::NarratorTokenStream,
-        phase_observer::{CollectingObserver, PhaseEventDetail},
+        phase_observer::{CollectingObserver, PhaseEvent, PhaseEventDetail},
+        storykeeper::{CommandSourced, CompletedTurn, SessionId},
         NoopObserver,
     },
     types::{
-        capability_lexicon::CapabilityLexicon,
         character::{CharacterSheet, SceneData},
+        character_state::CastState,
         entity::EntityId,
-        message::NarratorRendering,
-        prediction::{EmotionalRegister, EventType},
+        event::{NarrativeEvent, TurnId},
+        event_dependency::EventDependencies,
+        event_grammar::EventPayload,
+        knowledge::KnowledgeModel,
+        message::{NarratorRendering, PlayerInput},
+        narrative_layer::{EntitySharing, LayerKind, NarrativeLayer, NarrativeStack},
+        narrator_context::SceneJournal,
+        scene::SceneId,
+        setting::{SettingId, SettingNode},
+        turn_cycle::TurnCycleStage,
     },
+    StorytellerResult,
 };
 use storyteller_engine::{
     agents::narrator::NarratorAgent,
     context::{
-        assemble_narrator_context,
-        journal::{add_turn, render_journal},
+        event_composition::build_event_atoms,
+        journal::{add_turn, render_journal, JournalCompressor},
         preamble::render_preamble,
-        prediction::{decomposition_to_event_features, predict_character_behaviors},
-        DEFAULT_TOTAL_TOKEN_BUDGET,
     },
     inference::{
-        event_decomposition::{
-            event_decomposition_schema, event_decomposition_system_prompt, EventDecomposition,
-        },
-        intent_synthesis::synthesize_intents,
         intention_generation::{generate_intentions, intentions_to_preamble, GeneratedIntentions},
+        journal_summarization::LlmJournalCompressor,
+        lexicon_expansion::expand_lexicon,
     },
-    systems::arbitration::check_action_possibility,
+    pipeline,
 };
-use storyteller_ml::feature_schema::EventFeatureInput;
 
 /// gRPC implementation of the `StorytellerEngine` proto service.
 pub struct EngineServiceImpl {
@@ -114,6 +124,74 @@ impl std::fmt::Debug for EngineServiceImpl {
     }
 }
 
+impl EngineServiceImpl {
+    /// Reject callers who do not own `session_id`.
+    ///
+    /// The id must be a UUID: it names the session's directory, so anything
+    /// else is refused before a path is built from it. Reports `NotFound`
+    /// rather than `PermissionDenied` so session ids owned by other players
+    /// are indistinguishable from ids that do not exist.
+    fn authorize(&self, caller: &PlayerIdentity, session_id: &str) -> Result<(), Status> {
+        Uuid::parse_str(session_id)
+            .map_err(|e| Status::invalid_argument(format!("Invalid session id: {e}")))?;
+        if self.session_store.owners.is_owned_by(session_id, caller) {
+            Ok(())
+        } else {
+            Err(Status::not_found(format!("session {session_id} not found")))
+        }
+    }
+
+    /// The session's ledger id, once no turn is in flight. Layer changes
+    /// land between turns, so each turn plays entirely inside one layer.
+    async fn between_turns(&self, session_id: &str) -> Result<SessionId, Status> {
+        let ledger_session = Uuid::parse_str(session_id)
+            .map(SessionId)
+            .map_err(|e| Status::invalid_argument(format!("Invalid session id: {e}")))?;
+        let pending = self
+            .session_store
+            .ledger
+            .pending_command(&ledger_session)
+            .await
+            .map_err(|e| Status::internal(format!("Pending command lookup failed: {e}")))?;
+        if pending.is_some() {
+            return Err(Status::failed_precondition("a turn is in progress"));
+        }
+        Ok(ledger_session)
+    }
+
+    /// Fold ledger entries the live snapshot has not seen yet and publish
+    /// the result — the same fold the turn path and recovery perform.
+    async fn fold_ledger_tail(
+        &self,
+        session_id: &str,
+        ledger_session: &SessionId,
+    ) -> Result<RuntimeSnapshot, Status> {
+        let snapshot = self
+            .state_manager
+            .get_runtime_snapshot(session_id)
+            .ok_or_else(|| Status::not_found("session snapshot not found"))?;
+        let tail = self
+            .session_store
+            .ledger
+            .entries_since(ledger_session, snapshot.ledger_sequence)
+            .await
+            .map_err(|e| Status::internal(format!("Ledger read failed: {e}")))?;
+        let mut next = (*snapshot).clone();
+        replay(&mut next, &tail)
+            .map_err(|e| Status::internal(format!("Ledger replay failed: {e}")))?;
+
+        let published = next.clone();
+        self.state_manager
+            .update_runtime_snapshot(session_id, move |current| {
+                let mut next = published;
+                next.adopt_cached_compressions(current);
+                next
+            })
+            .await;
+        Ok(next)
+    }
+}
+
 /// Build an `EngineEvent` with a fresh UUIDv7 and current timestamp.
 ///
 /// Free function (not a method) so it's usable inside `tokio::spawn` closures
@@ -128,18 +206,175 @@ fn make_event(session_id: &str, turn: Option<u32>, payload: engine_event::Payloa
     }
 }
 
+/// Summarize the session's newest journal entry off the turn path.
+///
+/// The structured LLM compresses the entry while it is still at full
+/// detail; the result is ledgered, then cached on the live snapshot so
+/// later turns compress it to the summary instead of the first-sentence
+/// heuristic. Recovery replays the ledgered summary. Without a structured
+/// LLM, or if the call or the ledger write fails, the heuristic stands.
+fn spawn_journal_compression(
+    state_manager: Arc<EngineStateManager>,
+    session_store: Arc<SessionStore>,
+    providers: &EngineProviders,
+    session_id: &str,
+    journal: &SceneJournal,
+) {
+    let (Some(structured_llm), Some(entry), Ok(ledger_id)) = (
+        providers.structured_llm.clone(),
+        journal.entries.last().cloned(),
+        Uuid::parse_str(session_id),
+    ) else {
+        return;
+    };
+    let session_id = session_id.to_string();
+
+    tokio::spawn(async move {
+        let compressor = LlmJournalCompressor::new(structured_llm.as_ref());
+        let compression = match compressor.compress(&entry).await {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!(error = %e, session = %session_id, turn = entry.turn_number, "Journal summarization failed");
+                return;
+            }
+        };
+        if let Err(e) = session_store
+            .ledger
+            .record_journal_compression(&SessionId(ledger_id), entry.turn_number, &compression)
+            .await
+        {
+            tracing::warn!(error = %e, session = %session_id, turn = entry.turn_number, "Journal summary not ledgered; keeping the heuristic");
+            return;
+        }
+        state_manager
+            .update_runtime_snapshot(&session_id, move |snap| {
+                let mut next = snap.clone();
+                next.cache_compression(entry.turn_number, compression);
+                next
+            })
+            .await;
+    });
+}
+
+/// Mark a sourced command as never committing, freeing its turn for new
+/// input. A failure is only logged — the next submission supersedes the
+/// command anyway.
+async fn abandon_command(
+    session_store: &SessionStore,
+    ledger_session: &SessionId,
+    command: &CommandSourced,
+    reason: &str,
+) {
+    if let Err(e) = session_store
+        .ledger
+        .abandon_command(ledger_session, command, reason)
+        .await
+    {
+        tracing::warn!(error = %e, session = %ledger_session.0, turn = command.turn_number, "Failed to abandon command");
+    }
+}
+
+fn parse_entity_id(raw: &str) -> Result<EntityId, Status> {
+    Uuid::parse_str(raw)
+        .map(EntityId)
+        .map_err(|e| Status::invalid_argument(format!("Invalid entity id {raw}: {e}")))
+}
+
+/// An entity declared by an `EnterLayer` request. Only projected entities
+/// name a source.
+fn layer_entity(entity: &LayerEntity) -> Result<(EntityId, EntitySharing), Status> {
+    let entity_id = parse_entity_id(&entity.entity_id)?;
+    let sharing = match (entity.sharing.as_str(), entity.source_entity_id.as_deref()) {
+        ("shared", None) => EntitySharing::Shared,
+        ("discrete", None) => EntitySharing::Discrete,
+        ("projected", Some(source)) => EntitySharing::Projected {
+            source: parse_entity_id(source)?,
+        },
+        (sharing, _) => {
+            return Err(Status::invalid_argument(format!(
+                "Invalid sharing {sharing:?} for entity {}",
+                entity.entity_id
+            )))
+        }
+    };
+    Ok((entity_id, sharing))
+}
+
+/// The session's position in its narrative layers.
+fn layer_state(session_id: &str, snapshot: &RuntimeSnapshot) -> LayerState {
+    let current = snapshot.layers.current();
+    LayerState {
+        session_id: session_id.to_string(),
+        depth: snapshot.layers.depth() as u32,
+        layer_id: current.map(|layer| layer.id.0.to_string()),
+        title: current.map(|layer| layer.title.clone()),
+    }
+}
+
+/// Start tracking a scene's authored event dependencies. An invalid graph
+/// is logged and left untracked rather than failing the session.
+fn scene_event_dependencies(scene: &SceneData, session_id: &str) -> EventDependencies {
+    EventDependencies::new(scene.event_dependencies.clone()).unwrap_or_else(|e| {
+        tracing::warn!(error = %e, session = %session_id, "Scene event dependencies ignored");
+        EventDependencies::default()
+    })
+}
+
+/// Compose a scene into a setting.
+///
+/// With a `setting_id` the scene re-enters that setting, carrying every
+/// change earlier scenes left there. Without one, the composed setting is
+/// recorded as a new setting so later scenes can return to it.
+async fn compose_in_place(
+    composer: &SceneComposer,
+    settings: &dyn SettingTopologyStore,
+    selections: &SceneSelections,
+    setting_id: Option<&str>,
+) -> Result<ComposedScene, String> {
+    let Some(raw) = setting_id else {
+        let mut composed = composer.compose(selections)?;
+        let setting = SettingNode {
+            setting_id: SettingId::new(),
+            name: composed.scene.title.clone(),
+            base: composed.scene.setting.clone(),
+        };
+        settings
+            .upsert_setting(&setting)
+            .await
+            .map_err(|e| format!("record setting: {e}"))?;
+        settings
+            .place_scene(setting.setting_id, composed.scene.scene_id)
+            .await
+            .map_err(|e| format!("place scene: {e}"))?;
+        composed.setting_id = Some(setting.setting_id);
+        return Ok(composed);
+    };
+
+    let setting_id = Uuid::parse_str(raw)
+        .map(SettingId)
+        .map_err(|e| format!("invalid setting_id {raw:?}: {e}"))?;
+    let scene_id = SceneId::new();
+    let placed = settings
+        .place_scene(setting_id, scene_id)
+        .await
+        .map_err(|e| format!("place scene: {e}"))?;
+    composer.compose_in_setting(selections, scene_id, &placed)
+}
+
 /// Consume a token stream, batch by sentence/paragraph boundaries,
-/// and emit `NarratorProse` events. Returns the full accumulated prose.
+/// and emit `NarratorProse` events. Returns the full accumulated prose, or
+/// the backend's error if the stream broke off before completing.
 async fn stream_narrator_prose(
     mut token_stream: NarratorTokenStream,
     tx: &mpsc::Sender<Result<EngineEvent, Status>>,
     session_id: &str,
     turn: u32,
-) -> String {
+) -> StorytellerResult<String> {
     let mut full_buffer = String::new();
     let mut chunk_buffer = String::new();
 
     while let Some(token) = token_stream.0.recv().await {
+        let token = token?;
         full_buffer.push_str(&token);
         chunk_buffer.push_str(&token);
 
@@ -179,7 +414,7 @@ async fn stream_narrator_prose(
         let _ = tx.send(Ok(event)).await;
     }
 
-    full_buffer
+    Ok(full_buffer)
 }
 
 #[tonic::async_trait]
@@ -194,19 +429,27 @@ impl StorytellerEngine for EngineServiceImpl {
         &self,
         request: Request<ComposeSceneRequest>,
     ) -> Result<Response<Self::ComposeSceneStream>, Status> {
+        let caller = PlayerIdentity::of(&request);
         let req = request.into_inner();
         let (tx, rx) = mpsc::channel(32);
 
+        // An unowned session would be unreachable for its player, so both
+        // steps must succeed before composition starts.
+        let session_id = self
+            .session_store
+            .create_session()
+            .map_err(|e| Status::internal(format!("Failed to create session: {e}")))?;
+        self.session_store
+            .owners
+            .claim(&session_id, &caller)
+            .map_err(|e| Status::internal(format!("Failed to record session owner: {e}")))?;
+
         let composer = self.composer.clone();
         let state_manager = self.state_manager.clone();
         let session_store = self.session_store.clone();
         let providers = self.providers.clone();
 
         tokio::spawn(async move {
-            let session_id = session_store
-                .create_session()
-                .unwrap_or_else(|_| Uuid::now_v7().to_string());
-
             // Extract player character data before req fields are consumed.
             let player_character = req.player_character;
             if let Some(ref pc) = player_character {
@@ -263,7 +506,14 @@ impl StorytellerEngine for EngineServiceImpl {
                 seed: req.seed,
             };
 
-            let composed = match composer.compose(&selections) {
+            let composed = match compose_in_place(
+                &composer,
+                session_store.settings.as_ref(),
+                &selections,
+                req.setting_id.as_deref(),
+            )
+            .await
+            {
                 Ok(c) => c,
                 Err(e) => {
                     // Persist error event for debugging consistency with SubmitInput
@@ -299,10 +549,38 @@ impl StorytellerEngine for EngineServiceImpl {
                         setting_description: composed.scene.setting.description.clone(),
                         cast_names: cast_names.clone(),
                         composition_json: serde_json::to_string(&composed).unwrap_or_default(),
+                        setting_id: composed
+                            .setting_id
+                            .map(|id| id.0.to_string())
+                            .unwrap_or_default(),
                     }),
                 )))
                 .await;
 
+            // Arbitration rules: derived from the genre's world affordances,
+            // lexicon-expanded once here and cached with the composition.
+            let mut genre_rules = composer.genre_rules(&selections.genre_id);
+            if let Some(ref structured_llm) = providers.structured_llm {
+                let genre_description = composer
+                    .genres()
+                    .into_iter()
+                    .find(|g| g.id == selections.genre_id || g.entity_id == selections.genre_id)
+                    .map(|g| format!("{} — {}", g.display_name, g.description))
+                    .unwrap_or_else(|| selections.genre_id.clone());
+                match expand_lexicon(
+                    structured_llm.as_ref(),
+                    &genre_rules.lexicon,
+                    &genre_description,
+                )
+                .await
+                {
+                    Ok(lexicon) => genre_rules.lexicon = lexicon,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Lexicon expansion failed — using seed lexicon");
+                    }
+                }
+            }
+
             // Goal intersection
             let goals = composer.intersect_goals(&selections, &composed);
 
@@ -346,7 +624,9 @@ impl StorytellerEngine for EngineServiceImpl {
                 "characters": composed.characters,
                 "goals": goals,
                 "intentions": serde_json::to_value(&generated_intentions).unwrap_or(serde_json::Value::Null),
+                "genre_rules": genre_rules,
                 "player_character": player_character_json,
+                "setting_id": composed.setting_id,
             });
 
             if let Err(e) = session_store
@@ -414,6 +694,40 @@ impl StorytellerEngine for EngineServiceImpl {
                     .filter(|s| !s.is_empty())
             };
 
+            // Register the scene and its cast with the durable Storykeeper.
+            if let Some(mirror) = &session_store.storykeeper {
+                let entered = match Uuid::parse_str(&session_id) {
+                    Ok(id) => {
+                        let session = mirror.session(
+                            SessionId(id),
+                            player_entity_id_for_goals,
+                            composed.scene.scene_id,
+                            0,
+                            &NarrativeStack::default(),
+                        );
+                        mirror
+                            .enter_scene(&composed.scene, &composed.characters, &session)
+                            .await
+                            .map_err(|e| e.to_string())
+                    }
+                    Err(e) => Err(format!("invalid session id: {e}")),
+                };
+                if let Err(e) = entered {
+                    tracing::error!(error = %e, session = %session_id, "Storykeeper scene entry failed");
+                    let _ = tx
+                        .send(Ok(make_event(
+                            &session_id,
+                            Some(0),
+                            engine_event::Payload::Error(ErrorOccurred {
+                                phase: "composition".to_string(),
+                                message: format!("Storykeeper scene entry failed: {e}"),
+                            }),
+                        )))
+                        .await;
+                    return;
+                }
+            }
+
             let _ = tx
                 .send(Ok(make_event(
                     &session_id,
@@ -453,10 +767,7 @@ impl StorytellerEngine for EngineServiceImpl {
                 .find(|c| c.role.to_lowercase().contains("protagonist"))
                 .map(|c| c.entity_id);
 
-            let opening_journal = storyteller_core::types::narrator_context::SceneJournal::new(
-                composed.scene.scene_id,
-                1200,
-            );
+            let opening_journal = SceneJournal::new(composed.scene.scene_id, 1200);
             let opening_resolver = storyteller_core::types::resolver::ResolverOutput {
                 sequenced_actions: vec![],
                 original_predictions: vec![],
@@ -465,26 +776,30 @@ impl StorytellerEngine for EngineServiceImpl {
                 intent_statements: None,
             };
 
+            let opening_knowledge = KnowledgeModel::from_characters(&characters_refs);
+            let opening_turn = pipeline::TurnInputs {
+                scene: &composed.scene,
+                characters: &characters_refs,
+                journal: &opening_journal,
+                turn_number: 0,
+                player_input: "",
+                player_entity_id,
+                intentions: generated_intentions.as_ref(),
+                knowledge: &opening_knowledge,
+                narrative_layer: None,
+            };
+
             let obs = CollectingObserver::new();
-            let mut context = assemble_narrator_context(
-                &composed.scene,
-                &characters_refs,
-                &opening_journal,
+            let mut context = pipeline::assemble(
+                &opening_turn,
                 &opening_resolver,
-                "",
                 &entity_ids,
-                DEFAULT_TOTAL_TOKEN_BUDGET,
                 &obs,
-                player_entity_id,
                 None, // directive_context — no directives at scene open
-            );
+                None, // action_verdict — no player action yet
+            )
+            .context;
 
-            // Inject composition-time intentions into opening preamble
-            if let Some(ref intentions) = generated_intentions {
-                let (scene_direction, character_drives) = intentions_to_preamble(intentions);
-                context.preamble.scene_direction = Some(scene_direction);
-                context.preamble.character_drives = character_drives;
-            }
             // Inject player context from composed goals
             if let Some(player_ctx) = &goals_player_context {
                 context.preamble.player_context = Some(player_ctx.clone());
@@ -510,8 +825,12 @@ impl StorytellerEngine for EngineServiceImpl {
             let narrator_model_name = providers.narrator_model.clone();
             let noop = NoopObserver;
             let llm_start = Instant::now();
-            let opening_prose = match narrator.stream_render_opening(&noop).await {
+            let streamed = match narrator.stream_render_opening(&noop).await {
                 Ok(token_stream) => stream_narrator_prose(token_stream, &tx, &session_id, 0).await,
+                Err(e) => Err(e),
+            };
+            let opening_prose = match streamed {
+                Ok(prose) => prose,
                 Err(e) => {
                     tracing::error!(
                         error = %e,
@@ -554,6 +873,7 @@ impl StorytellerEngine for EngineServiceImpl {
                     .as_ref()
                     .map(|gi| serde_json::to_value(gi).unwrap_or_default()),
                 selections: serde_json::to_value(&selections).unwrap_or_default(),
+                genre_rules: serde_json::to_value(&genre_rules).ok(),
             };
             state_manager.create_session(&session_id, composition);
 
@@ -568,14 +888,33 @@ impl StorytellerEngine for EngineServiceImpl {
                 vec![],
                 &noop_journal,
             );
+            let opening_snapshot = RuntimeSnapshot {
+                turn_count: 0,
+                player_entity_id,
+                journal: opening_journal_with_prose,
+                cast_state: CastState::new(&characters_refs, &providers.grammars),
+                event_dependencies: scene_event_dependencies(&composed.scene, &session_id),
+                ..Default::default()
+            };
+            let published = opening_snapshot.clone();
             state_manager
-                .update_runtime_snapshot(&session_id, move |_snap| RuntimeSnapshot {
-                    turn_count: 0,
-                    player_entity_id,
-                    journal: opening_journal_with_prose,
-                    prediction_history: Default::default(),
-                })
+                .update_runtime_snapshot(&session_id, move |_snap| published)
                 .await;
+            spawn_journal_compression(
+                state_manager.clone(),
+                session_store.clone(),
+                &providers,
+                &session_id,
+                &opening_snapshot.journal,
+            );
+
+            // Baseline checkpoint — recovery replays the ledger from here.
+            if let Ok(id) = Uuid::parse_str(&session_id) {
+                let checkpoint = Checkpoint::new(SessionId(id), 0, None, opening_snapshot);
+                if let Err(e) = session_store.checkpoints.write(&checkpoint).await {
+                    tracing::warn!(error = %e, session = %session_id, "Checkpoint write failed");
+                }
+            }
 
             // Persist turn 0 to event log and turn index
             let mut turn0_event_ids = Vec::new();
@@ -638,10 +977,13 @@ impl StorytellerEngine for EngineServiceImpl {
         &self,
         request: Request<SubmitInputRequest>,
     ) -> Result<Response<Self::SubmitInputStream>, Status> {
+        let caller = PlayerIdentity::of(&request);
         let req = request.into_inner();
         let session_id = req.session_id.clone();
         let input = req.input;
 
+        self.authorize(&caller, &session_id)?;
+
         if !self.state_manager.has_session(&session_id) {
             return Err(Status::not_found("session not found"));
         }
@@ -692,8 +1034,6 @@ impl StorytellerEngine for EngineServiceImpl {
                     return;
                 }
             };
-            let characters_refs: Vec<&CharacterSheet> = characters.iter().collect();
-            let entity_ids: Vec<EntityId> = characters.iter().map(|c| c.entity_id).collect();
 
             // Optional goal types (graceful degradation if missing/malformed)
             let composed_goals: Option<ComposedGoals> = composition
@@ -704,6 +1044,11 @@ impl StorytellerEngine for EngineServiceImpl {
                 .intentions
                 .as_ref()
                 .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let genre_rules: GenreRules = composition
+                .genre_rules
+                .as_ref()
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or_default();
 
             // --- Load runtime snapshot ---
             let snapshot = match state_manager.get_runtime_snapshot(&session_id) {
@@ -717,6 +1062,79 @@ impl StorytellerEngine for EngineServiceImpl {
             };
             let turn = snapshot.turn_count + 1;
 
+            // Characters enter the turn feeling as they do now, not as they
+            // were composed. Sessions from before live state get seeded here.
+            let mut cast_state = snapshot.cast_state.clone();
+            if cast_state.is_empty() {
+                let composed: Vec<&CharacterSheet> = characters.iter().collect();
+                cast_state = CastState::new(&composed, &providers.grammars);
+            }
+            let characters: Vec<CharacterSheet> =
+                characters.iter().map(|c| cast_state.overlay(c)).collect();
+            let characters_refs: Vec<&CharacterSheet> = characters.iter().collect();
+            // Inside a narrative layer, the turn references the cast as the
+            // layer sees them: hidden members drop out, projections stand in.
+            let entity_ids: Vec<EntityId> = characters
+                .iter()
+                .filter_map(|c| snapshot.layers.resolve(c.entity_id))
+                .collect();
+
+            // --- Command sourcing: the input is durable before any processing ---
+            let ledger_session = match Uuid::parse_str(&session_id) {
+                Ok(id) => SessionId(id),
+                Err(e) => {
+                    let _ = tx
+                        .send(Err(Status::invalid_argument(format!(
+                            "Invalid session id: {e}"
+                        ))))
+                        .await;
+                    return;
+                }
+            };
+            // A command an earlier attempt sourced but never committed —
+            // the turn failed, or the server stopped mid-turn — is
+            // superseded by this input.
+            match session_store.ledger.pending_command(&ledger_session).await {
+                Ok(Some((pending, _))) => {
+                    abandon_command(
+                        &session_store,
+                        &ledger_session,
+                        &pending,
+                        "superseded by new input",
+                    )
+                    .await;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(error = %e, session = %session_id, "Pending command lookup failed");
+                }
+            }
+            let command = match session_store
+                .ledger
+                .source_command(
+                    &ledger_session,
+                    &PlayerInput {
+                        text: input.clone(),
+                        turn_number: turn,
+                    },
+                )
+                .await
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::error!(error = %e, session = %session_id, "Command sourcing failed");
+                    let _ = tx
+                        .send(Err(Status::internal(format!(
+                            "Failed to source command: {e}"
+                        ))))
+                        .await;
+                    return;
+                }
+            };
+            let turn_id = TurnId::new();
+            let mut ledger_event_ids = Vec::new();
+            let mut ledgered_events = Vec::new();
+
             // Emit InputReceived at the start of processing
             let _ = tx
                 .send(Ok(make_event(
@@ -734,6 +1152,45 @@ impl StorytellerEngine for EngineServiceImpl {
                 .map(|c| c.entity_id)
                 .or(snapshot.player_entity_id);
 
+            // The durable Storykeeper sources the command too.
+            let mirrored = session_store.storykeeper.as_ref().map(|mirror| {
+                let session = mirror.session(
+                    ledger_session,
+                    player_entity_id,
+                    scene.scene_id,
+                    turn,
+                    &snapshot.layers,
+                );
+                (mirror, session)
+            });
+            if let Some((mirror, mirror_session)) = &mirrored {
+                let sourced = mirror
+                    .source_command(
+                        &PlayerInput {
+                            text: input.clone(),
+                            turn_number: turn,
+                        },
+                        mirror_session,
+                    )
+                    .await;
+                if let Err(e) = sourced {
+                    tracing::error!(error = %e, session = %session_id, "Storykeeper command sourcing failed");
+                    abandon_command(
+                        &session_store,
+                        &ledger_session,
+                        &command,
+                        &format!("storykeeper sourcing failed: {e}"),
+                    )
+                    .await;
+                    let _ = tx
+                        .send(Err(Status::internal(format!(
+                            "Failed to source command: {e}"
+                        ))))
+                        .await;
+                    return;
+                }
+            }
+
             // Emit ProcessingUpdate before enrichment phase
             let _ = tx
                 .send(Ok(make_event(
@@ -756,49 +1213,25 @@ impl StorytellerEngine for EngineServiceImpl {
                 )))
                 .await;
 
-            let decomp_start = Instant::now();
-            let mut persisted_decomposition: Option<serde_json::Value> = None;
-            let mut event_decomposition: Option<EventDecomposition> = None;
-
-            if let Some(ref structured_llm) = providers.structured_llm {
-                // Include last narrator prose so the model can resolve pronouns
-                let decomp_input = if let Some(last_entry) = snapshot.journal.entries.last() {
-                    format!("[Narrator]\n{}\n\n[Player]\n{}", last_entry.content, input)
-                } else {
-                    input.clone()
-                };
+            // Knowledge is replayed from the cast and the committed truth
+            // set, so a recovered session enforces the same boundaries.
+            let knowledge = KnowledgeModel::replay(&characters_refs, snapshot.truth_set.atoms());
+            let layer_frame = snapshot.layers.frame();
+            let turn_inputs = pipeline::TurnInputs {
+                scene: &scene,
+                characters: &characters_refs,
+                journal: snapshot.current_journal(),
+                turn_number: turn,
+                player_input: &input,
+                player_entity_id,
+                intentions: generated_intentions.as_ref(),
+                knowledge: &knowledge,
+                narrative_layer: layer_frame.as_ref(),
+            };
 
-                let request = storyteller_core::traits::structured_llm::StructuredRequest {
-                    system: event_decomposition_system_prompt(),
-                    input: decomp_input,
-                    output_schema: event_decomposition_schema(),
-                    temperature: 0.1,
-                };
-                match structured_llm.extract(request).await {
-                    Ok(raw_json) => {
-                        tracing::debug!(
-                            raw = %serde_json::to_string(&raw_json).unwrap_or_default(),
-                            "Event decomposition raw response"
-                        );
-                        persisted_decomposition = Some(raw_json.clone());
-                        event_decomposition = EventDecomposition::from_json(&raw_json).ok();
-                    }
-                    Err(e) => {
-                        tracing::warn!(error = %e, "Event decomposition LLM call failed");
-                    }
-                }
-            }
-            let decomposition_ms = decomp_start.elapsed().as_millis() as u64;
-
-            let decomp_json = persisted_decomposition
-                .clone()
-                .unwrap_or_else(|| serde_json::json!({"status": "skipped"}));
-            let decomp_error =
-                if event_decomposition.is_none() && providers.structured_llm.is_some() {
-                    Some("Decomposition failed or produced unparseable output".to_string())
-                } else {
-                    None
-                };
+            let decomposition =
+                pipeline::decompose(&turn_inputs, providers.structured_llm.as_deref()).await;
+            let decomp_json = decomposition.persisted_json();
             if let Ok(eid) =
                 session_store
                     .events
@@ -813,24 +1246,38 @@ impl StorytellerEngine for EngineServiceImpl {
                     Some(turn),
                     engine_event::Payload::Decomposition(DecompositionComplete {
                         raw_json: serde_json::to_string(&decomp_json).unwrap_or_default(),
-                        timing_ms: decomposition_ms,
+                        timing_ms: decomposition.timing_ms,
                         model: providers.decomposition_model.clone(),
-                        error: decomp_error,
+                        error: decomposition.error(),
                     }),
                 )))
                 .await;
 
-            // Derive event features from decomposition for ML prediction
-            let event_features = if let Some(ref decomposition) = event_decomposition {
-                decomposition_to_event_features(decomposition)
-            } else {
-                EventFeatureInput {
-                    event_type: EventType::Interaction,
-                    emotional_register: EmotionalRegister::Neutral,
-                    confidence: 0.5,
-                    target_count: characters.len().saturating_sub(1) as u8,
+            // Ledger the classified events from the decomposition
+            if let Some(ref classification) = decomposition.classification() {
+                let atoms = build_event_atoms(classification, scene.scene_id, turn_id);
+                for atom in atoms {
+                    let event = NarrativeEvent {
+                        id: atom.id,
+                        timestamp: atom.timestamp,
+                        priority: atom.priority,
+                        payload: EventPayload::Atom(atom),
+                    };
+                    match session_store
+                        .ledger
+                        .append_event(&ledger_session, &command, &event)
+                        .await
+                    {
+                        Ok(_) => {
+                            ledger_event_ids.push(event.id);
+                            ledgered_events.push(event);
+                        }
+                        Err(e) => {
+                            tracing::warn!(error = %e, "Failed to ledger classified event");
+                        }
+                    }
                 }
-            };
+            }
 
             // --- Phase 2: ML Prediction ---
             let _ = tx
@@ -843,113 +1290,123 @@ impl StorytellerEngine for EngineServiceImpl {
                 )))
                 .await;
 
-            let predict_start = Instant::now();
-            let mut resolver_output = if let Some(ref predictor) = providers.predictor {
-                let predictions = predict_character_behaviors(
-                    predictor,
-                    &characters_refs,
-                    &scene,
-                    &input,
-                    &*providers.grammar,
-                    event_features,
-                    snapshot.prediction_history.as_map(),
-                );
-                storyteller_core::types::resolver::ResolverOutput {
-                    sequenced_actions: vec![],
-                    original_predictions: predictions,
-                    scene_dynamics: "ML-predicted character behavior".to_string(),
-                    conflicts: vec![],
-                    intent_statements: None,
-                }
-            } else {
-                storyteller_core::types::resolver::ResolverOutput {
-                    sequenced_actions: vec![],
-                    original_predictions: vec![],
-                    scene_dynamics: "Character behavior (ML predictor not available)".to_string(),
-                    conflicts: vec![],
-                    intent_statements: None,
-                }
-            };
-            let prediction_ms = predict_start.elapsed().as_millis() as u64;
-            let model_loaded = providers.predictor.is_some();
-
-            // Build updated prediction history from this turn's predictions
-            let mut new_prediction_history = snapshot.prediction_history.clone();
-            for pred in &resolver_output.original_predictions {
-                new_prediction_history.push_from_prediction(pred);
-            }
+            let prediction = pipeline::predict(
+                &turn_inputs,
+                providers.predictor.as_deref(),
+                &providers.grammars,
+                decomposition.event_features(characters.len()),
+                snapshot.prediction_history.as_map(),
+            );
 
             let _ = tx
                 .send(Ok(make_event(
                     &session_id,
                     Some(turn),
                     engine_event::Payload::Prediction(PredictionComplete {
-                        raw_json: serde_json::to_string(&resolver_output.original_predictions)
+                        raw_json: serde_json::to_string(&prediction.predictions)
                             .unwrap_or_default(),
-                        timing_ms: prediction_ms,
-                        model_loaded,
+                        timing_ms: prediction.timing_ms,
+                        model_loaded: prediction.model_loaded,
                     }),
                 )))
                 .await;
 
             // --- Phase 3: Action Arbitration ---
-            let arb_start = Instant::now();
-            let arbitration_result =
-                check_action_possibility(&input, &[], &CapabilityLexicon::new(), None);
-            let arb_ms = arb_start.elapsed().as_millis() as u64;
+            let adjudication_observer = CollectingObserver::new();
+            let arbitration = pipeline::adjudicate(
+                &turn_inputs,
+                pipeline::arbitrate(&input, &genre_rules),
+                providers.structured_llm.as_deref(),
+                &adjudication_observer,
+            )
+            .await;
+            let arb_ms = arbitration.timing_ms;
+            for event in adjudication_observer.take_events() {
+                if let Ok(json) = serde_json::to_value(&event) {
+                    if let Ok(eid) =
+                        session_store
+                            .events
+                            .append(&session_id, "adjudication", Some(turn), &json)
+                    {
+                        event_ids.push(eid);
+                    }
+                }
+            }
+            let arb_details = match arbitration.adjudication {
+                Some(ref a) => format!(
+                    "Adjudicated in {arb_ms}ms ({:.2} confidence): {}",
+                    a.confidence, a.rationale
+                ),
+                None => format!("Arbitration completed in {arb_ms}ms"),
+            };
 
             let _ = tx
                 .send(Ok(make_event(
                     &session_id,
                     Some(turn),
                     engine_event::Payload::Arbitration(ArbitrationComplete {
-                        verdict: format!("{:?}", arbitration_result),
-                        details: format!("Arbitration completed in {arb_ms}ms"),
+                        verdict: format!("{:?}", arbitration.result),
+                        details: arb_details,
                         player_input: input.clone(),
                         timing_ms: arb_ms,
                     }),
                 )))
                 .await;
 
-            // --- Phase 4: Intent Synthesis ---
-            let intent_start = Instant::now();
-            let journal_tail = snapshot
-                .journal
-                .entries
-                .iter()
-                .rev()
-                .take(2)
-                .rev()
-                .map(|e| e.content.as_str())
-                .collect::<Vec<_>>()
-                .join("\n\n");
-
-            let intent_statements = if let Some(ref intent_llm) = providers.intent_llm {
-                synthesize_intents(
-                    intent_llm.as_ref(),
-                    &characters_refs,
-                    &resolver_output.original_predictions,
-                    &journal_tail,
-                    &input,
-                    &scene,
-                    player_entity_id,
-                    generated_intentions.as_ref(),
-                )
-                .await
-            } else {
-                None
-            };
-            let intent_ms = intent_start.elapsed().as_millis() as u64;
+            // Resolve predictions through the genre's game design system: the
+            // hidden graduated-success mechanics sequence the cast's actions,
+            // settle contests, and attach state changes for the narrator. An
+            // action arbitration ruled impossible is not resolved at all.
+            let genre_id = composition
+                .selections
+                .get("genre_id")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let session_seed = composition
+                .selections
+                .get("seed")
+                .and_then(|v| v.as_u64())
+                .unwrap_or_else(|| ledger_session.0.as_u64_pair().1);
+            let game_design = providers.game_design.system_for(
+                genre_id,
+                &characters,
+                turn_seed(session_seed, turn),
+            );
+            let mut resolver_output = pipeline::resolve(
+                game_design.as_ref(),
+                prediction,
+                &pipeline::scene_world_model(&scene),
+                Some(&arbitration.result),
+            );
+            let resolution_json = serde_json::json!({
+                "system": game_design.name(),
+                "sequenced_actions": &resolver_output.sequenced_actions,
+                "conflicts": &resolver_output.conflicts,
+            });
+            if let Ok(eid) =
+                session_store
+                    .events
+                    .append(&session_id, "resolution", Some(turn), &resolution_json)
+            {
+                event_ids.push(eid);
+            }
 
-            resolver_output.intent_statements = intent_statements.clone();
+            // --- Phase 4: Intent Synthesis ---
+            let intents = pipeline::synthesize(
+                &turn_inputs,
+                providers.intent_llm.as_deref(),
+                &resolver_output.original_predictions,
+            )
+            .await;
+            resolver_output.intent_statements = intents.statements.clone();
 
             let _ = tx
                 .send(Ok(make_event(
                     &session_id,
                     Some(turn),
                     engine_event::Payload::IntentSynthesis(IntentSynthesisComplete {
-                        intent_statements: intent_statements.unwrap_or_default(),
-                        timing_ms: intent_ms,
+                        intent_statements: intents.statements.unwrap_or_default(),
+                        timing_ms: intents.timing_ms,
                     }),
                 )))
                 .await;
@@ -967,7 +1424,6 @@ impl StorytellerEngine for EngineServiceImpl {
 
             // --- Phase 5: Context Assembly ---
             let observer = CollectingObserver::new();
-            let assembly_start = Instant::now();
             let emotional_markers = extract_emotional_markers(&input);
 
             // Read applicable directives for this turn (graceful on empty — store is
@@ -982,26 +1438,17 @@ impl StorytellerEngine for EngineServiceImpl {
                         .map(|d| format!("[Dramatic Direction] {}", d.payload))
                 });
 
-            let mut context = assemble_narrator_context(
-                &scene,
-                &characters_refs,
-                &snapshot.journal,
+            let assembly = pipeline::assemble(
+                &turn_inputs,
                 &resolver_output,
-                &input,
                 &entity_ids,
-                DEFAULT_TOTAL_TOKEN_BUDGET,
                 &observer,
-                player_entity_id,
                 directive_context.as_deref(),
+                Some(&arbitration.result),
             );
-            let assembly_ms = assembly_start.elapsed().as_millis() as u64;
+            let assembly_ms = assembly.timing_ms;
+            let mut context = assembly.context;
 
-            // Inject composition-time goal intentions into preamble
-            if let Some(ref intentions) = generated_intentions {
-                let (scene_direction, character_drives) = intentions_to_preamble(intentions);
-                context.preamble.scene_direction = Some(scene_direction);
-                context.preamble.character_drives = character_drives;
-            }
             // Inject player context from composed goals
             if let Some(ref goals) = composed_goals {
                 use storyteller_composer::goals::GoalVisibility;
@@ -1090,21 +1537,33 @@ impl StorytellerEngine for EngineServiceImpl {
             );
             let noop = NoopObserver;
             let llm_start = Instant::now();
-            let token_stream = match narrator.stream_render(&context, &noop).await {
-                Ok(s) => s,
+            let streamed = match narrator.stream_render(&context, &noop).await {
+                Ok(token_stream) => {
+                    stream_narrator_prose(token_stream, &tx, &session_id, turn).await
+                }
+                Err(e) => Err(e),
+            };
+            let prose = match streamed {
+                Ok(prose) => prose,
                 Err(e) => {
                     tracing::error!(
                         error = %e,
                         session = %session_id,
                         "Narrator stream failed"
                     );
+                    abandon_command(
+                        &session_store,
+                        &ledger_session,
+                        &command,
+                        &format!("narrator failed: {e}"),
+                    )
+                    .await;
                     let _ = tx
                         .send(Err(Status::internal(format!("Narrator failed: {e}"))))
                         .await;
                     return;
                 }
             };
-            let prose = stream_narrator_prose(token_stream, &tx, &session_id, turn).await;
             let rendering = NarratorRendering {
                 text: prose,
                 stage_directions: Some(resolver_output.scene_dynamics.clone()),
@@ -1138,29 +1597,167 @@ impl StorytellerEngine for EngineServiceImpl {
                 )))
                 .await;
 
-            // --- Update journal (clone from snapshot, add new turn) ---
-            let noop_journal = NoopObserver;
-            let mut new_journal = snapshot.journal.clone();
-            add_turn(
-                &mut new_journal,
-                turn,
-                &rendering.text,
-                entity_ids,
-                emotional_markers,
-                &noop_journal,
-            );
+            // --- Commit the turn to the durable Storykeeper ---
+            // The ledger commit is the point of no return, so a turn the
+            // Storykeeper refuses fails here and can be sent again.
+            if let Some((mirror, mirror_session)) = &mirrored {
+                let completed = CompletedTurn {
+                    turn_number: turn,
+                    player_input: PlayerInput {
+                        text: input.clone(),
+                        turn_number: turn,
+                    },
+                    resolver_output: resolver_output.clone(),
+                    events: ledgered_events,
+                };
+                if let Err(e) = mirror.commit_turn(&completed, mirror_session).await {
+                    tracing::error!(error = %e, session = %session_id, "Storykeeper commit failed");
+                    abandon_command(
+                        &session_store,
+                        &ledger_session,
+                        &command,
+                        &format!("storykeeper commit failed: {e}"),
+                    )
+                    .await;
+                    let _ = tx
+                        .send(Ok(make_event(
+                            &session_id,
+                            Some(turn),
+                            engine_event::Payload::Error(ErrorOccurred {
+                                phase: "commit".to_string(),
+                                message: format!("Turn commit failed: {e}"),
+                            }),
+                        )))
+                        .await;
+                    return;
+                }
+            }
+
+            // --- Commit the turn to the ledger ---
+            if let Err(e) = session_store
+                .ledger
+                .commit_turn(
+                    &ledger_session,
+                    &command,
+                    &TurnCommitRecord {
+                        event_ids: ledger_event_ids,
+                        narrator_prose: rendering.text.clone(),
+                        player_entity_id,
+                        referenced_entities: entity_ids,
+                        emotional_markers,
+                        predictions: resolver_output.original_predictions.clone(),
+                        state_changes: resolver_output
+                            .sequenced_actions
+                            .iter()
+                            .flat_map(|r| r.outcomes.iter())
+                            .flat_map(|o| o.state_changes.iter().cloned())
+                            .collect(),
+                    },
+                )
+                .await
+            {
+                tracing::error!(error = %e, session = %session_id, "Turn commit failed");
+                let _ = tx
+                    .send(Ok(make_event(
+                        &session_id,
+                        Some(turn),
+                        engine_event::Payload::Error(ErrorOccurred {
+                            phase: "commit".to_string(),
+                            message: format!("Turn commit failed: {e}"),
+                        }),
+                    )))
+                    .await;
+                return;
+            }
 
-            // --- Update runtime snapshot ---
+            // --- Update runtime snapshot by folding the committed entries ---
+            // Recovery replays the same entries, so the live snapshot and a
+            // recovered one cannot drift apart. Journal summaries land in the
+            // ledger too, and are folded by whichever turn follows them.
+            let mut new_snapshot = (*snapshot).clone();
+            new_snapshot.cast_state = cast_state;
+            if new_snapshot.event_dependencies.is_empty() {
+                new_snapshot.event_dependencies = scene_event_dependencies(&scene, &session_id);
+            }
+            let dependencies_before = new_snapshot.event_dependencies.clone();
+            let folded = match session_store
+                .ledger
+                .entries_since(&ledger_session, snapshot.ledger_sequence)
+                .await
+            {
+                Ok(tail) => replay(&mut new_snapshot, &tail),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = folded {
+                tracing::error!(error = %e, session = %session_id, "Ledger replay failed");
+                let _ = tx
+                    .send(Ok(make_event(
+                        &session_id,
+                        Some(turn),
+                        engine_event::Payload::Error(ErrorOccurred {
+                            phase: "commit".to_string(),
+                            message: format!("Ledger replay failed: {e}"),
+                        }),
+                    )))
+                    .await;
+                return;
+            }
+            let dependency_update = new_snapshot
+                .event_dependencies
+                .changes_since(&dependencies_before);
+            if !dependency_update.is_empty() {
+                let event = PhaseEvent {
+                    timestamp: Utc::now(),
+                    turn_number: turn,
+                    stage: TurnCycleStage::CommittingPrevious,
+                    detail: PhaseEventDetail::EventDependenciesUpdated {
+                        resolved: dependency_update.resolved,
+                        enabled: dependency_update.enabled,
+                        foreclosed: dependency_update.foreclosed,
+                        reachable: new_snapshot.event_dependencies.reachable().count(),
+                    },
+                };
+                tracing::debug!(session = %session_id, "{event}");
+                if let Ok(json) = serde_json::to_value(&event) {
+                    if let Ok(eid) =
+                        session_store
+                            .events
+                            .append(&session_id, "dependencies", Some(turn), &json)
+                    {
+                        event_ids.push(eid);
+                    }
+                }
+            }
+            // Keep any summaries that landed while this turn ran.
+            let published = new_snapshot.clone();
             state_manager
-                .update_runtime_snapshot(&session_id, move |_snap| RuntimeSnapshot {
-                    turn_count: turn,
-                    player_entity_id,
-                    journal: new_journal,
-                    prediction_history: new_prediction_history,
+                .update_runtime_snapshot(&session_id, move |current| {
+                    let mut next = published;
+                    next.adopt_cached_compressions(current);
+                    next
                 })
                 .await;
+            spawn_journal_compression(
+                state_manager.clone(),
+                session_store.clone(),
+                &providers,
+                &session_id,
+                new_snapshot.current_journal(),
+            );
+
+            // --- Periodic checkpoint ---
+            if session_store.checkpoint_policy.should_checkpoint(turn) {
+                let checkpoint = Checkpoint::new(
+                    ledger_session,
+                    turn,
+                    new_snapshot.ledger_sequence,
+                    new_snapshot,
+                );
+                if let Err(e) = session_store.checkpoints.write(&checkpoint).await {
+                    tracing::warn!(error = %e, session = %session_id, "Checkpoint write failed");
+                }
+            }
 
-            // --- Persist turn ---
             let turn_entry = crate::persistence::TurnEntry {
                 turn,
                 timestamp: Utc::now().to_rfc3339(),
@@ -1190,7 +1787,10 @@ impl StorytellerEngine for EngineServiceImpl {
         &self,
         request: Request<ResumeSessionRequest>,
     ) -> Result<Response<Self::ResumeSessionStream>, Status> {
+        let caller = PlayerIdentity::of(&request);
         let session_id = request.into_inner().session_id;
+        self.authorize(&caller, &session_id)?;
+
         let (tx, rx) = mpsc::channel(32);
 
         let state_manager = self.state_manager.clone();
@@ -1226,6 +1826,7 @@ impl StorytellerEngine for EngineServiceImpl {
                 goals: comp.get("goals").cloned(),
                 intentions: comp.get("intentions").cloned(),
                 selections: comp.get("selections").cloned().unwrap_or_default(),
+                genre_rules: comp.get("genre_rules").cloned(),
             };
 
             // Emit SceneComposed
@@ -1256,6 +1857,11 @@ impl StorytellerEngine for EngineServiceImpl {
                             .to_string(),
                         cast_names,
                         composition_json: String::new(),
+                        setting_id: comp
+                            .get("setting_id")
+                            .and_then(|s| s.as_str())
+                            .unwrap_or("")
+                            .to_string(),
                     }),
                 )))
                 .await;
@@ -1265,16 +1871,71 @@ impl StorytellerEngine for EngineServiceImpl {
                 .turns
                 .read_all(&session_id)
                 .unwrap_or_default();
-            let turn_count = turns.len() as u32;
+
+            // Restore the latest checkpoint and replay the ledger after it.
+            // Sessions written before checkpoints existed only recover their
+            // turn count.
+            let recovered = match Uuid::parse_str(&session_id) {
+                Ok(id) => {
+                    let ledger_session = SessionId(id);
+                    // A turn the server stopped in the middle of is dropped;
+                    // the player resumes at that turn and can send anything.
+                    if let Ok(Some((pending, input))) =
+                        session_store.ledger.pending_command(&ledger_session).await
+                    {
+                        tracing::warn!(
+                            session = %session_id,
+                            turn = pending.turn_number,
+                            input = %input.text,
+                            "Abandoning command interrupted before commit"
+                        );
+                        abandon_command(
+                            &session_store,
+                            &ledger_session,
+                            &pending,
+                            "interrupted before commit",
+                        )
+                        .await;
+                    }
+                    recover(
+                        &session_store.checkpoints,
+                        &session_store.ledger,
+                        &ledger_session,
+                    )
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::warn!(error = %e, session = %session_id, "Checkpoint recovery failed");
+                        None
+                    })
+                }
+                Err(_) => None,
+            };
 
             state_manager.create_session(&session_id, composition);
-            state_manager
-                .update_runtime_snapshot(&session_id, |snap| {
-                    let mut new = snap.clone();
-                    new.turn_count = turn_count;
-                    new
-                })
-                .await;
+            match recovered {
+                Some(recovered) => {
+                    tracing::info!(
+                        session = %session_id,
+                        checkpoint = %recovered.checkpoint_id.0,
+                        replayed = recovered.replayed,
+                        "Recovered session from checkpoint"
+                    );
+                    let state = recovered.state;
+                    state_manager
+                        .update_runtime_snapshot(&session_id, move |_snap| state)
+                        .await;
+                }
+                None => {
+                    let turn_count = turns.len() as u32;
+                    state_manager
+                        .update_runtime_snapshot(&session_id, |snap| {
+                            let mut new = snap.clone();
+                            new.turn_count = turn_count;
+                            new
+                        })
+                        .await;
+                }
+            }
 
             // Load persisted events to extract narrator prose for each turn
             let events = session_store
@@ -1338,7 +1999,81 @@ impl StorytellerEngine for EngineServiceImpl {
         Ok(Response::new(ReceiverStream::new(rx)))
     }
 
-    async fn list_sessions(&self, _request: Request<()>) -> Result<Response<SessionList>, Status> {
+    async fn enter_layer(
+        &self,
+        request: Request<EnterLayerRequest>,
+    ) -> Result<Response<LayerState>, Status> {
+        let caller = PlayerIdentity::of(&request);
+        let req = request.into_inner();
+        let session_id = req.session_id;
+        self.authorize(&caller, &session_id)?;
+
+        let kind: LayerKind = serde_json::from_value(serde_json::Value::String(req.kind.clone()))
+            .map_err(|_| {
+            Status::invalid_argument(format!("Unknown layer kind: {}", req.kind))
+        })?;
+        let snapshot = self
+            .state_manager
+            .get_runtime_snapshot(&session_id)
+            .ok_or_else(|| Status::not_found(format!("session {session_id} not found")))?;
+
+        // The layer is played inside the scene the session is in.
+        let mut layer = NarrativeLayer::new(kind, req.title, snapshot.journal.scene_id);
+        if let Some(teller) = req.teller {
+            layer = layer.with_teller(teller);
+        }
+        if let Some(voice_note) = req.voice_note {
+            layer = layer.with_voice_note(voice_note);
+        }
+        for entity in &req.entities {
+            let (entity_id, sharing) = layer_entity(entity)?;
+            layer = layer.with_entity(entity_id, sharing);
+        }
+
+        let ledger_session = self.between_turns(&session_id).await?;
+        self.session_store
+            .ledger
+            .record_layer_entry(&ledger_session, snapshot.turn_count, &layer)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to ledger layer entry: {e}")))?;
+        let snapshot = self.fold_ledger_tail(&session_id, &ledger_session).await?;
+
+        Ok(Response::new(layer_state(&session_id, &snapshot)))
+    }
+
+    async fn exit_layer(
+        &self,
+        request: Request<ExitLayerRequest>,
+    ) -> Result<Response<LayerState>, Status> {
+        let caller = PlayerIdentity::of(&request);
+        let session_id = request.into_inner().session_id;
+        self.authorize(&caller, &session_id)?;
+
+        let snapshot = self
+            .state_manager
+            .get_runtime_snapshot(&session_id)
+            .ok_or_else(|| Status::not_found(format!("session {session_id} not found")))?;
+        let layer_id = snapshot
+            .layers
+            .current()
+            .map(|layer| layer.id)
+            .ok_or_else(|| Status::failed_precondition("not inside a narrative layer"))?;
+
+        let ledger_session = self.between_turns(&session_id).await?;
+        self.session_store
+            .ledger
+            .record_layer_exit(&ledger_session, snapshot.turn_count, layer_id)
+            .await
+            .map_err(|e| {
+                Status::failed_precondition(format!("Failed to ledger layer exit: {e}"))
+            })?;
+        let snapshot = self.fold_ledger_tail(&session_id, &ledger_session).await?;
+
+        Ok(Response::new(layer_state(&session_id, &snapshot)))
+    }
+
+    async fn list_sessions(&self, request: Request<()>) -> Result<Response<SessionList>, Status> {
+        let caller = PlayerIdentity::of(&request);
         let session_ids = self
             .session_store
             .list_session_ids()
@@ -1346,6 +2081,9 @@ impl StorytellerEngine for EngineServiceImpl {
 
         let mut summaries = Vec::new();
         for id in session_ids {
+            if !self.session_store.owners.is_owned_by(&id, &caller) {
+                continue;
+            }
             if let Ok(comp) = self.session_store.composition.read(&id) {
                 let turn_count = self.session_store.turns.turn_count(&id).unwrap_or(0) as u32;
 
@@ -1394,7 +2132,9 @@ impl StorytellerEngine for EngineServiceImpl {
         &self,
         request: Request<GetSceneStateRequest>,
     ) -> Result<Response<SceneState>, Status> {
+        let caller = PlayerIdentity::of(&request);
         let session_id = &request.get_ref().session_id;
+        self.authorize(&caller, session_id)?;
 
         let composition = self
             .state_manager
@@ -1460,11 +2200,12 @@ impl StorytellerEngine for EngineServiceImpl {
 
         // Probe Ollama to verify narrator LLM reachability
         let narrator_health = {
-            let probe_url = format!(
-                "{}/api/tags",
-                std::env::var("OLLAMA_URL")
-                    .unwrap_or_else(|_| "http://localhost:11434".to_string())
-            );
+            let ollama_url = match &providers.config {
+                Some(config) => config.provider().ollama_url,
+                None => std::env::var("OLLAMA_URL")
+                    .unwrap_or_else(|_| "http://localhost:11434".to_string()),
+            };
+            let probe_url = format!("{ollama_url}/api/tags");
             let client = reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(5))
                 .build();
@@ -1560,7 +2301,9 @@ impl StorytellerEngine for EngineServiceImpl {
         &self,
         request: Request<PredictionHistoryRequest>,
     ) -> Result<Response<PredictionHistoryResponse>, Status> {
+        let caller = PlayerIdentity::of(&request);
         let req = request.into_inner();
+        self.authorize(&caller, &req.session_id)?;
         let snapshot = self
             .state_manager
             .get_runtime_snapshot(&req.session_id)
@@ -1585,7 +2328,17 @@ impl StorytellerEngine for EngineServiceImpl {
         &self,
         request: Request<LogFilter>,
     ) -> Result<Response<Self::StreamLogsStream>, Status> {
+        let caller = PlayerIdentity::of(&request);
         let filter = request.into_inner();
+        match &filter.session_id {
+            Some(session_id) => self.authorize(&caller, session_id)?,
+            None if caller.is_admin => {}
+            None => {
+                return Err(Status::permission_denied(
+                    "server-wide logs require an admin credential; pass a session_id you own",
+                ))
+            }
+        }
         let mut rx = self.log_broadcast.subscribe();
         let (tx, rx_out) = mpsc::channel(32);
 
@@ -1603,6 +2356,11 @@ impl StorytellerEngine for EngineServiceImpl {
                                 continue;
                             }
                         }
+                        if let Some(ref session_id) = filter.session_id {
+                            if entry.fields.get("session") != Some(session_id) {
+                                continue;
+                            }
+                        }
                         if tx.send(Ok(entry)).await.is_err() {
                             break; // receiver dropped
                         }
@@ -1623,6 +2381,22 @@ impl StorytellerEngine for EngineServiceImpl {
 
         Ok(Response::new(ReceiverStream::new(rx_out)))
     }
+
+    async fn get_effective_config(
+        &self,
+        _request: Request<()>,
+    ) -> Result<Response<EffectiveConfig>, Status> {
+        let config = self.providers.config.as_ref().ok_or_else(|| {
+            Status::failed_precondition("server was started without a layered configuration")
+        })?;
+        let raw_json = serde_json::to_string_pretty(&config.redacted())
+            .map_err(|e| Status::internal(format!("failed to serialize config: {e}")))?;
+
+        Ok(Response::new(EffectiveConfig {
+            environment: storyteller_core::config::ConfigLoader::detect_environment(),
+            raw_json,
+        }))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -19,8 +19,10 @@
 //!
 //! ## Modules
 //!
-//! - [`routes`] — Route definitions (player input, session management, health)
-//! - [`middleware`] — Auth, rate limiting, and other middleware
+//! - [`routes`] — REST route definitions (player input, session management,
+//!   health); streaming endpoints respond with Server-Sent Events
+//! - [`middleware`] — Auth (API keys, HMAC bearer tokens), rate limiting,
+//!   and other middleware
 //! - [`state`] — Shared application state passed to handlers
 //! - [`persistence`] — Three-file session persistence (composition, events, turns)
 //! - [`engine`] — Server-side engine state and provider management
@@ -44,11 +46,22 @@ use axum::Router;
 /// Build the player-facing API router.
 ///
 /// The returned router is deployment-agnostic — mount it on any axum-compatible
-/// server. The `state` parameter carries engine references and configuration.
+/// server. The `state` parameter carries engine references and configuration;
+/// its engine service is the one the gRPC server should mount, so both
+/// surfaces share sessions.
+///
+/// Player and session routes require a credential accepted by `state.auth`
+/// (when enabled); the health probe stays open.
 pub fn router(state: state::AppState) -> Router {
-    Router::new()
+    let api = Router::new()
         .merge(routes::player::routes())
         .merge(routes::session::routes())
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.auth.clone(),
+            middleware::auth::authenticate,
+        ));
+    Router::new()
+        .merge(api)
         .merge(routes::health::routes())
         .with_state(state)
 }
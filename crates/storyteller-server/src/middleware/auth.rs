@@ -5,5 +5,542 @@
 //! Authentication middleware.
 //!
 //! Provides auth extraction and validation that deployment crates can
-//! optionally apply. The auth strategy (JWT, session tokens, platform-managed)
-//! depends on the deployment target.
+//! optionally apply. Credentials are verified locally — no identity provider
+//! round-trip — by one or more [`Authenticator`]s collected in an
+//! [`AuthPolicy`]:
+//!
+//! - [`StaticApiKeys`] — `x-api-key: <key>`, each key mapped to a player;
+//!   admin keys also grant operator access
+//! - [`HmacTokens`] — `authorization: Bearer <token>`, where the token is
+//!   `base64url(claims).base64url(HMAC-SHA256(secret, base64url(claims)))`
+//!   and the claims are `{"sub": "<player>", "exp": <unix seconds>}`. A
+//!   token without `exp` is rejected.
+//!
+//! The same policy guards both surfaces: [`AuthInterceptor`] for tonic and
+//! [`authenticate`] for axum. Each attaches the caller's [`PlayerIdentity`]
+//! to the request extensions, where the engine service reads it to record
+//! and enforce session ownership. A disabled policy (the default) treats
+//! every caller as [`PlayerIdentity::anonymous`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use axum::extract::{Request as HttpRequest, State};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tonic::metadata::MetadataMap;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use crate::routes::sse::ApiError;
+
+/// Header carrying a static API key.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Player id given to every caller when auth is disabled, and the owner of
+/// sessions created before ownership was recorded.
+pub const ANONYMOUS_PLAYER: &str = "anonymous";
+
+/// The authenticated caller.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlayerIdentity {
+    pub player_id: String,
+    /// Operator access: may read server-wide data such as the log stream.
+    #[serde(default)]
+    pub is_admin: bool,
+}
+
+impl PlayerIdentity {
+    pub fn new(player_id: impl Into<String>) -> Self {
+        Self {
+            player_id: player_id.into(),
+            is_admin: false,
+        }
+    }
+
+    /// An operator identity.
+    pub fn admin(player_id: impl Into<String>) -> Self {
+        Self {
+            player_id: player_id.into(),
+            is_admin: true,
+        }
+    }
+
+    /// The identity used when no authentication is configured.
+    ///
+    /// With nobody to keep out, the anonymous caller is the operator.
+    pub fn anonymous() -> Self {
+        Self::admin(ANONYMOUS_PLAYER)
+    }
+
+    /// The identity attached to a gRPC request, or anonymous if none was.
+    pub fn of<T>(request: &Request<T>) -> Self {
+        request
+            .extensions()
+            .get::<PlayerIdentity>()
+            .cloned()
+            .unwrap_or_else(Self::anonymous)
+    }
+
+    /// Wrap `message` in a gRPC request carrying this identity, for calling
+    /// the engine service from a REST handler.
+    pub fn request<T>(&self, message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        request.extensions_mut().insert(self.clone());
+        request
+    }
+}
+
+/// A credential presented by a caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Credential {
+    ApiKey(String),
+    Bearer(String),
+}
+
+impl Credential {
+    /// Extract a credential from raw header values. A bearer token takes
+    /// precedence over an API key.
+    pub fn from_headers(authorization: Option<&str>, api_key: Option<&str>) -> Option<Self> {
+        if let Some(token) = authorization.and_then(|v| v.strip_prefix("Bearer ")) {
+            return Some(Self::Bearer(token.trim().to_string()));
+        }
+        api_key.map(|key| Self::ApiKey(key.trim().to_string()))
+    }
+
+    fn from_http(headers: &HeaderMap) -> Option<Self> {
+        Self::from_headers(
+            headers
+                .get(axum::http::header::AUTHORIZATION)
+                .and_then(|v| v.to_str().ok()),
+            headers.get(API_KEY_HEADER).and_then(|v| v.to_str().ok()),
+        )
+    }
+
+    fn from_metadata(metadata: &MetadataMap) -> Option<Self> {
+        Self::from_headers(
+            metadata.get("authorization").and_then(|v| v.to_str().ok()),
+            metadata.get(API_KEY_HEADER).and_then(|v| v.to_str().ok()),
+        )
+    }
+}
+
+/// Verifies credentials of the kinds it understands.
+///
+/// Returns `None` for credentials it does not recognize or cannot verify;
+/// the policy tries the next authenticator.
+pub trait Authenticator: fmt::Debug + Send + Sync {
+    fn authenticate(&self, credential: &Credential) -> Option<PlayerIdentity>;
+}
+
+/// Static API keys, each mapped to a player identity.
+///
+/// Keys are held as SHA-256 digests so the plaintext is not kept in memory
+/// and lookups do not compare secrets byte-by-byte.
+#[derive(Clone, Default)]
+pub struct StaticApiKeys {
+    keys: HashMap<[u8; 32], PlayerIdentity>,
+}
+
+impl StaticApiKeys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `key` for `player_id`.
+    pub fn with_key(mut self, key: &str, player_id: impl Into<String>) -> Self {
+        self.keys
+            .insert(digest(key), PlayerIdentity::new(player_id));
+        self
+    }
+
+    /// Register `key` for `player_id` with operator access.
+    pub fn with_admin_key(mut self, key: &str, player_id: impl Into<String>) -> Self {
+        self.keys
+            .insert(digest(key), PlayerIdentity::admin(player_id));
+        self
+    }
+
+    /// Parse a `key=player,key=player` list (the `STORYTELLER_API_KEYS`
+    /// format).
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        Self::new().extend_from(spec, Self::with_key)
+    }
+
+    /// Parse a `key=player,key=player` list of operator keys (the
+    /// `STORYTELLER_ADMIN_KEYS` format).
+    pub fn parse_admin(spec: &str) -> Result<Self, String> {
+        Self::new().extend_from(spec, Self::with_admin_key)
+    }
+
+    fn extend_from(
+        mut self,
+        spec: &str,
+        add: fn(Self, &str, String) -> Self,
+    ) -> Result<Self, String> {
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (key, player) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("API key entry missing '=player': {entry}"))?;
+            if key.is_empty() || player.is_empty() {
+                return Err(format!("empty key or player in API key entry: {entry}"));
+            }
+            self = add(self, key, player.to_string());
+        }
+        Ok(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl fmt::Debug for StaticApiKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticApiKeys")
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+impl Authenticator for StaticApiKeys {
+    fn authenticate(&self, credential: &Credential) -> Option<PlayerIdentity> {
+        match credential {
+            Credential::ApiKey(key) => self.keys.get(&digest(key)).cloned(),
+            Credential::Bearer(_) => None,
+        }
+    }
+}
+
+fn digest(key: &str) -> [u8; 32] {
+    Sha256::digest(key.as_bytes()).into()
+}
+
+/// Claims carried in an HMAC bearer token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenClaims {
+    /// Player id.
+    pub sub: String,
+    /// Expiry, seconds since the Unix epoch. Required: a token that never
+    /// expires cannot be revoked short of rotating the secret.
+    pub exp: u64,
+}
+
+/// HMAC-SHA256 signed bearer tokens verified with a shared secret.
+#[derive(Clone)]
+pub struct HmacTokens {
+    secret: Vec<u8>,
+}
+
+impl HmacTokens {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: secret.into(),
+        }
+    }
+
+    fn mac(&self) -> Hmac<Sha256> {
+        // HMAC accepts keys of any length.
+        Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC key of any length")
+    }
+
+    /// Sign a token for `player_id`, expiring after `ttl`.
+    pub fn issue(&self, player_id: &str, ttl: Duration) -> String {
+        let claims = TokenClaims {
+            sub: player_id.to_string(),
+            exp: unix_now() + ttl.as_secs(),
+        };
+        let body = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims).expect("claims serialize"));
+        let mut mac = self.mac();
+        mac.update(body.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+        format!("{body}.{signature}")
+    }
+
+    /// Verify a token's signature and expiry, returning its claims.
+    pub fn verify(&self, token: &str) -> Result<TokenClaims, String> {
+        let (body, signature) = token
+            .split_once('.')
+            .ok_or_else(|| "malformed token".to_string())?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|e| format!("decode signature: {e}"))?;
+
+        let mut mac = self.mac();
+        mac.update(body.as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| "bad token signature".to_string())?;
+
+        let claims: TokenClaims = URL_SAFE_NO_PAD
+            .decode(body)
+            .map_err(|e| format!("decode claims: {e}"))
+            .and_then(|bytes| {
+                serde_json::from_slice(&bytes).map_err(|e| format!("parse claims: {e}"))
+            })?;
+        if claims.exp <= unix_now() {
+            return Err("token expired".to_string());
+        }
+        Ok(claims)
+    }
+}
+
+impl fmt::Debug for HmacTokens {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacTokens").finish_non_exhaustive()
+    }
+}
+
+impl Authenticator for HmacTokens {
+    fn authenticate(&self, credential: &Credential) -> Option<PlayerIdentity> {
+        match credential {
+            Credential::Bearer(token) => match self.verify(token) {
+                Ok(claims) => Some(PlayerIdentity::new(claims.sub)),
+                Err(e) => {
+                    tracing::debug!(error = %e, "bearer token rejected");
+                    None
+                }
+            },
+            Credential::ApiKey(_) => None,
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// The set of authenticators guarding the API.
+///
+/// With no authenticators the policy is disabled and every caller is
+/// anonymous. Otherwise a caller must present a credential that one of the
+/// authenticators accepts.
+#[derive(Debug, Clone, Default)]
+pub struct AuthPolicy {
+    authenticators: Vec<Arc<dyn Authenticator>>,
+}
+
+impl AuthPolicy {
+    /// A policy that admits everyone as [`PlayerIdentity::anonymous`].
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Add an authenticator.
+    pub fn with(mut self, authenticator: impl Authenticator + 'static) -> Self {
+        self.authenticators.push(Arc::new(authenticator));
+        self
+    }
+
+    /// Build a policy from the server's env configuration: API key lists
+    /// and/or a token secret. None set means auth is disabled.
+    pub fn from_config(
+        api_keys: Option<&str>,
+        admin_keys: Option<&str>,
+        token_secret: Option<&str>,
+    ) -> Result<Self, String> {
+        let mut policy = Self::disabled();
+        if let Some(spec) = api_keys {
+            let keys = StaticApiKeys::parse(spec)?;
+            if !keys.is_empty() {
+                policy = policy.with(keys);
+            }
+        }
+        if let Some(spec) = admin_keys {
+            let keys = StaticApiKeys::parse_admin(spec)?;
+            if !keys.is_empty() {
+                policy = policy.with(keys);
+            }
+        }
+        if let Some(secret) = token_secret.filter(|s| !s.is_empty()) {
+            policy = policy.with(HmacTokens::new(secret.as_bytes().to_vec()));
+        }
+        Ok(policy)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.authenticators.is_empty()
+    }
+
+    /// Resolve the caller's identity from an optional credential.
+    pub fn identify(&self, credential: Option<&Credential>) -> Result<PlayerIdentity, Status> {
+        if !self.is_enabled() {
+            return Ok(PlayerIdentity::anonymous());
+        }
+        let credential =
+            credential.ok_or_else(|| Status::unauthenticated("missing credentials"))?;
+        self.authenticators
+            .iter()
+            .find_map(|a| a.authenticate(credential))
+            .ok_or_else(|| Status::unauthenticated("invalid credentials"))
+    }
+}
+
+/// tonic interceptor attaching the caller's [`PlayerIdentity`].
+#[derive(Debug, Clone)]
+pub struct AuthInterceptor {
+    policy: AuthPolicy,
+}
+
+impl AuthInterceptor {
+    pub fn new(policy: AuthPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let credential = Credential::from_metadata(request.metadata());
+        let identity = self.policy.identify(credential.as_ref())?;
+        request.extensions_mut().insert(identity);
+        Ok(request)
+    }
+}
+
+/// axum middleware attaching the caller's [`PlayerIdentity`].
+///
+/// Mount with `axum::middleware::from_fn_with_state(policy, authenticate)`;
+/// handlers extract the identity with `Extension<PlayerIdentity>`.
+pub async fn authenticate(
+    State(policy): State<AuthPolicy>,
+    mut request: HttpRequest,
+    next: Next,
+) -> Response {
+    let credential = Credential::from_http(request.headers());
+    match policy.identify(credential.as_ref()) {
+        Ok(identity) => {
+            request.extensions_mut().insert(identity);
+            next.run(request).await
+        }
+        Err(status) => ApiError(status).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_policy_admits_anonymous() {
+        let policy = AuthPolicy::disabled();
+        assert!(!policy.is_enabled());
+        assert_eq!(policy.identify(None).unwrap(), PlayerIdentity::anonymous());
+    }
+
+    #[test]
+    fn api_keys_map_to_players() {
+        let policy =
+            AuthPolicy::from_config(Some("k1=alice, k2=bob"), Some("k9=ops"), None).unwrap();
+        let bob = policy
+            .identify(Some(&Credential::ApiKey("k2".to_string())))
+            .unwrap();
+        assert_eq!(bob, PlayerIdentity::new("bob"));
+        let ops = policy
+            .identify(Some(&Credential::ApiKey("k9".to_string())))
+            .unwrap();
+        assert_eq!(ops, PlayerIdentity::admin("ops"));
+
+        let err = policy
+            .identify(Some(&Credential::ApiKey("nope".to_string())))
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+        assert_eq!(
+            policy.identify(None).unwrap_err().code(),
+            tonic::Code::Unauthenticated
+        );
+    }
+
+    #[test]
+    fn malformed_api_key_spec_is_rejected() {
+        assert!(StaticApiKeys::parse("just-a-key").is_err());
+        assert!(StaticApiKeys::parse("=alice").is_err());
+    }
+
+    #[test]
+    fn hmac_tokens_round_trip() {
+        let tokens = HmacTokens::new(b"secret".to_vec());
+        let token = tokens.issue("alice", Duration::from_secs(60));
+        let claims = tokens.verify(&token).unwrap();
+        assert_eq!(claims.sub, "alice");
+        assert!(claims.exp > unix_now());
+    }
+
+    #[test]
+    fn hmac_tokens_reject_tampering_and_foreign_secrets() {
+        let tokens = HmacTokens::new(b"secret".to_vec());
+        let token = tokens.issue("alice", Duration::from_secs(60));
+
+        let (_, signature) = token.split_once('.').unwrap();
+        let forged_body = URL_SAFE_NO_PAD.encode(br#"{"sub":"mallory"}"#);
+        assert!(tokens
+            .verify(&format!("{forged_body}.{signature}"))
+            .is_err());
+
+        assert!(HmacTokens::new(b"other".to_vec()).verify(&token).is_err());
+    }
+
+    /// Sign arbitrary claims, bypassing `issue`.
+    fn sign(tokens: &HmacTokens, claims: &serde_json::Value) -> String {
+        let body = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims).unwrap());
+        let mut mac = tokens.mac();
+        mac.update(body.as_bytes());
+        format!(
+            "{body}.{}",
+            URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+        )
+    }
+
+    #[test]
+    fn expired_tokens_are_rejected() {
+        let tokens = HmacTokens::new(b"secret".to_vec());
+        let token = sign(&tokens, &serde_json::json!({"sub": "alice", "exp": 1}));
+        assert_eq!(tokens.verify(&token).unwrap_err(), "token expired");
+    }
+
+    #[test]
+    fn tokens_without_expiry_are_rejected() {
+        let tokens = HmacTokens::new(b"secret".to_vec());
+        let token = sign(&tokens, &serde_json::json!({"sub": "alice"}));
+        let err = tokens.verify(&token).unwrap_err();
+        assert!(err.contains("missing field `exp`"), "{err}");
+    }
+
+    #[test]
+    fn bearer_takes_precedence_over_api_key() {
+        let credential = Credential::from_headers(Some("Bearer abc"), Some("k1")).unwrap();
+        assert_eq!(credential, Credential::Bearer("abc".to_string()));
+        let credential = Credential::from_headers(None, Some("k1")).unwrap();
+        assert_eq!(credential, Credential::ApiKey("k1".to_string()));
+        assert!(Credential::from_headers(Some("Basic xyz"), None).is_none());
+    }
+
+    #[test]
+    fn interceptor_attaches_identity() {
+        let tokens = HmacTokens::new(b"secret".to_vec());
+        let token = tokens.issue("alice", Duration::from_secs(60));
+        let mut interceptor = AuthInterceptor::new(AuthPolicy::disabled().with(tokens));
+
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        let request = interceptor.call(request).unwrap();
+        assert_eq!(PlayerIdentity::of(&request).player_id, "alice");
+
+        assert!(interceptor.call(Request::new(())).is_err());
+    }
+}
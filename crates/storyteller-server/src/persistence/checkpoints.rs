@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Session checkpoints persisted to `checkpoints.jsonl`.
+//!
+//! Each line is one [`Checkpoint`]; the last line is the latest. Recovery
+//! restores it and replays `ledger.jsonl` entries after its sequence, so a
+//! checkpoint only has to be as fresh as the recovery time budget requires.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use storyteller_core::database::checkpoint::{Checkpoint, CheckpointStore};
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::storykeeper::SessionId;
+
+/// File-backed [`CheckpointStore`] — one `checkpoints.jsonl` per session directory.
+#[derive(Debug)]
+pub struct JsonlCheckpointStore<S> {
+    base_dir: PathBuf,
+    _state: PhantomData<fn() -> S>,
+}
+
+impl<S> Clone for JsonlCheckpointStore<S> {
+    fn clone(&self) -> Self {
+        Self {
+            base_dir: self.base_dir.clone(),
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<S> JsonlCheckpointStore<S> {
+    pub fn new(base_dir: &Path) -> Self {
+        Self {
+            base_dir: base_dir.to_path_buf(),
+            _state: PhantomData,
+        }
+    }
+
+    fn path_for(&self, session_id: &SessionId) -> PathBuf {
+        self.base_dir
+            .join(session_id.0.to_string())
+            .join("checkpoints.jsonl")
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> CheckpointStore<S> for JsonlCheckpointStore<S>
+where
+    S: Serialize + DeserializeOwned + Send + Sync,
+{
+    async fn write(&self, checkpoint: &Checkpoint<S>) -> StorytellerResult<()> {
+        let path = self.path_for(&checkpoint.session_id);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| StorytellerError::Checkpoint(format!("create dir: {e}")))?;
+        }
+
+        let mut line = serde_json::to_string(checkpoint)?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| StorytellerError::Checkpoint(format!("open checkpoints.jsonl: {e}")))?;
+        file.write_all(line.as_bytes())
+            .map_err(|e| StorytellerError::Checkpoint(format!("write checkpoint: {e}")))?;
+        file.sync_data()
+            .map_err(|e| StorytellerError::Checkpoint(format!("sync checkpoints.jsonl: {e}")))
+    }
+
+    async fn latest(&self, session_id: &SessionId) -> StorytellerResult<Option<Checkpoint<S>>> {
+        let path = self.path_for(session_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| StorytellerError::Checkpoint(format!("read checkpoints.jsonl: {e}")))?;
+
+        // A torn final line (crash mid-write) falls back to the previous
+        // checkpoint; the ledger still holds everything after it.
+        Ok(contents
+            .lines()
+            .rev()
+            .filter(|l| !l.trim().is_empty())
+            .find_map(|l| serde_json::from_str(l).ok()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn latest_returns_last_written_checkpoint() {
+        let dir = TempDir::new().unwrap();
+        let store = JsonlCheckpointStore::<Vec<String>>::new(dir.path());
+        let session_id = SessionId::new();
+
+        assert!(store.latest(&session_id).await.unwrap().is_none());
+
+        store
+            .write(&Checkpoint::new(
+                session_id,
+                0,
+                None,
+                vec!["opening".to_string()],
+            ))
+            .await
+            .unwrap();
+        let second = Checkpoint::new(session_id, 5, None, vec!["later".to_string()]);
+        store.write(&second).await.unwrap();
+
+        let latest = store.latest(&session_id).await.unwrap().unwrap();
+        assert_eq!(latest.id, second.id);
+        assert_eq!(latest.state, vec!["later".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn torn_final_line_falls_back_to_previous_checkpoint() {
+        let dir = TempDir::new().unwrap();
+        let store = JsonlCheckpointStore::<u32>::new(dir.path());
+        let session_id = SessionId::new();
+
+        let first = Checkpoint::new(session_id, 0, None, 7);
+        store.write(&first).await.unwrap();
+
+        let path = dir
+            .path()
+            .join(session_id.0.to_string())
+            .join("checkpoints.jsonl");
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"id\":\"trunc").unwrap();
+
+        let latest = store.latest(&session_id).await.unwrap().unwrap();
+        assert_eq!(latest.id, first.id);
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Command-sourced event ledger persisted to `ledger.jsonl`.
+//!
+//! Unlike `events.jsonl`, which is a best-effort debug stream, the ledger is
+//! the durable record of what the player sent and what each turn committed.
+//! Ordering and idempotency come from the core [`SessionLedger`]; this
+//! module only loads it from disk and appends new entries, syncing each
+//! write before returning so a sourced command survives a crash.
+
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use storyteller_core::database::ledger::{
+    EventLedger, LedgerAppend, LedgerEntry, LedgerSequence, SessionLedger, TurnCommitRecord,
+};
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::storykeeper::{CommandSourced, SessionId};
+use storyteller_core::types::event::NarrativeEvent;
+use storyteller_core::types::message::PlayerInput;
+use storyteller_core::types::narrative_layer::{LayerId, NarrativeLayer};
+use storyteller_core::types::narrator_context::JournalCompression;
+
+/// File-backed [`EventLedger`] — one `ledger.jsonl` per session directory.
+///
+/// Session ledgers are loaded lazily on first access and cached; clones
+/// share the same cache. File reads, writes, and syncs run on tokio's
+/// blocking pool, never on an async worker.
+#[derive(Debug, Clone)]
+pub struct JsonlLedger {
+    base_dir: PathBuf,
+    sessions: Arc<Mutex<HashMap<SessionId, SessionLedger>>>,
+}
+
+impl JsonlLedger {
+    pub fn new(base_dir: &Path) -> Self {
+        Self {
+            base_dir: base_dir.to_path_buf(),
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn path_for(&self, session_id: &SessionId) -> PathBuf {
+        self.base_dir
+            .join(session_id.0.to_string())
+            .join("ledger.jsonl")
+    }
+
+    /// Load a session's ledger from disk.
+    async fn load(&self, session_id: &SessionId) -> StorytellerResult<SessionLedger> {
+        let path = self.path_for(session_id);
+        let session_id = *session_id;
+        blocking(move || SessionLedger::from_entries(session_id, read_entries(&path)?)).await
+    }
+
+    /// Run `f` against the session's ledger, persisting the entry it appends.
+    ///
+    /// The cache lock is held across the write so appends stay in sequence
+    /// order on disk.
+    async fn append_with<T>(
+        &self,
+        session_id: &SessionId,
+        f: impl FnOnce(&mut SessionLedger) -> StorytellerResult<(T, LedgerAppend)>,
+    ) -> StorytellerResult<T> {
+        let mut sessions = self.sessions.lock().await;
+        if !sessions.contains_key(session_id) {
+            let loaded = self.load(session_id).await?;
+            sessions.insert(*session_id, loaded);
+        }
+        let ledger = sessions.get_mut(session_id).expect("just inserted");
+
+        // Apply to a scratch copy so a failed write leaves the cache matching disk.
+        let mut staged = ledger.clone();
+        let (value, append) = f(&mut staged)?;
+        if append.newly_appended {
+            let path = self.path_for(session_id);
+            let entry = append.entry;
+            blocking(move || write_entry(&path, &entry)).await?;
+        }
+        *ledger = staged;
+        Ok(value)
+    }
+}
+
+/// Run blocking file I/O off the async worker threads.
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> StorytellerResult<T> + Send + 'static,
+) -> StorytellerResult<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| StorytellerError::Ledger(format!("ledger I/O task failed: {e}")))?
+}
+
+fn read_entries(path: &Path) -> StorytellerResult<Vec<LedgerEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(path)
+        .map_err(|e| StorytellerError::Ledger(format!("read ledger.jsonl: {e}")))?;
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).map_err(StorytellerError::from))
+        .collect()
+}
+
+fn write_entry(path: &Path, entry: &LedgerEntry) -> StorytellerResult<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| StorytellerError::Ledger(format!("create dir: {e}")))?;
+    }
+
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| StorytellerError::Ledger(format!("open ledger.jsonl: {e}")))?;
+    file.write_all(line.as_bytes())
+        .map_err(|e| StorytellerError::Ledger(format!("write ledger entry: {e}")))?;
+    file.sync_data()
+        .map_err(|e| StorytellerError::Ledger(format!("sync ledger.jsonl: {e}")))
+}
+
+#[async_trait::async_trait]
+impl EventLedger for JsonlLedger {
+    async fn source_command(
+        &self,
+        session_id: &SessionId,
+        input: &PlayerInput,
+    ) -> StorytellerResult<CommandSourced> {
+        self.append_with(session_id, |ledger| ledger.source_command(input))
+            .await
+    }
+
+    async fn append_event(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        event: &NarrativeEvent,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.append_with(session_id, |ledger| {
+            let append = ledger.append_event(command, event)?;
+            Ok((append.entry.sequence, append))
+        })
+        .await
+    }
+
+    async fn commit_turn(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        commit: &TurnCommitRecord,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.append_with(session_id, |ledger| {
+            let append = ledger.commit_turn(command, commit)?;
+            Ok((append.entry.sequence, append))
+        })
+        .await
+    }
+
+    async fn abandon_command(
+        &self,
+        session_id: &SessionId,
+        command: &CommandSourced,
+        reason: &str,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.append_with(session_id, |ledger| {
+            let append = ledger.abandon_command(command, reason)?;
+            Ok((append.entry.sequence, append))
+        })
+        .await
+    }
+
+    async fn record_deferred_result(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        correlation_id: Uuid,
+        events: &[NarrativeEvent],
+    ) -> StorytellerResult<LedgerSequence> {
+        self.append_with(session_id, |ledger| {
+            let append = ledger.record_deferred_result(turn_number, correlation_id, events);
+            Ok((append.entry.sequence, append))
+        })
+        .await
+    }
+
+    async fn record_journal_compression(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        compression: &JournalCompression,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.append_with(session_id, |ledger| {
+            let append = ledger.record_journal_compression(turn_number, compression);
+            Ok((append.entry.sequence, append))
+        })
+        .await
+    }
+
+    async fn record_layer_entry(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        layer: &NarrativeLayer,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.append_with(session_id, |ledger| {
+            let append = ledger.record_layer_entry(turn_number, layer);
+            Ok((append.entry.sequence, append))
+        })
+        .await
+    }
+
+    async fn record_layer_exit(
+        &self,
+        session_id: &SessionId,
+        turn_number: u32,
+        layer_id: LayerId,
+    ) -> StorytellerResult<LedgerSequence> {
+        self.append_with(session_id, |ledger| {
+            let append = ledger.record_layer_exit(turn_number, layer_id)?;
+            Ok((append.entry.sequence, append))
+        })
+        .await
+    }
+
+    async fn entries_since(
+        &self,
+        session_id: &SessionId,
+        after: Option<LedgerSequence>,
+    ) -> StorytellerResult<Vec<LedgerEntry>> {
+        let sessions = self.sessions.lock().await;
+        if let Some(ledger) = sessions.get(session_id) {
+            return Ok(ledger.entries_since(after).to_vec());
+        }
+        drop(sessions);
+
+        let ledger = self.load(session_id).await?;
+        Ok(ledger.entries_since(after).to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use storyteller_core::database::ledger::LedgerEntryKind;
+    use storyteller_core::types::event::{EventId, EventPriority};
+    use storyteller_core::types::event_grammar::EventPayload;
+    use tempfile::TempDir;
+
+    fn input(turn_number: u32, text: &str) -> PlayerInput {
+        PlayerInput {
+            text: text.to_string(),
+            turn_number,
+        }
+    }
+
+    fn event() -> NarrativeEvent {
+        NarrativeEvent {
+            id: EventId::new(),
+            timestamp: Utc::now(),
+            priority: EventPriority::Normal,
+            payload: EventPayload::Untyped(serde_json::json!({"kind": "test"})),
+        }
+    }
+
+    #[tokio::test]
+    async fn sourced_command_is_on_disk_before_processing() {
+        let dir = TempDir::new().unwrap();
+        let ledger = JsonlLedger::new(dir.path());
+        let session_id = SessionId::new();
+
+        ledger
+            .source_command(&session_id, &input(1, "I open the gate."))
+            .await
+            .unwrap();
+
+        let contents = fs::read_to_string(
+            dir.path()
+                .join(session_id.0.to_string())
+                .join("ledger.jsonl"),
+        )
+        .unwrap();
+        assert_eq!(contents.lines().count(), 1);
+        assert!(contents.contains("I open the gate."));
+    }
+
+    #[tokio::test]
+    async fn reloaded_ledger_continues_sequence_and_idempotency() {
+        let dir = TempDir::new().unwrap();
+        let session_id = SessionId::new();
+        let e = event();
+
+        let sourced = {
+            let ledger = JsonlLedger::new(dir.path());
+            let sourced = ledger
+                .source_command(&session_id, &input(1, "look"))
+                .await
+                .unwrap();
+            ledger
+                .append_event(&session_id, &sourced, &e)
+                .await
+                .unwrap();
+            sourced
+        };
+
+        // A fresh instance simulates a server restart.
+        let restarted = JsonlLedger::new(dir.path());
+        let pending = restarted.pending_command(&session_id).await.unwrap();
+        assert_eq!(pending.unwrap().1.text, "look");
+
+        let again = restarted
+            .append_event(&session_id, &sourced, &e)
+            .await
+            .unwrap();
+        assert_eq!(
+            again,
+            LedgerSequence(2),
+            "duplicate event is not re-written"
+        );
+
+        let committed = restarted
+            .commit_turn(
+                &session_id,
+                &sourced,
+                &TurnCommitRecord {
+                    event_ids: vec![e.id],
+                    narrator_prose: "You look.".to_string(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(committed, LedgerSequence(3));
+
+        let entries = restarted.entries_since(&session_id, None).await.unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(matches!(
+            entries[2].kind,
+            LedgerEntryKind::TurnCommit { .. }
+        ));
+        assert!(restarted
+            .pending_command(&session_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -2,16 +2,25 @@
 // Copyright (c) 2026 Tasker Systems. All rights reserved.
 // See LICENSING.md for details.
 
-//! Session persistence — composition, events, turn index, and directives.
+//! Session persistence — composition, ownership, events, turn index,
+//! directives, ledger, and checkpoints.
 //!
-//! ## Four-file model
+//! ## Seven-file model
 //!
 //! Each session is a directory under `.story/sessions/{uuidv7}/` containing:
 //!
 //! - `composition.json` — write-once scene composition and setup data
+//! - `owner.json` — write-once owning player (see [`OwnershipStore`])
 //! - `events.jsonl` — append-only event stream (one [`PersistedEvent`] per line)
 //! - `turns.jsonl` — append-only turn index referencing event UUIDs
 //! - `directives.jsonl` — append-only async agent directives (dramaturge, world agent)
+//! - `ledger.jsonl` — command-sourced event ledger (player commands, classified
+//!   events, turn commits), synced on every append
+//! - `checkpoints.jsonl` — periodic runtime snapshots; recovery restores the
+//!   last one and replays the ledger entries after it
+//!
+//! With `persistence.storykeeper` on, scenes, sourced commands, and committed
+//! turns are also written through a [`StorykeeperMirror`].
 //!
 //! ## Usage
 //!
@@ -29,14 +38,22 @@
 //! store.turns.append(&session_id, &TurnEntry { turn: 1, event_ids: vec![event_id], .. })?;
 //! ```
 
+pub mod checkpoints;
 pub mod composition;
 pub mod directives;
 pub mod events;
+pub mod ledger;
+pub mod ownership;
 pub mod session_store;
+pub mod storykeeper;
 pub mod turns;
 
+pub use checkpoints::JsonlCheckpointStore;
 pub use composition::CompositionWriter;
 pub use directives::{DirectiveEntry, DirectiveStore};
 pub use events::{EventWriter, PersistedEvent};
+pub use ledger::JsonlLedger;
+pub use ownership::{OwnershipStore, SessionOwner};
 pub use session_store::SessionStore;
+pub use storykeeper::StorykeeperMirror;
 pub use turns::{TurnEntry, TurnWriter};
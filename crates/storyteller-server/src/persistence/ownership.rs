@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Write-once session ownership.
+//!
+//! The player who composed a session is recorded in `owner.json`. Sessions
+//! without one predate ownership and belong to the anonymous player.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::auth::{PlayerIdentity, ANONYMOUS_PLAYER};
+
+/// Contents of `owner.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionOwner {
+    pub player_id: String,
+    pub claimed_at: String,
+}
+
+/// Writes and reads owner.json files (write-once per session).
+#[derive(Debug, Clone)]
+pub struct OwnershipStore {
+    base_dir: PathBuf,
+}
+
+impl OwnershipStore {
+    pub fn new(base_dir: &Path) -> Self {
+        Self {
+            base_dir: base_dir.to_path_buf(),
+        }
+    }
+
+    fn owner_path(&self, session_id: &str) -> PathBuf {
+        self.base_dir.join(session_id).join("owner.json")
+    }
+
+    /// Record `owner` as the owner of `session_id`. Fails if already claimed.
+    pub fn claim(&self, session_id: &str, owner: &PlayerIdentity) -> Result<(), String> {
+        let path = self.owner_path(session_id);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("create session dir: {e}"))?;
+        }
+        let record = SessionOwner {
+            player_id: owner.player_id.clone(),
+            claimed_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let json =
+            serde_json::to_string_pretty(&record).map_err(|e| format!("serialize owner: {e}"))?;
+
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| format!("session {session_id} already claimed or write failed: {e}"))?;
+        file.write_all(json.as_bytes())
+            .map_err(|e| format!("write owner: {e}"))?;
+        Ok(())
+    }
+
+    /// The recorded owner, or `None` for sessions that predate ownership.
+    pub fn read(&self, session_id: &str) -> Result<Option<SessionOwner>, String> {
+        let path = self.owner_path(session_id);
+        match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map(Some)
+                .map_err(|e| format!("parse owner: {e}")),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("read owner: {e}")),
+        }
+    }
+
+    /// Whether `caller` owns `session_id`. Unreadable ownership records deny.
+    pub fn is_owned_by(&self, session_id: &str, caller: &PlayerIdentity) -> bool {
+        match self.read(session_id) {
+            Ok(Some(owner)) => owner.player_id == caller.player_id,
+            Ok(None) => caller.player_id == ANONYMOUS_PLAYER,
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn claim_and_check_owner() {
+        let dir = TempDir::new().unwrap();
+        let store = OwnershipStore::new(dir.path());
+        let alice = PlayerIdentity::new("alice");
+
+        store.claim("session-1", &alice).unwrap();
+
+        assert_eq!(store.read("session-1").unwrap().unwrap().player_id, "alice");
+        assert!(store.is_owned_by("session-1", &alice));
+        assert!(!store.is_owned_by("session-1", &PlayerIdentity::new("bob")));
+        assert!(!store.is_owned_by("session-1", &PlayerIdentity::anonymous()));
+    }
+
+    #[test]
+    fn claim_twice_fails() {
+        let dir = TempDir::new().unwrap();
+        let store = OwnershipStore::new(dir.path());
+
+        store
+            .claim("session-1", &PlayerIdentity::new("alice"))
+            .unwrap();
+        assert!(store
+            .claim("session-1", &PlayerIdentity::new("bob"))
+            .is_err());
+    }
+
+    #[test]
+    fn unclaimed_sessions_belong_to_anonymous() {
+        let dir = TempDir::new().unwrap();
+        let store = OwnershipStore::new(dir.path());
+
+        assert!(store.read("legacy").unwrap().is_none());
+        assert!(store.is_owned_by("legacy", &PlayerIdentity::anonymous()));
+        assert!(!store.is_owned_by("legacy", &PlayerIdentity::new("alice")));
+    }
+}
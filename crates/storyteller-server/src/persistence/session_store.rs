@@ -2,24 +2,45 @@
 // Copyright (c) 2026 Tasker Systems. All rights reserved.
 // See LICENSING.md for details.
 
-//! Unified session store composing composition, event, and turn writers.
+//! Unified session store composing composition, ownership, event, turn,
+//! ledger, and checkpoint writers, the setting store, and the optional
+//! Storykeeper mirror.
 
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+use storyteller_core::database::checkpoint::CheckpointPolicy;
+use storyteller_core::graph::settings::{InMemorySettingTopology, SettingTopologyStore};
+
+use super::checkpoints::JsonlCheckpointStore;
 use super::composition::CompositionWriter;
 use super::directives::DirectiveStore;
 use super::events::EventWriter;
+use super::ledger::JsonlLedger;
+use super::ownership::OwnershipStore;
+use super::storykeeper::StorykeeperMirror;
 use super::turns::TurnWriter;
+use crate::engine::RuntimeSnapshot;
 
 /// Manages session directories and delegates to specialized writers.
 #[derive(Debug, Clone)]
 pub struct SessionStore {
     base_dir: PathBuf,
     pub composition: CompositionWriter,
+    pub owners: OwnershipStore,
     pub events: EventWriter,
     pub turns: TurnWriter,
     pub directives: DirectiveStore,
+    pub ledger: JsonlLedger,
+    pub checkpoints: JsonlCheckpointStore<RuntimeSnapshot>,
+    /// How often committed turns are checkpointed.
+    pub checkpoint_policy: CheckpointPolicy,
+    /// Durable Storykeeper that sessions are also written through, when
+    /// configured.
+    pub storykeeper: Option<StorykeeperMirror>,
+    /// Settings scenes are placed in, with the state play leaves behind.
+    pub settings: Arc<dyn SettingTopologyStore>,
 }
 
 impl SessionStore {
@@ -35,12 +56,36 @@ impl SessionStore {
         Ok(Self {
             base_dir: base_dir.to_path_buf(),
             composition: CompositionWriter::new(base_dir),
+            owners: OwnershipStore::new(base_dir),
             events: EventWriter::new(base_dir),
             turns: TurnWriter::new(base_dir),
             directives: DirectiveStore::new(base_dir),
+            ledger: JsonlLedger::new(base_dir),
+            checkpoints: JsonlCheckpointStore::new(base_dir),
+            checkpoint_policy: CheckpointPolicy::default(),
+            storykeeper: None,
+            settings: Arc::new(InMemorySettingTopology::new()),
         })
     }
 
+    /// Checkpoint committed turns on `policy` instead of the default interval.
+    pub fn with_checkpoint_policy(mut self, policy: CheckpointPolicy) -> Self {
+        self.checkpoint_policy = policy;
+        self
+    }
+
+    /// Also write sessions through `mirror`.
+    pub fn with_storykeeper(mut self, mirror: StorykeeperMirror) -> Self {
+        self.storykeeper = Some(mirror);
+        self
+    }
+
+    /// Keep settings in `settings` instead of in process.
+    pub fn with_settings(mut self, settings: Arc<dyn SettingTopologyStore>) -> Self {
+        self.settings = settings;
+        self
+    }
+
     /// List all session IDs (directories in base_dir).
     pub fn list_session_ids(&self) -> Result<Vec<String>, String> {
         let mut ids = Vec::new();
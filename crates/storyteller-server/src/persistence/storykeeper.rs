@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Mirror of session play into a durable [`Storykeeper`].
+//!
+//! The session files stay the server's record for recovery; when
+//! `persistence.storykeeper` is on, each composed scene, sourced command,
+//! and committed turn is also written through the Storykeeper, so the
+//! baseline tables hold every session for the narrative-graph queries.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use storyteller_core::errors::StorytellerResult;
+use storyteller_core::traits::storykeeper::{
+    CompletedTurn, SessionContext, SessionId, Storykeeper,
+};
+use storyteller_core::types::character::{CharacterSheet, SceneData};
+use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::message::PlayerInput;
+use storyteller_core::types::narrative_layer::NarrativeStack;
+use storyteller_core::types::scene::SceneId;
+
+/// Writes sessions of one story through a [`Storykeeper`].
+#[derive(Debug, Clone)]
+pub struct StorykeeperMirror {
+    storykeeper: Arc<dyn Storykeeper>,
+    story_id: Uuid,
+}
+
+impl StorykeeperMirror {
+    pub fn new(storykeeper: Arc<dyn Storykeeper>, story_id: Uuid) -> Self {
+        Self {
+            storykeeper,
+            story_id,
+        }
+    }
+
+    /// The Storykeeper sessions are written through.
+    pub fn storykeeper(&self) -> &Arc<dyn Storykeeper> {
+        &self.storykeeper
+    }
+
+    /// The Storykeeper's view of a session at `turn_number` in `scene_id`.
+    ///
+    /// A session without a player character is recorded against the nil
+    /// entity.
+    pub fn session(
+        &self,
+        session_id: SessionId,
+        player_entity_id: Option<EntityId>,
+        scene_id: SceneId,
+        turn_number: u32,
+        layers: &NarrativeStack,
+    ) -> SessionContext {
+        SessionContext {
+            session_id,
+            story_id: self.story_id,
+            player_entity_id: player_entity_id.unwrap_or(EntityId(Uuid::nil())),
+            current_scene_id: Some(scene_id),
+            turn_number,
+            layers: layers.clone(),
+        }
+    }
+
+    /// Register a composed scene and enter it with its cast.
+    pub async fn enter_scene(
+        &self,
+        scene: &SceneData,
+        cast: &[CharacterSheet],
+        session: &SessionContext,
+    ) -> StorytellerResult<()> {
+        self.storykeeper.register_scene(scene, session).await?;
+        self.storykeeper
+            .enter_scene(&scene.scene_id, cast, session)
+            .await?;
+        Ok(())
+    }
+
+    /// Source a player command before the turn runs.
+    pub async fn source_command(
+        &self,
+        input: &PlayerInput,
+        session: &SessionContext,
+    ) -> StorytellerResult<()> {
+        self.storykeeper.source_command(input, session).await?;
+        Ok(())
+    }
+
+    /// Commit a completed turn.
+    pub async fn commit_turn(
+        &self,
+        completed: &CompletedTurn,
+        session: &SessionContext,
+    ) -> StorytellerResult<()> {
+        self.storykeeper.commit_turn(completed, session).await?;
+        Ok(())
+    }
+}
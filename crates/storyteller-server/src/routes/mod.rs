@@ -5,8 +5,11 @@
 //! Route definitions for the player-facing API.
 //!
 //! Each submodule defines a group of related routes. The top-level
-//! [`crate::router()`] merges them all.
+//! [`crate::router()`] merges them all. Handlers delegate to the same
+//! `EngineServiceImpl` the gRPC server mounts; [`sse`] adapts its streams
+//! and errors to HTTP.
 
 pub mod health;
 pub mod player;
 pub mod session;
+pub mod sse;
@@ -4,23 +4,51 @@
 
 //! Player input/output endpoints.
 //!
-//! These routes handle the core gameplay loop: accepting player input,
-//! streaming narrative responses, and providing turn-cycle progress events.
+//! These routes handle the core gameplay loop: accepting player input and
+//! streaming the turn's phases (decomposition, prediction, arbitration,
+//! narrator prose, turn completion) back as Server-Sent Events.
 //!
 //! See: `docs/technical/infrastructure-architecture.md` (player-facing protocol),
 //!      `docs/technical/scene-model.md` (ludic contract)
 
-use axum::{routing::post, Router};
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Extension;
+use axum::{Json, Router};
+use serde::Deserialize;
 
+use super::sse::{engine_event_stream, ApiError};
+use crate::middleware::auth::PlayerIdentity;
+use crate::proto::storyteller_engine_server::StorytellerEngine;
+use crate::proto::SubmitInputRequest;
 use crate::state::AppState;
 
 /// Player interaction routes.
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/api/v1/input", post(submit_input))
+    Router::new().route("/api/v1/sessions/{session_id}/input", post(submit_input))
 }
 
-/// Accept player input for the current scene.
-async fn submit_input() -> &'static str {
-    // Placeholder — will dispatch to the turn cycle pipeline.
-    "input received"
+/// Request body for `POST /api/v1/sessions/{session_id}/input`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlayerInput {
+    pub input: String,
+}
+
+/// Accept player input for the current scene and stream the turn.
+async fn submit_input(
+    State(state): State<AppState>,
+    Extension(caller): Extension<PlayerIdentity>,
+    Path(session_id): Path<String>,
+    Json(body): Json<PlayerInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    let events = state
+        .engine
+        .submit_input(caller.request(SubmitInputRequest {
+            session_id,
+            input: body.input,
+        }))
+        .await?
+        .into_inner();
+    Ok(engine_event_stream(events))
 }
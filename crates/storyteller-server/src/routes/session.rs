@@ -4,22 +4,91 @@
 
 //! Session management endpoints.
 //!
-//! Create, resume, and manage player sessions. Sessions are the container
+//! Create, resume, and inspect player sessions. Sessions are the container
 //! for an active playthrough of a story.
 //!
+//! - `POST /api/v1/sessions` — compose a scene (`ComposeSceneRequest` JSON),
+//!   streaming the composition events as SSE
+//! - `GET /api/v1/sessions` — list persisted sessions
+//! - `GET /api/v1/sessions/{session_id}/scene` — current scene state
+//! - `POST /api/v1/sessions/{session_id}/resume` — reload a session from
+//!   disk, streaming the same events a fresh composition emits
+//!
 //! See: `docs/technical/infrastructure-architecture.md` (session resilience)
 
-use axum::{routing::post, Router};
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::Extension;
+use axum::{Json, Router};
 
+use super::sse::{engine_event_stream, ApiError};
+use crate::middleware::auth::PlayerIdentity;
+use crate::proto::storyteller_engine_server::StorytellerEngine;
+use crate::proto::{
+    ComposeSceneRequest, GetSceneStateRequest, ResumeSessionRequest, SceneState, SessionList,
+};
 use crate::state::AppState;
 
 /// Session management routes.
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/api/v1/sessions", post(create_session))
+    Router::new()
+        .route("/api/v1/sessions", post(create_session).get(list_sessions))
+        .route("/api/v1/sessions/{session_id}/scene", get(scene_state))
+        .route("/api/v1/sessions/{session_id}/resume", post(resume_session))
+}
+
+/// Compose a scene and start a new play session.
+async fn create_session(
+    State(state): State<AppState>,
+    Extension(caller): Extension<PlayerIdentity>,
+    Json(request): Json<ComposeSceneRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let events = state
+        .engine
+        .compose_scene(caller.request(request))
+        .await?
+        .into_inner();
+    Ok(engine_event_stream(events))
+}
+
+/// List persisted sessions.
+async fn list_sessions(
+    State(state): State<AppState>,
+    Extension(caller): Extension<PlayerIdentity>,
+) -> Result<Json<SessionList>, ApiError> {
+    let sessions = state
+        .engine
+        .list_sessions(caller.request(()))
+        .await?
+        .into_inner();
+    Ok(Json(sessions))
+}
+
+/// Current scene state for a session.
+async fn scene_state(
+    State(state): State<AppState>,
+    Extension(caller): Extension<PlayerIdentity>,
+    Path(session_id): Path<String>,
+) -> Result<Json<SceneState>, ApiError> {
+    let scene = state
+        .engine
+        .get_scene_state(caller.request(GetSceneStateRequest { session_id }))
+        .await?
+        .into_inner();
+    Ok(Json(scene))
 }
 
-/// Create a new play session.
-async fn create_session() -> &'static str {
-    // Placeholder — will initialize engine state and return session ID.
-    "session created"
+/// Resume a persisted session.
+async fn resume_session(
+    State(state): State<AppState>,
+    Extension(caller): Extension<PlayerIdentity>,
+    Path(session_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let events = state
+        .engine
+        .resume_session(caller.request(ResumeSessionRequest { session_id }))
+        .await?
+        .into_inner();
+    Ok(engine_event_stream(events))
 }
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! REST plumbing shared by the route modules.
+//!
+//! The REST surface is a thin adapter over [`EngineServiceImpl`]: handlers
+//! call the same `StorytellerEngine` methods the gRPC server does. Streaming
+//! RPCs become Server-Sent Events — one SSE event per `EngineEvent`, named
+//! after its payload (`phase_started`, `narrator_prose`, `turn_complete`, …)
+//! with the event serialized as JSON in `data`. gRPC `Status` errors become
+//! JSON error responses with the matching HTTP status.
+//!
+//! [`EngineServiceImpl`]: crate::grpc::engine_service::EngineServiceImpl
+
+use std::convert::Infallible;
+
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Code, Status};
+
+use crate::proto::{engine_event, EngineEvent};
+
+/// A gRPC [`Status`] returned from a REST handler.
+#[derive(Debug)]
+pub struct ApiError(pub Status);
+
+impl From<Status> for ApiError {
+    fn from(status: Status) -> Self {
+        Self(status)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({
+            "error": self.0.message(),
+            "code": format!("{:?}", self.0.code()),
+        });
+        (http_status(self.0.code()), Json(body)).into_response()
+    }
+}
+
+/// HTTP status for a gRPC status code.
+pub fn http_status(code: Code) -> StatusCode {
+    match code {
+        Code::Ok => StatusCode::OK,
+        Code::InvalidArgument | Code::OutOfRange => StatusCode::BAD_REQUEST,
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted | Code::FailedPrecondition => StatusCode::CONFLICT,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        Code::Cancelled | Code::Unknown | Code::Internal | Code::DataLoss => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+/// SSE event name for a payload — the proto oneof field name.
+pub fn payload_kind(payload: &engine_event::Payload) -> &'static str {
+    use engine_event::Payload;
+    match payload {
+        Payload::PhaseStarted(_) => "phase_started",
+        Payload::Decomposition(_) => "decomposition",
+        Payload::Prediction(_) => "prediction",
+        Payload::Arbitration(_) => "arbitration",
+        Payload::IntentSynthesis(_) => "intent_synthesis",
+        Payload::Context(_) => "context",
+        Payload::NarratorToken(_) => "narrator_token",
+        Payload::NarratorComplete(_) => "narrator_complete",
+        Payload::SceneComposed(_) => "scene_composed",
+        Payload::Goals(_) => "goals",
+        Payload::TurnComplete(_) => "turn_complete",
+        Payload::NarratorProse(_) => "narrator_prose",
+        Payload::SceneReady(_) => "scene_ready",
+        Payload::InputReceived(_) => "input_received",
+        Payload::ProcessingUpdate(_) => "processing_update",
+        Payload::Error(_) => "error",
+    }
+}
+
+/// One SSE event for one item of an engine stream.
+fn to_sse_event(item: Result<EngineEvent, Status>) -> Event {
+    match item {
+        Ok(event) => {
+            let kind = event.payload.as_ref().map_or("event", payload_kind);
+            match serde_json::to_string(&event) {
+                Ok(json) => Event::default().event(kind).id(event.event_id).data(json),
+                Err(e) => Event::default()
+                    .event("error")
+                    .data(serde_json::json!({ "error": e.to_string() }).to_string()),
+            }
+        }
+        Err(status) => Event::default().event("error").data(
+            serde_json::json!({
+                "error": status.message(),
+                "code": format!("{:?}", status.code()),
+            })
+            .to_string(),
+        ),
+    }
+}
+
+/// Stream an engine RPC's events to the client as SSE.
+pub fn engine_event_stream(
+    events: ReceiverStream<Result<EngineEvent, Status>>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    Sse::new(events.map(|item| Ok(to_sse_event(item)))).keep_alive(KeepAlive::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::PhaseStarted;
+
+    #[test]
+    fn status_codes_map_to_http() {
+        assert_eq!(http_status(Code::NotFound), StatusCode::NOT_FOUND);
+        assert_eq!(http_status(Code::InvalidArgument), StatusCode::BAD_REQUEST);
+        assert_eq!(http_status(Code::PermissionDenied), StatusCode::FORBIDDEN);
+        assert_eq!(
+            http_status(Code::Internal),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[test]
+    fn engine_events_serialize_with_snake_case_payload_tags() {
+        let event = EngineEvent {
+            event_id: "e1".to_string(),
+            session_id: "s1".to_string(),
+            turn: Some(1),
+            timestamp: String::new(),
+            payload: Some(engine_event::Payload::PhaseStarted(PhaseStarted {
+                phase: "decomposition".to_string(),
+            })),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["payload"]["phase_started"]["phase"], "decomposition");
+        assert_eq!(
+            payload_kind(event.payload.as_ref().unwrap()),
+            "phase_started"
+        );
+    }
+}
@@ -2,73 +2,224 @@
 // Copyright (c) 2026 Tasker Systems. All rights reserved.
 // See LICENSING.md for details.
 
-//! gRPC server startup and configuration.
+//! gRPC and HTTP server startup and configuration.
+//!
+//! Both listeners share one [`AppState`]: the REST router and the gRPC
+//! `StorytellerEngine` service drive the same [`EngineServiceImpl`], so a
+//! session composed over one protocol can be played over the other.
 
 use std::net::SocketAddr;
 use std::time::Duration;
 
+use sqlx::postgres::PgPoolOptions;
+use tokio::net::TcpListener;
+use tonic::service::interceptor::InterceptedService;
 use tonic::transport::Server;
 use tracing::info;
 
 use std::sync::Arc;
 
 use storyteller_composer::SceneComposer;
-use storyteller_core::grammars::PlutchikWestern;
+use storyteller_core::config::{ConfigLoader, StorytellerConfig};
+use storyteller_core::database::checkpoint::CheckpointPolicy;
+use storyteller_core::grammars::GrammarRegistry;
+use storyteller_core::graph::age::age_available;
+use storyteller_core::graph::settings::AgeSettingTopology;
+use storyteller_core::traits::llm::LlmProvider;
 use storyteller_core::traits::structured_llm::StructuredLlmConfig;
+use storyteller_core::StorytellerResult;
+use storyteller_engine::inference::cloud::{CloudLlmConfig, CloudLlmProvider};
 use storyteller_engine::inference::external::{ExternalServerConfig, ExternalServerProvider};
 use storyteller_engine::inference::frame::CharacterPredictor;
 use storyteller_engine::inference::structured::OllamaStructuredProvider;
+use storyteller_storykeeper::bedrock::PostgresBedrock;
+use storyteller_storykeeper::PostgresStorykeeper;
 
 use crate::engine::{EngineProviders, EngineStateManager};
 use crate::grpc::composer_service::ComposerServiceImpl;
-use crate::grpc::engine_service::EngineServiceImpl;
 use crate::logging::LogBroadcast;
-use crate::persistence::SessionStore;
+use crate::middleware::auth::{AuthInterceptor, AuthPolicy};
+use crate::persistence::{SessionStore, StorykeeperMirror};
 use crate::proto::composer_service_server::ComposerServiceServer;
 use crate::proto::storyteller_engine_server::StorytellerEngineServer;
+use crate::state::AppState;
 
-/// Server configuration from environment variables.
+/// Server configuration resolved from the layered [`StorytellerConfig`].
+///
+/// Flattens the `server`, `provider`, `auth`, and `persistence` sections,
+/// with their defaults applied, and keeps the validated source config for
+/// the `GetEffectiveConfig` debug RPC.
 #[derive(Debug)]
 pub struct ServerConfig {
     pub grpc_port: u16,
+    /// Port for the REST/SSE API.
+    pub http_port: u16,
     pub data_path: String,
     pub sessions_dir: String,
+    /// How often committed turns are checkpointed.
+    pub checkpoint_policy: CheckpointPolicy,
     pub narrator_model: String,
     pub decomposition_model: String,
     pub intent_model: String,
     pub ollama_url: String,
+    /// Narrator backend: `ollama` (default), `anthropic`, `openai`, or
+    /// `local` (requires the `local-llm` feature).
+    pub narrator_provider: String,
+    /// API key for a hosted narrator.
+    pub cloud_api_key: Option<String>,
+    /// Base URL override for a hosted narrator (proxies, compatible APIs).
+    pub cloud_base_url: Option<String>,
+    /// Model directory for the `local` narrator.
+    pub local_model_path: Option<String>,
     /// Optional path to the ONNX character predictor model file.
     pub model_path: Option<String>,
+    /// Optional directory of data-driven emotional grammars.
+    pub grammars_path: Option<String>,
+    /// Static API keys as `key=player,key=player`.
+    pub api_keys: Option<String>,
+    /// Operator API keys, same format.
+    pub admin_keys: Option<String>,
+    /// Shared secret for HMAC-signed bearer tokens.
+    pub token_secret: Option<String>,
+    /// Postgres URL for the bedrock data layer, set only when
+    /// `persistence.bedrock_descriptors` opts in. When set, descriptors load
+    /// from bedrock with the files under `data_path` as fallback.
+    pub database_url: Option<String>,
+    /// Postgres URL for the Storykeeper tables, set only when
+    /// `persistence.storykeeper` opts in. When set, sessions are also
+    /// written through a [`PostgresStorykeeper`].
+    pub storykeeper_url: Option<String>,
+    /// The story mirrored sessions are recorded under.
+    pub story_id: uuid::Uuid,
+    /// The configuration these settings were resolved from.
+    pub source: Arc<StorytellerConfig>,
 }
 
 impl ServerConfig {
-    pub fn from_env() -> Self {
+    /// Load and validate the layered configuration.
+    ///
+    /// See [`ConfigLoader::load`] for how the TOML files are located. The
+    /// env vars the server used to read directly (`STORYTELLER_GRPC_PORT`,
+    /// `OLLAMA_URL`, ...) still apply through the base file's placeholders.
+    pub fn load() -> StorytellerResult<Self> {
+        ConfigLoader::load().map(Self::from_storyteller_config)
+    }
+
+    pub fn from_storyteller_config(config: StorytellerConfig) -> Self {
+        let server = config.server();
+        let provider = config.provider();
+        let auth = config.auth();
+        let persistence = config.persistence();
         Self {
-            grpc_port: std::env::var("STORYTELLER_GRPC_PORT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(50051),
-            data_path: std::env::var("STORYTELLER_DATA_PATH")
-                .unwrap_or_else(|_| "../storyteller-data/training-data/descriptors".to_string()),
-            sessions_dir: std::env::var("STORYTELLER_SESSIONS_DIR")
-                .unwrap_or_else(|_| ".story/sessions".to_string()),
-            narrator_model: std::env::var("STORYTELLER_NARRATOR_MODEL")
-                .unwrap_or_else(|_| "qwen2.5:14b".to_string()),
-            decomposition_model: std::env::var("STORYTELLER_DECOMPOSITION_MODEL")
-                .unwrap_or_else(|_| "qwen2.5:3b-instruct".to_string()),
-            intent_model: std::env::var("STORYTELLER_INTENT_MODEL")
-                .unwrap_or_else(|_| "qwen2.5:3b-instruct".to_string()),
-            ollama_url: std::env::var("OLLAMA_URL")
-                .unwrap_or_else(|_| "http://localhost:11434".to_string()),
-            model_path: std::env::var("STORYTELLER_MODEL_PATH").ok(),
+            grpc_port: server.grpc_port,
+            http_port: server.http_port,
+            data_path: server.data_path,
+            sessions_dir: persistence.sessions_dir.clone(),
+            checkpoint_policy: persistence.checkpoint_policy(),
+            narrator_model: provider.narrator_model,
+            decomposition_model: provider.decomposition_model,
+            intent_model: provider.intent_model,
+            ollama_url: provider.ollama_url,
+            narrator_provider: provider.narrator,
+            cloud_api_key: provider.cloud_api_key,
+            cloud_base_url: provider.cloud_base_url,
+            local_model_path: provider.local_model_path,
+            model_path: server.model_path,
+            grammars_path: server.grammars_path,
+            api_keys: auth.api_keys,
+            admin_keys: auth.admin_keys,
+            token_secret: auth.token_secret,
+            database_url: persistence
+                .bedrock_descriptors
+                .then(|| config.database.url.clone()),
+            storykeeper_url: persistence.storykeeper.then(|| config.database.url.clone()),
+            story_id: persistence.story_id,
+            source: Arc::new(config),
         }
     }
 }
 
-/// Start the gRPC server.
+/// Build the scene composer.
+///
+/// With a database configured, descriptors come from bedrock so writers'
+/// edits show up on restart without a JSON re-export; the descriptor files
+/// fill in what bedrock lacks and stand in if it is unreachable. Without a
+/// database, the files alone are used.
+async fn load_composer(config: &ServerConfig) -> Result<SceneComposer, Box<dyn std::error::Error>> {
+    let data_path = std::path::Path::new(&config.data_path);
+    let Some(url) = &config.database_url else {
+        info!("Loading descriptors from {}", config.data_path);
+        return Ok(SceneComposer::load(data_path)?);
+    };
+
+    info!(
+        "Loading descriptors from bedrock, falling back to {}",
+        config.data_path
+    );
+    match PgPoolOptions::new()
+        .max_connections(2)
+        .acquire_timeout(Duration::from_secs(5))
+        .connect(url)
+        .await
+    {
+        Ok(pool) => {
+            let bedrock = PostgresBedrock::new(pool);
+            Ok(SceneComposer::from_bedrock(&bedrock, Some(data_path)).await?)
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "bedrock database unreachable, using descriptor files");
+            Ok(SceneComposer::load(data_path)?)
+        }
+    }
+}
+
+/// Connect the durable Storykeeper and bring its schema up to date.
+///
+/// Unlike bedrock descriptors there is no fallback: a server told to keep
+/// sessions in Postgres refuses to start without it.
+async fn connect_storykeeper(url: &str) -> Result<PostgresStorykeeper, Box<dyn std::error::Error>> {
+    let pool = PgPoolOptions::new()
+        .max_connections(5)
+        .acquire_timeout(Duration::from_secs(5))
+        .connect(url)
+        .await?;
+    let storykeeper = PostgresStorykeeper::new(pool);
+    storykeeper.migrate().await?;
+    Ok(storykeeper)
+}
+
+/// Load the in-process candle narrator from `local_model_path`.
+#[cfg(feature = "local-llm")]
+fn local_narrator(
+    config: &ServerConfig,
+) -> Result<Arc<dyn LlmProvider>, Box<dyn std::error::Error>> {
+    use storyteller_engine::inference::local::CandleLlmProvider;
+
+    let dir = config
+        .local_model_path
+        .as_deref()
+        .ok_or("provider.local_model_path is required for the local narrator")?;
+    Ok(Arc::new(CandleLlmProvider::from_dir(
+        std::path::Path::new(dir),
+    )?))
+}
+
+#[cfg(not(feature = "local-llm"))]
+fn local_narrator(
+    _config: &ServerConfig,
+) -> Result<Arc<dyn LlmProvider>, Box<dyn std::error::Error>> {
+    Err("the local narrator requires storyteller-server built with the local-llm feature".into())
+}
+
+/// Start the gRPC and HTTP servers.
+///
+/// Serves both [`ComposerServiceImpl`] and the engine service over gRPC, and
+/// the REST API from [`crate::router()`] over HTTP, with real LLM providers
+/// constructed from [`ServerConfig`]. Returns when either listener fails.
 ///
-/// Serves both [`ComposerServiceImpl`] and [`EngineServiceImpl`] with real LLM
-/// providers constructed from [`ServerConfig`].
+/// Auth is enabled when `api_keys`, `admin_keys`, or `token_secret` is
+/// configured; both listeners then require a credential and scope sessions
+/// to its player.
 ///
 /// If `log_broadcast` is provided, the engine service will use it for the
 /// `StreamLogs` RPC. Pass the same [`LogBroadcast`] that was wired into the
@@ -77,25 +228,56 @@ pub async fn run_server(
     config: ServerConfig,
     log_broadcast: Option<LogBroadcast>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    info!("Loading descriptors from {}", config.data_path);
-    let composer = Arc::new(SceneComposer::load(std::path::Path::new(
-        &config.data_path,
-    ))?);
+    let composer = Arc::new(load_composer(&config).await?);
 
     let state_manager = Arc::new(EngineStateManager::new());
 
-    let session_store = Arc::new(SessionStore::new(std::path::Path::new(
-        &config.sessions_dir,
-    ))?);
+    let mut session_store = SessionStore::new(std::path::Path::new(&config.sessions_dir))?
+        .with_checkpoint_policy(config.checkpoint_policy);
+    if let Some(url) = &config.storykeeper_url {
+        let storykeeper = connect_storykeeper(url).await?;
+        info!(story_id = %config.story_id, "Writing sessions through the Postgres Storykeeper");
+        if age_available(storykeeper.pool()).await? {
+            session_store = session_store.with_settings(Arc::new(AgeSettingTopology::new(
+                storykeeper.pool().clone(),
+                config.story_id,
+            )));
+        } else {
+            tracing::warn!("Apache AGE is not installed; settings are kept in process");
+        }
+        session_store = session_store.with_storykeeper(StorykeeperMirror::new(
+            Arc::new(storykeeper),
+            config.story_id,
+        ));
+    }
+    let session_store = Arc::new(session_store);
 
     // Construct LLM providers from config.
     // Providers are built without requiring Ollama to be running — connections
     // are made lazily on first request.
-    let narrator_llm = Arc::new(ExternalServerProvider::new(ExternalServerConfig {
-        base_url: config.ollama_url.clone(),
-        model: config.narrator_model.clone(),
-        timeout: Duration::from_secs(120),
-    }));
+    let narrator_llm: Arc<dyn LlmProvider> = match config.narrator_provider.as_str() {
+        "ollama" => Arc::new(ExternalServerProvider::new(ExternalServerConfig {
+            base_url: config.ollama_url.clone(),
+            model: config.narrator_model.clone(),
+            timeout: Duration::from_secs(120),
+        })),
+        provider @ ("anthropic" | "openai") => {
+            let api_key = config.cloud_api_key.clone().ok_or_else(|| {
+                format!("provider.cloud_api_key is required for the {provider} narrator")
+            })?;
+            let mut cloud = if provider == "anthropic" {
+                CloudLlmConfig::anthropic(api_key, config.narrator_model.clone())
+            } else {
+                CloudLlmConfig::openai(api_key, config.narrator_model.clone())
+            };
+            if let Some(base_url) = &config.cloud_base_url {
+                cloud = cloud.with_base_url(base_url.clone());
+            }
+            Arc::new(CloudLlmProvider::new(cloud))
+        }
+        "local" => local_narrator(&config)?,
+        other => return Err(format!("unknown narrator provider: {other}").into()),
+    };
 
     let intent_llm = Arc::new(ExternalServerProvider::new(ExternalServerConfig {
         base_url: config.ollama_url.clone(),
@@ -131,50 +313,83 @@ pub async fn run_server(
         }
     });
 
-    let grammar = Arc::new(PlutchikWestern::new());
+    let mut grammars = GrammarRegistry::builtin();
+    if let Some(path) = &config.grammars_path {
+        let loaded = grammars.load_dir(std::path::Path::new(path))?;
+        tracing::info!(%path, ?loaded, "Emotional grammars loaded");
+    }
 
     let providers = Arc::new(EngineProviders {
         narrator_llm,
         structured_llm,
         intent_llm: Some(intent_llm),
         predictor,
-        grammar,
+        grammars: Arc::new(grammars),
+        // Per-genre odds: `game_design` config, then genre descriptors, then
+        // the built-in tunings.
+        game_design: Arc::new(composer.game_design(&config.source.game_design().genres)),
         narrator_model: config.narrator_model.clone(),
         decomposition_model: config.decomposition_model.clone(),
+        config: Some(config.source.clone()),
     });
 
     info!(
+        narrator_provider = %config.narrator_provider,
         narrator_model = %config.narrator_model,
         intent_model = %config.intent_model,
         ollama_url = %config.ollama_url,
         "LLM providers constructed"
     );
 
-    let addr: SocketAddr = format!("0.0.0.0:{}", config.grpc_port).parse()?;
-    info!("Starting gRPC server on {addr}");
+    let auth = AuthPolicy::from_config(
+        config.api_keys.as_deref(),
+        config.admin_keys.as_deref(),
+        config.token_secret.as_deref(),
+    )?;
+    if auth.is_enabled() {
+        info!("Authentication enabled");
+    } else {
+        tracing::warn!("No API keys or token secret configured — authentication disabled");
+    }
 
-    let composer_service = ComposerServiceImpl::new(composer.clone());
-    let engine_service = match log_broadcast {
-        Some(lb) => EngineServiceImpl::with_log_broadcast(
+    let state = match log_broadcast {
+        Some(lb) => AppState::with_log_broadcast(
             composer.clone(),
-            state_manager.clone(),
-            session_store.clone(),
-            providers.clone(),
+            state_manager,
+            session_store,
+            providers,
             lb,
         ),
-        None => EngineServiceImpl::new(
-            composer.clone(),
-            state_manager.clone(),
-            session_store.clone(),
-            providers.clone(),
-        ),
-    };
+        None => AppState::new(composer.clone(), state_manager, session_store, providers),
+    }
+    .with_auth(auth.clone());
 
-    Server::builder()
-        .add_service(ComposerServiceServer::new(composer_service))
-        .add_service(StorytellerEngineServer::new(engine_service))
-        .serve(addr)
-        .await?;
+    let grpc_addr: SocketAddr = format!("0.0.0.0:{}", config.grpc_port).parse()?;
+    let http_addr: SocketAddr = format!("0.0.0.0:{}", config.http_port).parse()?;
+
+    let composer_service = ComposerServiceImpl::new(composer);
+    let interceptor = AuthInterceptor::new(auth);
+    let grpc = Server::builder()
+        .add_service(ComposerServiceServer::with_interceptor(
+            composer_service,
+            interceptor.clone(),
+        ))
+        .add_service(InterceptedService::new(
+            StorytellerEngineServer::from_arc(state.engine.clone()),
+            interceptor,
+        ))
+        .serve(grpc_addr);
+
+    let listener = TcpListener::bind(http_addr).await?;
+    let http = axum::serve(listener, crate::router(state));
+
+    info!("Starting gRPC server on {grpc_addr}");
+    info!("Starting HTTP server on {http_addr}");
+
+    tokio::try_join!(
+        async { grpc.await.map_err(Box::<dyn std::error::Error>::from) },
+        async { http.await.map_err(Box::<dyn std::error::Error>::from) },
+    )?;
 
     Ok(())
 }
@@ -13,18 +13,28 @@ use std::sync::Arc;
 use storyteller_composer::SceneComposer;
 
 use crate::engine::{EngineProviders, EngineStateManager};
+use crate::grpc::engine_service::EngineServiceImpl;
+use crate::logging::LogBroadcast;
+use crate::middleware::auth::AuthPolicy;
 use crate::persistence::SessionStore;
 
 /// Shared state available to all API handlers and gRPC services.
 ///
 /// Constructed by the deployment crate (cli, shuttle, etc.) and threaded
 /// through axum's state extraction.
+///
+/// `engine` is the same service instance the gRPC server mounts (via
+/// `StorytellerEngineServer::from_arc`), so REST and gRPC clients share one
+/// `EngineStateManager` and see each other's sessions. `auth` guards both
+/// surfaces; it is disabled unless set with [`AppState::with_auth`].
 #[derive(Debug, Clone)]
 pub struct AppState {
     pub composer: Arc<SceneComposer>,
     pub state_manager: Arc<EngineStateManager>,
     pub session_store: Arc<SessionStore>,
     pub providers: Arc<EngineProviders>,
+    pub engine: Arc<EngineServiceImpl>,
+    pub auth: AuthPolicy,
 }
 
 impl AppState {
@@ -35,11 +45,51 @@ impl AppState {
         session_store: Arc<SessionStore>,
         providers: Arc<EngineProviders>,
     ) -> Self {
+        let engine = Arc::new(EngineServiceImpl::new(
+            composer.clone(),
+            state_manager.clone(),
+            session_store.clone(),
+            providers.clone(),
+        ));
+        Self {
+            composer,
+            state_manager,
+            session_store,
+            providers,
+            engine,
+            auth: AuthPolicy::disabled(),
+        }
+    }
+
+    /// Create an application state whose engine service streams logs from
+    /// an external [`LogBroadcast`].
+    pub fn with_log_broadcast(
+        composer: Arc<SceneComposer>,
+        state_manager: Arc<EngineStateManager>,
+        session_store: Arc<SessionStore>,
+        providers: Arc<EngineProviders>,
+        log_broadcast: LogBroadcast,
+    ) -> Self {
+        let engine = Arc::new(EngineServiceImpl::with_log_broadcast(
+            composer.clone(),
+            state_manager.clone(),
+            session_store.clone(),
+            providers.clone(),
+            log_broadcast,
+        ));
         Self {
             composer,
             state_manager,
             session_store,
             providers,
+            engine,
+            auth: AuthPolicy::disabled(),
         }
     }
+
+    /// Require callers to authenticate under `auth`.
+    pub fn with_auth(mut self, auth: AuthPolicy) -> Self {
+        self.auth = auth;
+        self
+    }
 }
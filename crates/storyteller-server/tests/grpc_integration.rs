@@ -9,10 +9,10 @@
 //! Tier 1 (no feature flags): Requires STORYTELLER_DATA_PATH.
 //! Tier 2 (test-llm feature): Also requires a running Ollama server.
 
-use storyteller_server::engine::{EngineProviders, EngineStateManager};
+use storyteller_server::engine::{Composition, EngineProviders, EngineStateManager};
 use storyteller_server::grpc::composer_service::ComposerServiceImpl;
 use storyteller_server::grpc::engine_service::EngineServiceImpl;
-use storyteller_server::persistence::SessionStore;
+use storyteller_server::persistence::{SessionStore, StorykeeperMirror};
 use storyteller_server::proto::composer_service_client::ComposerServiceClient;
 use storyteller_server::proto::composer_service_server::ComposerServiceServer;
 use storyteller_server::proto::storyteller_engine_client::StorytellerEngineClient;
@@ -20,6 +20,7 @@ use storyteller_server::proto::storyteller_engine_server::StorytellerEngineServe
 use storyteller_server::proto::*;
 
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tempfile::TempDir;
 use tokio::net::TcpListener;
@@ -49,6 +50,64 @@ impl storyteller_core::traits::llm::LlmProvider for MockLlm {
     }
 }
 
+/// Narrator whose first render fails, as a dropped provider connection would.
+#[derive(Debug, Default)]
+struct FlakyLlm {
+    failed_once: AtomicBool,
+}
+
+#[async_trait::async_trait]
+impl storyteller_core::traits::llm::LlmProvider for FlakyLlm {
+    async fn complete(
+        &self,
+        req: storyteller_core::traits::llm::CompletionRequest,
+    ) -> storyteller_core::errors::StorytellerResult<
+        storyteller_core::traits::llm::CompletionResponse,
+    > {
+        if !self.failed_once.swap(true, Ordering::SeqCst) {
+            return Err(storyteller_core::errors::StorytellerError::Llm(
+                "connection reset".to_string(),
+            ));
+        }
+        MockLlm.complete(req).await
+    }
+}
+
+/// Streams one sentence, then reports a dropped connection mid-response.
+#[derive(Debug)]
+struct TruncatingLlm;
+
+#[async_trait::async_trait]
+impl storyteller_core::traits::llm::LlmProvider for TruncatingLlm {
+    async fn complete(
+        &self,
+        req: storyteller_core::traits::llm::CompletionRequest,
+    ) -> storyteller_core::errors::StorytellerResult<
+        storyteller_core::traits::llm::CompletionResponse,
+    > {
+        MockLlm.complete(req).await
+    }
+
+    async fn stream_complete(
+        &self,
+        _req: storyteller_core::traits::llm::CompletionRequest,
+    ) -> storyteller_core::errors::StorytellerResult<
+        storyteller_core::traits::llm::NarratorTokenStream,
+    > {
+        let (sender, receiver) = storyteller_core::traits::narrator_token_channel();
+        tokio::spawn(async move {
+            let _ = sender.0.send(Ok("The gate creaks. ".to_string())).await;
+            let _ = sender
+                .0
+                .send(Err(storyteller_core::errors::StorytellerError::Llm(
+                    "stream interrupted".to_string(),
+                )))
+                .await;
+        });
+        Ok(receiver)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Test server helpers
 // ---------------------------------------------------------------------------
@@ -87,6 +146,7 @@ async fn start_test_server() -> Option<String> {
 /// Result of starting an engine test server.
 struct EngineTestServer {
     url: String,
+    session_store: Arc<SessionStore>,
     _temp_dir: TempDir, // keep alive for session store lifetime
 }
 
@@ -109,12 +169,15 @@ async fn start_engine_test_server(
         structured_llm: None,
         intent_llm: None,
         predictor: None,
-        grammar: Arc::new(storyteller_core::grammars::PlutchikWestern::new()),
+        grammars: Arc::new(storyteller_core::grammars::GrammarRegistry::builtin()),
+        game_design: Arc::new(storyteller_core::game_design::GameDesignRegistry::builtin()),
         narrator_model: "test-model".to_string(),
         decomposition_model: String::new(),
+        config: None,
     });
 
-    let engine_service = EngineServiceImpl::new(composer, state_manager, session_store, providers);
+    let engine_service =
+        EngineServiceImpl::new(composer, state_manager, session_store.clone(), providers);
 
     let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
         .await
@@ -134,14 +197,541 @@ async fn start_engine_test_server(
 
     Some(EngineTestServer {
         url,
+        session_store,
         _temp_dir: temp_dir,
     })
 }
 
+/// An engine test server with one hand-built session, which needs no
+/// descriptor data on disk.
+struct SeededTestServer {
+    url: String,
+    session_id: String,
+    engine: Arc<EngineServiceImpl>,
+    session_store: Arc<SessionStore>,
+    state_manager: Arc<EngineStateManager>,
+    _temp_dir: TempDir,
+}
+
+async fn start_seeded_test_server(
+    narrator_llm: Arc<dyn storyteller_core::traits::llm::LlmProvider>,
+) -> SeededTestServer {
+    start_seeded_test_server_with(narrator_llm, None).await
+}
+
+/// A seeded test server whose sessions are also written through `mirror`.
+async fn start_seeded_test_server_with(
+    narrator_llm: Arc<dyn storyteller_core::traits::llm::LlmProvider>,
+    mirror: Option<StorykeeperMirror>,
+) -> SeededTestServer {
+    use storyteller_composer::descriptors::DescriptorSet;
+    use storyteller_core::types::character::{SceneConstraints, SceneData, SceneSetting};
+    use storyteller_core::types::scene::{SceneId, SceneType};
+
+    let composer = Arc::new(storyteller_composer::SceneComposer::from_descriptors(
+        DescriptorSet {
+            archetypes: vec![],
+            genres: vec![],
+            profiles: vec![],
+            dynamics: vec![],
+            axes: vec![],
+            dimensions: vec![],
+            names: Default::default(),
+            settings: Default::default(),
+            goals: vec![],
+        },
+    ));
+    let temp_dir = TempDir::new().unwrap();
+    let mut session_store = SessionStore::new(temp_dir.path()).expect("create session store");
+    if let Some(mirror) = mirror {
+        session_store = session_store.with_storykeeper(mirror);
+    }
+    let session_store = Arc::new(session_store);
+    let state_manager = Arc::new(EngineStateManager::new());
+    let providers = Arc::new(EngineProviders {
+        narrator_llm,
+        structured_llm: None,
+        intent_llm: None,
+        predictor: None,
+        grammars: Arc::new(storyteller_core::grammars::GrammarRegistry::builtin()),
+        game_design: Arc::new(storyteller_core::game_design::GameDesignRegistry::builtin()),
+        narrator_model: "test-model".to_string(),
+        decomposition_model: String::new(),
+        config: None,
+    });
+
+    let scene = SceneData {
+        scene_id: SceneId::new(),
+        title: "The Gate".to_string(),
+        scene_type: SceneType::Connective,
+        setting: SceneSetting {
+            description: "A field gate at the edge of the village, at dusk.".to_string(),
+            affordances: vec![],
+            sensory_details: vec![],
+            aesthetic_detail: "Lichen on the top rail.".to_string(),
+        },
+        cast: vec![],
+        stakes: vec![],
+        constraints: SceneConstraints {
+            hard: vec![],
+            soft: vec![],
+            perceptual: vec![],
+        },
+        emotional_arc: vec![],
+        evaluation_criteria: vec![],
+        event_dependencies: Default::default(),
+    };
+    let session_id = uuid::Uuid::now_v7().to_string();
+    state_manager.create_session(
+        &session_id,
+        Composition {
+            scene: serde_json::to_value(scene).unwrap(),
+            characters: vec![],
+            goals: None,
+            intentions: None,
+            selections: serde_json::json!({}),
+            genre_rules: None,
+        },
+    );
+
+    let engine_service = Arc::new(EngineServiceImpl::new(
+        composer,
+        state_manager.clone(),
+        session_store.clone(),
+        providers,
+    ));
+
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let url = format!("http://{addr}");
+
+    let engine = engine_service.clone();
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(StorytellerEngineServer::from_arc(engine))
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+            .await
+            .unwrap();
+    });
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    SeededTestServer {
+        url,
+        session_id,
+        engine: engine_service,
+        session_store,
+        state_manager,
+        _temp_dir: temp_dir,
+    }
+}
+
+/// Submit input and drain the event stream, returning the events and the
+/// error status that ended it, if any.
+async fn submit(
+    client: &mut StorytellerEngineClient<Channel>,
+    session_id: &str,
+    input: &str,
+) -> (Vec<EngineEvent>, Option<tonic::Status>) {
+    let mut stream = client
+        .submit_input(SubmitInputRequest {
+            session_id: session_id.to_string(),
+            input: input.to_string(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let mut events = Vec::new();
+    loop {
+        match stream.message().await {
+            Ok(Some(event)) => events.push(event),
+            Ok(None) => return (events, None),
+            Err(status) => return (events, Some(status)),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Engine service tests
 // ---------------------------------------------------------------------------
 
+/// A turn whose narrator fails leaves its sourced command abandoned, so the
+/// player can send different input for the same turn.
+#[tokio::test]
+async fn failed_turn_accepts_new_input() {
+    use storyteller_core::database::ledger::{EventLedger, LedgerEntryKind};
+    use storyteller_core::traits::storykeeper::SessionId;
+
+    let server = start_seeded_test_server(Arc::new(FlakyLlm::default())).await;
+    let channel = Channel::from_shared(server.url.clone())
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = StorytellerEngineClient::new(channel);
+
+    let (_, failure) = submit(&mut client, &server.session_id, "I open the gate.").await;
+    let failure = failure.expect("narrator failure ends the stream");
+    assert!(failure.message().contains("Narrator failed"));
+
+    let (events, failure) = submit(&mut client, &server.session_id, "I climb over the gate.").await;
+    assert!(failure.is_none(), "retry should succeed: {failure:?}");
+    assert!(events.iter().any(|e| matches!(
+        &e.payload,
+        Some(engine_event::Payload::TurnComplete(TurnComplete {
+            turn: 1,
+            ..
+        }))
+    )));
+
+    let ledger_session = SessionId(uuid::Uuid::parse_str(&server.session_id).unwrap());
+    let entries = server
+        .session_store
+        .ledger
+        .entries_since(&ledger_session, None)
+        .await
+        .unwrap();
+    assert!(entries
+        .iter()
+        .any(|e| matches!(e.kind, LedgerEntryKind::CommandAbandoned { .. })));
+    assert!(server
+        .session_store
+        .ledger
+        .pending_command(&ledger_session)
+        .await
+        .unwrap()
+        .is_none());
+
+    let snapshot = server
+        .state_manager
+        .get_runtime_snapshot(&server.session_id)
+        .unwrap();
+    assert_eq!(snapshot.turn_count, 1);
+    assert_eq!(snapshot.journal.entries.len(), 1);
+}
+
+/// A narrator stream that breaks off mid-response fails the turn instead of
+/// committing the truncated prose.
+#[tokio::test]
+async fn truncated_narrator_stream_fails_the_turn() {
+    use storyteller_core::database::ledger::EventLedger;
+    use storyteller_core::traits::storykeeper::SessionId;
+
+    let server = start_seeded_test_server(Arc::new(TruncatingLlm)).await;
+    let channel = Channel::from_shared(server.url.clone())
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = StorytellerEngineClient::new(channel);
+
+    let (events, failure) = submit(&mut client, &server.session_id, "I open the gate.").await;
+    let failure = failure.expect("truncated stream ends the turn with an error");
+    assert!(failure.message().contains("stream interrupted"));
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e.payload, Some(engine_event::Payload::TurnComplete(_)))));
+
+    let ledger_session = SessionId(uuid::Uuid::parse_str(&server.session_id).unwrap());
+    assert!(server
+        .session_store
+        .ledger
+        .pending_command(&ledger_session)
+        .await
+        .unwrap()
+        .is_none());
+    let snapshot = server
+        .state_manager
+        .get_runtime_snapshot(&server.session_id)
+        .unwrap();
+    assert_eq!(snapshot.turn_count, 0);
+    assert!(snapshot.journal.entries.is_empty());
+}
+
+/// A turn played inside a narrative layer is framed by the layer in the
+/// preamble and journaled in the layer's own journal; exiting the layer
+/// returns the session to the main narrative.
+#[tokio::test]
+async fn turns_inside_a_narrative_layer_use_its_frame_and_journal() {
+    use storyteller_core::database::ledger::{EventLedger, LedgerEntryKind};
+    use storyteller_core::traits::storykeeper::SessionId;
+
+    let server = start_seeded_test_server(Arc::new(MockLlm)).await;
+    let channel = Channel::from_shared(server.url.clone())
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = StorytellerEngineClient::new(channel);
+
+    let outside = client
+        .exit_layer(ExitLayerRequest {
+            session_id: server.session_id.clone(),
+        })
+        .await
+        .expect_err("no layer to exit");
+    assert_eq!(outside.code(), tonic::Code::FailedPrecondition);
+
+    let entered = client
+        .enter_layer(EnterLayerRequest {
+            session_id: server.session_id.clone(),
+            kind: "memory".to_string(),
+            title: "The Winter the River Froze".to_string(),
+            teller: None,
+            voice_note: Some("Told in the past tense, half-remembered.".to_string()),
+            entities: vec![],
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(entered.depth, 1);
+    assert_eq!(entered.title.as_deref(), Some("The Winter the River Froze"));
+
+    let (events, failure) = submit(&mut client, &server.session_id, "I remember the ice.").await;
+    assert!(failure.is_none(), "turn should succeed: {failure:?}");
+    let preamble = events
+        .iter()
+        .find_map(|e| match &e.payload {
+            Some(engine_event::Payload::Context(context)) => Some(context.preamble_text.clone()),
+            _ => None,
+        })
+        .expect("context assembled");
+    assert!(preamble.contains("## Narrative Layer"));
+    assert!(preamble.contains("The Winter the River Froze"));
+
+    let snapshot = server
+        .state_manager
+        .get_runtime_snapshot(&server.session_id)
+        .unwrap();
+    assert!(snapshot.journal.entries.is_empty());
+    let layer_journal = &snapshot.layers.current().unwrap().journal;
+    assert_eq!(layer_journal.entries.len(), 1);
+    assert_eq!(layer_journal.entries[0].turn_number, 1);
+
+    let exited = client
+        .exit_layer(ExitLayerRequest {
+            session_id: server.session_id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(exited.depth, 0);
+    assert!(exited.layer_id.is_none());
+
+    let (events, failure) = submit(&mut client, &server.session_id, "I open the gate.").await;
+    assert!(failure.is_none(), "turn should succeed: {failure:?}");
+    assert!(!events.iter().any(|e| matches!(
+        &e.payload,
+        Some(engine_event::Payload::Context(context))
+            if context.preamble_text.contains("## Narrative Layer")
+    )));
+    let snapshot = server
+        .state_manager
+        .get_runtime_snapshot(&server.session_id)
+        .unwrap();
+    assert_eq!(snapshot.journal.entries.len(), 1);
+    assert_eq!(snapshot.layers.depth(), 0);
+
+    let ledger_session = SessionId(uuid::Uuid::parse_str(&server.session_id).unwrap());
+    let entries = server
+        .session_store
+        .ledger
+        .entries_since(&ledger_session, None)
+        .await
+        .unwrap();
+    assert!(entries
+        .iter()
+        .any(|e| matches!(e.kind, LedgerEntryKind::LayerEntered { .. })));
+    assert!(entries
+        .iter()
+        .any(|e| matches!(e.kind, LedgerEntryKind::LayerExited { .. })));
+}
+
+/// With a Storykeeper configured, each turn's command and commit are also
+/// written through it.
+#[tokio::test]
+async fn turns_are_written_through_the_storykeeper() {
+    use storyteller_core::traits::storykeeper::SessionId;
+    use storyteller_storykeeper::InMemoryStorykeeper;
+
+    let storykeeper = Arc::new(InMemoryStorykeeper::new());
+    let mirror = StorykeeperMirror::new(storykeeper.clone(), uuid::Uuid::now_v7());
+    let server = start_seeded_test_server_with(Arc::new(MockLlm), Some(mirror)).await;
+    let channel = Channel::from_shared(server.url.clone())
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = StorytellerEngineClient::new(channel);
+
+    let (_, failure) = submit(&mut client, &server.session_id, "I open the gate.").await;
+    assert!(failure.is_none(), "turn should succeed: {failure:?}");
+
+    let session = SessionId(uuid::Uuid::parse_str(&server.session_id).unwrap());
+    let sourced: Vec<String> = storykeeper
+        .sourced_commands(&session)
+        .into_iter()
+        .map(|c| c.text)
+        .collect();
+    assert_eq!(sourced, ["I open the gate."]);
+    let committed = storykeeper.committed_turns(&session);
+    assert_eq!(committed.len(), 1);
+    assert_eq!(committed[0].turn_number, 1);
+    assert_eq!(committed[0].player_input.text, "I open the gate.");
+}
+
+/// Compose a folklore reunion, optionally into an existing setting, and
+/// return its SceneComposed event.
+async fn compose_reunion(
+    client: &mut StorytellerEngineClient<Channel>,
+    setting_id: Option<String>,
+) -> SceneComposed {
+    let request = ComposeSceneRequest {
+        genre_id: "low_fantasy_folklore".to_string(),
+        profile_id: "quiet_reunion".to_string(),
+        cast: vec![
+            CastMember {
+                archetype_id: "wandering_artist".to_string(),
+                name: Some("Mira".to_string()),
+                role: "protagonist".to_string(),
+            },
+            CastMember {
+                archetype_id: "stoic_survivor".to_string(),
+                name: Some("Aldric".to_string()),
+                role: "antagonist".to_string(),
+            },
+        ],
+        seed: Some(7),
+        setting_id,
+        ..Default::default()
+    };
+    let mut stream = client.compose_scene(request).await.unwrap().into_inner();
+    let mut composed = None;
+    while let Some(event) = stream.message().await.unwrap() {
+        match event.payload {
+            Some(engine_event::Payload::SceneComposed(scene)) => composed = Some(scene),
+            Some(engine_event::Payload::Error(e)) if composed.is_none() => {
+                panic!("composition failed: {}", e.message)
+            }
+            _ => {}
+        }
+    }
+    composed.expect("SceneComposed event")
+}
+
+/// A scene composed into an earlier scene's setting sees what happened
+/// there.
+#[tokio::test]
+async fn compose_scene_revisits_setting_with_accumulated_state() {
+    use storyteller_core::types::scene::SceneId;
+    use storyteller_core::types::setting::{SettingChange, SettingId};
+
+    let Some(server) = start_engine_test_server(Arc::new(MockLlm)).await else {
+        eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+        return;
+    };
+    let channel = Channel::from_shared(server.url.clone())
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = StorytellerEngineClient::new(channel);
+
+    let first = compose_reunion(&mut client, None).await;
+    let setting_id = SettingId(uuid::Uuid::parse_str(&first.setting_id).expect("new setting"));
+    let composition: serde_json::Value = serde_json::from_str(&first.composition_json).unwrap();
+    let first_scene: SceneId =
+        serde_json::from_value(composition["scene"]["scene_id"].clone()).unwrap();
+    server
+        .session_store
+        .settings
+        .record_change(
+            setting_id,
+            first_scene,
+            SettingChange::Altered {
+                description: "The fire has burned down to embers.".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let second = compose_reunion(&mut client, Some(first.setting_id.clone())).await;
+    assert_eq!(second.setting_id, first.setting_id);
+    assert!(
+        second
+            .setting_description
+            .ends_with("The fire has burned down to embers."),
+        "{}",
+        second.setting_description
+    );
+    let state = server
+        .session_store
+        .settings
+        .state(setting_id)
+        .await
+        .unwrap()
+        .expect("visited setting");
+    assert_eq!(state.visits, 2);
+}
+
+/// Players may stream only the logs of sessions they own; the server-wide
+/// stream needs an admin credential.
+#[tokio::test]
+async fn stream_logs_is_scoped_to_owned_sessions() {
+    use storyteller_server::middleware::auth::PlayerIdentity;
+    use storyteller_server::proto::storyteller_engine_server::StorytellerEngine;
+
+    let server = start_seeded_test_server(Arc::new(MockLlm)).await;
+    let alice = PlayerIdentity::new("alice");
+    server
+        .session_store
+        .owners
+        .claim(&server.session_id, &alice)
+        .unwrap();
+    let filter = |session_id: Option<&str>| LogFilter {
+        session_id: session_id.map(str::to_string),
+        ..Default::default()
+    };
+
+    let code = |result: Result<_, tonic::Status>| result.err().map(|s| s.code());
+    let bob = PlayerIdentity::new("bob");
+    assert_eq!(
+        code(server.engine.stream_logs(bob.request(filter(None))).await),
+        Some(tonic::Code::PermissionDenied)
+    );
+    assert_eq!(
+        code(
+            server
+                .engine
+                .stream_logs(bob.request(filter(Some(&server.session_id))))
+                .await
+        ),
+        Some(tonic::Code::NotFound)
+    );
+    assert_eq!(
+        code(
+            server
+                .engine
+                .stream_logs(alice.request(filter(Some("../other"))))
+                .await
+        ),
+        Some(tonic::Code::InvalidArgument)
+    );
+    assert!(server
+        .engine
+        .stream_logs(alice.request(filter(Some(&server.session_id))))
+        .await
+        .is_ok());
+    assert!(server
+        .engine
+        .stream_logs(PlayerIdentity::admin("ops").request(filter(None)))
+        .await
+        .is_ok());
+}
+
 /// CheckHealth with mock LLM: narrator_llm is healthy; optional subsystems
 /// (structured_llm, intent_llm, predictor) are unavailable → overall degraded.
 #[tokio::test]
@@ -201,6 +791,26 @@ async fn check_health_reports_degraded_with_mock_llm() {
     }
 }
 
+/// GetEffectiveConfig refuses when the server was not started from a
+/// layered config (in-process test servers pass `config: None`).
+#[tokio::test]
+async fn get_effective_config_requires_layered_config() {
+    let Some(server) = start_engine_test_server(Arc::new(MockLlm)).await else {
+        eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+        return;
+    };
+
+    let channel = Channel::from_shared(server.url)
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = StorytellerEngineClient::new(channel);
+
+    let status = client.get_effective_config(()).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
 /// ComposeScene end-to-end: verify event stream order and non-empty narrator prose.
 ///
 /// Requires: STORYTELLER_DATA_PATH env var + a running Ollama server.
@@ -253,6 +863,7 @@ async fn compose_scene_integration() {
         setting_override: None,
         seed: None,
         player_character: None,
+        setting_id: None,
     };
 
     let response = client.compose_scene(request).await.unwrap();
@@ -489,6 +1100,7 @@ async fn narrator_complete_has_enriched_fields() {
         setting_override: None,
         seed: None,
         player_character: None,
+        setting_id: None,
     };
 
     let response = client.compose_scene(request).await.unwrap();
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Integration tests for the REST/SSE API.
+//!
+//! These tests mount [`storyteller_server::router`] on a real listener and
+//! drive it over HTTP. Requires STORYTELLER_DATA_PATH; the narrator is mocked.
+
+use storyteller_server::engine::{EngineProviders, EngineStateManager};
+use storyteller_server::middleware::auth::{AuthPolicy, StaticApiKeys};
+use storyteller_server::persistence::SessionStore;
+use storyteller_server::proto::{CastMember, ComposeSceneRequest, SceneState, SessionList};
+use storyteller_server::state::AppState;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+
+#[derive(Debug)]
+struct MockLlm;
+
+#[async_trait::async_trait]
+impl storyteller_core::traits::llm::LlmProvider for MockLlm {
+    async fn complete(
+        &self,
+        _req: storyteller_core::traits::llm::CompletionRequest,
+    ) -> storyteller_core::errors::StorytellerResult<
+        storyteller_core::traits::llm::CompletionResponse,
+    > {
+        Ok(storyteller_core::traits::llm::CompletionResponse {
+            content: "[mock narrator response]".to_string(),
+            tokens_used: 10,
+        })
+    }
+}
+
+struct RestTestServer {
+    url: String,
+    _temp_dir: TempDir,
+}
+
+/// Starts the REST router with a mock narrator and auth disabled.
+/// Returns `None` if STORYTELLER_DATA_PATH is not set.
+async fn start_rest_test_server() -> Option<RestTestServer> {
+    start_rest_test_server_with_auth(AuthPolicy::disabled()).await
+}
+
+async fn start_rest_test_server_with_auth(auth: AuthPolicy) -> Option<RestTestServer> {
+    let data_path = std::env::var("STORYTELLER_DATA_PATH").ok()?;
+    let composer = Arc::new(
+        storyteller_composer::SceneComposer::load(std::path::Path::new(&data_path))
+            .expect("load descriptors"),
+    );
+
+    let temp_dir = TempDir::new().unwrap();
+    let session_store = Arc::new(SessionStore::new(temp_dir.path()).expect("create session store"));
+    let providers = Arc::new(EngineProviders {
+        narrator_llm: Arc::new(MockLlm),
+        structured_llm: None,
+        intent_llm: None,
+        predictor: None,
+        grammars: Arc::new(storyteller_core::grammars::GrammarRegistry::builtin()),
+        game_design: Arc::new(storyteller_core::game_design::GameDesignRegistry::builtin()),
+        narrator_model: "test-model".to_string(),
+        decomposition_model: String::new(),
+        config: None,
+    });
+    let state = AppState::new(
+        composer,
+        Arc::new(EngineStateManager::new()),
+        session_store,
+        providers,
+    )
+    .with_auth(auth);
+
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        axum::serve(listener, storyteller_server::router(state))
+            .await
+            .unwrap();
+    });
+
+    Some(RestTestServer {
+        url,
+        _temp_dir: temp_dir,
+    })
+}
+
+#[tokio::test]
+async fn unknown_session_scene_state_is_not_found() {
+    let Some(server) = start_rest_test_server().await else {
+        eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+        return;
+    };
+
+    let response = reqwest::get(format!("{}/api/v1/sessions/missing/scene", server.url))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(body["error"].as_str().unwrap().contains("missing"));
+}
+
+#[tokio::test]
+async fn list_sessions_is_empty_for_fresh_store() {
+    let Some(server) = start_rest_test_server().await else {
+        eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+        return;
+    };
+
+    let sessions: SessionList = reqwest::get(format!("{}/api/v1/sessions", server.url))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(sessions.sessions.is_empty());
+}
+
+/// Composing over REST streams SSE events, and the composed session is
+/// then visible through the query endpoints.
+#[tokio::test(flavor = "multi_thread")]
+async fn compose_scene_streams_sse_and_registers_session() {
+    let Some(server) = start_rest_test_server().await else {
+        eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+        return;
+    };
+
+    let request = ComposeSceneRequest {
+        genre_id: "low_fantasy_folklore".to_string(),
+        profile_id: "quiet_reunion".to_string(),
+        cast: vec![
+            CastMember {
+                archetype_id: "wandering_artist".to_string(),
+                name: Some("Mira".to_string()),
+                role: "protagonist".to_string(),
+            },
+            CastMember {
+                archetype_id: "stoic_survivor".to_string(),
+                name: Some("Aldric".to_string()),
+                role: "antagonist".to_string(),
+            },
+        ],
+        ..Default::default()
+    };
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{}/api/v1/sessions", server.url))
+        .json(&request)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    assert!(response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/event-stream")));
+
+    let body = response.text().await.unwrap();
+    assert!(body.contains("event: scene_composed"));
+    assert!(body.contains("event: turn_complete"));
+
+    let session_id = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str::<serde_json::Value>(data).ok())
+        .find_map(|event| event["session_id"].as_str().map(str::to_string))
+        .expect("events carry a session id");
+
+    let scene: SceneState = client
+        .get(format!("{}/api/v1/sessions/{session_id}/scene", server.url))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(scene.session_id, session_id);
+    assert!(!scene.characters.is_empty());
+}
+
+#[tokio::test]
+async fn api_key_auth_guards_session_routes_but_not_health() {
+    let keys = StaticApiKeys::new().with_key("alice-key", "alice");
+    let Some(server) = start_rest_test_server_with_auth(AuthPolicy::disabled().with(keys)).await
+    else {
+        eprintln!("STORYTELLER_DATA_PATH not set — skipping");
+        return;
+    };
+    let client = reqwest::Client::new();
+
+    let health = client
+        .get(format!("{}/health", server.url))
+        .send()
+        .await
+        .unwrap();
+    assert!(health.status().is_success());
+
+    let anonymous = client
+        .get(format!("{}/api/v1/sessions", server.url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(anonymous.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let wrong_key = client
+        .get(format!("{}/api/v1/sessions", server.url))
+        .header("x-api-key", "mallory-key")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(wrong_key.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let alice = client
+        .get(format!("{}/api/v1/sessions", server.url))
+        .header("x-api-key", "alice-key")
+        .send()
+        .await
+        .unwrap();
+    assert!(alice.status().is_success());
+}
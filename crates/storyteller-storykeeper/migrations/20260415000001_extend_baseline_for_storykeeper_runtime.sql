@@ -0,0 +1,107 @@
+-- =============================================================================
+-- Storykeeper Runtime: baseline extensions + runtime-only tables
+-- =============================================================================
+-- Durable state behind the Storykeeper trait family (PostgresStorykeeper).
+--
+-- Sessions, turns, and committed events live in the baseline `sessions`,
+-- `turns`, and `event_ledger` tables. This migration relaxes the columns
+-- composed play cannot fill yet: composed scenes are registered in `scenes`
+-- but write no `scene_instances` or `players` rows, so those references
+-- become nullable. Stories are registered by the Storykeeper on first write.
+--
+-- State with no baseline home — sourced commands, per-session entity
+-- weights, and checkpoint snapshots — lives in the `storykeeper` schema.
+-- Domain payloads are stored as JSONB in their serde representation; the
+-- Rust types are the schema of record for their contents.
+-- =============================================================================
+
+-- ---------------------------------------------------------------------------
+-- sessions
+-- ---------------------------------------------------------------------------
+-- Upserted on scene entry and on every write so the latest scene position
+-- and turn number survive a server restart. Runtime sessions are played by
+-- a player entity; the account-level `players` row is optional until
+-- accounts exist.
+-- ---------------------------------------------------------------------------
+ALTER TABLE sessions
+    ALTER COLUMN player_id DROP NOT NULL,
+    ADD COLUMN player_entity_id UUID,
+    ADD COLUMN current_scene_id UUID,
+    ADD COLUMN turn_number      INT NOT NULL DEFAULT 0;
+
+-- ---------------------------------------------------------------------------
+-- turns
+-- ---------------------------------------------------------------------------
+-- Committed turns are written with provisional_status = 'committed'. The
+-- existing (session_id, turn_number) unique index makes commits idempotent.
+-- ---------------------------------------------------------------------------
+ALTER TABLE turns
+    ALTER COLUMN scene_instance_id DROP NOT NULL;
+
+-- ---------------------------------------------------------------------------
+-- event_ledger
+-- ---------------------------------------------------------------------------
+-- `payload` holds the full `NarrativeEvent`; the typed columns are derived
+-- from its atom for the baseline indexes. `event_kind` gains the grammar's
+-- remaining kinds so every atom has an exact label.
+-- ---------------------------------------------------------------------------
+ALTER TABLE event_ledger
+    ALTER COLUMN scene_id DROP NOT NULL;
+
+ALTER TYPE event_kind ADD VALUE IF NOT EXISTS 'emotional_expression';
+ALTER TYPE event_kind ADD VALUE IF NOT EXISTS 'speech_act';
+ALTER TYPE event_kind ADD VALUE IF NOT EXISTS 'environmental_change';
+ALTER TYPE event_kind ADD VALUE IF NOT EXISTS 'scene_lifecycle';
+ALTER TYPE event_kind ADD VALUE IF NOT EXISTS 'entity_lifecycle';
+
+CREATE INDEX idx_event_ledger_session_time ON event_ledger(session_id, committed_at);
+
+CREATE SCHEMA IF NOT EXISTS storykeeper;
+
+-- ---------------------------------------------------------------------------
+-- sourced_commands
+-- ---------------------------------------------------------------------------
+-- Command sourcing: player input persisted before the turn pipeline runs.
+-- ---------------------------------------------------------------------------
+CREATE TABLE storykeeper.sourced_commands (
+    id           UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    session_id   UUID        NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    turn_number  INT         NOT NULL,
+    input        JSONB       NOT NULL,
+    sourced_at   TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_storykeeper_sourced_commands_session
+    ON storykeeper.sourced_commands(session_id, turn_number);
+
+-- ---------------------------------------------------------------------------
+-- entity_weights
+-- ---------------------------------------------------------------------------
+-- Accumulated relational weight per entity per session. The story-wide
+-- `entities.relational_weight` is not session-scoped, so it cannot hold this.
+-- ---------------------------------------------------------------------------
+CREATE TABLE storykeeper.entity_weights (
+    session_id  UUID        NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    entity_id   UUID        NOT NULL,
+    weight      REAL        NOT NULL DEFAULT 0,
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (session_id, entity_id)
+);
+
+-- ---------------------------------------------------------------------------
+-- checkpoints
+-- ---------------------------------------------------------------------------
+-- Session snapshots for crash recovery. `snapshot` is a JSONB document whose
+-- shape is owned by the Storykeeper implementation.
+-- ---------------------------------------------------------------------------
+CREATE TABLE storykeeper.checkpoints (
+    id           UUID        PRIMARY KEY,
+    session_id   UUID        NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    scene_id     UUID,
+    turn_number  INT         NOT NULL,
+    snapshot     JSONB       NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_storykeeper_checkpoints_session
+    ON storykeeper.checkpoints(session_id, created_at DESC);
@@ -0,0 +1,66 @@
+-- =============================================================================
+-- Relational Web: AGE graph + edge details
+-- =============================================================================
+-- Backing storage for `AgeRelationalWeb` (storyteller-core::graph::relational_web).
+-- See: docs/technical/age-persistence/relational-web-age.md
+--
+-- `:RelEntity` vertices and `:RELATES_TO` edges live in the single
+-- `storyteller` AGE graph. Edge properties carry only the substrate central
+-- tendencies, for Cypher WHERE filters; the full `DirectedEdge` record lives
+-- in `storykeeper.relational_edge_details`, joined by (story, source, target).
+--
+-- NOTE: the AGE portion is skipped when the extension is not installed, so
+-- the migration set still applies to a plain PostgreSQL. Callers check
+-- `graph::age::age_available` and fall back to `InMemoryRelationalWeb`.
+-- =============================================================================
+
+-- ---------------------------------------------------------------------------
+-- relational_edge_details
+-- ---------------------------------------------------------------------------
+-- One row per directed edge. Like the other runtime tables, keyed by UUIDs
+-- with no foreign keys into the authored-story schema.
+-- ---------------------------------------------------------------------------
+CREATE TABLE storykeeper.relational_edge_details (
+    story_id    UUID        NOT NULL,
+    source_id   UUID        NOT NULL,
+    target_id   UUID        NOT NULL,
+    edge        JSONB       NOT NULL,
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (story_id, source_id, target_id)
+);
+
+CREATE INDEX idx_relational_edge_details_target
+    ON storykeeper.relational_edge_details(story_id, target_id);
+
+-- ---------------------------------------------------------------------------
+-- AGE graph and labels
+-- ---------------------------------------------------------------------------
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_available_extensions WHERE name = 'age') THEN
+        RAISE NOTICE 'Apache AGE not available; relational web uses the in-process fallback';
+        RETURN;
+    END IF;
+
+    CREATE EXTENSION IF NOT EXISTS age;
+    LOAD 'age';
+
+    IF NOT EXISTS (SELECT 1 FROM ag_catalog.ag_graph WHERE name = 'storyteller') THEN
+        PERFORM ag_catalog.create_graph('storyteller');
+    END IF;
+    IF NOT EXISTS (
+        SELECT 1 FROM ag_catalog.ag_label l
+        JOIN ag_catalog.ag_graph g ON g.graphid = l.graph
+        WHERE g.name = 'storyteller' AND l.name = 'RelEntity'
+    ) THEN
+        PERFORM ag_catalog.create_vlabel('storyteller', 'RelEntity');
+    END IF;
+    IF NOT EXISTS (
+        SELECT 1 FROM ag_catalog.ag_label l
+        JOIN ag_catalog.ag_graph g ON g.graphid = l.graph
+        WHERE g.name = 'storyteller' AND l.name = 'RELATES_TO'
+    ) THEN
+        PERFORM ag_catalog.create_elabel('storyteller', 'RELATES_TO');
+    END IF;
+END
+$$;
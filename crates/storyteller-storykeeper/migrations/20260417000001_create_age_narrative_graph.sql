@@ -0,0 +1,47 @@
+-- =============================================================================
+-- Narrative Graph: AGE labels over the baseline scene tables
+-- =============================================================================
+-- Graph projection for `AgeNarrativeGraph` (storyteller-core::graph::narrative).
+-- See: docs/technical/age-persistence/narrative-graph-age.md
+--
+-- The baseline `scenes` and `scene_transitions` tables stay authoritative.
+-- `:Scene` vertices and `:TRANSITIONS_TO` edges in the `storyteller` AGE
+-- graph project them for traversal: vertices carry `scene_type` and
+-- `authored_base`; edges carry `departure` and `transition_weight`.
+--
+-- NOTE: as with the relational web, the AGE portion is skipped when the
+-- extension is not installed. Callers fall back to `InMemoryNarrativeGraph`.
+-- =============================================================================
+
+-- ---------------------------------------------------------------------------
+-- AGE labels
+-- ---------------------------------------------------------------------------
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_available_extensions WHERE name = 'age') THEN
+        RAISE NOTICE 'Apache AGE not available; narrative graph uses the in-process fallback';
+        RETURN;
+    END IF;
+
+    CREATE EXTENSION IF NOT EXISTS age;
+    LOAD 'age';
+
+    IF NOT EXISTS (SELECT 1 FROM ag_catalog.ag_graph WHERE name = 'storyteller') THEN
+        PERFORM ag_catalog.create_graph('storyteller');
+    END IF;
+    IF NOT EXISTS (
+        SELECT 1 FROM ag_catalog.ag_label l
+        JOIN ag_catalog.ag_graph g ON g.graphid = l.graph
+        WHERE g.name = 'storyteller' AND l.name = 'Scene'
+    ) THEN
+        PERFORM ag_catalog.create_vlabel('storyteller', 'Scene');
+    END IF;
+    IF NOT EXISTS (
+        SELECT 1 FROM ag_catalog.ag_label l
+        JOIN ag_catalog.ag_graph g ON g.graphid = l.graph
+        WHERE g.name = 'storyteller' AND l.name = 'TRANSITIONS_TO'
+    ) THEN
+        PERFORM ag_catalog.create_elabel('storyteller', 'TRANSITIONS_TO');
+    END IF;
+END
+$$;
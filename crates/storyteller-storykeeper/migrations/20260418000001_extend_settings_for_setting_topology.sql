@@ -0,0 +1,57 @@
+-- =============================================================================
+-- Setting Topology: AGE graph over the baseline settings table
+-- =============================================================================
+-- Backing storage for `AgeSettingTopology` (storyteller-core::graph::settings).
+-- See: docs/technical/scene-model.md (scene-setting distinction)
+--
+-- Settings are rows in the baseline `settings` table. The authored
+-- `SceneSetting` is kept in `spatial_data.base` (its description mirrors the
+-- `description` column) and outgoing `SettingConnection`s in
+-- `spatial_data.exits`. `:Setting` vertices and `:CONNECTS_TO` edges in the
+-- `storyteller` AGE graph carry the topology for traversal.
+--
+-- NOTE: the AGE portion is skipped when the extension is not installed.
+-- Callers fall back to `InMemorySettingTopology`.
+-- =============================================================================
+
+-- ---------------------------------------------------------------------------
+-- settings
+-- ---------------------------------------------------------------------------
+-- `state` holds what play has done to a setting (`SettingState`), which
+-- outlives the scenes that happen there. NULL until first visited or changed.
+-- ---------------------------------------------------------------------------
+ALTER TABLE settings
+    ADD COLUMN state JSONB;
+
+-- ---------------------------------------------------------------------------
+-- AGE labels
+-- ---------------------------------------------------------------------------
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_available_extensions WHERE name = 'age') THEN
+        RAISE NOTICE 'Apache AGE not available; setting topology uses the in-process fallback';
+        RETURN;
+    END IF;
+
+    CREATE EXTENSION IF NOT EXISTS age;
+    LOAD 'age';
+
+    IF NOT EXISTS (SELECT 1 FROM ag_catalog.ag_graph WHERE name = 'storyteller') THEN
+        PERFORM ag_catalog.create_graph('storyteller');
+    END IF;
+    IF NOT EXISTS (
+        SELECT 1 FROM ag_catalog.ag_label l
+        JOIN ag_catalog.ag_graph g ON g.graphid = l.graph
+        WHERE g.name = 'storyteller' AND l.name = 'Setting'
+    ) THEN
+        PERFORM ag_catalog.create_vlabel('storyteller', 'Setting');
+    END IF;
+    IF NOT EXISTS (
+        SELECT 1 FROM ag_catalog.ag_label l
+        JOIN ag_catalog.ag_graph g ON g.graphid = l.graph
+        WHERE g.name = 'storyteller' AND l.name = 'CONNECTS_TO'
+    ) THEN
+        PERFORM ag_catalog.create_elabel('storyteller', 'CONNECTS_TO');
+    END IF;
+END
+$$;
@@ -0,0 +1,10 @@
+-- =============================================================================
+-- Session narrative layers
+-- =============================================================================
+-- The session's `NarrativeStack` (nested memories, dreams, embedded tales)
+-- in its serde representation. Written on every session upsert, so the
+-- stack survives a server restart between checkpoints.
+-- =============================================================================
+
+ALTER TABLE sessions
+    ADD COLUMN narrative_layers JSONB NOT NULL DEFAULT '{}';
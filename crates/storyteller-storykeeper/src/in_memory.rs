@@ -16,21 +16,29 @@ use std::sync::Mutex;
 
 use chrono::Utc;
 
-use storyteller_core::errors::StorytellerResult;
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
 use storyteller_core::traits::storykeeper::{
     BoundaryCheck, CheckpointId, CommandSourced, CommitResult, CompletedTurn, EntityRelevance,
-    EntityWeightChange, GateProximity, SceneExitResult, SceneLoadResult, SessionContext,
-    StorykeeperCommit, StorykeeperLifecycle, StorykeeperQuery,
+    EntityWeightChange, GateProximity, LayerEntryResult, LayerExitResult, SceneExitResult,
+    SceneLoadResult, SessionContext, SessionId, StorykeeperCommit, StorykeeperLifecycle,
+    StorykeeperQuery,
 };
+use storyteller_core::types::character::{CharacterSheet, SceneData};
 use storyteller_core::types::entity::{EntityId, EntityRef};
 use storyteller_core::types::event::NarrativeEvent;
+use storyteller_core::types::event_grammar::EventPayload;
+use storyteller_core::types::knowledge::KnowledgeModel;
 use storyteller_core::types::message::PlayerInput;
+use storyteller_core::types::narrative_layer::NarrativeLayer;
 use storyteller_core::types::narrator_context::{
-    NarratorContextInput, PersistentPreamble, RetrievedContext, SceneJournal,
+    NarratorContextInput, RetrievedContext, SceneJournal,
 };
 use storyteller_core::types::resolver::ResolverOutput;
 use storyteller_core::types::scene::SceneId;
 
+use crate::preamble::{current_scene, scene_preamble, unregistered};
+use crate::weights::turn_weight_increments;
+
 /// In-memory state for a single session.
 #[derive(Debug, Default)]
 struct SessionState {
@@ -42,6 +50,11 @@ struct SessionState {
     entity_weights: HashMap<EntityId, f32>,
     /// Sourced commands awaiting processing.
     sourced_commands: Vec<PlayerInput>,
+    /// Who knows what — seeded from the cast at scene entry, extended by
+    /// committed information transfers.
+    knowledge: KnowledgeModel,
+    /// Cast sheets entered with the current scene.
+    cast: Vec<CharacterSheet>,
 }
 
 /// In-memory Storykeeper — all state lives in process.
@@ -52,6 +65,8 @@ struct SessionState {
 pub struct InMemoryStorykeeper {
     /// Per-session state, keyed by session UUID.
     sessions: Mutex<HashMap<uuid::Uuid, SessionState>>,
+    /// Registered scene data, shared by every session.
+    scenes: Mutex<HashMap<SceneId, SceneData>>,
 }
 
 impl InMemoryStorykeeper {
@@ -59,9 +74,42 @@ impl InMemoryStorykeeper {
     pub fn new() -> Self {
         Self {
             sessions: Mutex::new(HashMap::new()),
+            scenes: Mutex::new(HashMap::new()),
         }
     }
 
+    /// A snapshot of a session's knowledge model, for context assembly.
+    ///
+    /// Empty for a session that has not entered a scene.
+    pub fn knowledge(&self, session_id: &SessionId) -> KnowledgeModel {
+        self.sessions
+            .lock()
+            .expect("session lock poisoned")
+            .get(&session_id.0)
+            .map(|state| state.knowledge.clone())
+            .unwrap_or_default()
+    }
+
+    /// All sourced commands for a session, in the order they were sourced.
+    pub fn sourced_commands(&self, session_id: &SessionId) -> Vec<PlayerInput> {
+        self.sessions
+            .lock()
+            .expect("session lock poisoned")
+            .get(&session_id.0)
+            .map(|state| state.sourced_commands.clone())
+            .unwrap_or_default()
+    }
+
+    /// All committed turns for a session, in commit order.
+    pub fn committed_turns(&self, session_id: &SessionId) -> Vec<CompletedTurn> {
+        self.sessions
+            .lock()
+            .expect("session lock poisoned")
+            .get(&session_id.0)
+            .map(|state| state.turns.clone())
+            .unwrap_or_default()
+    }
+
     fn get_or_create_session(
         &self,
         session_id: uuid::Uuid,
@@ -87,20 +135,20 @@ impl StorykeeperQuery for InMemoryStorykeeper {
         resolver_output: &ResolverOutput,
         player_input: &str,
     ) -> StorytellerResult<NarratorContextInput> {
-        // Minimal implementation — assembles context from in-memory state.
-        // The real implementation will query PostgreSQL and AGE.
-        let preamble = PersistentPreamble {
-            narrator_identity: String::new(),
-            anti_patterns: Vec::new(),
-            setting_description: String::new(),
-            cast_descriptions: Vec::new(),
-            boundaries: Vec::new(),
-            scene_direction: None,
-            character_drives: Vec::new(),
-            player_context: None,
-        };
+        let scene_id = current_scene(session)?;
+        let scene = self
+            .scenes
+            .lock()
+            .expect("scene lock poisoned")
+            .get(&scene_id)
+            .cloned()
+            .ok_or_else(|| unregistered(scene_id))?;
 
         let sessions = self.sessions.lock().expect("session lock poisoned");
+        let cast = sessions
+            .get(&session.session_id.0)
+            .map_or(&[][..], |state| &state.cast[..]);
+        let preamble = scene_preamble(&scene, cast, session);
         let retrieved = if let Some(state) = sessions.get(&session.session_id.0) {
             // Pull recent events as retrieved context
             state
@@ -122,7 +170,7 @@ impl StorykeeperQuery for InMemoryStorykeeper {
 
         Ok(NarratorContextInput {
             preamble,
-            journal: journal.clone(),
+            journal: session.layers.journal(journal).clone(),
             retrieved,
             resolver_output: resolver_output.clone(),
             player_input_summary: player_input.to_string(),
@@ -136,16 +184,24 @@ impl StorykeeperQuery for InMemoryStorykeeper {
         session: &SessionContext,
     ) -> StorytellerResult<EntityRelevance> {
         let entity_id = entity.entity_id().unwrap_or_default();
+        let Some(resolved) = session.layers.resolve(entity_id) else {
+            return Ok(EntityRelevance {
+                entity_id,
+                relevance: 0.0,
+                reason: "not present in the current narrative layer".to_string(),
+                context: Vec::new(),
+            });
+        };
 
         let sessions = self.sessions.lock().expect("session lock poisoned");
         let weight = sessions
             .get(&session.session_id.0)
-            .and_then(|s| s.entity_weights.get(&entity_id))
+            .and_then(|s| s.entity_weights.get(&resolved))
             .copied()
             .unwrap_or(0.0);
 
         Ok(EntityRelevance {
-            entity_id,
+            entity_id: resolved,
             relevance: weight.min(1.0),
             reason: "weight-based relevance".to_string(),
             context: Vec::new(),
@@ -160,9 +216,19 @@ impl StorykeeperQuery for InMemoryStorykeeper {
         Ok(Vec::new())
     }
 
-    fn check_information_boundary(&self, _entity_id: &EntityId, _fact: &str) -> BoundaryCheck {
-        // In-memory implementation permits all — no information boundaries enforced.
-        BoundaryCheck::Permitted
+    async fn check_information_boundary(
+        &self,
+        session: &SessionContext,
+        entity_id: &EntityId,
+        fact: &str,
+    ) -> StorytellerResult<BoundaryCheck> {
+        // Until a character is seeded, every fact is permitted to it.
+        let sessions = self.sessions.lock().expect("session lock poisoned");
+        Ok(sessions
+            .get(&session.session_id.0)
+            .map_or(BoundaryCheck::Permitted, |state| {
+                state.knowledge.check(entity_id, fact)
+            }))
     }
 }
 
@@ -198,25 +264,27 @@ impl StorykeeperCommit for InMemoryStorykeeper {
         // Record the turn
         state.turns.push(completed.clone());
 
-        // Record events and accumulate entity weights
+        // Record events, learning from information transfers
         let mut weight_changes = Vec::new();
         for event in &completed.events {
+            if let EventPayload::Atom(ref atom) = event.payload {
+                state.knowledge.observe(atom);
+            }
             state.events.push(event.clone());
         }
 
         // Simple weight accumulation: each event participation adds 0.1 weight
-        for action in &completed.resolver_output.sequenced_actions {
-            let entity_id = action.character_id;
+        for increment in turn_weight_increments(completed) {
+            let entity_id = increment.entity_id;
             let previous = *state.entity_weights.get(&entity_id).unwrap_or(&0.0);
-            let increment = 0.1 * action.outcomes.len() as f32;
-            let new_weight = previous + increment;
+            let new_weight = previous + increment.increment;
             state.entity_weights.insert(entity_id, new_weight);
 
             weight_changes.push(EntityWeightChange {
                 entity_id,
                 previous_weight: previous,
                 new_weight,
-                reason: format!("{} action outcomes", action.outcomes.len()),
+                reason: increment.reason,
             });
         }
 
@@ -230,18 +298,44 @@ impl StorykeeperCommit for InMemoryStorykeeper {
 
 #[async_trait::async_trait]
 impl StorykeeperLifecycle for InMemoryStorykeeper {
+    async fn register_scene(
+        &self,
+        scene: &SceneData,
+        _session: &SessionContext,
+    ) -> StorytellerResult<()> {
+        self.scenes
+            .lock()
+            .expect("scene lock poisoned")
+            .insert(scene.scene_id, scene.clone());
+        Ok(())
+    }
+
     async fn enter_scene(
         &self,
         scene_id: &SceneId,
+        cast: &[CharacterSheet],
         session: &SessionContext,
     ) -> StorytellerResult<SceneLoadResult> {
-        // Ensure session state exists
-        let _sessions = self.get_or_create_session(session.session_id.0);
+        let mut sessions = self.get_or_create_session(session.session_id.0);
+        let state = sessions
+            .get_mut(&session.session_id.0)
+            .expect("just created");
+        for sheet in cast {
+            state.knowledge.seed(sheet);
+        }
+        state.cast = cast.to_vec();
+        let title = self
+            .scenes
+            .lock()
+            .expect("scene lock poisoned")
+            .get(scene_id)
+            .map(|scene| scene.title.clone())
+            .unwrap_or_default();
 
         Ok(SceneLoadResult {
             scene_id: *scene_id,
-            title: String::new(),
-            cast: Vec::new(),
+            title,
+            cast: cast.to_vec(),
             retrieved_context: Vec::new(),
             journal: SceneJournal::new(*scene_id, 1200),
         })
@@ -254,6 +348,27 @@ impl StorykeeperLifecycle for InMemoryStorykeeper {
         })
     }
 
+    async fn enter_layer(
+        &self,
+        layer: NarrativeLayer,
+        session: &mut SessionContext,
+    ) -> StorytellerResult<LayerEntryResult> {
+        let layer_id = layer.id;
+        let depth = session.layers.push(layer);
+        Ok(LayerEntryResult { layer_id, depth })
+    }
+
+    async fn exit_layer(&self, session: &mut SessionContext) -> StorytellerResult<LayerExitResult> {
+        let layer = session
+            .layers
+            .pop()
+            .ok_or_else(|| StorytellerError::Scene("no narrative layer to exit".to_string()))?;
+        Ok(LayerExitResult {
+            layer,
+            depth: session.layers.depth(),
+        })
+    }
+
     async fn write_checkpoint(&self, _session: &SessionContext) -> StorytellerResult<CheckpointId> {
         // In-memory: no-op checkpoint, just return an ID
         Ok(CheckpointId::new())
@@ -264,7 +379,7 @@ impl StorykeeperLifecycle for InMemoryStorykeeper {
         _checkpoint_id: &CheckpointId,
     ) -> StorytellerResult<SceneLoadResult> {
         // In-memory: cannot resume from checkpoint (state is ephemeral)
-        Err(storyteller_core::StorytellerError::Config(
+        Err(StorytellerError::Config(
             "InMemoryStorykeeper does not support checkpoint resume".to_string(),
         ))
     }
@@ -274,7 +389,7 @@ impl StorykeeperLifecycle for InMemoryStorykeeper {
 mod tests {
     use super::*;
     use storyteller_core::types::event::{EventId, EventPriority};
-    use storyteller_core::types::event_grammar::EventPayload;
+    use storyteller_core::types::narrative_layer::NarrativeStack;
     use storyteller_core::types::prediction::{ActionPrediction, ActionType};
     use storyteller_core::types::resolver::{
         ActionOutcome, ResolvedCharacterAction, SuccessDegree,
@@ -287,6 +402,50 @@ mod tests {
             player_entity_id: EntityId::new(),
             current_scene_id: Some(SceneId::new()),
             turn_number: 1,
+            layers: NarrativeStack::default(),
+        }
+    }
+
+    fn test_character_sheet() -> CharacterSheet {
+        use storyteller_core::types::character::{
+            CharacterTensor, EmotionalState, SelfEdge, SelfEdgeTrust, SelfKnowledge,
+        };
+
+        CharacterSheet {
+            entity_id: EntityId::new(),
+            name: "Miller".to_string(),
+            voice: String::new(),
+            backstory: String::new(),
+            tensor: CharacterTensor::new(),
+            grammar_id: "plutchik_western".to_string(),
+            emotional_state: EmotionalState {
+                grammar_id: "plutchik_western".to_string(),
+                primaries: vec![],
+                mood_vector_notes: vec![],
+            },
+            self_edge: SelfEdge {
+                trust: SelfEdgeTrust {
+                    competence: 0.5,
+                    intentions: 0.5,
+                    reliability: 0.5,
+                },
+                affection: 0.5,
+                debt: 0.0,
+                history_pattern: String::new(),
+                history_weight: 0.0,
+                projection_content: String::new(),
+                projection_accuracy: 1.0,
+                self_knowledge: SelfKnowledge {
+                    knows: vec![],
+                    does_not_know: vec![],
+                },
+            },
+            triggers: vec![],
+            performance_notes: String::new(),
+            knows: vec![],
+            does_not_know: vec![],
+            capabilities: Default::default(),
+            relationships: Vec::new(),
         }
     }
 
@@ -386,18 +545,125 @@ mod tests {
         let session = test_session();
         let scene_id = SceneId::new();
 
-        let result = sk.enter_scene(&scene_id, &session).await.unwrap();
+        let result = sk.enter_scene(&scene_id, &[], &session).await.unwrap();
         assert_eq!(result.scene_id, scene_id);
     }
 
     #[tokio::test]
-    async fn information_boundary_permits_all() {
+    async fn information_boundary_permits_unseeded_entities() {
         let sk = InMemoryStorykeeper::new();
         let entity_id = EntityId::new();
         assert_eq!(
-            sk.check_information_boundary(&entity_id, "secret fact"),
+            sk.check_information_boundary(&test_session(), &entity_id, "secret fact")
+                .await
+                .unwrap(),
+            BoundaryCheck::Permitted,
+        );
+    }
+
+    #[tokio::test]
+    async fn information_boundaries_are_per_session() {
+        let sk = InMemoryStorykeeper::new();
+        let mut miller = test_character_sheet();
+        miller.does_not_know = vec!["That the mill wheel was sabotaged".to_string()];
+        let seeded = test_session();
+        let other = test_session();
+        let scene_id = seeded.current_scene_id.unwrap();
+        let loaded = sk
+            .enter_scene(&scene_id, std::slice::from_ref(&miller), &seeded)
+            .await
+            .unwrap();
+        assert_eq!(loaded.cast.len(), 1);
+        sk.enter_scene(&scene_id, &[], &other).await.unwrap();
+
+        let fact = "someone sabotaged the mill wheel";
+        assert_eq!(
+            sk.check_information_boundary(&seeded, &miller.entity_id, fact)
+                .await
+                .unwrap(),
+            BoundaryCheck::Withheld,
+        );
+        assert_eq!(
+            sk.check_information_boundary(&other, &miller.entity_id, fact)
+                .await
+                .unwrap(),
             BoundaryCheck::Permitted,
         );
+        assert!(sk
+            .knowledge(&other.session_id)
+            .learned(&miller.entity_id)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn information_boundary_lifts_after_a_committed_transfer() {
+        use storyteller_core::types::event_grammar::{
+            ConfidenceEvidence, EventAtom, EventConfidence, EventKind, EventSource, Participant,
+            ParticipantRole,
+        };
+
+        let sk = InMemoryStorykeeper::new();
+        let session = test_session();
+        let mut miller = test_character_sheet();
+        miller.does_not_know = vec!["That the mill wheel was sabotaged".to_string()];
+        sk.enter_scene(
+            &session.current_scene_id.unwrap(),
+            std::slice::from_ref(&miller),
+            &session,
+        )
+        .await
+        .unwrap();
+
+        let fact = "someone sabotaged the mill wheel";
+        assert_eq!(
+            sk.check_information_boundary(&session, &miller.entity_id, fact)
+                .await
+                .unwrap(),
+            BoundaryCheck::Withheld,
+        );
+
+        let mut turn = test_completed_turn();
+        turn.events = vec![NarrativeEvent {
+            id: EventId::new(),
+            timestamp: Utc::now(),
+            priority: EventPriority::Normal,
+            payload: EventPayload::Atom(EventAtom {
+                id: EventId::new(),
+                timestamp: Utc::now(),
+                kind: EventKind::InformationTransfer {
+                    content_summary: "the mill wheel was sabotaged".to_string(),
+                },
+                participants: vec![Participant {
+                    entity: EntityRef::Resolved(miller.entity_id),
+                    role: ParticipantRole::Target,
+                }],
+                relational_implications: vec![],
+                source: EventSource::System {
+                    component: "test".to_string(),
+                },
+                confidence: EventConfidence {
+                    value: 1.0,
+                    evidence: ConfidenceEvidence::SystemProduced,
+                },
+                priority: EventPriority::Normal,
+                scene_id: SceneId::new(),
+                turn_id: None,
+            }),
+        }];
+        sk.commit_turn(&turn, &session).await.unwrap();
+
+        assert_eq!(
+            sk.check_information_boundary(&session, &miller.entity_id, fact)
+                .await
+                .unwrap(),
+            BoundaryCheck::Permitted,
+        );
+        assert_eq!(
+            sk.knowledge(&session.session_id)
+                .learned(&miller.entity_id)
+                .len(),
+            1
+        );
     }
 
     #[tokio::test]
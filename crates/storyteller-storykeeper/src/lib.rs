@@ -7,8 +7,9 @@
 //! This crate owns all database access: migrations, the `MIGRATOR` static,
 //! and Storykeeper trait implementations.
 //!
-//! Two implementations:
+//! Implementations:
 //! - [`InMemoryStorykeeper`] — in-process state for tests and prototype
+//! - [`PostgresStorykeeper`] — sqlx queries against the baseline session tables
 //! - [`PostgresBedrock`] — sqlx queries against the `bedrock` schema
 //!
 //! See: `docs/technical/storykeeper-api-contract.md`
@@ -17,6 +18,11 @@
 pub mod bedrock;
 pub mod database;
 pub mod in_memory;
+pub mod postgres;
+
+mod preamble;
+mod weights;
 
 pub use bedrock::PostgresBedrock;
 pub use in_memory::InMemoryStorykeeper;
+pub use postgres::PostgresStorykeeper;
@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! PostgreSQL-backed Storykeeper implementation.
+//!
+//! `PostgresStorykeeper` wraps a `sqlx::PgPool` and implements
+//! `StorykeeperQuery`, `StorykeeperCommit`, and `StorykeeperLifecycle`
+//! against the baseline session, scene, and character tables and the
+//! `storykeeper` runtime schema,
+//! so session state survives server restarts. Query logic is split into
+//! sub-modules by concern.
+//!
+//! Behavior matches [`InMemoryStorykeeper`](crate::InMemoryStorykeeper)
+//! wherever the two overlap — the shared conformance suite in
+//! `tests/conformance/` runs against both.
+//!
+//! See: `docs/technical/storykeeper-api-contract.md`
+
+pub mod queries;
+
+use sqlx::PgPool;
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::storykeeper::{
+    BoundaryCheck, CheckpointId, CommandSourced, CommitResult, CompletedTurn, EntityRelevance,
+    GateProximity, LayerEntryResult, LayerExitResult, SceneExitResult, SceneLoadResult,
+    SessionContext, StorykeeperCommit, StorykeeperLifecycle, StorykeeperQuery,
+};
+use storyteller_core::types::character::{CharacterSheet, SceneData};
+use storyteller_core::types::entity::{EntityId, EntityRef};
+use storyteller_core::types::message::PlayerInput;
+use storyteller_core::types::narrative_layer::{NarrativeLayer, NarrativeStack};
+use storyteller_core::types::narrator_context::{
+    NarratorContextInput, RetrievedContext, SceneJournal,
+};
+use storyteller_core::types::resolver::ResolverOutput;
+use storyteller_core::types::scene::SceneId;
+
+use crate::database::migrator::MIGRATOR;
+use crate::preamble::{current_scene, scene_preamble, unregistered};
+
+/// Number of recent events surfaced as retrieved context.
+const RECENT_EVENT_CONTEXT: i64 = 5;
+
+/// Journal token budget for freshly entered scenes.
+const DEFAULT_JOURNAL_BUDGET: u32 = 1200;
+
+/// PostgreSQL-backed Storykeeper.
+///
+/// The pool is cheaply cloneable — each `PostgresStorykeeper` instance
+/// shares the same underlying connection pool.
+#[derive(Debug, Clone)]
+pub struct PostgresStorykeeper {
+    pool: PgPool,
+}
+
+impl PostgresStorykeeper {
+    /// Create a new `PostgresStorykeeper` from an existing connection pool.
+    ///
+    /// Does not run migrations — call [`migrate`](Self::migrate) at startup
+    /// if the schema may be behind.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Apply all pending migrations through the crate's `MIGRATOR`.
+    pub async fn migrate(&self) -> StorytellerResult<()> {
+        MIGRATOR
+            .run(&self.pool)
+            .await
+            .map_err(|e| StorytellerError::Database(e.into()))
+    }
+
+    /// The underlying connection pool.
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// All sourced commands for a session, in the order they were sourced.
+    pub async fn sourced_commands(
+        &self,
+        session_id: &storyteller_core::traits::storykeeper::SessionId,
+    ) -> StorytellerResult<Vec<PlayerInput>> {
+        queries::session::sourced_commands(&self.pool, session_id.0).await
+    }
+
+    /// The narrative layer stack last written for a session, or `None` if
+    /// the session has never been written.
+    pub async fn narrative_layers(
+        &self,
+        session_id: &storyteller_core::traits::storykeeper::SessionId,
+    ) -> StorytellerResult<Option<NarrativeStack>> {
+        queries::session::narrative_layers(&self.pool, session_id.0).await
+    }
+
+    async fn connection(&self) -> StorytellerResult<sqlx::pool::PoolConnection<sqlx::Postgres>> {
+        self.pool
+            .acquire()
+            .await
+            .map_err(StorytellerError::Database)
+    }
+
+    async fn load_scene(
+        &self,
+        scene_id: SceneId,
+        session: &SessionContext,
+    ) -> StorytellerResult<SceneLoadResult> {
+        let scene = queries::scene::scene_data(&self.pool, session.story_id, scene_id).await?;
+        let (title, cast) = match scene {
+            Some(scene) => {
+                let cast =
+                    queries::scene::cast_sheets(&self.pool, session.story_id, &scene).await?;
+                (scene.title, cast)
+            }
+            None => (String::new(), Vec::new()),
+        };
+        Ok(SceneLoadResult {
+            scene_id,
+            title,
+            cast,
+            retrieved_context: self.recent_event_context(session.session_id.0).await?,
+            journal: SceneJournal::new(scene_id, DEFAULT_JOURNAL_BUDGET),
+        })
+    }
+
+    async fn recent_event_context(
+        &self,
+        session_id: uuid::Uuid,
+    ) -> StorytellerResult<Vec<RetrievedContext>> {
+        let events =
+            queries::session::recent_events(&self.pool, session_id, RECENT_EVENT_CONTEXT).await?;
+        Ok(events
+            .into_iter()
+            .map(|e| RetrievedContext {
+                subject: format!("Event {}", e.id.0),
+                content: format!("{:?}", e.payload),
+                revealed: true,
+                emotional_context: None,
+                source_entities: Vec::new(),
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl StorykeeperQuery for PostgresStorykeeper {
+    async fn assemble_narrator_context(
+        &self,
+        session: &SessionContext,
+        journal: &SceneJournal,
+        resolver_output: &ResolverOutput,
+        player_input: &str,
+    ) -> StorytellerResult<NarratorContextInput> {
+        let scene_id = current_scene(session)?;
+        let scene = queries::scene::scene_data(&self.pool, session.story_id, scene_id)
+            .await?
+            .ok_or_else(|| unregistered(scene_id))?;
+        let cast = queries::scene::cast_sheets(&self.pool, session.story_id, &scene).await?;
+        let preamble = scene_preamble(&scene, &cast, session);
+
+        let retrieved = self.recent_event_context(session.session_id.0).await?;
+
+        Ok(NarratorContextInput {
+            preamble,
+            journal: session.layers.journal(journal).clone(),
+            retrieved,
+            resolver_output: resolver_output.clone(),
+            player_input_summary: player_input.to_string(),
+            estimated_tokens: 0,
+        })
+    }
+
+    async fn query_entity_relevance(
+        &self,
+        entity: &EntityRef,
+        session: &SessionContext,
+    ) -> StorytellerResult<EntityRelevance> {
+        let entity_id = entity.entity_id().unwrap_or_default();
+        let Some(resolved) = session.layers.resolve(entity_id) else {
+            return Ok(EntityRelevance {
+                entity_id,
+                relevance: 0.0,
+                reason: "not present in the current narrative layer".to_string(),
+                context: Vec::new(),
+            });
+        };
+        let weight =
+            queries::session::entity_weight(&self.pool, session.session_id.0, &resolved).await?;
+
+        Ok(EntityRelevance {
+            entity_id: resolved,
+            relevance: weight.min(1.0),
+            reason: "weight-based relevance".to_string(),
+            context: Vec::new(),
+        })
+    }
+
+    async fn query_gate_proximity(
+        &self,
+        session: &SessionContext,
+    ) -> StorytellerResult<Vec<GateProximity>> {
+        let current = current_scene(session)?;
+        queries::scene::gate_proximity(&self.pool, session, current).await
+    }
+
+    async fn check_information_boundary(
+        &self,
+        _session: &SessionContext,
+        _entity_id: &EntityId,
+        _fact: &str,
+    ) -> StorytellerResult<BoundaryCheck> {
+        // Character knowledge is not persisted, so there is nothing to
+        // answer from — refuse rather than permit every fact.
+        Err(StorytellerError::Config(
+            "PostgresStorykeeper does not persist information boundaries".to_string(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl StorykeeperCommit for PostgresStorykeeper {
+    async fn source_command(
+        &self,
+        input: &PlayerInput,
+        session: &SessionContext,
+    ) -> StorytellerResult<CommandSourced> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(StorytellerError::Database)?;
+        queries::session::upsert_session(&mut tx, session).await?;
+        let sourced_at = queries::session::insert_sourced_command(&mut *tx, session, input).await?;
+        tx.commit().await.map_err(StorytellerError::Database)?;
+
+        Ok(CommandSourced {
+            turn_number: session.turn_number,
+            sourced_at,
+        })
+    }
+
+    async fn commit_turn(
+        &self,
+        completed: &CompletedTurn,
+        session: &SessionContext,
+    ) -> StorytellerResult<CommitResult> {
+        queries::commit::commit_turn(&self.pool, completed, session).await
+    }
+}
+
+#[async_trait::async_trait]
+impl StorykeeperLifecycle for PostgresStorykeeper {
+    async fn register_scene(
+        &self,
+        scene: &SceneData,
+        session: &SessionContext,
+    ) -> StorytellerResult<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(StorytellerError::Database)?;
+        queries::session::upsert_session(&mut tx, session).await?;
+        queries::scene::upsert_scene(&mut tx, session.story_id, scene).await?;
+        tx.commit().await.map_err(StorytellerError::Database)
+    }
+
+    async fn enter_scene(
+        &self,
+        scene_id: &SceneId,
+        cast: &[CharacterSheet],
+        session: &SessionContext,
+    ) -> StorytellerResult<SceneLoadResult> {
+        let mut positioned = session.clone();
+        positioned.current_scene_id = Some(*scene_id);
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(StorytellerError::Database)?;
+        queries::session::upsert_session(&mut tx, &positioned).await?;
+        queries::scene::record_cast(&mut tx, session.story_id, cast).await?;
+        tx.commit().await.map_err(StorytellerError::Database)?;
+
+        let mut loaded = self.load_scene(*scene_id, &positioned).await?;
+        loaded.cast = cast.to_vec();
+        Ok(loaded)
+    }
+
+    async fn exit_scene(&self, session: &SessionContext) -> StorytellerResult<SceneExitResult> {
+        let checkpoint_id = queries::checkpoint::write_checkpoint(&self.pool, session).await?;
+        Ok(SceneExitResult {
+            checkpoint_id,
+            deferred_count: 0,
+        })
+    }
+
+    // The layer stack travels in the session context and is written to the
+    // session row after each push or pop.
+    async fn enter_layer(
+        &self,
+        layer: NarrativeLayer,
+        session: &mut SessionContext,
+    ) -> StorytellerResult<LayerEntryResult> {
+        let layer_id = layer.id;
+        let depth = session.layers.push(layer);
+
+        let mut conn = self.connection().await?;
+        queries::session::upsert_session(&mut conn, session).await?;
+        Ok(LayerEntryResult { layer_id, depth })
+    }
+
+    async fn exit_layer(&self, session: &mut SessionContext) -> StorytellerResult<LayerExitResult> {
+        let layer = session
+            .layers
+            .pop()
+            .ok_or_else(|| StorytellerError::Scene("no narrative layer to exit".to_string()))?;
+
+        let mut conn = self.connection().await?;
+        queries::session::upsert_session(&mut conn, session).await?;
+        Ok(LayerExitResult {
+            layer,
+            depth: session.layers.depth(),
+        })
+    }
+
+    async fn write_checkpoint(&self, session: &SessionContext) -> StorytellerResult<CheckpointId> {
+        queries::checkpoint::write_checkpoint(&self.pool, session).await
+    }
+
+    async fn resume_from_checkpoint(
+        &self,
+        checkpoint_id: &CheckpointId,
+    ) -> StorytellerResult<SceneLoadResult> {
+        let snapshot = queries::checkpoint::load_checkpoint(&self.pool, checkpoint_id).await?;
+        let scene_id = snapshot.session.current_scene_id.ok_or_else(|| {
+            StorytellerError::Scene(format!(
+                "checkpoint {} was taken outside of a scene",
+                checkpoint_id.0
+            ))
+        })?;
+
+        self.load_scene(scene_id, &snapshot.session).await
+    }
+}
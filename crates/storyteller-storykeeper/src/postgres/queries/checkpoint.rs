@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Checkpoint snapshots — write and load.
+
+use sqlx::PgPool;
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::storykeeper::{CheckpointId, SessionContext};
+
+use super::session::upsert_session;
+
+/// The document stored in `storykeeper.checkpoints.snapshot`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointSnapshot {
+    /// Session position at the time of the checkpoint.
+    pub session: SessionContext,
+}
+
+/// Write a checkpoint for the session and return its ID.
+pub async fn write_checkpoint(
+    pool: &PgPool,
+    session: &SessionContext,
+) -> StorytellerResult<CheckpointId> {
+    let checkpoint_id = CheckpointId::new();
+    let snapshot = CheckpointSnapshot {
+        session: session.clone(),
+    };
+
+    let mut tx = pool.begin().await.map_err(StorytellerError::Database)?;
+    upsert_session(&mut tx, session).await?;
+
+    sqlx::query(
+        "INSERT INTO storykeeper.checkpoints (id, session_id, scene_id, turn_number, snapshot) \
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(checkpoint_id.0)
+    .bind(session.session_id.0)
+    .bind(session.current_scene_id.map(|s| s.0))
+    .bind(session.turn_number as i32)
+    .bind(serde_json::to_value(&snapshot)?)
+    .execute(&mut *tx)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    tx.commit().await.map_err(StorytellerError::Database)?;
+    Ok(checkpoint_id)
+}
+
+/// Load a checkpoint snapshot by ID.
+///
+/// Returns `EntityNotFound` if no checkpoint with that ID exists.
+pub async fn load_checkpoint(
+    pool: &PgPool,
+    checkpoint_id: &CheckpointId,
+) -> StorytellerResult<CheckpointSnapshot> {
+    let snapshot: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT snapshot FROM storykeeper.checkpoints WHERE id = $1")
+            .bind(checkpoint_id.0)
+            .fetch_optional(pool)
+            .await
+            .map_err(StorytellerError::Database)?;
+
+    let snapshot = snapshot.ok_or_else(|| {
+        StorytellerError::EntityNotFound(format!("checkpoint {}", checkpoint_id.0))
+    })?;
+    Ok(serde_json::from_value(snapshot)?)
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Turn commit — the single transaction that makes a turn durable.
+//!
+//! The completed turn, its events, and the resulting entity weight changes
+//! are written together: either the whole turn is committed or none of it.
+//! Turns land in the baseline `turns` table and events in `event_ledger`,
+//! whose typed columns are derived from each event's atom.
+
+use sqlx::PgPool;
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::storykeeper::{
+    CommitResult, CompletedTurn, EntityWeightChange, SessionContext,
+};
+use storyteller_core::types::event::{EventPriority, NarrativeEvent};
+use storyteller_core::types::event_grammar::{EventKind, EventPayload};
+
+use super::session::upsert_session;
+use crate::weights::turn_weight_increments;
+
+/// Commit a completed turn for a session.
+pub async fn commit_turn(
+    pool: &PgPool,
+    completed: &CompletedTurn,
+    session: &SessionContext,
+) -> StorytellerResult<CommitResult> {
+    let session_id = session.session_id.0;
+    let mut tx = pool.begin().await.map_err(StorytellerError::Database)?;
+
+    upsert_session(&mut tx, session).await?;
+
+    let turn_id: uuid::Uuid = sqlx::query_scalar(
+        "INSERT INTO turns \
+             (session_id, turn_number, player_input, resolver_output, \
+              provisional_status, committed_at) \
+         VALUES ($1, $2, $3, $4, 'committed', now()) \
+         ON CONFLICT (session_id, turn_number) DO UPDATE SET \
+             player_input = EXCLUDED.player_input, \
+             resolver_output = EXCLUDED.resolver_output, \
+             provisional_status = 'committed', \
+             committed_at = now() \
+         RETURNING id",
+    )
+    .bind(session_id)
+    .bind(completed.turn_number as i32)
+    .bind(&completed.player_input.text)
+    .bind(serde_json::to_value(&completed.resolver_output)?)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    for event in &completed.events {
+        let columns = LedgerColumns::of(event)?;
+        sqlx::query(
+            "INSERT INTO event_ledger \
+                 (id, story_id, session_id, turn_id, event_type, event_kind, priority, \
+                  participants, relational_implications, source, confidence, payload) \
+             VALUES ($1, $2, $3, $4, $5::event_type, $6::event_kind, $7::event_priority, \
+                     $8, $9, $10, $11, $12) \
+             ON CONFLICT (id) DO NOTHING",
+        )
+        .bind(event.id.0)
+        .bind(session.story_id)
+        .bind(session_id)
+        .bind(turn_id)
+        .bind(columns.event_type)
+        .bind(columns.event_kind)
+        .bind(priority_label(event.priority))
+        .bind(columns.participants)
+        .bind(columns.relational_implications)
+        .bind(columns.source)
+        .bind(columns.confidence)
+        .bind(serde_json::to_value(event)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+    }
+
+    let mut weight_changes = Vec::new();
+    for increment in turn_weight_increments(completed) {
+        let previous: Option<f32> = sqlx::query_scalar(
+            "SELECT weight FROM storykeeper.entity_weights \
+             WHERE session_id = $1 AND entity_id = $2 \
+             FOR UPDATE",
+        )
+        .bind(session_id)
+        .bind(increment.entity_id.0)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+
+        let previous = previous.unwrap_or(0.0);
+        let new_weight = previous + increment.increment;
+
+        sqlx::query(
+            "INSERT INTO storykeeper.entity_weights (session_id, entity_id, weight) \
+             VALUES ($1, $2, $3) \
+             ON CONFLICT (session_id, entity_id) DO UPDATE SET \
+                 weight = EXCLUDED.weight, \
+                 updated_at = now()",
+        )
+        .bind(session_id)
+        .bind(increment.entity_id.0)
+        .bind(new_weight)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorytellerError::Database)?;
+
+        weight_changes.push(EntityWeightChange {
+            entity_id: increment.entity_id,
+            previous_weight: previous,
+            new_weight,
+            reason: increment.reason,
+        });
+    }
+
+    tx.commit().await.map_err(StorytellerError::Database)?;
+
+    Ok(CommitResult {
+        events_committed: completed.events.clone(),
+        weight_changes,
+        gates_triggered: Vec::new(),
+    })
+}
+
+/// The typed `event_ledger` columns for one event.
+///
+/// Compound and untyped payloads carry no atom, so their atom-level columns
+/// fall back to `unknown` and JSON `null`; `payload` always holds the event.
+struct LedgerColumns {
+    event_type: &'static str,
+    event_kind: &'static str,
+    participants: serde_json::Value,
+    relational_implications: serde_json::Value,
+    source: serde_json::Value,
+    confidence: serde_json::Value,
+}
+
+impl LedgerColumns {
+    fn of(event: &NarrativeEvent) -> StorytellerResult<Self> {
+        Ok(match &event.payload {
+            EventPayload::Atom(atom) => Self {
+                event_type: "atom",
+                event_kind: kind_label(&atom.kind),
+                participants: serde_json::to_value(&atom.participants)?,
+                relational_implications: serde_json::to_value(&atom.relational_implications)?,
+                source: serde_json::to_value(&atom.source)?,
+                confidence: serde_json::to_value(&atom.confidence)?,
+            },
+            EventPayload::Compound(compound) => Self {
+                event_type: "compound",
+                event_kind: "unknown",
+                participants: serde_json::json!([]),
+                relational_implications: serde_json::to_value(&compound.emergent_implications)?,
+                source: serde_json::Value::Null,
+                confidence: serde_json::Value::Null,
+            },
+            EventPayload::Untyped(_) => Self {
+                event_type: "atom",
+                event_kind: "unknown",
+                participants: serde_json::json!([]),
+                relational_implications: serde_json::json!([]),
+                source: serde_json::Value::Null,
+                confidence: serde_json::Value::Null,
+            },
+        })
+    }
+}
+
+/// The `event_kind` enum label for an atom's kind.
+fn kind_label(kind: &EventKind) -> &'static str {
+    match kind {
+        EventKind::StateAssertion { .. } => "state_assertion",
+        EventKind::ActionOccurrence { .. } => "action_occurrence",
+        EventKind::SpatialChange { .. } => "spatial_change",
+        EventKind::EmotionalExpression { .. } => "emotional_expression",
+        EventKind::InformationTransfer { .. } => "information_transfer",
+        EventKind::SpeechAct { .. } => "speech_act",
+        EventKind::RelationalShift { .. } => "relational_shift",
+        EventKind::EnvironmentalChange { .. } => "environmental_change",
+        EventKind::SceneLifecycle { .. } => "scene_lifecycle",
+        EventKind::EntityLifecycle { .. } => "entity_lifecycle",
+    }
+}
+
+/// The `event_priority` enum label for a priority.
+fn priority_label(priority: EventPriority) -> &'static str {
+    match priority {
+        EventPriority::Immediate => "immediate",
+        EventPriority::High => "high",
+        EventPriority::Normal => "normal",
+        EventPriority::Low => "low",
+        EventPriority::Deferred => "deferred",
+    }
+}
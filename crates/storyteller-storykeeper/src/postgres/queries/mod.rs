@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Query sub-modules for `PostgresStorykeeper`.
+//!
+//! Each module handles one area of the runtime state, which spans the
+//! baseline `sessions` / `turns` / `event_ledger` tables and the
+//! `storykeeper` schema:
+//! - `session` — session rows, sourced commands, entity weights, recent events
+//! - `scene` — scene registration, cast sheets, gate proximity
+//! - `commit` — the turn commit transaction
+//! - `checkpoint` — checkpoint snapshots
+
+pub mod checkpoint;
+pub mod commit;
+pub mod scene;
+pub mod session;
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Scene-scoped queries — scene registration, cast sheets, and gates.
+//!
+//! Scenes are rows in the baseline `scenes` table with the full
+//! [`SceneData`] in `scene_data`; keys written by other owners (the
+//! narrative graph's `approach_vectors`) are preserved on re-registration.
+//! Cast sheets are versioned rows in `characters`. Gate proximity walks
+//! `scene_transitions` from the current scene and scores each gate's
+//! approach vectors against the session's committed events.
+
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgPool};
+use storyteller_core::database::checkpoint::TruthSet;
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::graph::narrative::{
+    approach_satisfaction, predicate_satisfaction, scene_type_label,
+};
+use storyteller_core::traits::storykeeper::{GateProximity, SessionContext};
+use storyteller_core::types::character::{CharacterSheet, SceneData};
+use storyteller_core::types::narrative::{NarrativeMass, SceneApproach, TruthPredicate};
+use storyteller_core::types::scene::SceneId;
+
+use super::session::committed_events;
+
+/// How many transitions ahead of the current scene gates are looked for.
+const GATE_HORIZON_HOPS: i32 = 3;
+
+/// Insert or replace a scene's data.
+///
+/// Composed scenes are recorded with `generated` provenance and no authored
+/// mass. A scene id already registered under another story is left alone.
+pub async fn upsert_scene(
+    conn: &mut PgConnection,
+    story_id: uuid::Uuid,
+    scene: &SceneData,
+) -> StorytellerResult<()> {
+    let unauthored = NarrativeMass {
+        authored_base: 0.0,
+        structural_modifier: 0.0,
+        dynamic_adjustment: 0.0,
+    };
+    sqlx::query(
+        "INSERT INTO scenes \
+             (id, story_id, title, scene_type, provenance, narrative_mass, scene_data) \
+         VALUES ($1, $2, $3, $4::scene_type, 'generated', $5, $6) \
+         ON CONFLICT (id) DO UPDATE SET \
+             title = EXCLUDED.title, \
+             scene_type = EXCLUDED.scene_type, \
+             scene_data = scenes.scene_data || EXCLUDED.scene_data \
+         WHERE scenes.story_id = EXCLUDED.story_id",
+    )
+    .bind(scene.scene_id.0)
+    .bind(story_id)
+    .bind(&scene.title)
+    .bind(scene_type_label(scene.scene_type))
+    .bind(Json(unauthored))
+    .bind(serde_json::to_value(scene)?)
+    .execute(conn)
+    .await
+    .map_err(StorytellerError::Database)?;
+    Ok(())
+}
+
+/// The registered data for a scene, or `None` if the story has no such
+/// scene.
+///
+/// Errors if the row exists but holds no [`SceneData`] — e.g. a scene only
+/// ever written as a narrative graph node.
+pub async fn scene_data(
+    pool: &PgPool,
+    story_id: uuid::Uuid,
+    scene_id: SceneId,
+) -> StorytellerResult<Option<SceneData>> {
+    let data: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT scene_data FROM scenes WHERE story_id = $1 AND id = $2")
+            .bind(story_id)
+            .bind(scene_id.0)
+            .fetch_optional(pool)
+            .await
+            .map_err(StorytellerError::Database)?;
+
+    data.map(|v| {
+        serde_json::from_value(v).map_err(|e| {
+            StorytellerError::Scene(format!("scene {} has no scene data: {e}", scene_id.0))
+        })
+    })
+    .transpose()
+}
+
+/// Record cast sheets as the next version of each character, skipping
+/// sheets identical to the latest stored version.
+pub async fn record_cast(
+    conn: &mut PgConnection,
+    story_id: uuid::Uuid,
+    cast: &[CharacterSheet],
+) -> StorytellerResult<()> {
+    for sheet in cast {
+        sqlx::query(
+            "INSERT INTO characters (entity_id, story_id, name, version, sheet) \
+             SELECT $1, $2, $3, COALESCE(MAX(version), 0) + 1, $4 \
+             FROM characters WHERE entity_id = $1 AND story_id = $2 \
+             HAVING (array_agg(sheet ORDER BY version DESC))[1] IS DISTINCT FROM $4",
+        )
+        .bind(sheet.entity_id.0)
+        .bind(story_id)
+        .bind(&sheet.name)
+        .bind(serde_json::to_value(sheet)?)
+        .execute(&mut *conn)
+        .await
+        .map_err(StorytellerError::Database)?;
+    }
+    Ok(())
+}
+
+/// The latest stored sheet of every character cast in a scene.
+pub async fn cast_sheets(
+    pool: &PgPool,
+    story_id: uuid::Uuid,
+    scene: &SceneData,
+) -> StorytellerResult<Vec<CharacterSheet>> {
+    let entity_ids: Vec<uuid::Uuid> = scene.cast.iter().map(|c| c.entity_id.0).collect();
+    let rows: Vec<serde_json::Value> = sqlx::query_scalar(
+        "SELECT DISTINCT ON (entity_id) sheet FROM characters \
+         WHERE story_id = $1 AND entity_id = ANY($2) \
+         ORDER BY entity_id, version DESC",
+    )
+    .bind(story_id)
+    .bind(&entity_ids)
+    .fetch_all(pool)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    rows.into_iter()
+        .map(|v| serde_json::from_value(v).map_err(StorytellerError::from))
+        .collect()
+}
+
+/// Gates within [`GATE_HORIZON_HOPS`] of the current scene that the session
+/// has not completed or bypassed, nearest first.
+///
+/// A gate's satisfaction is its best approach vector's; a gate without
+/// approach vectors is open. Unmet conditions are the best approach's
+/// predicates that are not fully satisfied.
+pub async fn gate_proximity(
+    pool: &PgPool,
+    session: &SessionContext,
+    current: SceneId,
+) -> StorytellerResult<Vec<GateProximity>> {
+    let gates: Vec<(uuid::Uuid, String, Json<Vec<SceneApproach>>)> = sqlx::query_as(
+        "WITH RECURSIVE reach (scene_id, hops) AS ( \
+             SELECT $2::uuid, 0 \
+             UNION \
+             SELECT t.to_scene_id, r.hops + 1 \
+             FROM reach r \
+             JOIN scene_transitions t \
+               ON t.from_scene_id = r.scene_id AND t.story_id = $1 \
+             WHERE r.hops < $3 \
+         ), nearest AS ( \
+             SELECT scene_id, MIN(hops) AS hops FROM reach \
+             WHERE hops > 0 GROUP BY scene_id \
+         ) \
+         SELECT s.id, s.title, \
+                COALESCE(s.scene_data->'approach_vectors', '[]'::jsonb) \
+         FROM nearest n \
+         JOIN scenes s ON s.id = n.scene_id AND s.story_id = $1 \
+         WHERE s.scene_type = 'gate' \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM scene_activation_states a \
+               WHERE a.session_id = $4 AND a.scene_id = s.id \
+                 AND a.state IN ('completed', 'bypassed')) \
+         ORDER BY n.hops, s.title",
+    )
+    .bind(session.story_id)
+    .bind(current.0)
+    .bind(GATE_HORIZON_HOPS)
+    .bind(session.session_id.0)
+    .fetch_all(pool)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    if gates.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let truth = TruthSet {
+        events: committed_events(pool, session.session_id.0).await?,
+        pending: Vec::new(),
+    };
+
+    Ok(gates
+        .into_iter()
+        .map(|(id, title, approaches)| {
+            let best = approaches
+                .0
+                .into_iter()
+                .map(|a| (approach_satisfaction(&a, &truth), a))
+                .max_by(|a, b| a.0.total_cmp(&b.0));
+            let (satisfaction, unmet_conditions) = match best {
+                Some((satisfaction, approach)) => (
+                    satisfaction,
+                    approach
+                        .predicates
+                        .iter()
+                        .filter(|wp| predicate_satisfaction(&wp.predicate, &truth) < 1.0)
+                        .map(|wp| describe_predicate(&wp.predicate))
+                        .collect(),
+                ),
+                None => (1.0, Vec::new()),
+            };
+            GateProximity {
+                gate_name: title,
+                target_scene_id: SceneId(id),
+                satisfaction,
+                unmet_conditions,
+            }
+        })
+        .collect())
+}
+
+fn describe_predicate(predicate: &TruthPredicate) -> String {
+    match predicate {
+        TruthPredicate::FactEstablished { fact } => format!("fact established: {fact}"),
+        TruthPredicate::EntityInvolved {
+            entity_id,
+            min_events,
+        } => format!("entity {} involved in {min_events} events", entity_id.0),
+        TruthPredicate::EmotionExpressed { emotion } => format!("emotion expressed: {emotion}"),
+        TruthPredicate::SceneEntered { scene_id } => format!("scene {} entered", scene_id.0),
+    }
+}
@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Session-scoped queries — session upsert, command sourcing, and reads.
+
+use sqlx::{PgConnection, PgExecutor, PgPool};
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::storykeeper::SessionContext;
+use storyteller_core::types::entity::EntityId;
+use storyteller_core::types::event::NarrativeEvent;
+use storyteller_core::types::message::PlayerInput;
+use storyteller_core::types::narrative_layer::NarrativeStack;
+
+/// Insert the session row, or refresh its scene position, turn number, and
+/// narrative layer stack.
+///
+/// Composed stories have no `stories` row of their own yet, so one is
+/// registered for the session's story on first write. The stored turn number
+/// never moves backwards, and a `None` scene in the context leaves the stored
+/// scene untouched.
+pub async fn upsert_session(
+    conn: &mut PgConnection,
+    session: &SessionContext,
+) -> StorytellerResult<()> {
+    sqlx::query(
+        "INSERT INTO stories (id, title) VALUES ($1, 'Composed story') \
+         ON CONFLICT (id) DO NOTHING",
+    )
+    .bind(session.story_id)
+    .execute(&mut *conn)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    sqlx::query(
+        "INSERT INTO sessions \
+             (id, story_id, player_entity_id, current_scene_id, turn_number, \
+              narrative_layers, status) \
+         VALUES ($1, $2, $3, $4, $5, $6, 'active') \
+         ON CONFLICT (id) DO UPDATE SET \
+             current_scene_id = COALESCE(EXCLUDED.current_scene_id, \
+                                         sessions.current_scene_id), \
+             turn_number = GREATEST(EXCLUDED.turn_number, sessions.turn_number), \
+             narrative_layers = EXCLUDED.narrative_layers, \
+             updated_at = now()",
+    )
+    .bind(session.session_id.0)
+    .bind(session.story_id)
+    .bind(session.player_entity_id.0)
+    .bind(session.current_scene_id.map(|s| s.0))
+    .bind(session.turn_number as i32)
+    .bind(serde_json::to_value(&session.layers)?)
+    .execute(&mut *conn)
+    .await
+    .map_err(StorytellerError::Database)?;
+    Ok(())
+}
+
+/// The stored narrative layer stack for a session, or `None` if the session
+/// has never been written.
+pub async fn narrative_layers(
+    pool: &PgPool,
+    session_id: uuid::Uuid,
+) -> StorytellerResult<Option<NarrativeStack>> {
+    let layers: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT narrative_layers FROM sessions WHERE id = $1")
+            .bind(session_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(StorytellerError::Database)?;
+
+    layers
+        .map(|v| serde_json::from_value(v).map_err(StorytellerError::from))
+        .transpose()
+}
+
+/// Durably record a player command for the given turn.
+pub async fn insert_sourced_command<'e>(
+    executor: impl PgExecutor<'e>,
+    session: &SessionContext,
+    input: &PlayerInput,
+) -> StorytellerResult<chrono::DateTime<chrono::Utc>> {
+    let sourced_at: chrono::DateTime<chrono::Utc> = sqlx::query_scalar(
+        "INSERT INTO storykeeper.sourced_commands (session_id, turn_number, input) \
+         VALUES ($1, $2, $3) \
+         RETURNING sourced_at",
+    )
+    .bind(session.session_id.0)
+    .bind(session.turn_number as i32)
+    .bind(serde_json::to_value(input)?)
+    .fetch_one(executor)
+    .await
+    .map_err(StorytellerError::Database)?;
+    Ok(sourced_at)
+}
+
+/// Return every sourced command for a session in sourcing order.
+pub async fn sourced_commands(
+    pool: &PgPool,
+    session_id: uuid::Uuid,
+) -> StorytellerResult<Vec<PlayerInput>> {
+    let rows: Vec<serde_json::Value> = sqlx::query_scalar(
+        "SELECT input FROM storykeeper.sourced_commands \
+         WHERE session_id = $1 \
+         ORDER BY sourced_at, id",
+    )
+    .bind(session_id)
+    .fetch_all(pool)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    rows.into_iter()
+        .map(|v| serde_json::from_value(v).map_err(StorytellerError::from))
+        .collect()
+}
+
+/// Accumulated weight for an entity in a session, or `0.0` if untracked.
+pub async fn entity_weight(
+    pool: &PgPool,
+    session_id: uuid::Uuid,
+    entity_id: &EntityId,
+) -> StorytellerResult<f32> {
+    let weight: Option<f32> = sqlx::query_scalar(
+        "SELECT weight FROM storykeeper.entity_weights \
+         WHERE session_id = $1 AND entity_id = $2",
+    )
+    .bind(session_id)
+    .bind(entity_id.0)
+    .fetch_optional(pool)
+    .await
+    .map_err(StorytellerError::Database)?;
+    Ok(weight.unwrap_or(0.0))
+}
+
+/// The `limit` most recently committed events for a session, newest first.
+pub async fn recent_events(
+    pool: &PgPool,
+    session_id: uuid::Uuid,
+    limit: i64,
+) -> StorytellerResult<Vec<NarrativeEvent>> {
+    let rows: Vec<serde_json::Value> = sqlx::query_scalar(
+        "SELECT payload FROM event_ledger \
+         WHERE session_id = $1 \
+         ORDER BY committed_at DESC, id DESC \
+         LIMIT $2",
+    )
+    .bind(session_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    rows.into_iter()
+        .map(|v| serde_json::from_value(v).map_err(StorytellerError::from))
+        .collect()
+}
+
+/// Every committed event for a session, in commit order.
+pub async fn committed_events(
+    pool: &PgPool,
+    session_id: uuid::Uuid,
+) -> StorytellerResult<Vec<NarrativeEvent>> {
+    let rows: Vec<serde_json::Value> = sqlx::query_scalar(
+        "SELECT payload FROM event_ledger \
+         WHERE session_id = $1 \
+         ORDER BY committed_at, id",
+    )
+    .bind(session_id)
+    .fetch_all(pool)
+    .await
+    .map_err(StorytellerError::Database)?;
+
+    rows.into_iter()
+        .map(|v| serde_json::from_value(v).map_err(StorytellerError::from))
+        .collect()
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Narrator preamble rule shared by every Storykeeper backend.
+//!
+//! Both backends describe the current scene from its registered
+//! [`SceneData`] and the cast sheets entered with it, so the conformance
+//! suite can assert they assemble the same preamble. Narrator identity and
+//! anti-patterns are voice configuration and stay with the engine.
+
+use storyteller_core::errors::{StorytellerError, StorytellerResult};
+use storyteller_core::traits::storykeeper::SessionContext;
+use storyteller_core::types::character::{CharacterSheet, SceneData};
+use storyteller_core::types::narrator_context::{CastDescription, PersistentPreamble};
+use storyteller_core::types::scene::SceneId;
+
+/// The session's current scene, or an error outside of a scene.
+pub(crate) fn current_scene(session: &SessionContext) -> StorytellerResult<SceneId> {
+    session.current_scene_id.ok_or_else(|| {
+        StorytellerError::Scene(format!(
+            "session {} has not entered a scene",
+            session.session_id.0
+        ))
+    })
+}
+
+/// Error for a current scene that was never registered.
+pub(crate) fn unregistered(scene_id: SceneId) -> StorytellerError {
+    StorytellerError::Scene(format!("scene {} is not registered", scene_id.0))
+}
+
+/// Build the Tier 1 preamble for a registered scene.
+///
+/// Cast entries are matched to sheets by entity ID; a cast member without
+/// a sheet gets an empty voice note.
+pub(crate) fn scene_preamble(
+    scene: &SceneData,
+    cast: &[CharacterSheet],
+    session: &SessionContext,
+) -> PersistentPreamble {
+    let mut setting_description = scene.setting.description.clone();
+    if !scene.setting.aesthetic_detail.is_empty() {
+        setting_description.push_str("\n\n");
+        setting_description.push_str(&scene.setting.aesthetic_detail);
+    }
+
+    let cast_descriptions = scene
+        .cast
+        .iter()
+        .map(|entry| CastDescription {
+            entity_id: entry.entity_id,
+            name: entry.name.clone(),
+            role: entry.role.clone(),
+            voice_note: cast
+                .iter()
+                .find(|sheet| sheet.entity_id == entry.entity_id)
+                .map(|sheet| sheet.voice.clone())
+                .unwrap_or_default(),
+            is_player: entry.entity_id == session.player_entity_id,
+            relationships: Vec::new(),
+        })
+        .collect();
+
+    PersistentPreamble {
+        narrator_identity: String::new(),
+        anti_patterns: Vec::new(),
+        setting_description,
+        cast_descriptions,
+        boundaries: scene.constraints.hard.clone(),
+        scene_direction: None,
+        character_drives: Vec::new(),
+        player_context: None,
+        narrative_layer: session.layers.frame(),
+        action_verdict: None,
+    }
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Entity weight accumulation rule shared by every Storykeeper backend.
+//!
+//! Keeping the rule in one place is what lets the conformance suite assert
+//! that in-memory and PostgreSQL sessions accumulate identical weights.
+
+use storyteller_core::traits::storykeeper::CompletedTurn;
+use storyteller_core::types::entity::EntityId;
+
+/// Weight added per resolved action outcome.
+const WEIGHT_PER_OUTCOME: f32 = 0.1;
+
+/// A single weight increment produced by committing a turn.
+#[derive(Debug, Clone)]
+pub(crate) struct WeightIncrement {
+    pub entity_id: EntityId,
+    pub increment: f32,
+    pub reason: String,
+}
+
+/// Compute the weight increments for a completed turn, in action order.
+///
+/// Each event participation adds 0.1 weight per action outcome. An entity
+/// that acts more than once in a turn yields one increment per action so
+/// the caller can report each change individually.
+pub(crate) fn turn_weight_increments(completed: &CompletedTurn) -> Vec<WeightIncrement> {
+    completed
+        .resolver_output
+        .sequenced_actions
+        .iter()
+        .map(|action| WeightIncrement {
+            entity_id: action.character_id,
+            increment: WEIGHT_PER_OUTCOME * action.outcomes.len() as f32,
+            reason: format!("{} action outcomes", action.outcomes.len()),
+        })
+        .collect()
+}
@@ -0,0 +1,505 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Storykeeper conformance suite — behavior every implementation must share.
+//!
+//! Each check takes a `&dyn Storykeeper` so the same assertions run against
+//! `InMemoryStorykeeper` (always) and `PostgresStorykeeper` (behind the
+//! `test-db` feature). Each check creates its own session, so checks may
+//! share a backend instance.
+
+use chrono::Utc;
+
+use storyteller_core::traits::storykeeper::{
+    CompletedTurn, SessionContext, SessionId, Storykeeper,
+};
+use storyteller_core::types::character::{
+    CastEntry, CharacterSheet, CharacterTensor, EmotionalState, SceneConstraints, SceneData,
+    SceneSetting, SelfEdge, SelfEdgeTrust, SelfKnowledge,
+};
+use storyteller_core::types::entity::{EntityId, EntityRef};
+use storyteller_core::types::event::{EventId, EventPriority, NarrativeEvent};
+use storyteller_core::types::event_grammar::EventPayload;
+use storyteller_core::types::message::PlayerInput;
+use storyteller_core::types::narrative_layer::{
+    EntitySharing, LayerKind, NarrativeLayer, NarrativeStack,
+};
+use storyteller_core::types::narrator_context::SceneJournal;
+use storyteller_core::types::prediction::{ActionPrediction, ActionType};
+use storyteller_core::types::resolver::{
+    ActionOutcome, ResolvedCharacterAction, ResolverOutput, SuccessDegree,
+};
+use storyteller_core::types::scene::{SceneId, SceneType};
+
+// ---------------------------------------------------------------------------
+// Fixtures
+// ---------------------------------------------------------------------------
+
+fn session() -> SessionContext {
+    SessionContext {
+        session_id: SessionId::new(),
+        story_id: uuid::Uuid::now_v7(),
+        player_entity_id: EntityId::new(),
+        current_scene_id: Some(SceneId::new()),
+        turn_number: 1,
+        layers: NarrativeStack::default(),
+    }
+}
+
+fn sheet(name: &str, voice: &str) -> CharacterSheet {
+    CharacterSheet {
+        entity_id: EntityId::new(),
+        name: name.to_string(),
+        voice: voice.to_string(),
+        backstory: String::new(),
+        tensor: CharacterTensor::new(),
+        grammar_id: "plutchik_western".to_string(),
+        emotional_state: EmotionalState {
+            grammar_id: "plutchik_western".to_string(),
+            primaries: vec![],
+            mood_vector_notes: vec![],
+        },
+        self_edge: SelfEdge {
+            trust: SelfEdgeTrust {
+                competence: 0.5,
+                intentions: 0.5,
+                reliability: 0.5,
+            },
+            affection: 0.5,
+            debt: 0.0,
+            history_pattern: String::new(),
+            history_weight: 0.0,
+            projection_content: String::new(),
+            projection_accuracy: 1.0,
+            self_knowledge: SelfKnowledge {
+                knows: vec![],
+                does_not_know: vec![],
+            },
+        },
+        triggers: vec![],
+        performance_notes: String::new(),
+        knows: vec![],
+        does_not_know: vec![],
+        capabilities: Default::default(),
+        relationships: Vec::new(),
+    }
+}
+
+/// Scene data for the session's current scene, cast with the given sheets.
+fn scene(session: &SessionContext, cast: &[(&CharacterSheet, &str)]) -> SceneData {
+    SceneData {
+        scene_id: session.current_scene_id.unwrap(),
+        title: "The Ford".to_string(),
+        scene_type: SceneType::Connective,
+        setting: SceneSetting {
+            description: "A shallow ford at dusk".to_string(),
+            affordances: vec![],
+            sensory_details: vec![],
+            aesthetic_detail: "Mist on the water".to_string(),
+        },
+        cast: cast
+            .iter()
+            .map(|(sheet, role)| CastEntry {
+                entity_id: sheet.entity_id,
+                name: sheet.name.clone(),
+                role: role.to_string(),
+            })
+            .collect(),
+        stakes: vec![],
+        constraints: SceneConstraints {
+            hard: vec!["The river cannot be crossed at night".to_string()],
+            soft: vec![],
+            perceptual: vec![],
+        },
+        emotional_arc: vec![],
+        evaluation_criteria: vec![],
+        event_dependencies: Default::default(),
+    }
+}
+
+/// A fresh session whose current scene is registered with an empty cast.
+async fn registered_session(sk: &dyn Storykeeper) -> SessionContext {
+    let session = session();
+    sk.register_scene(&scene(&session, &[]), &session)
+        .await
+        .unwrap();
+    session
+}
+
+fn event(marker: usize) -> NarrativeEvent {
+    NarrativeEvent {
+        id: EventId::new(),
+        timestamp: Utc::now(),
+        priority: EventPriority::Normal,
+        payload: EventPayload::Untyped(serde_json::json!({ "marker": marker })),
+    }
+}
+
+fn outcome(description: &str) -> ActionOutcome {
+    ActionOutcome {
+        action: ActionPrediction {
+            description: description.to_string(),
+            confidence: 0.9,
+            action_type: ActionType::Examine,
+            target: None,
+        },
+        success: SuccessDegree::FullSuccess,
+        consequences: vec![],
+        state_changes: vec![],
+    }
+}
+
+fn completed_turn(
+    turn_number: u32,
+    actor: EntityId,
+    outcomes: usize,
+    events: Vec<NarrativeEvent>,
+) -> CompletedTurn {
+    CompletedTurn {
+        turn_number,
+        player_input: PlayerInput {
+            text: format!("turn {turn_number}"),
+            turn_number,
+        },
+        resolver_output: ResolverOutput {
+            sequenced_actions: vec![ResolvedCharacterAction {
+                character_id: actor,
+                character_name: "Bramblehoof".to_string(),
+                outcomes: (0..outcomes).map(|_| outcome("Looks around")).collect(),
+            }],
+            original_predictions: vec![],
+            scene_dynamics: String::new(),
+            conflicts: vec![],
+            intent_statements: None,
+        },
+        events,
+    }
+}
+
+fn empty_resolver_output() -> ResolverOutput {
+    ResolverOutput {
+        sequenced_actions: vec![],
+        original_predictions: vec![],
+        scene_dynamics: String::new(),
+        conflicts: vec![],
+        intent_statements: None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Checks
+// ---------------------------------------------------------------------------
+
+pub async fn source_command_acknowledges_turn(sk: &dyn Storykeeper) {
+    let mut session = session();
+    session.turn_number = 3;
+    let input = PlayerInput {
+        text: "I open the gate.".to_string(),
+        turn_number: 3,
+    };
+
+    let before = Utc::now();
+    let sourced = sk.source_command(&input, &session).await.unwrap();
+    assert_eq!(sourced.turn_number, 3);
+    assert!(sourced.sourced_at >= before - chrono::Duration::seconds(5));
+}
+
+pub async fn commit_turn_reports_events_and_weights(sk: &dyn Storykeeper) {
+    let session = session();
+    let actor = EntityId::new();
+    let turn = completed_turn(1, actor, 2, vec![event(0), event(1)]);
+
+    let result = sk.commit_turn(&turn, &session).await.unwrap();
+    assert_eq!(result.events_committed.len(), 2);
+    assert_eq!(result.weight_changes.len(), 1);
+
+    let change = &result.weight_changes[0];
+    assert_eq!(change.entity_id, actor);
+    assert!(change.previous_weight.abs() < f32::EPSILON);
+    assert!((change.new_weight - 0.2).abs() < 1e-6);
+    assert_eq!(change.reason, "2 action outcomes");
+    assert!(result.gates_triggered.is_empty());
+}
+
+pub async fn weights_accumulate_across_turns(sk: &dyn Storykeeper) {
+    let mut session = session();
+    let actor = EntityId::new();
+
+    let first = sk
+        .commit_turn(&completed_turn(1, actor, 1, vec![]), &session)
+        .await
+        .unwrap();
+    session.turn_number = 2;
+    let second = sk
+        .commit_turn(&completed_turn(2, actor, 3, vec![]), &session)
+        .await
+        .unwrap();
+
+    let first_new = first.weight_changes[0].new_weight;
+    assert!(
+        (second.weight_changes[0].previous_weight - first_new).abs() < 1e-6,
+        "second commit should start from the first commit's weight"
+    );
+    assert!((second.weight_changes[0].new_weight - 0.4).abs() < 1e-6);
+}
+
+pub async fn entity_relevance_tracks_weight(sk: &dyn Storykeeper) {
+    let mut session = session();
+    let actor = EntityId::new();
+    let entity = EntityRef::Resolved(actor);
+
+    let before = sk.query_entity_relevance(&entity, &session).await.unwrap();
+    assert_eq!(before.entity_id, actor);
+    assert!(before.relevance.abs() < f32::EPSILON);
+
+    sk.commit_turn(&completed_turn(1, actor, 1, vec![]), &session)
+        .await
+        .unwrap();
+    let after = sk.query_entity_relevance(&entity, &session).await.unwrap();
+    assert!(after.relevance > 0.0);
+
+    // Relevance saturates at 1.0 no matter how much weight accumulates.
+    for turn in 2..=4 {
+        session.turn_number = turn;
+        sk.commit_turn(&completed_turn(turn, actor, 5, vec![]), &session)
+            .await
+            .unwrap();
+    }
+    let saturated = sk.query_entity_relevance(&entity, &session).await.unwrap();
+    assert!((saturated.relevance - 1.0).abs() < f32::EPSILON);
+}
+
+pub async fn narrator_context_surfaces_recent_events(sk: &dyn Storykeeper) {
+    let mut session = registered_session(sk).await;
+    let actor = EntityId::new();
+    let events: Vec<NarrativeEvent> = (0..7).map(event).collect();
+
+    session.turn_number = 1;
+    sk.commit_turn(&completed_turn(1, actor, 1, events[..4].to_vec()), &session)
+        .await
+        .unwrap();
+    session.turn_number = 2;
+    sk.commit_turn(&completed_turn(2, actor, 1, events[4..].to_vec()), &session)
+        .await
+        .unwrap();
+
+    let journal = SceneJournal::new(session.current_scene_id.unwrap(), 1200);
+    let context = sk
+        .assemble_narrator_context(&session, &journal, &empty_resolver_output(), "I wait.")
+        .await
+        .unwrap();
+
+    assert_eq!(context.player_input_summary, "I wait.");
+    assert_eq!(context.retrieved.len(), 5);
+    let expected: Vec<String> = events
+        .iter()
+        .rev()
+        .take(5)
+        .map(|e| format!("Event {}", e.id.0))
+        .collect();
+    let actual: Vec<String> = context
+        .retrieved
+        .iter()
+        .map(|r| r.subject.clone())
+        .collect();
+    assert_eq!(actual, expected, "retrieved context should be newest first");
+}
+
+pub async fn sessions_are_isolated(sk: &dyn Storykeeper) {
+    let a = session();
+    let b = registered_session(sk).await;
+    let actor = EntityId::new();
+
+    sk.commit_turn(&completed_turn(1, actor, 1, vec![event(0)]), &a)
+        .await
+        .unwrap();
+
+    let relevance = sk
+        .query_entity_relevance(&EntityRef::Resolved(actor), &b)
+        .await
+        .unwrap();
+    assert!(relevance.relevance.abs() < f32::EPSILON);
+
+    let journal = SceneJournal::new(b.current_scene_id.unwrap(), 1200);
+    let context = sk
+        .assemble_narrator_context(&b, &journal, &empty_resolver_output(), "")
+        .await
+        .unwrap();
+    assert!(context.retrieved.is_empty());
+}
+
+pub async fn enter_scene_loads_requested_scene(sk: &dyn Storykeeper) {
+    let session = session();
+    let scene_id = SceneId::new();
+
+    let loaded = sk.enter_scene(&scene_id, &[], &session).await.unwrap();
+    assert_eq!(loaded.scene_id, scene_id);
+    assert_eq!(loaded.journal.scene_id, scene_id);
+    assert_eq!(loaded.journal.turn_count(), 0);
+}
+
+pub async fn narrator_context_describes_registered_scene(sk: &dyn Storykeeper) {
+    let mut session = session();
+    let player = sheet("Ada", "Clipped, watchful");
+    let ferryman = sheet("Tam", "Slow, amused");
+    session.player_entity_id = player.entity_id;
+    let scene_id = session.current_scene_id.unwrap();
+    let data = scene(
+        &session,
+        &[(&player, "protagonist"), (&ferryman, "ferryman")],
+    );
+
+    sk.register_scene(&data, &session).await.unwrap();
+    let loaded = sk
+        .enter_scene(&scene_id, &[player.clone(), ferryman.clone()], &session)
+        .await
+        .unwrap();
+    assert_eq!(loaded.title, "The Ford");
+
+    let journal = SceneJournal::new(scene_id, 1200);
+    let preamble = sk
+        .assemble_narrator_context(&session, &journal, &empty_resolver_output(), "")
+        .await
+        .unwrap()
+        .preamble;
+    assert_eq!(
+        preamble.setting_description,
+        "A shallow ford at dusk\n\nMist on the water"
+    );
+    assert_eq!(preamble.boundaries, data.constraints.hard);
+    assert_eq!(preamble.cast_descriptions.len(), 2);
+    let ada = &preamble.cast_descriptions[0];
+    assert_eq!(
+        (ada.name.as_str(), ada.voice_note.as_str()),
+        ("Ada", "Clipped, watchful")
+    );
+    assert!(ada.is_player);
+    let tam = &preamble.cast_descriptions[1];
+    assert_eq!(
+        (tam.role.as_str(), tam.voice_note.as_str()),
+        ("ferryman", "Slow, amused")
+    );
+    assert!(!tam.is_player);
+}
+
+pub async fn narrator_context_requires_registered_scene(sk: &dyn Storykeeper) {
+    let mut session = session();
+    let journal = SceneJournal::new(session.current_scene_id.unwrap(), 1200);
+
+    let result = sk
+        .assemble_narrator_context(&session, &journal, &empty_resolver_output(), "")
+        .await;
+    assert!(result.is_err(), "unregistered scene should not assemble");
+
+    session.current_scene_id = None;
+    let result = sk
+        .assemble_narrator_context(&session, &journal, &empty_resolver_output(), "")
+        .await;
+    assert!(result.is_err(), "no current scene should not assemble");
+}
+
+pub async fn layers_nest_and_unwind(sk: &dyn Storykeeper) {
+    let mut session = session();
+    let scene_id = session.current_scene_id.unwrap();
+
+    let memory = NarrativeLayer::new(LayerKind::Memory, "The Winter Road", scene_id);
+    let memory_id = memory.id;
+    let entered = sk.enter_layer(memory, &mut session).await.unwrap();
+    assert_eq!(entered.layer_id, memory_id);
+    assert_eq!(entered.depth, 1);
+
+    let dream = NarrativeLayer::new(LayerKind::Dream, "Drowned Bells", scene_id);
+    let entered = sk.enter_layer(dream, &mut session).await.unwrap();
+    assert_eq!(entered.depth, 2);
+
+    let left = sk.exit_layer(&mut session).await.unwrap();
+    assert_eq!(left.layer.title, "Drowned Bells");
+    assert_eq!(left.depth, 1);
+    let left = sk.exit_layer(&mut session).await.unwrap();
+    assert_eq!(left.layer.id, memory_id);
+    assert_eq!(left.depth, 0);
+    assert!(session.layers.is_empty());
+
+    assert!(sk.exit_layer(&mut session).await.is_err());
+}
+
+pub async fn narrator_context_uses_current_layer(sk: &dyn Storykeeper) {
+    let mut session = registered_session(sk).await;
+    let scene_id = session.current_scene_id.unwrap();
+    let main = SceneJournal::new(scene_id, 1200);
+
+    let context = sk
+        .assemble_narrator_context(&session, &main, &empty_resolver_output(), "")
+        .await
+        .unwrap();
+    assert!(context.preamble.narrative_layer.is_none());
+
+    let remembered = SceneId::new();
+    let layer = NarrativeLayer::new(LayerKind::FrameStory, "The Tinker's Tale", remembered)
+        .with_teller("the tinker");
+    sk.enter_layer(layer, &mut session).await.unwrap();
+
+    let context = sk
+        .assemble_narrator_context(&session, &main, &empty_resolver_output(), "")
+        .await
+        .unwrap();
+    let frame = context.preamble.narrative_layer.expect("layer frame");
+    assert_eq!(frame.kind, LayerKind::FrameStory);
+    assert_eq!(frame.depth, 1);
+    assert_eq!(frame.teller.as_deref(), Some("the tinker"));
+    assert_eq!(context.journal.scene_id, remembered);
+}
+
+pub async fn layers_limit_entity_relevance(sk: &dyn Storykeeper) {
+    let mut session = session();
+    let scene_id = session.current_scene_id.unwrap();
+    let present = EntityId::new();
+    let absent = EntityId::new();
+    let younger = EntityId::new();
+
+    sk.commit_turn(&completed_turn(1, present, 1, vec![]), &session)
+        .await
+        .unwrap();
+    session.turn_number = 2;
+    sk.commit_turn(&completed_turn(2, absent, 1, vec![]), &session)
+        .await
+        .unwrap();
+
+    let layer = NarrativeLayer::new(LayerKind::Memory, "Before the Flood", scene_id)
+        .with_entity(younger, EntitySharing::Projected { source: present });
+    sk.enter_layer(layer, &mut session).await.unwrap();
+
+    let projected = sk
+        .query_entity_relevance(&EntityRef::Resolved(present), &session)
+        .await
+        .unwrap();
+    assert_eq!(projected.entity_id, younger);
+
+    let hidden = sk
+        .query_entity_relevance(&EntityRef::Resolved(absent), &session)
+        .await
+        .unwrap();
+    assert!(hidden.relevance.abs() < f32::EPSILON);
+
+    sk.exit_layer(&mut session).await.unwrap();
+    let restored = sk
+        .query_entity_relevance(&EntityRef::Resolved(absent), &session)
+        .await
+        .unwrap();
+    assert!(restored.relevance > 0.0);
+}
+
+pub async fn checkpoints_have_distinct_ids(sk: &dyn Storykeeper) {
+    let session = session();
+    sk.enter_scene(&session.current_scene_id.unwrap(), &[], &session)
+        .await
+        .unwrap();
+
+    let first = sk.write_checkpoint(&session).await.unwrap();
+    let second = sk.write_checkpoint(&session).await.unwrap();
+    assert_ne!(first, second);
+
+    let exit = sk.exit_scene(&session).await.unwrap();
+    assert_ne!(exit.checkpoint_id, second);
+}
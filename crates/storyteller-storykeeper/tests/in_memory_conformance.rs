@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (c) 2026 Tasker Systems. All rights reserved.
+// See LICENSING.md for details.
+
+//! Storykeeper conformance suite against `InMemoryStorykeeper`.
+
+mod conformance;
+
+use storyteller_storykeeper::InMemoryStorykeeper;
+
+#[tokio::test]
+async fn source_command_acknowledges_turn() {
+    conformance::source_command_acknowledges_turn(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn commit_turn_reports_events_and_weights() {
+    conformance::commit_turn_reports_events_and_weights(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn weights_accumulate_across_turns() {
+    conformance::weights_accumulate_across_turns(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn entity_relevance_tracks_weight() {
+    conformance::entity_relevance_tracks_weight(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn narrator_context_surfaces_recent_events() {
+    conformance::narrator_context_surfaces_recent_events(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn sessions_are_isolated() {
+    conformance::sessions_are_isolated(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn enter_scene_loads_requested_scene() {
+    conformance::enter_scene_loads_requested_scene(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn narrator_context_describes_registered_scene() {
+    conformance::narrator_context_describes_registered_scene(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn narrator_context_requires_registered_scene() {
+    conformance::narrator_context_requires_registered_scene(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn layers_nest_and_unwind() {
+    conformance::layers_nest_and_unwind(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn narrator_context_uses_current_layer() {
+    conformance::narrator_context_uses_current_layer(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn layers_limit_entity_relevance() {
+    conformance::layers_limit_entity_relevance(&InMemoryStorykeeper::new()).await;
+}
+
+#[tokio::test]
+async fn checkpoints_have_distinct_ids() {
+    conformance::checkpoints_have_distinct_ids(&InMemoryStorykeeper::new()).await;
+}
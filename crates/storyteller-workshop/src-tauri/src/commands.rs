@@ -321,6 +321,7 @@ pub async fn compose_scene(
             gender_presentation: pc.gender_presentation,
             intent: pc.intent,
         }),
+        setting_id: None,
     };
 
     // Acquire lock, start stream, then drop lock before consuming
@@ -54,7 +54,7 @@ pub fn run() {
 
             let connect_handle = handle.clone();
             tauri::async_runtime::spawn(async move {
-                let config = ClientConfig::from_env();
+                let config = ClientConfig::load();
                 info!(endpoint = %config.endpoint, "Connecting to storyteller server");
 
                 match StorytellerClient::connect(config).await {
@@ -74,11 +74,11 @@ pub fn run() {
                 tokio::time::sleep(std::time::Duration::from_secs(2)).await;
 
                 // Start log streaming on a separate client connection
-                let log_config = ClientConfig::from_env();
+                let log_config = ClientConfig::load();
                 match StorytellerClient::connect(log_config).await {
                     Ok(mut log_client) => {
                         info!("Log streaming client connected");
-                        match log_client.stream_logs(None, None).await {
+                        match log_client.stream_logs(None, None, None).await {
                             Ok(mut stream) => {
                                 // Emit a synthetic entry so the Logs tab confirms connectivity
                                 let _ = connect_handle.emit(